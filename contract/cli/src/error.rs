@@ -0,0 +1,99 @@
+use crowdfunding_client::ClientError;
+use crowdfunding_indexer::IndexerError;
+use thiserror::Error;
+
+use crate::RoleArg;
+
+#[derive(Debug, Error)]
+pub enum CliError {
+    #[error("missing required option `{0}`")]
+    MissingOption(&'static str),
+    #[error("`{0}` is not a valid address")]
+    InvalidAddress(String),
+    #[error("`{0}` is not a contract address")]
+    NotAContract(String),
+    #[error("`{0}` is not a 32-byte hex hash")]
+    InvalidHash(String),
+    #[error("fee must not be negative, got {0}")]
+    NegativeFee(i128),
+    #[error("rate must be at most 10000 basis points, got {0}")]
+    InvalidBps(u32),
+    #[error("expiry {0} is not in the future")]
+    ExpiryNotInFuture(u64),
+    #[error("minimum amount must not be negative, got {0}")]
+    NegativeMinAmount(i128),
+    #[error("amount must be positive, got {0}")]
+    NonPositiveAmount(i128),
+    #[error("this command needs the indexer state; pass `--state <db>`")]
+    StateRequired,
+    #[error("pool {0} does not exist in the indexed state")]
+    UnknownPool(u64),
+    #[error("`{0}` is not an accepted token in the indexed state")]
+    TokenNotAccepted(String),
+    #[error("`{0}` is the platform token or queued to become it")]
+    PlatformToken(String),
+    #[error("the indexed contract has not been initialized")]
+    NotInitialized,
+    #[error("the indexed contract is already initialized")]
+    AlreadyInitialized,
+    #[error("delay must be between {min} and {max} seconds, got {delay}")]
+    InvalidDelay { delay: u64, min: u64, max: u64 },
+    #[error("pause duration must be between {min} and {max} seconds, got {duration}")]
+    InvalidPauseDuration { duration: u64, min: u64, max: u64 },
+    #[error("the indexed contract is already paused")]
+    AlreadyPaused,
+    #[error("the indexed contract is not paused")]
+    NotPaused,
+    #[error("only {balance} of `{token}` has been collected in fees")]
+    InsufficientFees { token: String, balance: i128 },
+    #[error("an emergency withdrawal is already pending (unlocks at {unlock_time})")]
+    WithdrawalPending { unlock_time: u64 },
+    #[error("`{account}` is neither the admin nor a holder of the {role:?} role")]
+    MissingRole { account: String, role: RoleArg },
+    #[error("`{account}` already holds the {role:?} role")]
+    RoleAlreadyGranted { account: String, role: RoleArg },
+    #[error("`{account}` does not hold the {role:?} role")]
+    RoleNotGranted { account: String, role: RoleArg },
+    #[error("`{0}` has no fee tier")]
+    NoFeeTier(String),
+    #[error("no admin transfer is pending")]
+    NoPendingAdmin,
+    #[error("`{0}` is not the proposed admin")]
+    NotProposedAdmin(String),
+    #[error("no emergency withdrawal is pending")]
+    NoPendingWithdrawal,
+    #[error("the emergency withdrawal unlocks at {unlock_time}, {remaining}s from now")]
+    WithdrawalLocked { unlock_time: u64, remaining: u64 },
+    #[error("an upgrade is already pending (executable at {executable_at})")]
+    UpgradePending { executable_at: u64 },
+    #[error("no upgrade is pending")]
+    NoPendingUpgrade,
+    #[error("the upgrade becomes executable at {executable_at}, {remaining}s from now")]
+    UpgradeLocked { executable_at: u64, remaining: u64 },
+    #[error("a council needs between 1 and {signers} required signatures, got {required}")]
+    InvalidThreshold { required: u32, signers: usize },
+    #[error("a council is set; propose this through `council propose` instead")]
+    CouncilActive,
+    #[error("no council is set")]
+    NoCouncil,
+    #[error("`{0}` is not a council member")]
+    NotCouncilMember(String),
+    #[error("council proposal {0} does not exist")]
+    UnknownProposal(u64),
+    #[error("council proposal {0} was already executed")]
+    ProposalExecuted(u64),
+    #[error("council proposal {proposal_id} expired at {expires_at}")]
+    ProposalExpired { proposal_id: u64, expires_at: u64 },
+    #[error("`{signer}` already approved council proposal {proposal_id}")]
+    AlreadyApproved { proposal_id: u64, signer: String },
+    #[error("council proposal {proposal_id} has {approvals} of {required} required approvals")]
+    InsufficientApprovals {
+        proposal_id: u64,
+        approvals: usize,
+        required: u32,
+    },
+    #[error(transparent)]
+    Client(#[from] ClientError),
+    #[error(transparent)]
+    State(#[from] IndexerError),
+}
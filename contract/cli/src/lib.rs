@@ -0,0 +1,1430 @@
+//! Operator CLI for the admin flows of `CrowdfundingContract`.
+//!
+//! Every command that changes the contract prints an unsigned transaction
+//! envelope (base64 XDR) built with [`crowdfunding_client`], ready to be
+//! simulated, signed offline and submitted. Passing `--state` with a SQLite
+//! database written by the indexer enables checks against the indexed
+//! contract state, such as refusing to execute an emergency withdrawal
+//! before it unlocks.
+pub mod error;
+
+use std::{path::PathBuf, str::FromStr};
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use crowdfunding_client::{
+    types, xdr::ScAddress, Call, CampaignAmendmentRules, CouncilAction, CrowdfundingClient,
+    FeeEntity, FeeTier, FromScVal, MultiSigConfig, Role, TransactionBuilder,
+};
+use crowdfunding_indexer::{
+    sqlite::SqliteStore,
+    state::{CouncilProposal, Platform},
+    FeeEntity as IndexedFeeEntity, IndexerError, Role as IndexedRole, State,
+};
+
+pub use error::CliError;
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "crowdfunding-admin",
+    about = "Administer a crowdfunding contract deployment"
+)]
+pub struct Cli {
+    #[command(flatten)]
+    pub tx: TxOptions,
+
+    /// SQLite database written by the indexer; enables state checks.
+    #[arg(long, global = true)]
+    pub state: Option<PathBuf>,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+/// Options for the emitted transaction envelope.
+#[derive(Debug, Args)]
+pub struct TxOptions {
+    /// Contract address (`C...`).
+    #[arg(long, global = true)]
+    pub contract: Option<String>,
+
+    /// Source account of the transaction (`G...`). Role-gated commands pass
+    /// it as the caller, so it must be the admin or hold the required role.
+    #[arg(long, global = true)]
+    pub source: Option<String>,
+
+    /// Sequence number the transaction uses (current account sequence + 1).
+    #[arg(long, global = true)]
+    pub sequence: Option<i64>,
+
+    /// Inclusion fee in stroops, before simulation adds the resource fee.
+    #[arg(long, global = true, default_value_t = TransactionBuilder::DEFAULT_FEE)]
+    pub base_fee: u32,
+
+    /// Seconds from now after which the transaction is no longer valid.
+    #[arg(long, global = true, default_value_t = 300)]
+    pub valid_for: u64,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Set the admin, platform token and creation fee.
+    Initialize {
+        #[arg(long)]
+        admin: String,
+        #[arg(long)]
+        token: String,
+        #[arg(long, allow_negative_numbers = true)]
+        creation_fee: i128,
+    },
+    /// Pause the contract.
+    Pause,
+    /// Unpause the contract.
+    Unpause,
+    /// Pause individual features, leaving the rest of the contract running.
+    PauseFeatures {
+        #[arg(long = "feature", value_enum, required = true)]
+        features: Vec<FeatureArg>,
+        /// Code recorded with the pause to explain it.
+        #[arg(long, default_value_t = 0)]
+        reason: u32,
+        /// Seconds until the pause lapses on its own, at most the maximum
+        /// pause duration.
+        #[arg(long)]
+        duration: u64,
+    },
+    /// Lift pauses placed with `pause-features`.
+    UnpauseFeatures {
+        #[arg(long = "feature", value_enum, required = true)]
+        features: Vec<FeatureArg>,
+    },
+    /// Change how long a pause may last before contributors can withdraw
+    /// their funds through the escape hatch.
+    SetMaxPauseDuration {
+        /// Duration in seconds.
+        #[arg(long)]
+        duration: u64,
+    },
+    /// Change how far creators may extend campaign deadlines and which goal
+    /// changes let earlier donors claim a refund.
+    SetCampaignAmendmentRules {
+        /// Total seconds a campaign deadline may be pushed back.
+        #[arg(long)]
+        max_extension: u64,
+        /// Allow only one extension per campaign, as the contract does by
+        /// default.
+        #[arg(long, default_value_t = true, action = clap::ArgAction::Set)]
+        single_extension: bool,
+        /// Goal change, in basis points of the old goal, that opens refunds.
+        #[arg(long)]
+        material_goal_change_bps: u32,
+    },
+    /// Queue a change to the fee charged for creating campaigns; pool fees
+    /// are set with `set-entity-creation-fee`.
+    SetCreationFee {
+        #[arg(long, allow_negative_numbers = true)]
+        fee: i128,
+    },
+    /// Queue a change to the fee charged for creating one kind of campaign
+    /// or pool; `campaign` is the same as `set-creation-fee`.
+    SetEntityCreationFee {
+        #[arg(long, value_enum)]
+        entity: FeeEntityArg,
+        #[arg(long, allow_negative_numbers = true)]
+        fee: i128,
+    },
+    /// Queue a change to the platform fee taken from every donation and
+    /// contribution.
+    SetPlatformFee {
+        /// Rate in basis points of the amount contributed.
+        #[arg(long)]
+        bps: u32,
+    },
+    /// Override the platform fee of one pool; takes effect immediately.
+    SetPoolPlatformFee {
+        #[arg(long)]
+        pool: u64,
+        /// Rate in basis points; omit to return the pool to the platform rate.
+        #[arg(long)]
+        bps: Option<u32>,
+    },
+    /// Waive or reduce the fees of a creator, replacing any tier they have.
+    AssignFeeTier {
+        #[arg(long)]
+        creator: String,
+        #[command(flatten)]
+        tier: FeeTierArgs,
+        /// Ledger timestamp at which the tier lapses; 0 never lapses.
+        #[arg(long, default_value_t = 0)]
+        expires_at: u64,
+    },
+    /// Return a creator to the standard fees.
+    RevokeFeeTier {
+        #[arg(long)]
+        creator: String,
+    },
+    /// Queue a change to the platform token, which must be an accepted token.
+    SetCrowdfundingToken {
+        #[arg(long)]
+        token: String,
+    },
+    /// Accept a token for campaigns and pools, or change its minimum amount.
+    SetAcceptedToken {
+        #[arg(long)]
+        token: String,
+        /// Smallest donation or contribution accepted.
+        #[arg(long, default_value_t = 0, allow_negative_numbers = true)]
+        min_amount: i128,
+    },
+    /// Stop accepting a token. The platform token cannot be removed.
+    RemoveAcceptedToken {
+        #[arg(long)]
+        token: String,
+    },
+    /// List the accepted tokens.
+    AcceptedTokens,
+    /// Show the creation and platform fees and platform token, and any
+    /// queued change to them. Fee and token changes take effect a day after they are queued.
+    Parameters,
+    /// Propose a new admin; the transfer completes once they accept.
+    ProposeAdmin {
+        #[arg(long)]
+        new_admin: String,
+    },
+    /// Accept a pending admin transfer; `--source` must be the proposed admin.
+    AcceptAdmin,
+    /// Withdraw a pending admin proposal.
+    CancelAdminProposal,
+    /// Grant an operational role to an account.
+    GrantRole {
+        #[arg(long, value_enum)]
+        role: RoleArg,
+        #[arg(long)]
+        account: String,
+    },
+    /// Revoke an operational role from an account.
+    RevokeRole {
+        #[arg(long, value_enum)]
+        role: RoleArg,
+        #[arg(long)]
+        account: String,
+    },
+    /// Withdraw collected creation and platform fees.
+    WithdrawFees {
+        #[arg(long)]
+        token: String,
+        #[arg(long, allow_negative_numbers = true)]
+        amount: i128,
+        /// Account receiving the fees.
+        #[arg(long)]
+        to: String,
+    },
+    /// Two-step emergency withdrawal of contract funds.
+    #[command(subcommand)]
+    EmergencyWithdraw(EmergencyCommand),
+    /// Timelocked upgrade of the contract code.
+    #[command(subcommand)]
+    Upgrade(UpgradeCommand),
+    /// M-of-N council that takes over the privileged setters once set.
+    #[command(subcommand)]
+    Council(CouncilCommand),
+    /// Rewrite up to `--batch-size` stored records into the current layout;
+    /// repeat until the contract reports completion.
+    Migrate {
+        #[arg(long)]
+        batch_size: u32,
+    },
+    /// Rewrite a pool's contribution records during a migration, listing
+    /// every contributor the indexer has seen for the pool.
+    MigratePoolContributions {
+        #[arg(long)]
+        pool: u64,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum EmergencyCommand {
+    /// Request a withdrawal; it can be executed once its unlock time passes.
+    /// Escrowed donations and contributions cannot be withdrawn.
+    Request {
+        #[arg(long)]
+        token: String,
+        #[arg(long, allow_negative_numbers = true)]
+        amount: i128,
+        /// Account receiving the funds; defaults to `--source`.
+        #[arg(long)]
+        recipient: Option<String>,
+    },
+    /// Execute the pending withdrawal.
+    Execute,
+    /// Withdraw the pending request.
+    Cancel,
+    /// Change the delay applied to later requests.
+    SetDelay {
+        /// Delay in seconds.
+        #[arg(long)]
+        delay: u64,
+    },
+    /// Show the pending withdrawal and its unlock time.
+    Status,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum UpgradeCommand {
+    /// Propose new code; it can be installed once the timelock passes.
+    Propose {
+        /// Hash of the uploaded wasm, as 64 hex characters.
+        #[arg(long)]
+        wasm_hash: String,
+    },
+    /// Install the pending upgrade.
+    Execute,
+    /// Withdraw the pending upgrade.
+    Cancel,
+    /// Change the delay applied to later proposals.
+    SetTimelock {
+        /// Delay in seconds.
+        #[arg(long)]
+        delay: u64,
+    },
+    /// Show the pending upgrade and when it becomes executable.
+    Status,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum CouncilCommand {
+    /// Hand the privileged setters to a council; the admin can do this once.
+    Set(CouncilArgs),
+    /// Propose an action; `--source` must be a member and approves it.
+    #[command(subcommand)]
+    Propose(CouncilActionCommand),
+    /// Approve a proposal; `--source` must be a member.
+    Approve {
+        #[arg(long)]
+        proposal: u64,
+    },
+    /// Carry out a proposal once enough current members approved it.
+    Execute {
+        #[arg(long)]
+        proposal: u64,
+    },
+    /// Show the council and its open proposals.
+    Status,
+}
+
+#[derive(Debug, Args)]
+pub struct CouncilArgs {
+    #[arg(long)]
+    pub required_signatures: u32,
+    #[arg(long = "signer", required = true)]
+    pub signers: Vec<String>,
+}
+
+/// The privileged action a council proposal carries out.
+#[derive(Debug, Subcommand)]
+pub enum CouncilActionCommand {
+    SetCreationFee {
+        #[arg(long, allow_negative_numbers = true)]
+        fee: i128,
+    },
+    SetCrowdfundingToken {
+        #[arg(long)]
+        token: String,
+    },
+    RequestEmergencyWithdraw {
+        #[arg(long)]
+        token: String,
+        #[arg(long, allow_negative_numbers = true)]
+        amount: i128,
+        #[arg(long)]
+        recipient: String,
+    },
+    ExecuteEmergencyWithdraw,
+    CancelEmergencyWithdraw,
+    ProposeUpgrade {
+        /// Hash of the uploaded wasm, as 64 hex characters.
+        #[arg(long)]
+        wasm_hash: String,
+    },
+    ExecuteUpgrade,
+    CancelUpgrade,
+    SetEntityCreationFee {
+        #[arg(long, value_enum)]
+        entity: FeeEntityArg,
+        #[arg(long, allow_negative_numbers = true)]
+        fee: i128,
+    },
+    SetPlatformFee {
+        #[arg(long)]
+        bps: u32,
+    },
+    SetPoolPlatformFee {
+        #[arg(long)]
+        pool: u64,
+        #[arg(long)]
+        bps: Option<u32>,
+    },
+    SetAcceptedToken {
+        #[arg(long)]
+        token: String,
+        #[arg(long, default_value_t = 0, allow_negative_numbers = true)]
+        min_amount: i128,
+    },
+    RemoveAcceptedToken {
+        #[arg(long)]
+        token: String,
+    },
+    WithdrawFees {
+        #[arg(long)]
+        token: String,
+        #[arg(long, allow_negative_numbers = true)]
+        amount: i128,
+        /// Account receiving the fees.
+        #[arg(long)]
+        to: String,
+    },
+    AssignFeeTier {
+        #[arg(long)]
+        creator: String,
+        #[command(flatten)]
+        tier: FeeTierArgs,
+        /// Ledger timestamp at which the tier lapses; 0 never lapses.
+        #[arg(long, default_value_t = 0)]
+        expires_at: u64,
+    },
+    SetEmergencyWithdrawDelay {
+        /// Delay in seconds.
+        #[arg(long)]
+        delay: u64,
+    },
+    SetUpgradeTimelock {
+        /// Delay in seconds.
+        #[arg(long)]
+        delay: u64,
+    },
+    /// Replace the council with a new one.
+    SetCouncil(CouncilArgs),
+    /// Remove the council, returning the setters to the admin and roles.
+    DisableCouncil,
+}
+
+impl CouncilArgs {
+    fn config(&self) -> Result<MultiSigConfig, CliError> {
+        let signers = self
+            .signers
+            .iter()
+            .map(|signer| parse_address(signer))
+            .collect::<Result<Vec<_>, _>>()?;
+        let required = self.required_signatures;
+        if required == 0 || required as usize > signers.len() {
+            return Err(CliError::InvalidThreshold {
+                required,
+                signers: signers.len(),
+            });
+        }
+        Ok(MultiSigConfig {
+            required_signatures: required,
+            signers,
+        })
+    }
+}
+
+impl CouncilActionCommand {
+    fn action(&self) -> Result<CouncilAction, CliError> {
+        Ok(match self {
+            Self::SetCreationFee { fee } => {
+                if *fee < 0 {
+                    return Err(CliError::NegativeFee(*fee));
+                }
+                CouncilAction::SetCreationFee(*fee)
+            }
+            Self::SetCrowdfundingToken { token } => {
+                CouncilAction::SetCrowdfundingToken(parse_contract(token)?)
+            }
+            Self::RequestEmergencyWithdraw {
+                token,
+                amount,
+                recipient,
+            } => {
+                if *amount <= 0 {
+                    return Err(CliError::NonPositiveAmount(*amount));
+                }
+                CouncilAction::RequestEmergencyWithdraw(
+                    parse_contract(token)?,
+                    *amount,
+                    parse_address(recipient)?,
+                )
+            }
+            Self::ExecuteEmergencyWithdraw => CouncilAction::ExecuteEmergencyWithdraw,
+            Self::CancelEmergencyWithdraw => CouncilAction::CancelEmergencyWithdraw,
+            Self::ProposeUpgrade { wasm_hash } => {
+                CouncilAction::ProposeUpgrade(parse_hash(wasm_hash)?)
+            }
+            Self::ExecuteUpgrade => CouncilAction::ExecuteUpgrade,
+            Self::CancelUpgrade => CouncilAction::CancelUpgrade,
+            Self::SetEntityCreationFee { entity, fee } => {
+                if *fee < 0 {
+                    return Err(CliError::NegativeFee(*fee));
+                }
+                CouncilAction::SetEntityCreationFee(entity.entity(), *fee)
+            }
+            Self::SetPlatformFee { bps } => CouncilAction::SetPlatformFee(check_bps(*bps)?),
+            Self::SetPoolPlatformFee { pool, bps } => {
+                CouncilAction::SetPoolPlatformFee(*pool, bps.map(check_bps).transpose()?)
+            }
+            Self::SetAcceptedToken { token, min_amount } => CouncilAction::SetAcceptedToken(
+                parse_contract(token)?,
+                check_min_amount(*min_amount)?,
+            ),
+            Self::RemoveAcceptedToken { token } => {
+                CouncilAction::RemoveAcceptedToken(parse_contract(token)?)
+            }
+            Self::WithdrawFees { token, amount, to } => {
+                if *amount <= 0 {
+                    return Err(CliError::NonPositiveAmount(*amount));
+                }
+                CouncilAction::WithdrawFees(parse_contract(token)?, *amount, parse_address(to)?)
+            }
+            Self::AssignFeeTier {
+                creator,
+                tier,
+                expires_at,
+            } => CouncilAction::AssignFeeTier(parse_address(creator)?, tier.tier()?, *expires_at),
+            Self::SetEmergencyWithdrawDelay { delay } => {
+                CouncilAction::SetEmergencyWithdrawDelay(check_delay(
+                    *delay,
+                    types::MIN_EMERGENCY_WITHDRAW_DELAY,
+                    types::MAX_EMERGENCY_WITHDRAW_DELAY,
+                )?)
+            }
+            Self::SetUpgradeTimelock { delay } => CouncilAction::SetUpgradeTimelock(check_delay(
+                *delay,
+                types::MIN_UPGRADE_TIMELOCK,
+                types::MAX_UPGRADE_TIMELOCK,
+            )?),
+            Self::SetCouncil(args) => CouncilAction::SetCouncil(args.config()?),
+            Self::DisableCouncil => CouncilAction::DisableCouncil,
+        })
+    }
+}
+
+/// Fee tier of `assign-fee-tier`; exactly one option is given.
+#[derive(Debug, Args)]
+#[group(required = true, multiple = false)]
+pub struct FeeTierArgs {
+    /// No creation fee, and no platform fee on funds raised.
+    #[arg(long)]
+    pub waived: bool,
+    /// Basis points taken off creation fees and the platform fee rate.
+    #[arg(long)]
+    pub discount_bps: Option<u32>,
+    /// Creation fee charged for every campaign and pool in place of the fee
+    /// schedule; the platform fee rate is unchanged.
+    #[arg(long, allow_negative_numbers = true)]
+    pub flat_fee: Option<i128>,
+}
+
+impl FeeTierArgs {
+    fn tier(&self) -> Result<FeeTier, CliError> {
+        match (self.discount_bps, self.flat_fee) {
+            (Some(bps), _) => Ok(FeeTier::Discount(check_bps(bps)?)),
+            (_, Some(fee)) if fee < 0 => Err(CliError::NegativeFee(fee)),
+            (_, Some(fee)) => Ok(FeeTier::FlatFee(fee)),
+            (None, None) => Ok(FeeTier::Waived),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub enum RoleArg {
+    Pauser,
+    FeeManager,
+    Treasury,
+    PoolModerator,
+    Verifier,
+}
+
+impl RoleArg {
+    fn role(self) -> Role {
+        match self {
+            Self::Pauser => Role::Pauser,
+            Self::FeeManager => Role::FeeManager,
+            Self::Treasury => Role::Treasury,
+            Self::PoolModerator => Role::PoolModerator,
+            Self::Verifier => Role::Verifier,
+        }
+    }
+
+    fn indexed(self) -> IndexedRole {
+        match self {
+            Self::Pauser => IndexedRole::Pauser,
+            Self::FeeManager => IndexedRole::FeeManager,
+            Self::Treasury => IndexedRole::Treasury,
+            Self::PoolModerator => IndexedRole::PoolModerator,
+            Self::Verifier => IndexedRole::Verifier,
+        }
+    }
+}
+
+/// A kind of campaign or pool with its own creation fee.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub enum FeeEntityArg {
+    Campaign,
+    PublicPool,
+    PrivatePool,
+    MultisigPool,
+}
+
+impl FeeEntityArg {
+    const POOLS: [FeeEntityArg; 3] = [Self::PublicPool, Self::PrivatePool, Self::MultisigPool];
+
+    fn entity(self) -> FeeEntity {
+        match self {
+            Self::Campaign => FeeEntity::Campaign,
+            Self::PublicPool => FeeEntity::PublicPool,
+            Self::PrivatePool => FeeEntity::PrivatePool,
+            Self::MultisigPool => FeeEntity::MultiSigPool,
+        }
+    }
+
+    fn indexed(self) -> IndexedFeeEntity {
+        match self {
+            Self::Campaign => IndexedFeeEntity::Campaign,
+            Self::PublicPool => IndexedFeeEntity::PublicPool,
+            Self::PrivatePool => IndexedFeeEntity::PrivatePool,
+            Self::MultisigPool => IndexedFeeEntity::MultiSigPool,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Campaign => "campaign",
+            Self::PublicPool => "public pool",
+            Self::PrivatePool => "private pool",
+            Self::MultisigPool => "multi-sig pool",
+        }
+    }
+}
+
+/// A feature that can be paused on its own.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub enum FeatureArg {
+    CampaignCreation,
+    Donations,
+    PoolCreation,
+    Contributions,
+    Refunds,
+    PoolStateUpdates,
+}
+
+impl FeatureArg {
+    fn bit(self) -> u32 {
+        match self {
+            Self::CampaignCreation => types::PAUSE_CAMPAIGN_CREATION,
+            Self::Donations => types::PAUSE_DONATIONS,
+            Self::PoolCreation => types::PAUSE_POOL_CREATION,
+            Self::Contributions => types::PAUSE_CONTRIBUTIONS,
+            Self::Refunds => types::PAUSE_REFUNDS,
+            Self::PoolStateUpdates => types::PAUSE_POOL_STATE_UPDATES,
+        }
+    }
+
+    fn mask(features: &[Self]) -> u32 {
+        features
+            .iter()
+            .fold(0, |mask, feature| mask | feature.bit())
+    }
+}
+
+/// Run `cli` at unix time `now`, returning the text to print.
+pub fn run(cli: &Cli, now: u64) -> Result<String, CliError> {
+    let state = cli.state.as_ref().map(load_state).transpose()?;
+    let platform = state.as_ref().map(|state| state.platform.as_ref());
+
+    match &cli.command {
+        Command::Initialize {
+            admin,
+            token,
+            creation_fee,
+        } => {
+            if *creation_fee < 0 {
+                return Err(CliError::NegativeFee(*creation_fee));
+            }
+            let admin = parse_address(admin)?;
+            let token = parse_contract(token)?;
+            if let Some(Some(_)) = platform {
+                return Err(CliError::AlreadyInitialized);
+            }
+            envelope(cli, now, |client| {
+                client.initialize(admin, token, *creation_fee)
+            })
+        }
+        Command::Pause => {
+            let caller = authorize(cli, state.as_ref(), RoleArg::Pauser)?;
+            if let Some(platform) = platform {
+                if initialized(platform)?.paused {
+                    return Err(CliError::AlreadyPaused);
+                }
+            }
+            envelope(cli, now, |client| client.pause(caller))
+        }
+        Command::Unpause => {
+            let caller = authorize(cli, state.as_ref(), RoleArg::Pauser)?;
+            if let Some(platform) = platform {
+                if !initialized(platform)?.paused {
+                    return Err(CliError::NotPaused);
+                }
+            }
+            envelope(cli, now, |client| client.unpause(caller))
+        }
+        Command::PauseFeatures {
+            features,
+            reason,
+            duration,
+        } => {
+            let caller = authorize(cli, state.as_ref(), RoleArg::Pauser)?;
+            let features = FeatureArg::mask(features);
+            let max = platform
+                .flatten()
+                .and_then(|platform| platform.max_pause_duration)
+                .unwrap_or(types::DEFAULT_MAX_PAUSE_DURATION);
+            check_pause_duration(*duration, 1, max)?;
+            envelope(cli, now, |client| {
+                client.pause_features(caller, features, *reason, *duration)
+            })
+        }
+        Command::UnpauseFeatures { features } => {
+            let caller = authorize(cli, state.as_ref(), RoleArg::Pauser)?;
+            let features = FeatureArg::mask(features);
+            if let Some(state) = &state {
+                let paused = state
+                    .feature_pauses
+                    .iter()
+                    .any(|(feature, pause)| features & feature != 0 && now < pause.expires_at);
+                if !paused {
+                    return Err(CliError::NotPaused);
+                }
+            }
+            envelope(cli, now, |client| client.unpause_features(caller, features))
+        }
+        Command::SetMaxPauseDuration { duration } => {
+            check_pause_duration(
+                *duration,
+                types::MIN_MAX_PAUSE_DURATION,
+                types::MAX_MAX_PAUSE_DURATION,
+            )?;
+            if let Some(platform) = platform {
+                initialized(platform)?;
+            }
+            envelope(cli, now, |client| client.set_max_pause_duration(*duration))
+        }
+        Command::SetCampaignAmendmentRules {
+            max_extension,
+            single_extension,
+            material_goal_change_bps,
+        } => {
+            let rules = CampaignAmendmentRules {
+                max_extension: *max_extension,
+                single_extension: *single_extension,
+                material_goal_change_bps: check_bps(*material_goal_change_bps)?,
+            };
+            if let Some(platform) = platform {
+                initialized(platform)?;
+            }
+            envelope(cli, now, |client| {
+                client.set_campaign_amendment_rules(rules)
+            })
+        }
+        Command::SetCreationFee { fee } => {
+            if *fee < 0 {
+                return Err(CliError::NegativeFee(*fee));
+            }
+            let caller = authorize(cli, state.as_ref(), RoleArg::FeeManager)?;
+            no_council(platform.flatten())?;
+            envelope(cli, now, |client| client.set_creation_fee(caller, *fee))
+        }
+        Command::SetEntityCreationFee { entity, fee } => {
+            if *fee < 0 {
+                return Err(CliError::NegativeFee(*fee));
+            }
+            let caller = authorize(cli, state.as_ref(), RoleArg::FeeManager)?;
+            no_council(platform.flatten())?;
+            envelope(cli, now, |client| {
+                client.set_entity_creation_fee(caller, entity.entity(), *fee)
+            })
+        }
+        Command::SetPlatformFee { bps } => {
+            let bps = check_bps(*bps)?;
+            let caller = authorize(cli, state.as_ref(), RoleArg::FeeManager)?;
+            no_council(platform.flatten())?;
+            envelope(cli, now, |client| client.set_platform_fee(caller, bps))
+        }
+        Command::SetPoolPlatformFee { pool, bps } => {
+            let bps = bps.map(check_bps).transpose()?;
+            no_council(platform.flatten())?;
+            if let Some(state) = &state {
+                initialized(state.platform.as_ref())?;
+                if !state.pools.contains_key(pool) {
+                    return Err(CliError::UnknownPool(*pool));
+                }
+            }
+            envelope(cli, now, |client| client.set_pool_platform_fee(*pool, bps))
+        }
+        Command::AssignFeeTier {
+            creator,
+            tier,
+            expires_at,
+        } => {
+            let creator = parse_address(creator)?;
+            let tier = tier.tier()?;
+            if *expires_at != 0 && *expires_at <= now {
+                return Err(CliError::ExpiryNotInFuture(*expires_at));
+            }
+            let caller = authorize(cli, state.as_ref(), RoleArg::Verifier)?;
+            no_council(platform.flatten())?;
+            envelope(cli, now, |client| {
+                client.assign_fee_tier(caller, creator, tier, *expires_at)
+            })
+        }
+        Command::RevokeFeeTier { creator } => {
+            let creator = parse_address(creator)?;
+            let caller = authorize(cli, state.as_ref(), RoleArg::Verifier)?;
+            if let Some(state) = &state {
+                if !state.fee_tiers.contains_key(&creator.to_string()) {
+                    return Err(CliError::NoFeeTier(creator.to_string()));
+                }
+            }
+            envelope(cli, now, |client| client.revoke_fee_tier(caller, creator))
+        }
+        Command::SetCrowdfundingToken { token } => {
+            let token = parse_contract(token)?;
+            let caller = authorize(cli, state.as_ref(), RoleArg::FeeManager)?;
+            no_council(platform.flatten())?;
+            if let Some(state) = &state {
+                if !state.accepted_tokens.contains_key(&token.to_string()) {
+                    return Err(CliError::TokenNotAccepted(token.to_string()));
+                }
+            }
+            envelope(cli, now, |client| {
+                client.set_crowdfunding_token(caller, token)
+            })
+        }
+        Command::SetAcceptedToken { token, min_amount } => {
+            let token = parse_contract(token)?;
+            let min_amount = check_min_amount(*min_amount)?;
+            let caller = authorize(cli, state.as_ref(), RoleArg::FeeManager)?;
+            no_council(platform.flatten())?;
+            envelope(cli, now, |client| {
+                client.set_accepted_token(caller, token, min_amount)
+            })
+        }
+        Command::RemoveAcceptedToken { token } => {
+            let token = parse_contract(token)?;
+            let caller = authorize(cli, state.as_ref(), RoleArg::FeeManager)?;
+            no_council(platform.flatten())?;
+            if let Some(state) = &state {
+                let token = token.to_string();
+                if !state.accepted_tokens.contains_key(&token) {
+                    return Err(CliError::TokenNotAccepted(token));
+                }
+                let platform = initialized(state.platform.as_ref())?;
+                let queued = platform
+                    .pending_parameters
+                    .as_ref()
+                    .and_then(|pending| pending.token.as_ref());
+                if platform.token == token || queued == Some(&token) {
+                    return Err(CliError::PlatformToken(token));
+                }
+            }
+            envelope(cli, now, |client| {
+                client.remove_accepted_token(caller, token)
+            })
+        }
+        Command::AcceptedTokens => {
+            let state = state.as_ref().ok_or(CliError::StateRequired)?;
+            initialized(state.platform.as_ref())?;
+            let lines: Vec<String> = state
+                .accepted_tokens
+                .iter()
+                .map(|(token, accepted)| {
+                    format!(
+                        "{token} {} ({} decimals), minimum {}",
+                        accepted.symbol, accepted.decimals, accepted.min_amount
+                    )
+                })
+                .collect();
+            Ok(lines.join("\n"))
+        }
+        Command::Parameters => {
+            let state = state.as_ref().ok_or(CliError::StateRequired)?;
+            let platform = initialized(state.platform.as_ref())?;
+            let mut output = format!(
+                "creation fee: {}\ntoken:        {}",
+                platform.creation_fee, platform.token
+            );
+            if let Some(pending) = &platform.pending_parameters {
+                let effective = if now >= pending.effective_at {
+                    "in effect".to_string()
+                } else {
+                    format!("in {}s", pending.effective_at - now)
+                };
+                output.push_str(&format!(
+                    "\nqueued change by {}\n  effective at: {} ({effective})",
+                    pending.queued_by, pending.effective_at,
+                ));
+                if let Some(fee) = pending.creation_fee {
+                    output.push_str(&format!("\n  creation fee: {fee}"));
+                }
+                if let Some(token) = &pending.token {
+                    output.push_str(&format!("\n  token:        {token}"));
+                }
+            }
+            if !state.entity_creation_fees.is_empty() || !state.pending_entity_fees.is_empty() {
+                output.push_str("\npool creation fees");
+                for entity in FeeEntityArg::POOLS {
+                    let indexed = entity.indexed();
+                    let fee = state
+                        .entity_creation_fees
+                        .get(&indexed)
+                        .copied()
+                        .unwrap_or_default();
+                    output.push_str(&format!("\n  {:<15} {fee}", format!("{}:", entity.label())));
+                    if let Some(pending) = state.pending_entity_fees.get(&indexed) {
+                        let effective = if now >= pending.effective_at {
+                            "in effect".to_string()
+                        } else {
+                            format!("in {}s", pending.effective_at - now)
+                        };
+                        output.push_str(&format!(
+                            " (queued {} by {}, effective at {} ({effective}))",
+                            pending.fee, pending.queued_by, pending.effective_at,
+                        ));
+                    }
+                }
+            }
+            if state.platform_fee_bps != 0
+                || state.pending_platform_fee.is_some()
+                || !state.pool_platform_fees.is_empty()
+            {
+                output.push_str(&format!("\nplatform fee: {} bps", state.platform_fee_bps));
+                if let Some(pending) = &state.pending_platform_fee {
+                    let effective = if now >= pending.effective_at {
+                        "in effect".to_string()
+                    } else {
+                        format!("in {}s", pending.effective_at - now)
+                    };
+                    output.push_str(&format!(
+                        " (queued {} by {}, effective at {} ({effective}))",
+                        pending.bps, pending.queued_by, pending.effective_at,
+                    ));
+                }
+                for (pool_id, bps) in &state.pool_platform_fees {
+                    output.push_str(&format!("\n  pool {pool_id}: {bps} bps"));
+                }
+            }
+            Ok(output)
+        }
+        Command::ProposeAdmin { new_admin } => {
+            let new_admin = parse_address(new_admin)?;
+            if let Some(platform) = platform {
+                initialized(platform)?;
+            }
+            envelope(cli, now, |client| client.propose_admin(new_admin))
+        }
+        Command::AcceptAdmin => {
+            if let Some(platform) = platform {
+                let pending = initialized(platform)?
+                    .pending_admin
+                    .as_ref()
+                    .ok_or(CliError::NoPendingAdmin)?;
+                if let Some(source) = &cli.tx.source {
+                    if source != pending {
+                        return Err(CliError::NotProposedAdmin(source.clone()));
+                    }
+                }
+            }
+            envelope(cli, now, CrowdfundingClient::accept_admin)
+        }
+        Command::CancelAdminProposal => {
+            if let Some(platform) = platform {
+                if initialized(platform)?.pending_admin.is_none() {
+                    return Err(CliError::NoPendingAdmin);
+                }
+            }
+            envelope(cli, now, CrowdfundingClient::cancel_admin_proposal)
+        }
+        Command::GrantRole { role, account } => {
+            let account = parse_address(account)?;
+            if let Some(state) = &state {
+                initialized(state.platform.as_ref())?;
+                if has_role(state, &account.to_string(), *role) {
+                    return Err(CliError::RoleAlreadyGranted {
+                        account: account.to_string(),
+                        role: *role,
+                    });
+                }
+            }
+            envelope(cli, now, |client| client.grant_role(role.role(), account))
+        }
+        Command::RevokeRole { role, account } => {
+            let account = parse_address(account)?;
+            if let Some(state) = &state {
+                initialized(state.platform.as_ref())?;
+                if !has_role(state, &account.to_string(), *role) {
+                    return Err(CliError::RoleNotGranted {
+                        account: account.to_string(),
+                        role: *role,
+                    });
+                }
+            }
+            envelope(cli, now, |client| client.revoke_role(role.role(), account))
+        }
+        Command::WithdrawFees { token, amount, to } => {
+            if *amount <= 0 {
+                return Err(CliError::NonPositiveAmount(*amount));
+            }
+            let token = parse_contract(token)?;
+            let to = parse_address(to)?;
+            let caller = authorize(cli, state.as_ref(), RoleArg::Treasury)?;
+            no_council(platform.flatten())?;
+            if let Some(state) = &state {
+                initialized(state.platform.as_ref())?;
+                let balance = state
+                    .fee_balances
+                    .get(&token.to_string())
+                    .copied()
+                    .unwrap_or_default();
+                if *amount > balance {
+                    return Err(CliError::InsufficientFees {
+                        token: token.to_string(),
+                        balance,
+                    });
+                }
+            }
+            envelope(cli, now, |client| {
+                client.withdraw_fees(caller, token, *amount, to)
+            })
+        }
+        Command::EmergencyWithdraw(EmergencyCommand::Request {
+            token,
+            amount,
+            recipient,
+        }) => {
+            if *amount <= 0 {
+                return Err(CliError::NonPositiveAmount(*amount));
+            }
+            let token = parse_contract(token)?;
+            let recipient = recipient.as_deref().map(parse_address).transpose()?;
+            let caller = authorize(cli, state.as_ref(), RoleArg::Treasury)?;
+            no_council(platform.flatten())?;
+            if let Some(platform) = platform {
+                if let Some(pending) = &initialized(platform)?.emergency_withdrawal {
+                    return Err(CliError::WithdrawalPending {
+                        unlock_time: pending.unlock_time,
+                    });
+                }
+            }
+            envelope(cli, now, |client| {
+                client.request_emergency_withdraw(caller, token, *amount, recipient)
+            })
+        }
+        Command::EmergencyWithdraw(EmergencyCommand::Execute) => {
+            let caller = authorize(cli, state.as_ref(), RoleArg::Treasury)?;
+            no_council(platform.flatten())?;
+            if let Some(platform) = platform {
+                let pending = initialized(platform)?
+                    .emergency_withdrawal
+                    .as_ref()
+                    .ok_or(CliError::NoPendingWithdrawal)?;
+                if now < pending.unlock_time {
+                    return Err(CliError::WithdrawalLocked {
+                        unlock_time: pending.unlock_time,
+                        remaining: pending.unlock_time - now,
+                    });
+                }
+            }
+            envelope(cli, now, |client| client.execute_emergency_withdraw(caller))
+        }
+        Command::EmergencyWithdraw(EmergencyCommand::Cancel) => {
+            let caller = authorize(cli, state.as_ref(), RoleArg::Treasury)?;
+            no_council(platform.flatten())?;
+            if let Some(platform) = platform {
+                if initialized(platform)?.emergency_withdrawal.is_none() {
+                    return Err(CliError::NoPendingWithdrawal);
+                }
+            }
+            envelope(cli, now, |client| client.cancel_emergency_withdraw(caller))
+        }
+        Command::EmergencyWithdraw(EmergencyCommand::SetDelay { delay }) => {
+            check_delay(
+                *delay,
+                types::MIN_EMERGENCY_WITHDRAW_DELAY,
+                types::MAX_EMERGENCY_WITHDRAW_DELAY,
+            )?;
+            no_council(platform.flatten())?;
+            if let Some(platform) = platform {
+                initialized(platform)?;
+            }
+            envelope(cli, now, |client| {
+                client.set_emergency_withdraw_delay(*delay)
+            })
+        }
+        Command::EmergencyWithdraw(EmergencyCommand::Status) => {
+            let platform = initialized(platform.ok_or(CliError::StateRequired)?)?;
+            let Some(pending) = &platform.emergency_withdrawal else {
+                return Ok("no emergency withdrawal is pending".to_string());
+            };
+            let unlock = if now >= pending.unlock_time {
+                "unlocked".to_string()
+            } else {
+                format!("in {}s", pending.unlock_time - now)
+            };
+            Ok(format!(
+                "pending emergency withdrawal\n  token:       {}\n  amount:      {}\n  recipient:   {}\n  unlock time: {} ({unlock})",
+                pending.token, pending.amount, pending.recipient, pending.unlock_time,
+            ))
+        }
+        Command::Upgrade(UpgradeCommand::Propose { wasm_hash }) => {
+            let wasm_hash = parse_hash(wasm_hash)?;
+            no_council(platform.flatten())?;
+            if let Some(platform) = platform {
+                if let Some(pending) = &initialized(platform)?.pending_upgrade {
+                    return Err(CliError::UpgradePending {
+                        executable_at: pending.executable_at,
+                    });
+                }
+            }
+            envelope(cli, now, |client| client.propose_upgrade(wasm_hash))
+        }
+        Command::Upgrade(UpgradeCommand::Execute) => {
+            no_council(platform.flatten())?;
+            if let Some(platform) = platform {
+                let pending = initialized(platform)?
+                    .pending_upgrade
+                    .as_ref()
+                    .ok_or(CliError::NoPendingUpgrade)?;
+                if now < pending.executable_at {
+                    return Err(CliError::UpgradeLocked {
+                        executable_at: pending.executable_at,
+                        remaining: pending.executable_at - now,
+                    });
+                }
+            }
+            envelope(cli, now, CrowdfundingClient::execute_upgrade)
+        }
+        Command::Upgrade(UpgradeCommand::Cancel) => {
+            no_council(platform.flatten())?;
+            if let Some(platform) = platform {
+                if initialized(platform)?.pending_upgrade.is_none() {
+                    return Err(CliError::NoPendingUpgrade);
+                }
+            }
+            envelope(cli, now, CrowdfundingClient::cancel_upgrade)
+        }
+        Command::Upgrade(UpgradeCommand::SetTimelock { delay }) => {
+            check_delay(
+                *delay,
+                types::MIN_UPGRADE_TIMELOCK,
+                types::MAX_UPGRADE_TIMELOCK,
+            )?;
+            no_council(platform.flatten())?;
+            if let Some(platform) = platform {
+                initialized(platform)?;
+            }
+            envelope(cli, now, |client| client.set_upgrade_timelock(*delay))
+        }
+        Command::Upgrade(UpgradeCommand::Status) => {
+            let platform = initialized(platform.ok_or(CliError::StateRequired)?)?;
+            let Some(pending) = &platform.pending_upgrade else {
+                return Ok("no upgrade is pending".to_string());
+            };
+            let executable = if now >= pending.executable_at {
+                "executable".to_string()
+            } else {
+                format!("in {}s", pending.executable_at - now)
+            };
+            Ok(format!(
+                "pending upgrade\n  wasm hash:     {}\n  executable at: {} ({executable})",
+                hex(&pending.wasm_hash),
+                pending.executable_at,
+            ))
+        }
+        Command::Council(CouncilCommand::Set(args)) => {
+            let config = args.config()?;
+            if let Some(platform) = platform {
+                no_council(platform)?;
+                initialized(platform)?;
+            }
+            envelope(cli, now, |client| client.set_council(config))
+        }
+        Command::Council(CouncilCommand::Propose(action)) => {
+            let action = action.action()?;
+            let proposer = council_member(cli, state.as_ref())?;
+            envelope(cli, now, |client| {
+                client.propose_council_action(proposer, action)
+            })
+        }
+        Command::Council(CouncilCommand::Approve { proposal }) => {
+            let signer = council_member(cli, state.as_ref())?;
+            if let Some(state) = &state {
+                let open = open_proposal(state, *proposal, now)?;
+                if open.approvals.contains(&signer.to_string()) {
+                    return Err(CliError::AlreadyApproved {
+                        proposal_id: *proposal,
+                        signer: signer.to_string(),
+                    });
+                }
+            }
+            envelope(cli, now, |client| {
+                client.approve_council_action(signer, *proposal)
+            })
+        }
+        Command::Council(CouncilCommand::Execute { proposal }) => {
+            let executor = council_member(cli, state.as_ref())?;
+            if let Some(state) = &state {
+                let open = open_proposal(state, *proposal, now)?;
+                let council = initialized(state.platform.as_ref())?
+                    .council
+                    .as_ref()
+                    .ok_or(CliError::NoCouncil)?;
+                let approvals = open
+                    .approvals
+                    .iter()
+                    .filter(|signer| council.signers.contains(signer))
+                    .count();
+                if approvals < council.required_signatures as usize {
+                    return Err(CliError::InsufficientApprovals {
+                        proposal_id: *proposal,
+                        approvals,
+                        required: council.required_signatures,
+                    });
+                }
+            }
+            envelope(cli, now, |client| {
+                client.execute_council_action(executor, *proposal)
+            })
+        }
+        Command::Council(CouncilCommand::Status) => {
+            let state = state.as_ref().ok_or(CliError::StateRequired)?;
+            let platform = initialized(state.platform.as_ref())?;
+            let Some(council) = &platform.council else {
+                return Ok("no council is set".to_string());
+            };
+            let mut output = format!(
+                "council ({} of {})\n  signers: {}",
+                council.required_signatures,
+                council.signers.len(),
+                council.signers.join(", "),
+            );
+            for (id, proposal) in &state.council_proposals {
+                if proposal.executed || now >= proposal.expires_at {
+                    continue;
+                }
+                output.push_str(&format!(
+                    "\nproposal {id}: {}\n  approvals:  {}/{}\n  expires at: {} (in {}s)",
+                    proposal.action,
+                    proposal.approvals.len(),
+                    council.required_signatures,
+                    proposal.expires_at,
+                    proposal.expires_at - now,
+                ));
+            }
+            Ok(output)
+        }
+        Command::Migrate { batch_size } => {
+            if *batch_size == 0 {
+                return Err(CliError::NonPositiveAmount(0));
+            }
+            if let Some(platform) = platform {
+                initialized(platform)?;
+            }
+            envelope(cli, now, |client| client.migrate(*batch_size))
+        }
+        Command::MigratePoolContributions { pool } => {
+            let state = state.as_ref().ok_or(CliError::StateRequired)?;
+            initialized(state.platform.as_ref())?;
+            let contributors = state
+                .pools
+                .get(pool)
+                .ok_or(CliError::UnknownPool(*pool))?
+                .contributions
+                .keys()
+                .map(|contributor| parse_address(contributor))
+                .collect::<Result<Vec<_>, _>>()?;
+            envelope(cli, now, |client| {
+                client.migrate_pool_contributions(*pool, contributors)
+            })
+        }
+    }
+}
+
+fn load_state(path: &PathBuf) -> Result<State, CliError> {
+    let store = SqliteStore::open(path).map_err(IndexerError::from)?;
+    Ok(store.load().map_err(IndexerError::from)?)
+}
+
+fn initialized(platform: Option<&Platform>) -> Result<&Platform, CliError> {
+    platform.ok_or(CliError::NotInitialized)
+}
+
+fn has_role(state: &State, account: &str, role: RoleArg) -> bool {
+    state
+        .roles
+        .get(account)
+        .is_some_and(|roles| roles.contains(&role.indexed()))
+}
+
+/// The `--source` account as the caller of a role-gated entrypoint, checked
+/// against the indexed admin and roles when state is available.
+fn authorize(cli: &Cli, state: Option<&State>, role: RoleArg) -> Result<ScAddress, CliError> {
+    let source = cli
+        .tx
+        .source
+        .as_deref()
+        .ok_or(CliError::MissingOption("--source"))?;
+    let caller = parse_address(source)?;
+    if let Some(state) = state {
+        let platform = initialized(state.platform.as_ref())?;
+        if platform.admin != source && !has_role(state, source, role) {
+            return Err(CliError::MissingRole {
+                account: source.to_string(),
+                role,
+            });
+        }
+    }
+    Ok(caller)
+}
+
+/// Fail while a council is set, since it takes over the privileged setters.
+fn no_council(platform: Option<&Platform>) -> Result<(), CliError> {
+    match platform.and_then(|platform| platform.council.as_ref()) {
+        Some(_) => Err(CliError::CouncilActive),
+        None => Ok(()),
+    }
+}
+
+/// The `--source` account as a council member, checked against the indexed
+/// council when state is available.
+fn council_member(cli: &Cli, state: Option<&State>) -> Result<ScAddress, CliError> {
+    let source = cli
+        .tx
+        .source
+        .as_deref()
+        .ok_or(CliError::MissingOption("--source"))?;
+    let member = parse_address(source)?;
+    if let Some(state) = state {
+        let council = initialized(state.platform.as_ref())?
+            .council
+            .as_ref()
+            .ok_or(CliError::NoCouncil)?;
+        if !council.signers.iter().any(|signer| signer == source) {
+            return Err(CliError::NotCouncilMember(source.to_string()));
+        }
+    }
+    Ok(member)
+}
+
+/// An indexed proposal that can still be approved and executed at `now`.
+fn open_proposal(state: &State, id: u64, now: u64) -> Result<&CouncilProposal, CliError> {
+    let proposal = state
+        .council_proposals
+        .get(&id)
+        .ok_or(CliError::UnknownProposal(id))?;
+    if proposal.executed {
+        return Err(CliError::ProposalExecuted(id));
+    }
+    if now >= proposal.expires_at {
+        return Err(CliError::ProposalExpired {
+            proposal_id: id,
+            expires_at: proposal.expires_at,
+        });
+    }
+    Ok(proposal)
+}
+
+/// Reject a basis-point rate the contract would refuse.
+fn check_bps(bps: u32) -> Result<u32, CliError> {
+    if bps > types::BPS_DENOMINATOR {
+        return Err(CliError::InvalidBps(bps));
+    }
+    Ok(bps)
+}
+
+/// Reject a delay outside the bounds the contract enforces.
+fn check_delay(delay: u64, min: u64, max: u64) -> Result<u64, CliError> {
+    if !(min..=max).contains(&delay) {
+        return Err(CliError::InvalidDelay { delay, min, max });
+    }
+    Ok(delay)
+}
+
+/// Reject a pause duration outside the bounds the contract enforces.
+fn check_pause_duration(duration: u64, min: u64, max: u64) -> Result<u64, CliError> {
+    if !(min..=max).contains(&duration) {
+        return Err(CliError::InvalidPauseDuration { duration, min, max });
+    }
+    Ok(duration)
+}
+
+/// Reject a minimum token amount the contract would refuse.
+fn check_min_amount(min_amount: i128) -> Result<i128, CliError> {
+    if min_amount < 0 {
+        return Err(CliError::NegativeMinAmount(min_amount));
+    }
+    Ok(min_amount)
+}
+
+fn parse_address(value: &str) -> Result<ScAddress, CliError> {
+    ScAddress::from_str(value).map_err(|_| CliError::InvalidAddress(value.to_string()))
+}
+
+fn parse_contract(value: &str) -> Result<ScAddress, CliError> {
+    match parse_address(value)? {
+        address @ ScAddress::Contract(_) => Ok(address),
+        _ => Err(CliError::NotAContract(value.to_string())),
+    }
+}
+
+/// Parse a 32-byte hash given as hex.
+fn parse_hash(value: &str) -> Result<[u8; 32], CliError> {
+    let invalid = || CliError::InvalidHash(value.to_string());
+    if value.len() != 64 || !value.is_ascii() {
+        return Err(invalid());
+    }
+    let mut hash = [0u8; 32];
+    for (byte, pair) in hash.iter_mut().zip(value.as_bytes().chunks(2)) {
+        let pair = std::str::from_utf8(pair).map_err(|_| invalid())?;
+        *byte = u8::from_str_radix(pair, 16).map_err(|_| invalid())?;
+    }
+    Ok(hash)
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Build the call with a client for `--contract` and wrap it in an unsigned
+/// envelope for `--source`.
+fn envelope<T: FromScVal>(
+    cli: &Cli,
+    now: u64,
+    call: impl FnOnce(&CrowdfundingClient) -> Result<Call<T>, crowdfunding_client::ClientError>,
+) -> Result<String, CliError> {
+    let tx = &cli.tx;
+    let contract = tx
+        .contract
+        .as_deref()
+        .ok_or(CliError::MissingOption("--contract"))?;
+    let source = tx
+        .source
+        .as_deref()
+        .ok_or(CliError::MissingOption("--source"))?;
+    let sequence = tx.sequence.ok_or(CliError::MissingOption("--sequence"))?;
+
+    let contract = parse_contract(contract)?;
+    let call = call(&CrowdfundingClient::new(contract))?;
+    let builder = TransactionBuilder::new(source, sequence)
+        .map_err(|_| CliError::InvalidAddress(source.to_string()))?
+        .fee(tx.base_fee)
+        .time_bounds(0, now.saturating_add(tx.valid_for));
+    Ok(builder.build_base64(&call)?)
+}
@@ -0,0 +1,1323 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+use crowdfunding_cli::{run, Cli, CliError};
+use crowdfunding_client::{
+    xdr::{
+        ContractId, Hash, HostFunction, Int128Parts, Limits, OperationBody, ReadXdr, ScAddress,
+        ScVal, TransactionEnvelope,
+    },
+    CampaignAmendmentRules, ToScVal,
+};
+use crowdfunding_indexer::{
+    sqlite::SqliteStore,
+    state::{
+        AcceptedToken, CouncilProposal, CreatorFeeTier, EmergencyWithdrawal, FeaturePause,
+        MultiSig, PendingEntityFee, PendingParameters, PendingPlatformFee, PendingUpgrade,
+        Platform, Pool, PoolContribution,
+    },
+    FeeEntity, FeeTier, PoolState, Role, State,
+};
+
+const ADMIN: &str = "GAAZI4TCR3TY5OJHCTJC2A4QSY6CJWJH5IAJTGKIN2ER7LBNVKOCCWN7";
+const OPERATOR: &str = "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H";
+const NOW: u64 = 1_700_000_000;
+
+fn contract(seed: u8) -> String {
+    ScAddress::Contract(ContractId(Hash([seed; 32]))).to_string()
+}
+
+fn cli(args: &[&str]) -> Cli {
+    let contract = contract(1);
+    let mut argv = vec![
+        "crowdfunding-admin",
+        "--contract",
+        &contract,
+        "--source",
+        ADMIN,
+        "--sequence",
+        "7",
+    ];
+    argv.extend_from_slice(args);
+    Cli::try_parse_from(argv).unwrap()
+}
+
+/// Writes `state` to a fresh indexer database and returns its path.
+fn save_state(name: &str, state: &State) -> PathBuf {
+    let path = std::env::temp_dir().join(format!(
+        "crowdfunding-cli-{}-{name}.sqlite",
+        std::process::id()
+    ));
+    let _ = std::fs::remove_file(&path);
+    SqliteStore::open(&path).unwrap().save(state).unwrap();
+    path
+}
+
+fn state_db(name: &str, platform: Option<Platform>) -> PathBuf {
+    save_state(
+        name,
+        &State {
+            platform,
+            ..State::default()
+        },
+    )
+}
+
+fn platform(paused: bool, emergency_withdrawal: Option<EmergencyWithdrawal>) -> Platform {
+    Platform {
+        admin: ADMIN.to_string(),
+        token: contract(2),
+        creation_fee: 10,
+        paused,
+        paused_at: None,
+        unpaused_at: None,
+        max_pause_duration: None,
+        emergency_withdrawal,
+        emergency_withdraw_delay: None,
+        pending_admin: None,
+        upgrade_timelock: None,
+        pending_upgrade: None,
+        council: None,
+        pending_parameters: None,
+    }
+}
+
+fn pending(unlock_time: u64) -> EmergencyWithdrawal {
+    EmergencyWithdrawal {
+        token: contract(2),
+        amount: 500,
+        recipient: OPERATOR.to_string(),
+        unlock_time,
+    }
+}
+
+/// Decode an emitted envelope into its single contract invocation.
+fn invocation(output: &str) -> (String, Vec<ScVal>) {
+    let TransactionEnvelope::Tx(envelope) =
+        TransactionEnvelope::from_xdr_base64(output, Limits::none()).unwrap()
+    else {
+        panic!("expected a v1 envelope");
+    };
+    assert!(envelope.signatures.is_empty());
+    assert_eq!(envelope.tx.seq_num.0, 7);
+    let OperationBody::InvokeHostFunction(op) = &envelope.tx.operations[0].body else {
+        panic!("expected an invoke host function operation");
+    };
+    let HostFunction::InvokeContract(args) = &op.host_function else {
+        panic!("expected a contract invocation");
+    };
+    assert_eq!(args.contract_address.to_string(), contract(1));
+    (
+        args.function_name.to_utf8_string_lossy(),
+        args.args.to_vec(),
+    )
+}
+
+#[test]
+fn initialize_emits_unsigned_envelope() {
+    let token = contract(2);
+    let output = run(
+        &cli(&[
+            "initialize",
+            "--admin",
+            ADMIN,
+            "--token",
+            &token,
+            "--creation-fee",
+            "25",
+        ]),
+        NOW,
+    )
+    .unwrap();
+
+    let (function, args) = invocation(&output);
+    assert_eq!(function, "initialize");
+    assert_eq!(args.len(), 3);
+    assert_eq!(args[1], ScVal::Address(token.parse().unwrap()));
+}
+
+#[test]
+fn negative_fees_are_refused() {
+    let result = run(&cli(&["set-creation-fee", "--fee", "-1"]), NOW);
+    assert!(matches!(result, Err(CliError::NegativeFee(-1))));
+
+    let token = contract(2);
+    let result = run(
+        &cli(&[
+            "initialize",
+            "--admin",
+            ADMIN,
+            "--token",
+            &token,
+            "--creation-fee",
+            "-5",
+        ]),
+        NOW,
+    );
+    assert!(matches!(result, Err(CliError::NegativeFee(-5))));
+
+    let output = run(&cli(&["set-creation-fee", "--fee", "0"]), NOW).unwrap();
+    assert_eq!(invocation(&output).0, "set_creation_fee");
+}
+
+#[test]
+fn inputs_are_validated() {
+    let result = run(&cli(&["set-crowdfunding-token", "--token", ADMIN]), NOW);
+    assert!(matches!(result, Err(CliError::NotAContract(_))));
+
+    let result = run(&cli(&["set-crowdfunding-token", "--token", "nope"]), NOW);
+    assert!(matches!(result, Err(CliError::InvalidAddress(_))));
+
+    let token = contract(2);
+    let result = run(
+        &cli(&[
+            "emergency-withdraw",
+            "request",
+            "--token",
+            &token,
+            "--amount",
+            "0",
+        ]),
+        NOW,
+    );
+    assert!(matches!(result, Err(CliError::NonPositiveAmount(0))));
+
+    let result = run(&cli(&["migrate", "--batch-size", "0"]), NOW);
+    assert!(matches!(result, Err(CliError::NonPositiveAmount(0))));
+    let output = run(&cli(&["migrate", "--batch-size", "25"]), NOW).unwrap();
+    assert_eq!(invocation(&output).1, vec![ScVal::U32(25)]);
+
+    let result = run(&cli(&["migrate-pool-contributions", "--pool", "1"]), NOW);
+    assert!(matches!(result, Err(CliError::StateRequired)));
+
+    let token = contract(2);
+    let mut state = State {
+        platform: Some(platform(false, None)),
+        ..State::default()
+    };
+    state.pools.insert(
+        1,
+        Pool {
+            id: 1,
+            creator: ADMIN.to_string(),
+            name: "Pool".to_string(),
+            description: String::new(),
+            target_amount: 1_000,
+            is_private: false,
+            deadline: NOW,
+            state: PoolState::Active,
+            metadata: None,
+            multisig: None,
+            total_raised: 40,
+            contributor_count: 2,
+            last_donation_at: NOW,
+            closed_by: None,
+            pending_owner: None,
+            contributions: [(ADMIN, 40), (OPERATOR, 0)]
+                .into_iter()
+                .map(|(contributor, amount)| {
+                    let contribution = PoolContribution {
+                        asset: token.clone(),
+                        amount,
+                    };
+                    (contributor.to_string(), contribution)
+                })
+                .collect(),
+            refunds: Vec::new(),
+        },
+    );
+    let db = save_state("migrate-pool-contributions", &state);
+    let db = db.to_str().unwrap();
+    let result = run(
+        &cli(&["--state", db, "migrate-pool-contributions", "--pool", "2"]),
+        NOW,
+    );
+    assert!(matches!(result, Err(CliError::UnknownPool(2))));
+    // Refunded contributors still have records to rewrite
+    let output = run(
+        &cli(&["--state", db, "migrate-pool-contributions", "--pool", "1"]),
+        NOW,
+    )
+    .unwrap();
+    let (function, args) = invocation(&output);
+    assert_eq!(function, "migrate_pool_contributions");
+    let contributors = vec![
+        ADMIN.parse::<ScAddress>().unwrap(),
+        OPERATOR.parse::<ScAddress>().unwrap(),
+    ];
+    assert_eq!(args, vec![ScVal::U64(1), contributors.to_sc_val().unwrap()]);
+
+    let result = run(
+        &Cli::try_parse_from(["crowdfunding-admin", "pause"]).unwrap(),
+        NOW,
+    );
+    assert!(matches!(result, Err(CliError::MissingOption("--source"))));
+    let result = run(
+        &Cli::try_parse_from(["crowdfunding-admin", "pause", "--source", ADMIN]).unwrap(),
+        NOW,
+    );
+    assert!(matches!(result, Err(CliError::MissingOption("--contract"))));
+}
+
+#[test]
+fn state_checks_pause_flags() {
+    let db = state_db("pause", Some(platform(true, None)));
+    let db = db.to_str().unwrap();
+
+    let result = run(&cli(&["--state", db, "pause"]), NOW);
+    assert!(matches!(result, Err(CliError::AlreadyPaused)));
+    let output = run(&cli(&["--state", db, "unpause"]), NOW).unwrap();
+    assert_eq!(invocation(&output).0, "unpause");
+    let output = run(
+        &cli(&[
+            "--state",
+            db,
+            "set-max-pause-duration",
+            "--duration",
+            "86400",
+        ]),
+        NOW,
+    )
+    .unwrap();
+    assert_eq!(
+        invocation(&output),
+        (
+            "set_max_pause_duration".to_string(),
+            vec![ScVal::U64(86_400)]
+        )
+    );
+    for duration in ["86399", "7776001"] {
+        let result = run(
+            &cli(&[
+                "--state",
+                db,
+                "set-max-pause-duration",
+                "--duration",
+                duration,
+            ]),
+            NOW,
+        );
+        assert!(matches!(
+            result,
+            Err(CliError::InvalidPauseDuration {
+                min: 86_400,
+                max: 7_776_000,
+                ..
+            })
+        ));
+    }
+
+    let token = contract(2);
+    let result = run(
+        &cli(&[
+            "--state",
+            db,
+            "initialize",
+            "--admin",
+            ADMIN,
+            "--token",
+            &token,
+            "--creation-fee",
+            "0",
+        ]),
+        NOW,
+    );
+    assert!(matches!(result, Err(CliError::AlreadyInitialized)));
+
+    let empty = state_db("uninitialized", None);
+    let result = run(&cli(&["--state", empty.to_str().unwrap(), "pause"]), NOW);
+    assert!(matches!(result, Err(CliError::NotInitialized)));
+}
+
+#[test]
+fn emergency_withdrawal_status_and_unlock() {
+    let db = state_db(
+        "emergency",
+        Some(platform(false, Some(pending(NOW + 3_600)))),
+    );
+    let db = db.to_str().unwrap();
+
+    let status = run(&cli(&["--state", db, "emergency-withdraw", "status"]), NOW).unwrap();
+    assert!(status.contains("amount:      500"));
+    assert!(status.contains(&format!("recipient:   {OPERATOR}")));
+    assert!(status.contains(&format!("unlock time: {} (in 3600s)", NOW + 3_600)));
+
+    let result = run(&cli(&["--state", db, "emergency-withdraw", "execute"]), NOW);
+    assert!(matches!(
+        result,
+        Err(CliError::WithdrawalLocked {
+            remaining: 3_600,
+            ..
+        })
+    ));
+
+    let token = contract(2);
+    let result = run(
+        &cli(&[
+            "--state",
+            db,
+            "emergency-withdraw",
+            "request",
+            "--token",
+            &token,
+            "--amount",
+            "1",
+        ]),
+        NOW,
+    );
+    assert!(matches!(result, Err(CliError::WithdrawalPending { .. })));
+
+    let later = NOW + 3_600;
+    let status = run(
+        &cli(&["--state", db, "emergency-withdraw", "status"]),
+        later,
+    )
+    .unwrap();
+    assert!(status.contains("(unlocked)"));
+    let output = run(
+        &cli(&["--state", db, "emergency-withdraw", "execute"]),
+        later,
+    )
+    .unwrap();
+    assert_eq!(invocation(&output).0, "execute_emergency_withdraw");
+
+    let set_delay = |delay: &str| {
+        run(
+            &cli(&[
+                "--state",
+                db,
+                "emergency-withdraw",
+                "set-delay",
+                "--delay",
+                delay,
+            ]),
+            NOW,
+        )
+    };
+    let output = set_delay("3600").unwrap();
+    assert_eq!(
+        invocation(&output),
+        (
+            "set_emergency_withdraw_delay".to_string(),
+            vec![ScVal::U64(3_600)]
+        )
+    );
+    for delay in ["3599", "2592001"] {
+        assert!(matches!(
+            set_delay(delay),
+            Err(CliError::InvalidDelay {
+                min: 3_600,
+                max: 2_592_000,
+                ..
+            })
+        ));
+    }
+}
+
+#[test]
+fn emergency_withdrawal_without_pending_request() {
+    let db = state_db("no-emergency", Some(platform(false, None)));
+    let db = db.to_str().unwrap();
+
+    let status = run(&cli(&["--state", db, "emergency-withdraw", "status"]), NOW).unwrap();
+    assert_eq!(status, "no emergency withdrawal is pending");
+    let result = run(&cli(&["--state", db, "emergency-withdraw", "execute"]), NOW);
+    assert!(matches!(result, Err(CliError::NoPendingWithdrawal)));
+    let result = run(&cli(&["--state", db, "emergency-withdraw", "cancel"]), NOW);
+    assert!(matches!(result, Err(CliError::NoPendingWithdrawal)));
+
+    let result = run(&cli(&["emergency-withdraw", "status"]), NOW);
+    assert!(matches!(result, Err(CliError::StateRequired)));
+}
+
+#[test]
+fn admin_transfer_checks_pending_proposal() {
+    let output = run(&cli(&["propose-admin", "--new-admin", ADMIN]), NOW).unwrap();
+    assert_eq!(invocation(&output).0, "propose_admin");
+
+    let db = state_db("no-admin-proposal", Some(platform(false, None)));
+    let result = run(
+        &cli(&["--state", db.to_str().unwrap(), "accept-admin"]),
+        NOW,
+    );
+    assert!(matches!(result, Err(CliError::NoPendingAdmin)));
+    let result = run(
+        &cli(&["--state", db.to_str().unwrap(), "cancel-admin-proposal"]),
+        NOW,
+    );
+    assert!(matches!(result, Err(CliError::NoPendingAdmin)));
+
+    let proposed = OPERATOR;
+    let db = state_db(
+        "admin-proposal",
+        Some(Platform {
+            pending_admin: Some(proposed.to_string()),
+            ..platform(false, None)
+        }),
+    );
+    let db = db.to_str().unwrap();
+    let result = run(&cli(&["--state", db, "accept-admin"]), NOW);
+    assert!(matches!(result, Err(CliError::NotProposedAdmin(_))));
+    let output = run(
+        &cli(&["--state", db, "accept-admin", "--source", proposed]),
+        NOW,
+    )
+    .unwrap();
+    assert_eq!(invocation(&output).0, "accept_admin");
+}
+
+#[test]
+fn role_gated_commands_pass_source_as_caller() {
+    let output = run(&cli(&["pause"]), NOW).unwrap();
+    let (function, args) = invocation(&output);
+    assert_eq!(function, "pause");
+    assert_eq!(args, vec![ScVal::Address(ADMIN.parse().unwrap())]);
+
+    let output = run(&cli(&["set-creation-fee", "--fee", "5"]), NOW).unwrap();
+    let (_, args) = invocation(&output);
+    assert_eq!(args[0], ScVal::Address(ADMIN.parse().unwrap()));
+}
+
+#[test]
+fn state_checks_roles() {
+    let mut state = State {
+        platform: Some(platform(false, None)),
+        ..State::default()
+    };
+    state
+        .roles
+        .insert(OPERATOR.to_string(), [Role::Pauser].into_iter().collect());
+    let db = save_state("roles", &state);
+    let db = db.to_str().unwrap();
+
+    let output = run(&cli(&["--state", db, "pause", "--source", OPERATOR]), NOW).unwrap();
+    assert_eq!(invocation(&output).0, "pause");
+
+    let result = run(
+        &cli(&[
+            "--state",
+            db,
+            "set-creation-fee",
+            "--fee",
+            "1",
+            "--source",
+            OPERATOR,
+        ]),
+        NOW,
+    );
+    assert!(matches!(result, Err(CliError::MissingRole { .. })));
+
+    let result = run(
+        &cli(&[
+            "--state",
+            db,
+            "grant-role",
+            "--role",
+            "pauser",
+            "--account",
+            OPERATOR,
+        ]),
+        NOW,
+    );
+    assert!(matches!(result, Err(CliError::RoleAlreadyGranted { .. })));
+    let result = run(
+        &cli(&[
+            "--state",
+            db,
+            "revoke-role",
+            "--role",
+            "treasury",
+            "--account",
+            OPERATOR,
+        ]),
+        NOW,
+    );
+    assert!(matches!(result, Err(CliError::RoleNotGranted { .. })));
+
+    let output = run(
+        &cli(&[
+            "--state",
+            db,
+            "grant-role",
+            "--role",
+            "treasury",
+            "--account",
+            OPERATOR,
+        ]),
+        NOW,
+    )
+    .unwrap();
+    let (function, args) = invocation(&output);
+    assert_eq!(function, "grant_role");
+    assert_eq!(args[0], ScVal::U32(2));
+}
+
+#[test]
+fn upgrade_status_and_timelock() {
+    let hash = "ab".repeat(32);
+    let output = run(&cli(&["upgrade", "propose", "--wasm-hash", &hash]), NOW).unwrap();
+    let (function, args) = invocation(&output);
+    assert_eq!(function, "propose_upgrade");
+    assert_eq!(args, vec![ScVal::Bytes(vec![0xab; 32].try_into().unwrap())]);
+
+    let result = run(&cli(&["upgrade", "propose", "--wasm-hash", "abcd"]), NOW);
+    assert!(matches!(result, Err(CliError::InvalidHash(_))));
+
+    let db = state_db(
+        "upgrade",
+        Some(Platform {
+            pending_upgrade: Some(PendingUpgrade {
+                wasm_hash: [0xab; 32],
+                executable_at: NOW + 60,
+            }),
+            ..platform(false, None)
+        }),
+    );
+    let db = db.to_str().unwrap();
+
+    let status = run(&cli(&["--state", db, "upgrade", "status"]), NOW).unwrap();
+    assert!(status.contains(&format!("wasm hash:     {hash}")));
+    assert!(status.contains(&format!("executable at: {} (in 60s)", NOW + 60)));
+    let result = run(&cli(&["--state", db, "upgrade", "execute"]), NOW);
+    assert!(matches!(
+        result,
+        Err(CliError::UpgradeLocked { remaining: 60, .. })
+    ));
+    let result = run(
+        &cli(&["--state", db, "upgrade", "propose", "--wasm-hash", &hash]),
+        NOW,
+    );
+    assert!(matches!(result, Err(CliError::UpgradePending { .. })));
+
+    let output = run(&cli(&["--state", db, "upgrade", "execute"]), NOW + 60).unwrap();
+    assert_eq!(invocation(&output).0, "execute_upgrade");
+
+    let db = state_db("no-upgrade", Some(platform(false, None)));
+    let result = run(
+        &cli(&["--state", db.to_str().unwrap(), "upgrade", "cancel"]),
+        NOW,
+    );
+    assert!(matches!(result, Err(CliError::NoPendingUpgrade)));
+
+    let set_timelock = |delay: &str| run(&cli(&["upgrade", "set-timelock", "--delay", delay]), NOW);
+    let output = set_timelock("86400").unwrap();
+    assert_eq!(
+        invocation(&output),
+        ("set_upgrade_timelock".to_string(), vec![ScVal::U64(86_400)])
+    );
+    for delay in ["86399", "2592001"] {
+        assert!(matches!(
+            set_timelock(delay),
+            Err(CliError::InvalidDelay {
+                min: 86_400,
+                max: 2_592_000,
+                ..
+            })
+        ));
+    }
+}
+
+#[test]
+fn feature_pauses_combine_flags() {
+    let output = run(
+        &cli(&[
+            "pause-features",
+            "--feature",
+            "contributions",
+            "--feature",
+            "pool-creation",
+            "--reason",
+            "4",
+            "--duration",
+            "3600",
+        ]),
+        NOW,
+    )
+    .unwrap();
+    let (function, args) = invocation(&output);
+    assert_eq!(function, "pause_features");
+    assert_eq!(
+        args[1..],
+        [ScVal::U32(0b1100), ScVal::U32(4), ScVal::U64(3_600)]
+    );
+
+    let mut state = State::new();
+    state.platform = Some(Platform {
+        max_pause_duration: Some(7_200),
+        ..platform(false, None)
+    });
+    state.feature_pauses.insert(
+        0b1000,
+        FeaturePause {
+            reason: 4,
+            paused_at: NOW - 10,
+            expires_at: NOW + 10,
+        },
+    );
+    let db = save_state("feature-pauses", &state);
+    let db = db.to_str().unwrap();
+    let unpause = |feature: &str, now: u64| {
+        run(
+            &cli(&["--state", db, "unpause-features", "--feature", feature]),
+            now,
+        )
+    };
+
+    // Every pause lapses within the configured maximum
+    let pause = |duration: &str| {
+        run(
+            &cli(&[
+                "--state",
+                db,
+                "pause-features",
+                "--feature",
+                "refunds",
+                "--duration",
+                duration,
+            ]),
+            NOW,
+        )
+    };
+    assert!(pause("7200").is_ok());
+    for duration in ["0", "7201"] {
+        assert!(matches!(
+            pause(duration),
+            Err(CliError::InvalidPauseDuration { max: 7_200, .. })
+        ));
+    }
+
+    let output = unpause("contributions", NOW).unwrap();
+    assert_eq!(invocation(&output).1[1], ScVal::U32(0b1000));
+    assert!(matches!(unpause("refunds", NOW), Err(CliError::NotPaused)));
+    // The pause has lapsed on its own by then
+    assert!(matches!(
+        unpause("contributions", NOW + 10),
+        Err(CliError::NotPaused)
+    ));
+}
+
+#[test]
+fn emergency_withdrawal_recipient_defaults_to_source() {
+    let token = contract(2);
+    let request = |extra: &[&str]| {
+        let mut args = vec![
+            "emergency-withdraw",
+            "request",
+            "--token",
+            &token,
+            "--amount",
+            "5",
+        ];
+        args.extend_from_slice(extra);
+        invocation(&run(&cli(&args), NOW).unwrap()).1
+    };
+
+    assert_eq!(request(&[])[3], ScVal::Void);
+    assert_eq!(
+        request(&["--recipient", OPERATOR])[3],
+        ScVal::Address(OPERATOR.parse().unwrap())
+    );
+
+    let db = state_db(
+        "emergency-cancel",
+        Some(platform(false, Some(pending(NOW + 60)))),
+    );
+    let output = run(
+        &cli(&[
+            "--state",
+            db.to_str().unwrap(),
+            "emergency-withdraw",
+            "cancel",
+        ]),
+        NOW,
+    )
+    .unwrap();
+    assert_eq!(invocation(&output).0, "cancel_emergency_withdraw");
+}
+
+#[test]
+fn council_proposals_are_checked_against_state() {
+    let output = run(
+        &cli(&[
+            "council",
+            "set",
+            "--required-signatures",
+            "2",
+            "--signer",
+            ADMIN,
+            "--signer",
+            OPERATOR,
+        ]),
+        NOW,
+    )
+    .unwrap();
+    assert_eq!(invocation(&output).0, "set_council");
+    assert!(matches!(
+        run(
+            &cli(&[
+                "council",
+                "set",
+                "--required-signatures",
+                "3",
+                "--signer",
+                ADMIN,
+                "--signer",
+                OPERATOR,
+            ]),
+            NOW,
+        ),
+        Err(CliError::InvalidThreshold {
+            required: 3,
+            signers: 2
+        })
+    ));
+
+    let output = run(
+        &cli(&["council", "propose", "set-creation-fee", "--fee", "40"]),
+        NOW,
+    )
+    .unwrap();
+    let (function, args) = invocation(&output);
+    assert_eq!(function, "propose_council_action");
+    let ScVal::Vec(Some(action)) = &args[1] else {
+        panic!("expected an enum case");
+    };
+    assert_eq!(
+        action[0],
+        ScVal::Symbol("SetCreationFee".try_into().unwrap())
+    );
+
+    let mut state = State::new();
+    state.platform = Some(Platform {
+        council: Some(MultiSig {
+            required_signatures: 2,
+            signers: vec![ADMIN.to_string(), OPERATOR.to_string()],
+        }),
+        ..platform(false, None)
+    });
+    state.council_proposals.insert(
+        1,
+        CouncilProposal {
+            action: "SetCreationFee".into(),
+            proposer: ADMIN.to_string(),
+            approvals: vec![ADMIN.to_string()],
+            expires_at: NOW + 60,
+            executed: false,
+        },
+    );
+    let db = save_state("council", &state);
+    let db = db.to_str().unwrap();
+    let council = |args: &[&str], now: u64| {
+        let mut argv = vec!["--state", db, "council"];
+        argv.extend_from_slice(args);
+        run(&cli(&argv), now)
+    };
+
+    // The direct setter is handed to the council
+    assert!(matches!(
+        run(
+            &cli(&["--state", db, "set-creation-fee", "--fee", "5"]),
+            NOW
+        ),
+        Err(CliError::CouncilActive)
+    ));
+    let token = contract(2);
+    assert!(matches!(
+        run(
+            &cli(&[
+                "--state",
+                db,
+                "withdraw-fees",
+                "--token",
+                &token,
+                "--amount",
+                "5",
+                "--to",
+                ADMIN,
+            ]),
+            NOW
+        ),
+        Err(CliError::CouncilActive)
+    ));
+    for args in [
+        &["assign-fee-tier", "--creator", OPERATOR, "--waived"][..],
+        &["emergency-withdraw", "set-delay", "--delay", "3600"],
+        &["upgrade", "set-timelock", "--delay", "86400"],
+    ] {
+        let mut argv = vec!["--state", db];
+        argv.extend_from_slice(args);
+        assert!(matches!(
+            run(&cli(&argv), NOW),
+            Err(CliError::CouncilActive)
+        ));
+    }
+    let output = council(
+        &["propose", "set-upgrade-timelock", "--delay", "86400"],
+        NOW,
+    )
+    .unwrap();
+    let ScVal::Vec(Some(action)) = &invocation(&output).1[1] else {
+        panic!("expected an enum case");
+    };
+    assert_eq!(
+        action[0],
+        ScVal::Symbol("SetUpgradeTimelock".try_into().unwrap())
+    );
+    let output = council(
+        &[
+            "propose",
+            "withdraw-fees",
+            "--token",
+            &token,
+            "--amount",
+            "5",
+            "--to",
+            ADMIN,
+        ],
+        NOW,
+    )
+    .unwrap();
+    let ScVal::Vec(Some(action)) = &invocation(&output).1[1] else {
+        panic!("expected an enum case");
+    };
+    assert_eq!(action[0], ScVal::Symbol("WithdrawFees".try_into().unwrap()));
+    assert!(matches!(
+        council(&["approve", "--proposal", "1"], NOW),
+        Err(CliError::AlreadyApproved { proposal_id: 1, .. })
+    ));
+    assert!(matches!(
+        council(&["approve", "--proposal", "2"], NOW),
+        Err(CliError::UnknownProposal(2))
+    ));
+    assert!(matches!(
+        council(&["execute", "--proposal", "1"], NOW),
+        Err(CliError::InsufficientApprovals {
+            proposal_id: 1,
+            approvals: 1,
+            required: 2
+        })
+    ));
+    assert!(matches!(
+        council(&["execute", "--proposal", "1"], NOW + 60),
+        Err(CliError::ProposalExpired {
+            proposal_id: 1,
+            expires_at
+        }) if expires_at == NOW + 60
+    ));
+
+    let status = council(&["status"], NOW).unwrap();
+    assert!(status.contains("council (2 of 2)"));
+    assert!(status.contains("proposal 1: SetCreationFee"));
+    assert!(status.contains("approvals:  1/2"));
+
+    state
+        .council_proposals
+        .get_mut(&1)
+        .unwrap()
+        .approvals
+        .push(OPERATOR.to_string());
+    let db = save_state("council-approved", &state);
+    let output = run(
+        &cli(&[
+            "--state",
+            db.to_str().unwrap(),
+            "council",
+            "execute",
+            "--proposal",
+            "1",
+        ]),
+        NOW,
+    )
+    .unwrap();
+    assert_eq!(invocation(&output).0, "execute_council_action");
+}
+
+#[test]
+fn parameters_show_queued_changes() {
+    assert!(matches!(
+        run(&cli(&["parameters"]), NOW),
+        Err(CliError::StateRequired)
+    ));
+
+    let db = state_db("parameters", Some(platform(false, None)));
+    let output = run(&cli(&["--state", db.to_str().unwrap(), "parameters"]), NOW).unwrap();
+    assert_eq!(
+        output,
+        format!("creation fee: 10\ntoken:        {}", contract(2))
+    );
+
+    let db = state_db(
+        "parameters-queued",
+        Some(Platform {
+            pending_parameters: Some(PendingParameters {
+                creation_fee: Some(25),
+                token: None,
+                queued_by: OPERATOR.to_string(),
+                effective_at: NOW + 90,
+            }),
+            ..platform(false, None)
+        }),
+    );
+    let output = run(&cli(&["--state", db.to_str().unwrap(), "parameters"]), NOW).unwrap();
+    assert!(output.contains(&format!("queued change by {OPERATOR}")));
+    assert!(output.contains(&format!("effective at: {} (in 90s)", NOW + 90)));
+    assert!(output.ends_with("creation fee: 25"));
+}
+
+#[test]
+fn fee_withdrawals_are_checked_against_collected_fees() {
+    let mut state = State {
+        platform: Some(platform(false, None)),
+        ..State::default()
+    };
+    state.fee_balances.insert(contract(2), 30);
+    state
+        .roles
+        .insert(OPERATOR.to_string(), [Role::Treasury].into_iter().collect());
+    let db = save_state("fee-withdrawals", &state);
+    let db = db.to_str().unwrap();
+    let token = contract(2);
+    let withdraw = |amount: &str, source: &str| {
+        run(
+            &cli(&[
+                "--state",
+                db,
+                "withdraw-fees",
+                "--token",
+                &token,
+                "--amount",
+                amount,
+                "--to",
+                ADMIN,
+                "--source",
+                source,
+            ]),
+            NOW,
+        )
+    };
+
+    assert!(matches!(
+        withdraw("31", OPERATOR),
+        Err(CliError::InsufficientFees { balance: 30, .. })
+    ));
+    assert!(matches!(
+        withdraw("0", OPERATOR),
+        Err(CliError::NonPositiveAmount(0))
+    ));
+
+    let output = withdraw("30", OPERATOR).unwrap();
+    let (function, args) = invocation(&output);
+    assert_eq!(function, "withdraw_fees");
+    assert_eq!(args[0], ScVal::Address(OPERATOR.parse().unwrap()));
+    assert_eq!(args[3], ScVal::Address(ADMIN.parse().unwrap()));
+}
+
+#[test]
+fn entity_fees_are_set_and_shown() {
+    let output = run(
+        &cli(&[
+            "set-entity-creation-fee",
+            "--entity",
+            "multisig-pool",
+            "--fee",
+            "30",
+        ]),
+        NOW,
+    )
+    .unwrap();
+    let (function, args) = invocation(&output);
+    assert_eq!(function, "set_entity_creation_fee");
+    assert_eq!(args[1], ScVal::U32(3));
+    let result = run(
+        &cli(&[
+            "set-entity-creation-fee",
+            "--entity",
+            "public-pool",
+            "--fee",
+            "-1",
+        ]),
+        NOW,
+    );
+    assert!(matches!(result, Err(CliError::NegativeFee(-1))));
+
+    let mut state = State {
+        platform: Some(platform(false, None)),
+        ..State::default()
+    };
+    state.entity_creation_fees.insert(FeeEntity::PublicPool, 5);
+    state.pending_entity_fees.insert(
+        FeeEntity::PrivatePool,
+        PendingEntityFee {
+            fee: 20,
+            queued_by: OPERATOR.to_string(),
+            effective_at: NOW + 60,
+        },
+    );
+    let db = save_state("entity-fees", &state);
+    let output = run(&cli(&["--state", db.to_str().unwrap(), "parameters"]), NOW).unwrap();
+    assert!(output.contains("\n  public pool:    5\n"));
+    assert!(output.contains(&format!(
+        "\n  private pool:   0 (queued 20 by {OPERATOR}, effective at {} (in 60s))",
+        NOW + 60
+    )));
+    assert!(output.ends_with("\n  multi-sig pool: 0"));
+}
+
+#[test]
+fn campaign_amendment_rules_are_set() {
+    let output = run(
+        &cli(&[
+            "set-campaign-amendment-rules",
+            "--max-extension",
+            "604800",
+            "--material-goal-change-bps",
+            "500",
+        ]),
+        NOW,
+    )
+    .unwrap();
+    let rules = CampaignAmendmentRules {
+        max_extension: 604_800,
+        single_extension: true,
+        material_goal_change_bps: 500,
+    };
+    assert_eq!(
+        invocation(&output),
+        (
+            "set_campaign_amendment_rules".to_string(),
+            vec![rules.to_sc_val().unwrap()]
+        )
+    );
+
+    let output = run(
+        &cli(&[
+            "set-campaign-amendment-rules",
+            "--max-extension",
+            "604800",
+            "--single-extension",
+            "false",
+            "--material-goal-change-bps",
+            "500",
+        ]),
+        NOW,
+    )
+    .unwrap();
+    let rules = CampaignAmendmentRules {
+        single_extension: false,
+        ..rules
+    };
+    assert_eq!(invocation(&output).1, vec![rules.to_sc_val().unwrap()]);
+
+    let result = run(
+        &cli(&[
+            "set-campaign-amendment-rules",
+            "--max-extension",
+            "0",
+            "--material-goal-change-bps",
+            "10001",
+        ]),
+        NOW,
+    );
+    assert!(matches!(result, Err(CliError::InvalidBps(10_001))));
+}
+
+#[test]
+fn platform_fees_are_set_and_shown() {
+    let output = run(&cli(&["set-platform-fee", "--bps", "250"]), NOW).unwrap();
+    let (function, args) = invocation(&output);
+    assert_eq!(function, "set_platform_fee");
+    assert_eq!(args[1], ScVal::U32(250));
+    let result = run(&cli(&["set-platform-fee", "--bps", "10001"]), NOW);
+    assert!(matches!(result, Err(CliError::InvalidBps(10_001))));
+
+    let output = run(&cli(&["set-pool-platform-fee", "--pool", "3"]), NOW).unwrap();
+    let (function, args) = invocation(&output);
+    assert_eq!(function, "set_pool_platform_fee");
+    assert_eq!(args, vec![ScVal::U64(3), ScVal::Void]);
+
+    let mut state = State {
+        platform: Some(platform(false, None)),
+        ..State::default()
+    };
+    state.platform_fee_bps = 250;
+    state.pending_platform_fee = Some(PendingPlatformFee {
+        bps: 100,
+        queued_by: OPERATOR.to_string(),
+        effective_at: NOW + 60,
+    });
+    state.pool_platform_fees.insert(3, 0);
+    let db = save_state("platform-fees", &state);
+    let db = db.to_str().unwrap();
+    let result = run(
+        &cli(&[
+            "--state",
+            db,
+            "set-pool-platform-fee",
+            "--pool",
+            "4",
+            "--bps",
+            "5",
+        ]),
+        NOW,
+    );
+    assert!(matches!(result, Err(CliError::UnknownPool(4))));
+
+    let output = run(&cli(&["--state", db, "parameters"]), NOW).unwrap();
+    assert!(output.ends_with(&format!(
+        "\nplatform fee: 250 bps (queued 100 by {OPERATOR}, effective at {} (in 60s))\n  pool 3: 0 bps",
+        NOW + 60
+    )));
+}
+
+#[test]
+fn fee_tiers_are_assigned_and_revoked() {
+    let output = run(
+        &cli(&[
+            "assign-fee-tier",
+            "--creator",
+            OPERATOR,
+            "--discount-bps",
+            "5000",
+            "--expires-at",
+            &(NOW + 60).to_string(),
+        ]),
+        NOW,
+    )
+    .unwrap();
+    let (function, args) = invocation(&output);
+    assert_eq!(function, "assign_fee_tier");
+    assert_eq!(args[0], ScVal::Address(ADMIN.parse().unwrap()));
+    assert_eq!(args[1], ScVal::Address(OPERATOR.parse().unwrap()));
+    assert_eq!(args[3], ScVal::U64(NOW + 60));
+
+    let result = run(
+        &cli(&["assign-fee-tier", "--creator", OPERATOR, "--flat-fee", "-1"]),
+        NOW,
+    );
+    assert!(matches!(result, Err(CliError::NegativeFee(-1))));
+    let result = run(
+        &cli(&[
+            "assign-fee-tier",
+            "--creator",
+            OPERATOR,
+            "--waived",
+            "--expires-at",
+            &NOW.to_string(),
+        ]),
+        NOW,
+    );
+    assert!(matches!(result, Err(CliError::ExpiryNotInFuture(NOW))));
+    let argv = [
+        "crowdfunding-admin",
+        "assign-fee-tier",
+        "--creator",
+        OPERATOR,
+        "--waived",
+        "--flat-fee",
+        "5",
+    ];
+    assert!(Cli::try_parse_from(argv).is_err());
+
+    let mut state = State {
+        platform: Some(platform(false, None)),
+        ..State::default()
+    };
+    state.fee_tiers.insert(
+        OPERATOR.to_string(),
+        CreatorFeeTier {
+            tier: FeeTier::Waived,
+            assigned_by: ADMIN.to_string(),
+            expires_at: 0,
+        },
+    );
+    let db = save_state("fee-tiers", &state);
+    let db = db.to_str().unwrap();
+    let output = run(
+        &cli(&["--state", db, "revoke-fee-tier", "--creator", OPERATOR]),
+        NOW,
+    )
+    .unwrap();
+    assert_eq!(invocation(&output).0, "revoke_fee_tier");
+    let result = run(
+        &cli(&["--state", db, "revoke-fee-tier", "--creator", ADMIN]),
+        NOW,
+    );
+    assert!(matches!(result, Err(CliError::NoFeeTier(account)) if account == ADMIN));
+}
+
+#[test]
+fn accepted_tokens_are_managed_and_listed() {
+    let usdc = contract(3);
+    let output = run(
+        &cli(&["set-accepted-token", "--token", &usdc, "--min-amount", "50"]),
+        NOW,
+    )
+    .unwrap();
+    let (function, args) = invocation(&output);
+    assert_eq!(function, "set_accepted_token");
+    assert_eq!(args[1], ScVal::Address(usdc.parse().unwrap()));
+    assert_eq!(args[2], ScVal::I128(Int128Parts { hi: 0, lo: 50 }));
+    let result = run(
+        &cli(&["set-accepted-token", "--token", &usdc, "--min-amount", "-1"]),
+        NOW,
+    );
+    assert!(matches!(result, Err(CliError::NegativeMinAmount(-1))));
+
+    let mut state = State {
+        platform: Some(platform(false, None)),
+        ..State::default()
+    };
+    for (token, symbol) in [(contract(2), "XLM"), (usdc.clone(), "USDC")] {
+        state.accepted_tokens.insert(
+            token,
+            AcceptedToken {
+                symbol: symbol.to_string(),
+                decimals: 7,
+                min_amount: 50,
+            },
+        );
+    }
+    let db = save_state("accepted-tokens", &state);
+    let db = db.to_str().unwrap();
+
+    let output = run(&cli(&["--state", db, "accepted-tokens"]), NOW).unwrap();
+    assert!(output.contains(&format!("{usdc} USDC (7 decimals), minimum 50")));
+    assert_eq!(output.lines().count(), 2);
+
+    let output = run(
+        &cli(&["--state", db, "remove-accepted-token", "--token", &usdc]),
+        NOW,
+    )
+    .unwrap();
+    assert_eq!(invocation(&output).0, "remove_accepted_token");
+    let platform_token = contract(2);
+    let result = run(
+        &cli(&[
+            "--state",
+            db,
+            "remove-accepted-token",
+            "--token",
+            &platform_token,
+        ]),
+        NOW,
+    );
+    assert!(matches!(result, Err(CliError::PlatformToken(_))));
+    let unknown = contract(4);
+    for command in ["remove-accepted-token", "set-crowdfunding-token"] {
+        let result = run(&cli(&["--state", db, command, "--token", &unknown]), NOW);
+        assert!(matches!(result, Err(CliError::TokenNotAccepted(token)) if token == unknown));
+    }
+
+    let output = run(
+        &cli(&[
+            "council",
+            "propose",
+            "remove-accepted-token",
+            "--token",
+            &usdc,
+        ]),
+        NOW,
+    )
+    .unwrap();
+    assert_eq!(invocation(&output).0, "propose_council_action");
+}
@@ -0,0 +1,301 @@
+use std::{marker::PhantomData, str::FromStr};
+
+use stellar_xdr::curr::{
+    HostFunction, InvokeContractArgs, InvokeHostFunctionOp, Operation, OperationBody, ScAddress,
+    ScSymbol, ScVal, VecM,
+};
+
+use crate::{
+    error::ClientError,
+    scval::{FromScVal, ToScVal},
+    types::{
+        AcceptedToken, CampaignAmendmentRules, CampaignAmendments, CampaignDetails, CampaignId,
+        CampaignMetadata, ContributionQuote, CouncilAction, CouncilProposal, CreatorFeeTier,
+        EmergencyWithdrawal, FeaturePause, FeeEntity, FeeTier, MigrationCursor, MultiSigConfig,
+        PendingEntityFee, PendingParameters, PendingPlatformFee, PendingUpgrade, PoolConfig,
+        PoolMetadata, PoolState, Role,
+    },
+};
+
+/// Name and argument names of one contract entrypoint.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FunctionSpec {
+    pub name: &'static str,
+    pub inputs: &'static [&'static str],
+}
+
+/// An encoded invocation of one entrypoint returning `T`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Call<T> {
+    function: &'static str,
+    args: InvokeContractArgs,
+    _returns: PhantomData<fn() -> T>,
+}
+
+impl<T> Call<T> {
+    pub fn function(&self) -> &'static str {
+        self.function
+    }
+
+    pub fn args(&self) -> &InvokeContractArgs {
+        &self.args
+    }
+
+    pub fn host_function(&self) -> HostFunction {
+        HostFunction::InvokeContract(self.args.clone())
+    }
+
+    /// An `InvokeHostFunction` operation with no authorization entries.
+    pub fn operation(&self) -> Operation {
+        Operation {
+            source_account: None,
+            body: OperationBody::InvokeHostFunction(InvokeHostFunctionOp {
+                host_function: self.host_function(),
+                auth: VecM::default(),
+            }),
+        }
+    }
+}
+
+impl<T: FromScVal> Call<T> {
+    /// Decode the value returned by a successful invocation.
+    pub fn decode(&self, value: &ScVal) -> Result<T, ClientError> {
+        T::from_sc_val(value)
+    }
+}
+
+/// Builds calls against one deployed contract.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CrowdfundingClient {
+    contract: ScAddress,
+}
+
+impl CrowdfundingClient {
+    pub fn new(contract: ScAddress) -> Self {
+        Self { contract }
+    }
+
+    /// A client for the contract with the given `C...` strkey.
+    pub fn from_strkey(contract: &str) -> Result<Self, ClientError> {
+        match ScAddress::from_str(contract)? {
+            address @ ScAddress::Contract(_) => Ok(Self::new(address)),
+            _ => Err(ClientError::UnexpectedValue {
+                expected: "contract address",
+            }),
+        }
+    }
+
+    pub fn contract(&self) -> &ScAddress {
+        &self.contract
+    }
+
+    fn call<T>(&self, function: &'static str, args: Vec<ScVal>) -> Result<Call<T>, ClientError> {
+        Ok(Call {
+            function,
+            args: InvokeContractArgs {
+                contract_address: self.contract.clone(),
+                function_name: ScSymbol(function.try_into()?),
+                args: args.try_into()?,
+            },
+            _returns: PhantomData,
+        })
+    }
+}
+
+macro_rules! contract_functions {
+    ($($(#[$doc:meta])* fn $name:ident($($arg:ident: $ty:ty),* $(,)?) -> $ret:ty;)*) => {
+        impl CrowdfundingClient {
+            $(
+                $(#[$doc])*
+                #[allow(clippy::too_many_arguments)]
+                pub fn $name(&self, $($arg: $ty),*) -> Result<Call<$ret>, ClientError> {
+                    self.call(stringify!($name), vec![$($arg.to_sc_val()?),*])
+                }
+            )*
+        }
+
+        /// Every entrypoint wrapped by [`CrowdfundingClient`].
+        pub const FUNCTIONS: &[FunctionSpec] = &[$(FunctionSpec {
+            name: stringify!($name),
+            inputs: &[$(stringify!($arg)),*],
+        },)*];
+    };
+}
+
+contract_functions! {
+    /// Fails with `InvalidFee` if the creation fee has risen above `max_fee`,
+    /// or `InvalidToken` if `token_address` is not an accepted token.
+    fn create_campaign(
+        id: CampaignId,
+        title: String,
+        creator: ScAddress,
+        goal: i128,
+        deadline: u64,
+        token_address: ScAddress,
+        max_fee: i128,
+    ) -> ();
+    fn get_campaign(id: CampaignId) -> CampaignDetails;
+    fn get_all_campaigns() -> Vec<CampaignId>;
+    fn get_donor_count(campaign_id: CampaignId) -> u32;
+    fn get_campaign_balance(campaign_id: CampaignId) -> i128;
+    fn get_total_raised(campaign_id: CampaignId) -> i128;
+    fn get_contribution(campaign_id: CampaignId, contributor: ScAddress) -> i128;
+    fn get_campaign_goal(campaign_id: CampaignId) -> i128;
+    fn is_campaign_completed(campaign_id: CampaignId) -> bool;
+    fn donate(campaign_id: CampaignId, donor: ScAddress, asset: ScAddress, amount: i128) -> ();
+    /// The campaign creator, or an account holding `PoolModerator`, may cancel.
+    fn cancel_campaign(campaign_id: CampaignId, caller: ScAddress, reason: String) -> ();
+    /// Available once the campaign is cancelled, or to donors whose last
+    /// donation predates a material amendment.
+    fn refund_campaign(campaign_id: CampaignId, contributor: ScAddress) -> ();
+    /// Fails with `InvalidDeadline` where the amendment rules forbid it.
+    fn extend_campaign_deadline(campaign_id: CampaignId, new_deadline: u64) -> ();
+    fn update_campaign_goal(campaign_id: CampaignId, new_goal: i128) -> ();
+    /// After the first donation the title is fixed and metadata may only be
+    /// extended; other changes fail with `InvalidTitle` or `InvalidMetadata`.
+    fn update_campaign_details(
+        campaign_id: CampaignId,
+        title: String,
+        metadata: CampaignMetadata,
+    ) -> ();
+    fn get_campaign_amendments(campaign_id: CampaignId) -> CampaignAmendments;
+    fn set_campaign_amendment_rules(rules: CampaignAmendmentRules) -> ();
+    fn get_campaign_amendment_rules() -> CampaignAmendmentRules;
+    /// The creator changes only once `new_owner` calls
+    /// `accept_campaign_ownership`.
+    fn transfer_campaign_ownership(campaign_id: CampaignId, new_owner: ScAddress) -> ();
+    fn accept_campaign_ownership(campaign_id: CampaignId) -> ();
+    fn cancel_campaign_transfer(campaign_id: CampaignId) -> ();
+    fn get_pending_campaign_owner(campaign_id: CampaignId) -> Option<ScAddress>;
+    fn create_pool(creator: ScAddress, config: PoolConfig, max_fee: i128) -> u64;
+    fn save_pool(
+        name: String,
+        metadata: PoolMetadata,
+        creator: ScAddress,
+        target_amount: i128,
+        deadline: u64,
+        required_signatures: Option<u32>,
+        signers: Option<Vec<ScAddress>>,
+        max_fee: i128,
+    ) -> u64;
+    fn get_pool(pool_id: u64) -> Option<PoolConfig>;
+    /// Returns `(description, external_url, image_hash)`.
+    fn get_pool_metadata(pool_id: u64) -> (String, String, String);
+    fn get_pool_creator(pool_id: u64) -> Option<ScAddress>;
+    /// Fails with `Unauthorized` for pools without a recorded creator.
+    fn transfer_pool_ownership(pool_id: u64, new_owner: ScAddress) -> ();
+    fn accept_pool_ownership(pool_id: u64) -> ();
+    fn cancel_pool_transfer(pool_id: u64) -> ();
+    fn get_pending_pool_owner(pool_id: u64) -> Option<ScAddress>;
+    fn update_pool_state(pool_id: u64, new_state: PoolState) -> ();
+    fn set_crowdfunding_token(caller: ScAddress, token: ScAddress) -> ();
+    fn get_crowdfunding_token() -> ScAddress;
+    fn set_creation_fee(caller: ScAddress, fee: i128) -> ();
+    fn get_creation_fee() -> i128;
+    /// Changes queued by the fee and token setters that are not yet in effect.
+    fn get_pending_parameters() -> Option<PendingParameters>;
+    /// Pool fee changes are queued like `set_creation_fee`, which the
+    /// `Campaign` entity goes through.
+    fn set_entity_creation_fee(caller: ScAddress, entity: FeeEntity, fee: i128) -> ();
+    fn get_entity_creation_fee(entity: FeeEntity) -> i128;
+    fn get_pending_entity_creation_fee(entity: FeeEntity) -> Option<PendingEntityFee>;
+    /// `bps` is in basis points of every donation and contribution; changes
+    /// are queued like `set_creation_fee`.
+    fn set_platform_fee(caller: ScAddress, bps: u32) -> ();
+    fn get_platform_fee() -> u32;
+    fn get_pending_platform_fee() -> Option<PendingPlatformFee>;
+    /// Takes effect immediately; `None` returns the pool to the platform rate.
+    fn set_pool_platform_fee(pool_id: u64, bps: Option<u32>) -> ();
+    fn get_pool_platform_fee(pool_id: u64) -> u32;
+    fn quote_contribution(pool_id: u64, amount: i128) -> ContributionQuote;
+    /// An `expires_at` of 0 never expires.
+    fn assign_fee_tier(caller: ScAddress, creator: ScAddress, tier: FeeTier, expires_at: u64) -> ();
+    fn revoke_fee_tier(caller: ScAddress, creator: ScAddress) -> ();
+    /// `None` once the tier has lapsed.
+    fn get_fee_tier(creator: ScAddress) -> Option<CreatorFeeTier>;
+    /// Reads the token's symbol and decimals; setting a registered token
+    /// again updates its minimum.
+    fn set_accepted_token(caller: ScAddress, token: ScAddress, min_amount: i128) -> ();
+    fn remove_accepted_token(caller: ScAddress, token: ScAddress) -> ();
+    fn get_accepted_token(token: ScAddress) -> Option<AcceptedToken>;
+    fn get_accepted_tokens() -> Vec<ScAddress>;
+    fn initialize(admin: ScAddress, token: ScAddress, creation_fee: i128) -> ();
+    fn pause(caller: ScAddress) -> ();
+    fn unpause(caller: ScAddress) -> ();
+    fn is_paused() -> bool;
+    fn get_paused_at() -> Option<u64>;
+    /// `features` is a mask of `PAUSE_*` bits; `duration` must be positive and
+    /// at most the maximum pause duration.
+    fn pause_features(caller: ScAddress, features: u32, reason: u32, duration: u64) -> ();
+    fn unpause_features(caller: ScAddress, features: u32) -> ();
+    fn get_paused_features() -> u32;
+    fn get_feature_pause(feature: u32) -> Option<FeaturePause>;
+    /// `duration` must lie within `MIN_MAX_PAUSE_DURATION..=MAX_MAX_PAUSE_DURATION`.
+    fn set_max_pause_duration(duration: u64) -> ();
+    fn get_max_pause_duration() -> u64;
+    fn is_escape_hatch_open() -> bool;
+    /// Only succeeds once the contract has been paused for the max pause duration.
+    fn escape_pool_contribution(pool_id: u64, contributor: ScAddress) -> ();
+    fn escape_campaign_contribution(campaign_id: CampaignId, contributor: ScAddress) -> ();
+    fn get_admin() -> ScAddress;
+    fn get_pending_admin() -> Option<ScAddress>;
+    fn propose_admin(new_admin: ScAddress) -> ();
+    /// Must be authorized by the proposed admin.
+    fn accept_admin() -> ();
+    fn cancel_admin_proposal() -> ();
+    fn grant_role(role: Role, account: ScAddress) -> ();
+    fn revoke_role(role: Role, account: ScAddress) -> ();
+    fn has_role(role: Role, account: ScAddress) -> bool;
+    fn get_roles(account: ScAddress) -> Vec<Role>;
+    fn set_council(config: MultiSigConfig) -> ();
+    fn get_council() -> Option<MultiSigConfig>;
+    /// Returns the proposal id; the proposer's approval is recorded with it.
+    fn propose_council_action(proposer: ScAddress, action: CouncilAction) -> u64;
+    fn approve_council_action(signer: ScAddress, proposal_id: u64) -> ();
+    fn execute_council_action(executor: ScAddress, proposal_id: u64) -> ();
+    fn get_council_proposal(proposal_id: u64) -> Option<CouncilProposal>;
+    fn version() -> u32;
+    /// `delay` must lie within `MIN_UPGRADE_TIMELOCK..=MAX_UPGRADE_TIMELOCK`.
+    fn set_upgrade_timelock(delay: u64) -> ();
+    fn get_upgrade_timelock() -> u64;
+    fn propose_upgrade(wasm_hash: [u8; 32]) -> ();
+    /// Only succeeds once the pending upgrade's `executable_at` has passed.
+    fn execute_upgrade() -> ();
+    fn cancel_upgrade() -> ();
+    fn get_pending_upgrade() -> Option<PendingUpgrade>;
+    fn get_schema_version() -> u32;
+    fn get_migration_cursor() -> Option<MigrationCursor>;
+    /// Returns `true` once every record has been rewritten; pools with
+    /// contributors wait for `migrate_pool_contributions`.
+    fn migrate(batch_size: u32) -> bool;
+    /// `contributors` must account for the pool's whole `total_raised`.
+    fn migrate_pool_contributions(pool_id: u64, contributors: Vec<ScAddress>) -> ();
+    fn contribute(
+        pool_id: u64,
+        contributor: ScAddress,
+        asset: ScAddress,
+        amount: i128,
+        is_private: bool,
+    ) -> ();
+    fn refund(pool_id: u64, contributor: ScAddress) -> ();
+    /// `recipient` defaults to `caller`.
+    fn request_emergency_withdraw(
+        caller: ScAddress,
+        token: ScAddress,
+        amount: i128,
+        recipient: Option<ScAddress>,
+    ) -> ();
+    fn execute_emergency_withdraw(caller: ScAddress) -> ();
+    fn cancel_emergency_withdraw(caller: ScAddress) -> ();
+    fn get_emergency_withdrawal() -> Option<EmergencyWithdrawal>;
+    /// `delay` must lie within
+    /// `MIN_EMERGENCY_WITHDRAW_DELAY..=MAX_EMERGENCY_WITHDRAW_DELAY`.
+    fn set_emergency_withdraw_delay(delay: u64) -> ();
+    fn get_emergency_withdraw_delay() -> u64;
+    fn get_escrowed_balance(token: ScAddress) -> i128;
+    fn get_withdrawable_balance(token: ScAddress) -> i128;
+    fn withdraw_fees(caller: ScAddress, token: ScAddress, amount: i128, to: ScAddress) -> ();
+    fn get_fee_balance(token: ScAddress) -> i128;
+    fn close_pool(pool_id: u64, caller: ScAddress) -> ();
+    fn is_closed(pool_id: u64) -> bool;
+}
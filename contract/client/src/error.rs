@@ -0,0 +1,136 @@
+use std::fmt;
+
+use stellar_xdr::curr::ScError;
+use thiserror::Error;
+
+macro_rules! contract_errors {
+    ($($name:ident = $code:literal,)*) => {
+        /// Mirror of the contract's `CrowdfundingError`, keyed by its code.
+        #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+        #[repr(u32)]
+        pub enum ContractError {
+            $($name = $code,)*
+        }
+
+        impl ContractError {
+            /// Every error the contract can return, in code order.
+            pub const ALL: &'static [ContractError] = &[$(ContractError::$name,)*];
+
+            pub fn from_code(code: u32) -> Option<Self> {
+                match code {
+                    $($code => Some(Self::$name),)*
+                    _ => None,
+                }
+            }
+
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(Self::$name => stringify!($name),)*
+                }
+            }
+        }
+    };
+}
+
+contract_errors! {
+    CampaignNotFound = 1,
+    InvalidTitle = 2,
+    InvalidGoal = 3,
+    InvalidDeadline = 4,
+    CampaignAlreadyExists = 5,
+    PoolNotFound = 6,
+    InvalidPoolName = 7,
+    InvalidPoolTarget = 8,
+    InvalidPoolDeadline = 9,
+    PoolAlreadyExists = 10,
+    InvalidPoolState = 11,
+    ContractPaused = 12,
+    ContractAlreadyPaused = 13,
+    ContractAlreadyUnpaused = 14,
+    ContractAlreadyInitialized = 15,
+    InvalidAmount = 16,
+    TokenTransferFailed = 17,
+    InvalidMultiSigConfig = 18,
+    NotAuthorizedSigner = 19,
+    AlreadyApproved = 20,
+    DisbursementNotFound = 21,
+    DisbursementAlreadyExecuted = 22,
+    InsufficientApprovals = 23,
+    SignerAlreadyExists = 24,
+    SignerNotFound = 25,
+    CannotRemoveLastSigner = 26,
+    InvalidSignerCount = 27,
+    NotInitialized = 28,
+    Unauthorized = 29,
+    InvalidMetadata = 30,
+    CampaignExpired = 31,
+    InvalidDonationAmount = 32,
+    CampaignAlreadyFunded = 33,
+    EmergencyWithdrawalAlreadyRequested = 34,
+    EmergencyWithdrawalNotRequested = 35,
+    EmergencyWithdrawalPeriodNotPassed = 36,
+    InvalidToken = 37,
+    InvalidFee = 38,
+    InsufficientBalance = 39,
+    RefundNotAvailable = 40,
+    PoolNotExpired = 41,
+    PoolAlreadyDisbursed = 42,
+    NoContributionToRefund = 43,
+    RefundGracePeriodNotPassed = 44,
+    PoolAlreadyClosed = 45,
+    PoolNotDisbursedOrRefunded = 46,
+    ArithmeticOverflow = 47,
+    NotFound = 48,
+    AlreadyExists = 49,
+    TimelockNotPassed = 50,
+}
+
+impl ContractError {
+    pub fn code(self) -> u32 {
+        self as u32
+    }
+}
+
+impl TryFrom<u32> for ContractError {
+    type Error = u32;
+
+    fn try_from(code: u32) -> Result<Self, Self::Error> {
+        Self::from_code(code).ok_or(code)
+    }
+}
+
+impl fmt::Display for ContractError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (contract error {})", self.name(), self.code())
+    }
+}
+
+impl std::error::Error for ContractError {}
+
+#[derive(Debug, Error)]
+pub enum ClientError {
+    #[error(transparent)]
+    Contract(#[from] ContractError),
+    #[error("contract returned unknown error code {0}")]
+    UnknownContractError(u32),
+    #[error("invocation failed: {0:?}")]
+    Host(ScError),
+    #[error("expected {expected} value")]
+    UnexpectedValue { expected: &'static str },
+    #[error("missing struct field `{0}`")]
+    MissingField(&'static str),
+    #[error(transparent)]
+    Xdr(#[from] stellar_xdr::curr::Error),
+}
+
+impl From<ScError> for ClientError {
+    fn from(error: ScError) -> Self {
+        match error {
+            ScError::Contract(code) => match ContractError::from_code(code) {
+                Some(error) => Self::Contract(error),
+                None => Self::UnknownContractError(code),
+            },
+            other => Self::Host(other),
+        }
+    }
+}
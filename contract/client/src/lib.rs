@@ -0,0 +1,29 @@
+//! Typed off-chain client for `CrowdfundingContract`.
+//!
+//! [`CrowdfundingClient`] exposes one method per `CrowdfundingTrait`
+//! entrypoint. Each method encodes its arguments into a [`Call`], which can
+//! decode the entrypoint's return value and be wrapped into an unsigned
+//! transaction envelope with [`TransactionBuilder`] without touching the
+//! network. Contract failures decode into [`ContractError`].
+//!
+//! Envelopes produced here carry no Soroban resource data or authorization
+//! entries; run them through RPC `simulateTransaction` before signing.
+pub mod client;
+pub mod error;
+pub mod scval;
+pub mod transaction;
+pub mod types;
+
+pub use client::{Call, CrowdfundingClient, FunctionSpec, FUNCTIONS};
+pub use error::{ClientError, ContractError};
+pub use scval::{FromScVal, ToScVal};
+pub use transaction::TransactionBuilder;
+pub use types::{
+    AcceptedToken, CampaignAmendmentRules, CampaignAmendments, CampaignDetails, CampaignId,
+    CampaignMetadata, CampaignStatus, ContributionQuote, CouncilAction, CouncilProposal,
+    CreatorFeeTier, EmergencyWithdrawal, FeaturePause, FeeEntity, FeeTier, MigrationCursor,
+    MultiSigConfig, PendingEntityFee, PendingParameters, PendingPlatformFee, PendingUpgrade,
+    PoolConfig, PoolMetadata, PoolState, Role,
+};
+
+pub use stellar_xdr::curr as xdr;
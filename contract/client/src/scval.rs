@@ -0,0 +1,256 @@
+//! Conversions between Rust values and the `ScVal` encoding used by the
+//! contract's spec.
+use stellar_xdr::curr::{
+    Int128Parts, ScAddress, ScBytes, ScMap, ScMapEntry, ScString, ScSymbol, ScVal, ScVec,
+};
+
+use crate::error::ClientError;
+
+pub trait ToScVal {
+    fn to_sc_val(&self) -> Result<ScVal, ClientError>;
+}
+
+pub trait FromScVal: Sized {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError>;
+}
+
+fn unexpected<T>(expected: &'static str) -> Result<T, ClientError> {
+    Err(ClientError::UnexpectedValue { expected })
+}
+
+impl ToScVal for () {
+    fn to_sc_val(&self) -> Result<ScVal, ClientError> {
+        Ok(ScVal::Void)
+    }
+}
+
+impl FromScVal for () {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError> {
+        match value {
+            ScVal::Void => Ok(()),
+            _ => unexpected("void"),
+        }
+    }
+}
+
+impl ToScVal for bool {
+    fn to_sc_val(&self) -> Result<ScVal, ClientError> {
+        Ok(ScVal::Bool(*self))
+    }
+}
+
+impl FromScVal for bool {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError> {
+        match value {
+            ScVal::Bool(v) => Ok(*v),
+            _ => unexpected("bool"),
+        }
+    }
+}
+
+impl ToScVal for u32 {
+    fn to_sc_val(&self) -> Result<ScVal, ClientError> {
+        Ok(ScVal::U32(*self))
+    }
+}
+
+impl FromScVal for u32 {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError> {
+        match value {
+            ScVal::U32(v) => Ok(*v),
+            _ => unexpected("u32"),
+        }
+    }
+}
+
+impl ToScVal for u64 {
+    fn to_sc_val(&self) -> Result<ScVal, ClientError> {
+        Ok(ScVal::U64(*self))
+    }
+}
+
+impl FromScVal for u64 {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError> {
+        match value {
+            ScVal::U64(v) => Ok(*v),
+            _ => unexpected("u64"),
+        }
+    }
+}
+
+impl ToScVal for i128 {
+    fn to_sc_val(&self) -> Result<ScVal, ClientError> {
+        Ok(ScVal::I128(Int128Parts {
+            hi: (*self >> 64) as i64,
+            lo: *self as u64,
+        }))
+    }
+}
+
+impl FromScVal for i128 {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError> {
+        match value {
+            ScVal::I128(parts) => Ok(((parts.hi as i128) << 64) | parts.lo as i128),
+            _ => unexpected("i128"),
+        }
+    }
+}
+
+impl ToScVal for String {
+    fn to_sc_val(&self) -> Result<ScVal, ClientError> {
+        Ok(ScVal::String(ScString(self.as_str().try_into()?)))
+    }
+}
+
+impl FromScVal for String {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError> {
+        match value {
+            ScVal::String(s) => Ok(s.to_utf8_string_lossy()),
+            _ => unexpected("string"),
+        }
+    }
+}
+
+impl ToScVal for [u8; 32] {
+    fn to_sc_val(&self) -> Result<ScVal, ClientError> {
+        Ok(ScVal::Bytes(ScBytes(self.to_vec().try_into()?)))
+    }
+}
+
+impl FromScVal for [u8; 32] {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError> {
+        match value {
+            ScVal::Bytes(bytes) => bytes
+                .as_slice()
+                .try_into()
+                .or_else(|_| unexpected("32 bytes")),
+            _ => unexpected("bytes"),
+        }
+    }
+}
+
+impl ToScVal for ScAddress {
+    fn to_sc_val(&self) -> Result<ScVal, ClientError> {
+        Ok(ScVal::Address(self.clone()))
+    }
+}
+
+impl FromScVal for ScAddress {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError> {
+        match value {
+            ScVal::Address(address) => Ok(address.clone()),
+            _ => unexpected("address"),
+        }
+    }
+}
+
+/// `None` is encoded as `Void`, `Some(v)` as `v` itself.
+impl<T: ToScVal> ToScVal for Option<T> {
+    fn to_sc_val(&self) -> Result<ScVal, ClientError> {
+        match self {
+            Some(value) => value.to_sc_val(),
+            None => Ok(ScVal::Void),
+        }
+    }
+}
+
+impl<T: FromScVal> FromScVal for Option<T> {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError> {
+        match value {
+            ScVal::Void => Ok(None),
+            other => T::from_sc_val(other).map(Some),
+        }
+    }
+}
+
+impl<T: ToScVal> ToScVal for Vec<T> {
+    fn to_sc_val(&self) -> Result<ScVal, ClientError> {
+        let items = self
+            .iter()
+            .map(ToScVal::to_sc_val)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(ScVal::Vec(Some(ScVec(items.try_into()?))))
+    }
+}
+
+impl<T: FromScVal> FromScVal for Vec<T> {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError> {
+        match value {
+            ScVal::Vec(Some(items)) => items.iter().map(T::from_sc_val).collect(),
+            _ => unexpected("vec"),
+        }
+    }
+}
+
+impl<A: FromScVal, B: FromScVal, C: FromScVal> FromScVal for (A, B, C) {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError> {
+        match value {
+            ScVal::Vec(Some(items)) if items.len() == 3 => Ok((
+                A::from_sc_val(&items[0])?,
+                B::from_sc_val(&items[1])?,
+                C::from_sc_val(&items[2])?,
+            )),
+            _ => unexpected("3-tuple"),
+        }
+    }
+}
+
+/// Encode a `#[contracttype]` struct: a map keyed by field name, sorted.
+pub(crate) fn struct_to_sc_val(
+    mut fields: Vec<(&'static str, ScVal)>,
+) -> Result<ScVal, ClientError> {
+    fields.sort_by(|a, b| a.0.cmp(b.0));
+    let entries = fields
+        .into_iter()
+        .map(|(key, val)| {
+            Ok(ScMapEntry {
+                key: ScVal::Symbol(ScSymbol(key.try_into()?)),
+                val,
+            })
+        })
+        .collect::<Result<Vec<_>, ClientError>>()?;
+    Ok(ScVal::Map(Some(ScMap(entries.try_into()?))))
+}
+
+/// Encode a `#[contracttype]` enum case: a vec of the case name followed by
+/// its fields.
+pub(crate) fn union_to_sc_val(
+    case: &'static str,
+    fields: Vec<ScVal>,
+) -> Result<ScVal, ClientError> {
+    let mut items = vec![ScVal::Symbol(ScSymbol(case.try_into()?))];
+    items.extend(fields);
+    Ok(ScVal::Vec(Some(ScVec(items.try_into()?))))
+}
+
+/// Split an encoded `#[contracttype]` enum case into its name and fields.
+pub(crate) fn union_case(value: &ScVal) -> Result<(String, &[ScVal]), ClientError> {
+    match value {
+        ScVal::Vec(Some(items)) => match items.split_first() {
+            Some((ScVal::Symbol(case), fields)) => Ok((case.to_utf8_string_lossy(), fields)),
+            _ => unexpected("enum case"),
+        },
+        _ => unexpected("enum case"),
+    }
+}
+
+/// Field accessor over an encoded `#[contracttype]` struct.
+pub(crate) struct StructFields<'a>(&'a ScMap);
+
+impl<'a> StructFields<'a> {
+    pub(crate) fn new(value: &'a ScVal) -> Result<Self, ClientError> {
+        match value {
+            ScVal::Map(Some(map)) => Ok(Self(map)),
+            _ => unexpected("struct"),
+        }
+    }
+
+    pub(crate) fn get<T: FromScVal>(&self, field: &'static str) -> Result<T, ClientError> {
+        let entry = self
+            .0
+            .iter()
+            .find(|entry| matches!(&entry.key, ScVal::Symbol(key) if key.as_slice() == field.as_bytes()))
+            .ok_or(ClientError::MissingField(field))?;
+        T::from_sc_val(&entry.val)
+    }
+}
@@ -0,0 +1,75 @@
+use std::str::FromStr;
+
+use stellar_xdr::curr::{
+    Limits, Memo, MuxedAccount, Preconditions, SequenceNumber, TimeBounds, TimePoint, Transaction,
+    TransactionEnvelope, TransactionExt, TransactionV1Envelope, VecM, WriteXdr,
+};
+
+use crate::{client::Call, error::ClientError};
+
+/// Wraps a [`Call`] into an unsigned transaction envelope.
+///
+/// The envelope has no Soroban resource data or authorization entries; it is
+/// meant to be passed to RPC `simulateTransaction`, which fills both in, and
+/// then signed by the source account.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransactionBuilder {
+    source: MuxedAccount,
+    sequence: i64,
+    fee: u32,
+    time_bounds: Option<TimeBounds>,
+}
+
+impl TransactionBuilder {
+    /// Inclusion fee used unless [`TransactionBuilder::fee`] is called.
+    pub const DEFAULT_FEE: u32 = 100;
+
+    /// `source` is a `G...` or `M...` strkey and `sequence` the sequence
+    /// number the transaction will use (the account's current one plus one).
+    pub fn new(source: &str, sequence: i64) -> Result<Self, ClientError> {
+        Ok(Self {
+            source: MuxedAccount::from_str(source)?,
+            sequence,
+            fee: Self::DEFAULT_FEE,
+            time_bounds: None,
+        })
+    }
+
+    pub fn fee(mut self, fee: u32) -> Self {
+        self.fee = fee;
+        self
+    }
+
+    /// Restrict validity to `[min_time, max_time]`; `max_time` 0 means no upper bound.
+    pub fn time_bounds(mut self, min_time: u64, max_time: u64) -> Self {
+        self.time_bounds = Some(TimeBounds {
+            min_time: TimePoint(min_time),
+            max_time: TimePoint(max_time),
+        });
+        self
+    }
+
+    pub fn build<T>(&self, call: &Call<T>) -> Result<TransactionEnvelope, ClientError> {
+        let cond = match &self.time_bounds {
+            Some(bounds) => Preconditions::Time(bounds.clone()),
+            None => Preconditions::None,
+        };
+        Ok(TransactionEnvelope::Tx(TransactionV1Envelope {
+            tx: Transaction {
+                source_account: self.source.clone(),
+                fee: self.fee,
+                seq_num: SequenceNumber(self.sequence),
+                cond,
+                memo: Memo::None,
+                operations: vec![call.operation()].try_into()?,
+                ext: TransactionExt::V0,
+            },
+            signatures: VecM::default(),
+        }))
+    }
+
+    /// The unsigned envelope as base64 XDR.
+    pub fn build_base64<T>(&self, call: &Call<T>) -> Result<String, ClientError> {
+        Ok(self.build(call)?.to_xdr_base64(Limits::none())?)
+    }
+}
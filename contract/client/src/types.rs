@@ -0,0 +1,961 @@
+//! Off-chain mirrors of the contract's `#[contracttype]` values.
+use stellar_xdr::curr::{ScAddress, ScVal};
+
+use crate::{
+    error::ClientError,
+    scval::{struct_to_sc_val, union_case, union_to_sc_val, FromScVal, StructFields, ToScVal},
+};
+
+/// Identifier of a campaign, as passed to `create_campaign`.
+pub type CampaignId = [u8; 32];
+
+/// Feature bits accepted by `pause_features`, mirroring the contract's
+/// `PAUSE_*` constants.
+pub const PAUSE_CAMPAIGN_CREATION: u32 = 1 << 0;
+pub const PAUSE_DONATIONS: u32 = 1 << 1;
+pub const PAUSE_POOL_CREATION: u32 = 1 << 2;
+pub const PAUSE_CONTRIBUTIONS: u32 = 1 << 3;
+pub const PAUSE_REFUNDS: u32 = 1 << 4;
+pub const PAUSE_POOL_STATE_UPDATES: u32 = 1 << 5;
+pub const PAUSE_ALL_FEATURES: u32 = (1 << 6) - 1;
+
+/// Bounds on the upgrade timelock, mirroring the contract's
+/// `MIN_UPGRADE_TIMELOCK` and `MAX_UPGRADE_TIMELOCK`.
+pub const MIN_UPGRADE_TIMELOCK: u64 = 24 * 60 * 60;
+pub const MAX_UPGRADE_TIMELOCK: u64 = 30 * 24 * 60 * 60;
+
+/// Bounds on the emergency withdrawal delay, mirroring the contract's
+/// `MIN_EMERGENCY_WITHDRAW_DELAY` and `MAX_EMERGENCY_WITHDRAW_DELAY`.
+pub const MIN_EMERGENCY_WITHDRAW_DELAY: u64 = 60 * 60;
+pub const MAX_EMERGENCY_WITHDRAW_DELAY: u64 = 30 * 24 * 60 * 60;
+
+/// How long the contract or a feature may stay paused unless the admin
+/// configures otherwise, mirroring the contract's `DEFAULT_MAX_PAUSE_DURATION`.
+pub const DEFAULT_MAX_PAUSE_DURATION: u64 = 30 * 24 * 60 * 60;
+/// Bounds on the configurable maximum pause duration, mirroring the
+/// contract's `MIN_MAX_PAUSE_DURATION` and `MAX_MAX_PAUSE_DURATION`.
+pub const MIN_MAX_PAUSE_DURATION: u64 = 24 * 60 * 60;
+pub const MAX_MAX_PAUSE_DURATION: u64 = 90 * 24 * 60 * 60;
+
+/// Platform fee rates are in basis points of this denominator, which is
+/// also the highest rate the contract accepts.
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+/// Limits on how creators may amend a campaign after creating it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CampaignAmendmentRules {
+    pub max_extension: u64,
+    pub single_extension: bool,
+    pub material_goal_change_bps: u32,
+}
+
+impl ToScVal for CampaignAmendmentRules {
+    fn to_sc_val(&self) -> Result<ScVal, ClientError> {
+        struct_to_sc_val(vec![
+            ("max_extension", self.max_extension.to_sc_val()?),
+            ("single_extension", self.single_extension.to_sc_val()?),
+            (
+                "material_goal_change_bps",
+                self.material_goal_change_bps.to_sc_val()?,
+            ),
+        ])
+    }
+}
+
+impl FromScVal for CampaignAmendmentRules {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError> {
+        let fields = StructFields::new(value)?;
+        Ok(Self {
+            max_extension: fields.get("max_extension")?,
+            single_extension: fields.get("single_extension")?,
+            material_goal_change_bps: fields.get("material_goal_change_bps")?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CampaignAmendments {
+    pub extensions: u32,
+    pub extended_by: u64,
+    pub revision: u32,
+}
+
+impl ToScVal for CampaignAmendments {
+    fn to_sc_val(&self) -> Result<ScVal, ClientError> {
+        struct_to_sc_val(vec![
+            ("extensions", self.extensions.to_sc_val()?),
+            ("extended_by", self.extended_by.to_sc_val()?),
+            ("revision", self.revision.to_sc_val()?),
+        ])
+    }
+}
+
+impl FromScVal for CampaignAmendments {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError> {
+        let fields = StructFields::new(value)?;
+        Ok(Self {
+            extensions: fields.get("extensions")?,
+            extended_by: fields.get("extended_by")?,
+            revision: fields.get("revision")?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CampaignDetails {
+    pub id: CampaignId,
+    pub title: String,
+    pub creator: ScAddress,
+    pub goal: i128,
+    pub deadline: u64,
+    pub total_raised: i128,
+    pub token_address: ScAddress,
+    pub status: CampaignStatus,
+    pub metadata: CampaignMetadata,
+}
+
+impl ToScVal for CampaignDetails {
+    fn to_sc_val(&self) -> Result<ScVal, ClientError> {
+        struct_to_sc_val(vec![
+            ("id", self.id.to_sc_val()?),
+            ("title", self.title.to_sc_val()?),
+            ("creator", self.creator.to_sc_val()?),
+            ("goal", self.goal.to_sc_val()?),
+            ("deadline", self.deadline.to_sc_val()?),
+            ("total_raised", self.total_raised.to_sc_val()?),
+            ("token_address", self.token_address.to_sc_val()?),
+            ("status", self.status.to_sc_val()?),
+            ("metadata", self.metadata.to_sc_val()?),
+        ])
+    }
+}
+
+impl FromScVal for CampaignDetails {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError> {
+        let fields = StructFields::new(value)?;
+        Ok(Self {
+            id: fields.get("id")?,
+            title: fields.get("title")?,
+            creator: fields.get("creator")?,
+            goal: fields.get("goal")?,
+            deadline: fields.get("deadline")?,
+            total_raised: fields.get("total_raised")?,
+            token_address: fields.get("token_address")?,
+            status: fields.get("status")?,
+            metadata: fields.get("metadata")?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CampaignMetadata {
+    pub description: String,
+    pub external_url: String,
+    pub image_hash: String,
+}
+
+impl ToScVal for CampaignMetadata {
+    fn to_sc_val(&self) -> Result<ScVal, ClientError> {
+        struct_to_sc_val(vec![
+            ("description", self.description.to_sc_val()?),
+            ("external_url", self.external_url.to_sc_val()?),
+            ("image_hash", self.image_hash.to_sc_val()?),
+        ])
+    }
+}
+
+impl FromScVal for CampaignMetadata {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError> {
+        let fields = StructFields::new(value)?;
+        Ok(Self {
+            description: fields.get("description")?,
+            external_url: fields.get("external_url")?,
+            image_hash: fields.get("image_hash")?,
+        })
+    }
+}
+
+/// `created_at` is ignored by `create_pool`, which stamps the ledger time.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PoolConfig {
+    pub name: String,
+    pub description: String,
+    pub target_amount: i128,
+    pub is_private: bool,
+    pub duration: u64,
+    pub created_at: u64,
+}
+
+impl ToScVal for PoolConfig {
+    fn to_sc_val(&self) -> Result<ScVal, ClientError> {
+        struct_to_sc_val(vec![
+            ("name", self.name.to_sc_val()?),
+            ("description", self.description.to_sc_val()?),
+            ("target_amount", self.target_amount.to_sc_val()?),
+            ("is_private", self.is_private.to_sc_val()?),
+            ("duration", self.duration.to_sc_val()?),
+            ("created_at", self.created_at.to_sc_val()?),
+        ])
+    }
+}
+
+impl FromScVal for PoolConfig {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError> {
+        let fields = StructFields::new(value)?;
+        Ok(Self {
+            name: fields.get("name")?,
+            description: fields.get("description")?,
+            target_amount: fields.get("target_amount")?,
+            is_private: fields.get("is_private")?,
+            duration: fields.get("duration")?,
+            created_at: fields.get("created_at")?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PoolMetadata {
+    pub description: String,
+    pub external_url: String,
+    pub image_hash: String,
+}
+
+impl ToScVal for PoolMetadata {
+    fn to_sc_val(&self) -> Result<ScVal, ClientError> {
+        struct_to_sc_val(vec![
+            ("description", self.description.to_sc_val()?),
+            ("external_url", self.external_url.to_sc_val()?),
+            ("image_hash", self.image_hash.to_sc_val()?),
+        ])
+    }
+}
+
+impl FromScVal for PoolMetadata {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError> {
+        let fields = StructFields::new(value)?;
+        Ok(Self {
+            description: fields.get("description")?,
+            external_url: fields.get("external_url")?,
+            image_hash: fields.get("image_hash")?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingUpgrade {
+    pub wasm_hash: [u8; 32],
+    pub proposed_at: u64,
+    pub executable_at: u64,
+}
+
+impl ToScVal for PendingUpgrade {
+    fn to_sc_val(&self) -> Result<ScVal, ClientError> {
+        struct_to_sc_val(vec![
+            ("wasm_hash", self.wasm_hash.to_sc_val()?),
+            ("proposed_at", self.proposed_at.to_sc_val()?),
+            ("executable_at", self.executable_at.to_sc_val()?),
+        ])
+    }
+}
+
+impl FromScVal for PendingUpgrade {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError> {
+        let fields = StructFields::new(value)?;
+        Ok(Self {
+            wasm_hash: fields.get("wasm_hash")?,
+            proposed_at: fields.get("proposed_at")?,
+            executable_at: fields.get("executable_at")?,
+        })
+    }
+}
+
+/// Creation fee and platform token changes queued to take effect together
+/// at `effective_at`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingParameters {
+    pub creation_fee: Option<i128>,
+    pub crowdfunding_token: Option<ScAddress>,
+    pub queued_by: ScAddress,
+    pub effective_at: u64,
+}
+
+impl ToScVal for PendingParameters {
+    fn to_sc_val(&self) -> Result<ScVal, ClientError> {
+        struct_to_sc_val(vec![
+            ("creation_fee", self.creation_fee.to_sc_val()?),
+            ("crowdfunding_token", self.crowdfunding_token.to_sc_val()?),
+            ("queued_by", self.queued_by.to_sc_val()?),
+            ("effective_at", self.effective_at.to_sc_val()?),
+        ])
+    }
+}
+
+impl FromScVal for PendingParameters {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError> {
+        let fields = StructFields::new(value)?;
+        Ok(Self {
+            creation_fee: fields.get("creation_fee")?,
+            crowdfunding_token: fields.get("crowdfunding_token")?,
+            queued_by: fields.get("queued_by")?,
+            effective_at: fields.get("effective_at")?,
+        })
+    }
+}
+
+/// A creation fee change for one entity queued to take effect at
+/// `effective_at`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingEntityFee {
+    pub fee: i128,
+    pub queued_by: ScAddress,
+    pub effective_at: u64,
+}
+
+impl ToScVal for PendingEntityFee {
+    fn to_sc_val(&self) -> Result<ScVal, ClientError> {
+        struct_to_sc_val(vec![
+            ("fee", self.fee.to_sc_val()?),
+            ("queued_by", self.queued_by.to_sc_val()?),
+            ("effective_at", self.effective_at.to_sc_val()?),
+        ])
+    }
+}
+
+impl FromScVal for PendingEntityFee {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError> {
+        let fields = StructFields::new(value)?;
+        Ok(Self {
+            fee: fields.get("fee")?,
+            queued_by: fields.get("queued_by")?,
+            effective_at: fields.get("effective_at")?,
+        })
+    }
+}
+
+/// Fee treatment of a creator, assigned through `assign_fee_tier`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FeeTier {
+    Waived,
+    /// Basis points taken off creation fees and the platform fee rate.
+    Discount(u32),
+    /// Creation fee charged for every entity; the platform fee rate is
+    /// unchanged.
+    FlatFee(i128),
+}
+
+impl ToScVal for FeeTier {
+    fn to_sc_val(&self) -> Result<ScVal, ClientError> {
+        match self {
+            Self::Waived => union_to_sc_val("Waived", vec![]),
+            Self::Discount(bps) => union_to_sc_val("Discount", vec![bps.to_sc_val()?]),
+            Self::FlatFee(fee) => union_to_sc_val("FlatFee", vec![fee.to_sc_val()?]),
+        }
+    }
+}
+
+impl FromScVal for FeeTier {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError> {
+        let (case, fields) = union_case(value)?;
+        let tier = match (case.as_str(), fields) {
+            ("Waived", []) => Self::Waived,
+            ("Discount", [bps]) => Self::Discount(FromScVal::from_sc_val(bps)?),
+            ("FlatFee", [fee]) => Self::FlatFee(FromScVal::from_sc_val(fee)?),
+            _ => {
+                return Err(ClientError::UnexpectedValue {
+                    expected: "fee tier",
+                })
+            }
+        };
+        Ok(tier)
+    }
+}
+
+/// A creator's fee tier. `expires_at` of 0 never expires.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreatorFeeTier {
+    pub tier: FeeTier,
+    pub assigned_by: ScAddress,
+    pub expires_at: u64,
+}
+
+impl ToScVal for CreatorFeeTier {
+    fn to_sc_val(&self) -> Result<ScVal, ClientError> {
+        struct_to_sc_val(vec![
+            ("tier", self.tier.to_sc_val()?),
+            ("assigned_by", self.assigned_by.to_sc_val()?),
+            ("expires_at", self.expires_at.to_sc_val()?),
+        ])
+    }
+}
+
+impl FromScVal for CreatorFeeTier {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError> {
+        let fields = StructFields::new(value)?;
+        Ok(Self {
+            tier: fields.get("tier")?,
+            assigned_by: fields.get("assigned_by")?,
+            expires_at: fields.get("expires_at")?,
+        })
+    }
+}
+
+/// A token campaigns and pools may raise in, with the metadata read from
+/// the token contract when it was registered.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AcceptedToken {
+    pub symbol: String,
+    pub decimals: u32,
+    /// Smallest donation or contribution accepted, before the platform fee.
+    pub min_amount: i128,
+}
+
+impl ToScVal for AcceptedToken {
+    fn to_sc_val(&self) -> Result<ScVal, ClientError> {
+        struct_to_sc_val(vec![
+            ("symbol", self.symbol.to_sc_val()?),
+            ("decimals", self.decimals.to_sc_val()?),
+            ("min_amount", self.min_amount.to_sc_val()?),
+        ])
+    }
+}
+
+impl FromScVal for AcceptedToken {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError> {
+        let fields = StructFields::new(value)?;
+        Ok(Self {
+            symbol: fields.get("symbol")?,
+            decimals: fields.get("decimals")?,
+            min_amount: fields.get("min_amount")?,
+        })
+    }
+}
+
+/// A platform fee rate change queued to take effect at `effective_at`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingPlatformFee {
+    pub bps: u32,
+    pub queued_by: ScAddress,
+    pub effective_at: u64,
+}
+
+impl ToScVal for PendingPlatformFee {
+    fn to_sc_val(&self) -> Result<ScVal, ClientError> {
+        struct_to_sc_val(vec![
+            ("bps", self.bps.to_sc_val()?),
+            ("queued_by", self.queued_by.to_sc_val()?),
+            ("effective_at", self.effective_at.to_sc_val()?),
+        ])
+    }
+}
+
+impl FromScVal for PendingPlatformFee {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError> {
+        let fields = StructFields::new(value)?;
+        Ok(Self {
+            bps: fields.get("bps")?,
+            queued_by: fields.get("queued_by")?,
+            effective_at: fields.get("effective_at")?,
+        })
+    }
+}
+
+/// Split of a contribution between the platform fee and the net amount
+/// credited to the pool.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContributionQuote {
+    pub fee_bps: u32,
+    pub fee: i128,
+    pub net: i128,
+}
+
+impl ToScVal for ContributionQuote {
+    fn to_sc_val(&self) -> Result<ScVal, ClientError> {
+        struct_to_sc_val(vec![
+            ("fee_bps", self.fee_bps.to_sc_val()?),
+            ("fee", self.fee.to_sc_val()?),
+            ("net", self.net.to_sc_val()?),
+        ])
+    }
+}
+
+impl FromScVal for ContributionQuote {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError> {
+        let fields = StructFields::new(value)?;
+        Ok(Self {
+            fee_bps: fields.get("fee_bps")?,
+            fee: fields.get("fee")?,
+            net: fields.get("net")?,
+        })
+    }
+}
+
+/// A pending emergency withdrawal. `executed` is always `false` for a
+/// request that is still stored.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyWithdrawal {
+    pub recipient: ScAddress,
+    pub amount: i128,
+    pub token: ScAddress,
+    pub requested_at: u64,
+    pub executed: bool,
+    pub unlock_time: u64,
+}
+
+impl ToScVal for EmergencyWithdrawal {
+    fn to_sc_val(&self) -> Result<ScVal, ClientError> {
+        struct_to_sc_val(vec![
+            ("recipient", self.recipient.to_sc_val()?),
+            ("amount", self.amount.to_sc_val()?),
+            ("token", self.token.to_sc_val()?),
+            ("requested_at", self.requested_at.to_sc_val()?),
+            ("executed", self.executed.to_sc_val()?),
+            ("unlock_time", self.unlock_time.to_sc_val()?),
+        ])
+    }
+}
+
+impl FromScVal for EmergencyWithdrawal {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError> {
+        let fields = StructFields::new(value)?;
+        Ok(Self {
+            recipient: fields.get("recipient")?,
+            amount: fields.get("amount")?,
+            token: fields.get("token")?,
+            requested_at: fields.get("requested_at")?,
+            executed: fields.get("executed")?,
+            unlock_time: fields.get("unlock_time")?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrationCursor {
+    pub campaign_index: u32,
+    pub next_pool_id: u64,
+    pub next_contribution_pool_id: u64,
+}
+
+impl ToScVal for MigrationCursor {
+    fn to_sc_val(&self) -> Result<ScVal, ClientError> {
+        struct_to_sc_val(vec![
+            ("campaign_index", self.campaign_index.to_sc_val()?),
+            ("next_pool_id", self.next_pool_id.to_sc_val()?),
+            (
+                "next_contribution_pool_id",
+                self.next_contribution_pool_id.to_sc_val()?,
+            ),
+        ])
+    }
+}
+
+impl FromScVal for MigrationCursor {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError> {
+        let fields = StructFields::new(value)?;
+        Ok(Self {
+            campaign_index: fields.get("campaign_index")?,
+            next_pool_id: fields.get("next_pool_id")?,
+            next_contribution_pool_id: fields.get("next_contribution_pool_id")?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MultiSigConfig {
+    pub required_signatures: u32,
+    pub signers: Vec<ScAddress>,
+}
+
+impl ToScVal for MultiSigConfig {
+    fn to_sc_val(&self) -> Result<ScVal, ClientError> {
+        struct_to_sc_val(vec![
+            ("required_signatures", self.required_signatures.to_sc_val()?),
+            ("signers", self.signers.to_sc_val()?),
+        ])
+    }
+}
+
+impl FromScVal for MultiSigConfig {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError> {
+        let fields = StructFields::new(value)?;
+        Ok(Self {
+            required_signatures: fields.get("required_signatures")?,
+            signers: fields.get("signers")?,
+        })
+    }
+}
+
+/// A privileged action carried out through an approved council proposal.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CouncilAction {
+    SetCreationFee(i128),
+    SetCrowdfundingToken(ScAddress),
+    /// Token, amount and recipient of the withdrawal.
+    RequestEmergencyWithdraw(ScAddress, i128, ScAddress),
+    ExecuteEmergencyWithdraw,
+    CancelEmergencyWithdraw,
+    ProposeUpgrade([u8; 32]),
+    ExecuteUpgrade,
+    CancelUpgrade,
+    SetCouncil(MultiSigConfig),
+    SetEntityCreationFee(FeeEntity, i128),
+    SetPlatformFee(u32),
+    /// Pool id and its platform fee override; `None` clears the override.
+    SetPoolPlatformFee(u64, Option<u32>),
+    /// Token and its minimum amount.
+    SetAcceptedToken(ScAddress, i128),
+    RemoveAcceptedToken(ScAddress),
+    /// Token, amount and recipient of collected fees to withdraw.
+    WithdrawFees(ScAddress, i128, ScAddress),
+    /// Creator, tier and expiry of the assignment; an expiry of 0 never lapses.
+    AssignFeeTier(ScAddress, FeeTier, u64),
+    SetEmergencyWithdrawDelay(u64),
+    SetUpgradeTimelock(u64),
+    DisableCouncil,
+}
+
+impl ToScVal for CouncilAction {
+    fn to_sc_val(&self) -> Result<ScVal, ClientError> {
+        match self {
+            Self::SetCreationFee(fee) => union_to_sc_val("SetCreationFee", vec![fee.to_sc_val()?]),
+            Self::SetCrowdfundingToken(token) => {
+                union_to_sc_val("SetCrowdfundingToken", vec![token.to_sc_val()?])
+            }
+            Self::RequestEmergencyWithdraw(token, amount, recipient) => union_to_sc_val(
+                "RequestEmergencyWithdraw",
+                vec![
+                    token.to_sc_val()?,
+                    amount.to_sc_val()?,
+                    recipient.to_sc_val()?,
+                ],
+            ),
+            Self::ExecuteEmergencyWithdraw => union_to_sc_val("ExecuteEmergencyWithdraw", vec![]),
+            Self::CancelEmergencyWithdraw => union_to_sc_val("CancelEmergencyWithdraw", vec![]),
+            Self::ProposeUpgrade(wasm_hash) => {
+                union_to_sc_val("ProposeUpgrade", vec![wasm_hash.to_sc_val()?])
+            }
+            Self::ExecuteUpgrade => union_to_sc_val("ExecuteUpgrade", vec![]),
+            Self::CancelUpgrade => union_to_sc_val("CancelUpgrade", vec![]),
+            Self::SetCouncil(config) => union_to_sc_val("SetCouncil", vec![config.to_sc_val()?]),
+            Self::SetEntityCreationFee(entity, fee) => union_to_sc_val(
+                "SetEntityCreationFee",
+                vec![entity.to_sc_val()?, fee.to_sc_val()?],
+            ),
+            Self::SetPlatformFee(bps) => union_to_sc_val("SetPlatformFee", vec![bps.to_sc_val()?]),
+            Self::SetPoolPlatformFee(pool_id, bps) => union_to_sc_val(
+                "SetPoolPlatformFee",
+                vec![pool_id.to_sc_val()?, bps.to_sc_val()?],
+            ),
+            Self::SetAcceptedToken(token, min_amount) => union_to_sc_val(
+                "SetAcceptedToken",
+                vec![token.to_sc_val()?, min_amount.to_sc_val()?],
+            ),
+            Self::RemoveAcceptedToken(token) => {
+                union_to_sc_val("RemoveAcceptedToken", vec![token.to_sc_val()?])
+            }
+            Self::WithdrawFees(token, amount, to) => union_to_sc_val(
+                "WithdrawFees",
+                vec![token.to_sc_val()?, amount.to_sc_val()?, to.to_sc_val()?],
+            ),
+            Self::AssignFeeTier(creator, tier, expires_at) => union_to_sc_val(
+                "AssignFeeTier",
+                vec![
+                    creator.to_sc_val()?,
+                    tier.to_sc_val()?,
+                    expires_at.to_sc_val()?,
+                ],
+            ),
+            Self::SetEmergencyWithdrawDelay(delay) => {
+                union_to_sc_val("SetEmergencyWithdrawDelay", vec![delay.to_sc_val()?])
+            }
+            Self::SetUpgradeTimelock(delay) => {
+                union_to_sc_val("SetUpgradeTimelock", vec![delay.to_sc_val()?])
+            }
+            Self::DisableCouncil => union_to_sc_val("DisableCouncil", vec![]),
+        }
+    }
+}
+
+impl FromScVal for CouncilAction {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError> {
+        let (case, fields) = union_case(value)?;
+        let action = match (case.as_str(), fields) {
+            ("SetCreationFee", [fee]) => Self::SetCreationFee(FromScVal::from_sc_val(fee)?),
+            ("SetCrowdfundingToken", [token]) => {
+                Self::SetCrowdfundingToken(FromScVal::from_sc_val(token)?)
+            }
+            ("RequestEmergencyWithdraw", [token, amount, recipient]) => {
+                Self::RequestEmergencyWithdraw(
+                    FromScVal::from_sc_val(token)?,
+                    FromScVal::from_sc_val(amount)?,
+                    FromScVal::from_sc_val(recipient)?,
+                )
+            }
+            ("ExecuteEmergencyWithdraw", []) => Self::ExecuteEmergencyWithdraw,
+            ("CancelEmergencyWithdraw", []) => Self::CancelEmergencyWithdraw,
+            ("ProposeUpgrade", [wasm_hash]) => {
+                Self::ProposeUpgrade(FromScVal::from_sc_val(wasm_hash)?)
+            }
+            ("ExecuteUpgrade", []) => Self::ExecuteUpgrade,
+            ("CancelUpgrade", []) => Self::CancelUpgrade,
+            ("SetCouncil", [config]) => Self::SetCouncil(FromScVal::from_sc_val(config)?),
+            ("SetEntityCreationFee", [entity, fee]) => Self::SetEntityCreationFee(
+                FromScVal::from_sc_val(entity)?,
+                FromScVal::from_sc_val(fee)?,
+            ),
+            ("SetPlatformFee", [bps]) => Self::SetPlatformFee(FromScVal::from_sc_val(bps)?),
+            ("SetPoolPlatformFee", [pool_id, bps]) => Self::SetPoolPlatformFee(
+                FromScVal::from_sc_val(pool_id)?,
+                FromScVal::from_sc_val(bps)?,
+            ),
+            ("SetAcceptedToken", [token, min_amount]) => Self::SetAcceptedToken(
+                FromScVal::from_sc_val(token)?,
+                FromScVal::from_sc_val(min_amount)?,
+            ),
+            ("RemoveAcceptedToken", [token]) => {
+                Self::RemoveAcceptedToken(FromScVal::from_sc_val(token)?)
+            }
+            ("WithdrawFees", [token, amount, to]) => Self::WithdrawFees(
+                FromScVal::from_sc_val(token)?,
+                FromScVal::from_sc_val(amount)?,
+                FromScVal::from_sc_val(to)?,
+            ),
+            ("AssignFeeTier", [creator, tier, expires_at]) => Self::AssignFeeTier(
+                FromScVal::from_sc_val(creator)?,
+                FromScVal::from_sc_val(tier)?,
+                FromScVal::from_sc_val(expires_at)?,
+            ),
+            ("SetEmergencyWithdrawDelay", [delay]) => {
+                Self::SetEmergencyWithdrawDelay(FromScVal::from_sc_val(delay)?)
+            }
+            ("SetUpgradeTimelock", [delay]) => {
+                Self::SetUpgradeTimelock(FromScVal::from_sc_val(delay)?)
+            }
+            ("DisableCouncil", []) => Self::DisableCouncil,
+            _ => {
+                return Err(ClientError::UnexpectedValue {
+                    expected: "council action",
+                })
+            }
+        };
+        Ok(action)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CouncilProposal {
+    pub action: CouncilAction,
+    pub approvals: Vec<ScAddress>,
+    pub created_at: u64,
+    pub expires_at: u64,
+    pub executed: bool,
+}
+
+impl ToScVal for CouncilProposal {
+    fn to_sc_val(&self) -> Result<ScVal, ClientError> {
+        struct_to_sc_val(vec![
+            ("action", self.action.to_sc_val()?),
+            ("approvals", self.approvals.to_sc_val()?),
+            ("created_at", self.created_at.to_sc_val()?),
+            ("expires_at", self.expires_at.to_sc_val()?),
+            ("executed", self.executed.to_sc_val()?),
+        ])
+    }
+}
+
+impl FromScVal for CouncilProposal {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError> {
+        let fields = StructFields::new(value)?;
+        Ok(Self {
+            action: fields.get("action")?,
+            approvals: fields.get("approvals")?,
+            created_at: fields.get("created_at")?,
+            expires_at: fields.get("expires_at")?,
+            executed: fields.get("executed")?,
+        })
+    }
+}
+
+/// A pause on a single feature. It lapses once the ledger reaches
+/// `expires_at`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeaturePause {
+    pub reason: u32,
+    pub paused_at: u64,
+    pub expires_at: u64,
+}
+
+impl ToScVal for FeaturePause {
+    fn to_sc_val(&self) -> Result<ScVal, ClientError> {
+        struct_to_sc_val(vec![
+            ("reason", self.reason.to_sc_val()?),
+            ("paused_at", self.paused_at.to_sc_val()?),
+            ("expires_at", self.expires_at.to_sc_val()?),
+        ])
+    }
+}
+
+impl FromScVal for FeaturePause {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError> {
+        let fields = StructFields::new(value)?;
+        Ok(Self {
+            reason: fields.get("reason")?,
+            paused_at: fields.get("paused_at")?,
+            expires_at: fields.get("expires_at")?,
+        })
+    }
+}
+
+/// Mirror of the contract's `CampaignStatus`, encoded as its `u32`
+/// discriminant.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[repr(u32)]
+pub enum CampaignStatus {
+    #[default]
+    Active = 0,
+    Cancelled = 1,
+}
+
+impl CampaignStatus {
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::Active),
+            1 => Some(Self::Cancelled),
+            _ => None,
+        }
+    }
+}
+
+impl ToScVal for CampaignStatus {
+    fn to_sc_val(&self) -> Result<ScVal, ClientError> {
+        Ok(ScVal::U32(*self as u32))
+    }
+}
+
+impl FromScVal for CampaignStatus {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError> {
+        u32::from_sc_val(value).and_then(|v| {
+            CampaignStatus::from_u32(v).ok_or(ClientError::UnexpectedValue {
+                expected: "campaign status",
+            })
+        })
+    }
+}
+
+/// Mirror of the contract's `PoolState`, encoded as its `u32` discriminant.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[repr(u32)]
+pub enum PoolState {
+    #[default]
+    Active = 0,
+    Paused = 1,
+    Completed = 2,
+    Cancelled = 3,
+    Disbursed = 4,
+    Closed = 5,
+}
+
+impl PoolState {
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::Active),
+            1 => Some(Self::Paused),
+            2 => Some(Self::Completed),
+            3 => Some(Self::Cancelled),
+            4 => Some(Self::Disbursed),
+            5 => Some(Self::Closed),
+            _ => None,
+        }
+    }
+}
+
+impl ToScVal for PoolState {
+    fn to_sc_val(&self) -> Result<ScVal, ClientError> {
+        Ok(ScVal::U32(*self as u32))
+    }
+}
+
+impl FromScVal for PoolState {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError> {
+        u32::from_sc_val(value).and_then(|v| {
+            PoolState::from_u32(v).ok_or(ClientError::UnexpectedValue {
+                expected: "pool state",
+            })
+        })
+    }
+}
+
+/// Mirror of the contract's `Role`, encoded as its `u32` discriminant.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[repr(u32)]
+pub enum Role {
+    Pauser = 0,
+    FeeManager = 1,
+    Treasury = 2,
+    PoolModerator = 3,
+    Verifier = 4,
+}
+
+impl Role {
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::Pauser),
+            1 => Some(Self::FeeManager),
+            2 => Some(Self::Treasury),
+            3 => Some(Self::PoolModerator),
+            4 => Some(Self::Verifier),
+            _ => None,
+        }
+    }
+}
+
+impl ToScVal for Role {
+    fn to_sc_val(&self) -> Result<ScVal, ClientError> {
+        Ok(ScVal::U32(*self as u32))
+    }
+}
+
+impl FromScVal for Role {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError> {
+        u32::from_sc_val(value).and_then(|v| {
+            Role::from_u32(v).ok_or(ClientError::UnexpectedValue { expected: "role" })
+        })
+    }
+}
+
+/// Mirror of the contract's `FeeEntity`, encoded as its `u32` discriminant.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[repr(u32)]
+pub enum FeeEntity {
+    Campaign = 0,
+    PublicPool = 1,
+    PrivatePool = 2,
+    MultiSigPool = 3,
+}
+
+impl FeeEntity {
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::Campaign),
+            1 => Some(Self::PublicPool),
+            2 => Some(Self::PrivatePool),
+            3 => Some(Self::MultiSigPool),
+            _ => None,
+        }
+    }
+}
+
+impl ToScVal for FeeEntity {
+    fn to_sc_val(&self) -> Result<ScVal, ClientError> {
+        Ok(ScVal::U32(*self as u32))
+    }
+}
+
+impl FromScVal for FeeEntity {
+    fn from_sc_val(value: &ScVal) -> Result<Self, ClientError> {
+        u32::from_sc_val(value).and_then(|v| {
+            FeeEntity::from_u32(v).ok_or(ClientError::UnexpectedValue {
+                expected: "fee entity",
+            })
+        })
+    }
+}
@@ -0,0 +1,1333 @@
+use crowdfunding_client::{
+    types as client_types,
+    xdr::{
+        HostFunction, Limits, OperationBody, Preconditions, ReadXdr, ScAddress, ScError,
+        ScSpecEntry, ScVal, TransactionEnvelope,
+    },
+    AcceptedToken, Call, CampaignAmendmentRules, CampaignAmendments, CampaignDetails,
+    CampaignMetadata, CampaignStatus, ClientError, ContractError, ContributionQuote, CouncilAction,
+    CouncilProposal, CreatorFeeTier, CrowdfundingClient, EmergencyWithdrawal, FeaturePause,
+    FeeEntity, FeeTier, FromScVal, MultiSigConfig, PendingEntityFee, PendingParameters,
+    PendingPlatformFee, PendingUpgrade, PoolConfig, PoolMetadata, PoolState, Role, ToScVal,
+    TransactionBuilder, FUNCTIONS,
+};
+use hello_world::{
+    base::{errors::CrowdfundingError, types},
+    crowdfunding::CrowdfundingContract,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    xdr::ScErrorType,
+    Address, Env, IntoVal, Symbol, TryFromVal, Val,
+};
+
+const SOURCE: &str = "GAAZI4TCR3TY5OJHCTJC2A4QSY6CJWJH5IAJTGKIN2ER7LBNVKOCCWN7";
+
+/// Runs the client's calls against the contract under `testutils`.
+struct Harness {
+    env: Env,
+    contract_id: Address,
+    client: CrowdfundingClient,
+}
+
+impl Harness {
+    fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CrowdfundingContract, ());
+        let client = CrowdfundingClient::new(sc_address(&env, &contract_id));
+        Self {
+            env,
+            contract_id,
+            client,
+        }
+    }
+
+    fn address(&self) -> ScAddress {
+        sc_address(&self.env, &Address::generate(&self.env))
+    }
+
+    fn token(&self) -> (Address, ScAddress) {
+        let token = self
+            .env
+            .register_stellar_asset_contract_v2(Address::generate(&self.env))
+            .address();
+        let encoded = sc_address(&self.env, &token);
+        (token, encoded)
+    }
+
+    fn invoke<T: FromScVal>(&self, call: Result<Call<T>, ClientError>) -> Result<T, ClientError> {
+        let call = call.unwrap();
+        let mut args = soroban_sdk::Vec::<Val>::new(&self.env);
+        for arg in call.args().args.iter() {
+            args.push_back(Val::try_from_val(&self.env, arg).unwrap());
+        }
+        let function = Symbol::new(&self.env, call.function());
+        match self.env.try_invoke_contract::<Val, soroban_sdk::Error>(
+            &self.contract_id,
+            &function,
+            args,
+        ) {
+            Ok(Ok(value)) => call.decode(&ScVal::try_from_val(&self.env, &value).unwrap()),
+            Err(Ok(error)) if error.is_type(ScErrorType::Contract) => {
+                Err(ScError::Contract(error.get_code()).into())
+            }
+            other => panic!("unexpected invocation outcome: {other:?}"),
+        }
+    }
+}
+
+fn sc_address(env: &Env, address: &Address) -> ScAddress {
+    ScAddress::from_sc_val(&ScVal::try_from_val(env, &address.to_val()).unwrap()).unwrap()
+}
+
+fn campaign_id(seed: u8) -> [u8; 32] {
+    let mut id = [0u8; 32];
+    id[0] = seed;
+    id
+}
+
+#[test]
+fn campaign_calls_round_trip() {
+    let h = Harness::new();
+    let (token, token_address) = h.token();
+    let admin = h.address();
+    h.invoke(h.client.initialize(admin, token_address.clone(), 0))
+        .unwrap();
+
+    let creator = h.address();
+    let id = campaign_id(1);
+    let deadline = h.env.ledger().timestamp() + 86_400;
+    h.invoke(h.client.create_campaign(
+        id,
+        "Save the Whales".to_string(),
+        creator.clone(),
+        1_000,
+        deadline,
+        token_address.clone(),
+        0,
+    ))
+    .unwrap();
+
+    let donor = Address::generate(&h.env);
+    StellarAssetClient::new(&h.env, &token).mint(&donor, &400);
+    let donor = sc_address(&h.env, &donor);
+    h.invoke(
+        h.client
+            .donate(id, donor.clone(), token_address.clone(), 400),
+    )
+    .unwrap();
+
+    let campaign = h.invoke(h.client.get_campaign(id)).unwrap();
+    assert_eq!(
+        campaign,
+        CampaignDetails {
+            id,
+            title: "Save the Whales".to_string(),
+            creator,
+            goal: 1_000,
+            deadline,
+            total_raised: 400,
+            token_address,
+            status: CampaignStatus::Active,
+            metadata: CampaignMetadata::default(),
+        }
+    );
+    assert_eq!(h.invoke(h.client.get_all_campaigns()).unwrap(), vec![id]);
+    assert_eq!(h.invoke(h.client.get_donor_count(id)).unwrap(), 1);
+    assert_eq!(h.invoke(h.client.get_contribution(id, donor)).unwrap(), 400);
+    assert!(!h.invoke(h.client.is_campaign_completed(id)).unwrap());
+}
+
+#[test]
+fn campaign_cancellation_round_trip() {
+    let h = Harness::new();
+    let (token, token_address) = h.token();
+    h.invoke(h.client.initialize(h.address(), token_address.clone(), 0))
+        .unwrap();
+
+    let creator = h.address();
+    let id = campaign_id(2);
+    h.invoke(h.client.create_campaign(
+        id,
+        "Cancelled".to_string(),
+        creator.clone(),
+        1_000,
+        86_400,
+        token_address.clone(),
+        0,
+    ))
+    .unwrap();
+    let donor = Address::generate(&h.env);
+    StellarAssetClient::new(&h.env, &token).mint(&donor, &250);
+    let donor = sc_address(&h.env, &donor);
+    h.invoke(h.client.donate(id, donor.clone(), token_address, 250))
+        .unwrap();
+
+    assert!(matches!(
+        h.invoke(h.client.refund_campaign(id, donor.clone())),
+        Err(ClientError::Contract(ContractError::RefundNotAvailable))
+    ));
+    h.invoke(
+        h.client
+            .cancel_campaign(id, creator, "Permit denied".to_string()),
+    )
+    .unwrap();
+    assert_eq!(
+        h.invoke(h.client.get_campaign(id)).unwrap().status,
+        CampaignStatus::Cancelled
+    );
+    h.invoke(h.client.refund_campaign(id, donor.clone()))
+        .unwrap();
+    assert_eq!(h.invoke(h.client.get_contribution(id, donor)).unwrap(), 0);
+}
+
+#[test]
+fn campaign_amendment_round_trip() {
+    let h = Harness::new();
+    let (_, token_address) = h.token();
+    h.invoke(h.client.initialize(h.address(), token_address.clone(), 0))
+        .unwrap();
+    let id = campaign_id(3);
+    h.invoke(h.client.create_campaign(
+        id,
+        "Amended".to_string(),
+        h.address(),
+        1_000,
+        86_400,
+        token_address,
+        0,
+    ))
+    .unwrap();
+
+    let rules = CampaignAmendmentRules {
+        max_extension: 7_200,
+        single_extension: false,
+        material_goal_change_bps: 500,
+    };
+    h.invoke(h.client.set_campaign_amendment_rules(rules.clone()))
+        .unwrap();
+    assert_eq!(
+        h.invoke(h.client.get_campaign_amendment_rules()).unwrap(),
+        rules
+    );
+
+    h.invoke(h.client.extend_campaign_deadline(id, 90_000))
+        .unwrap();
+    h.invoke(h.client.update_campaign_goal(id, 2_000)).unwrap();
+    assert!(matches!(
+        h.invoke(h.client.extend_campaign_deadline(id, 100_000)),
+        Err(ClientError::Contract(ContractError::InvalidDeadline))
+    ));
+    assert_eq!(
+        h.invoke(h.client.get_campaign_amendments(id)).unwrap(),
+        CampaignAmendments {
+            extensions: 1,
+            extended_by: 3_600,
+            revision: 2,
+        }
+    );
+    let campaign = h.invoke(h.client.get_campaign(id)).unwrap();
+    assert_eq!((campaign.goal, campaign.deadline), (2_000, 90_000));
+}
+
+#[test]
+fn campaign_details_round_trip() {
+    let h = Harness::new();
+    let (token, token_address) = h.token();
+    h.invoke(h.client.initialize(h.address(), token_address.clone(), 0))
+        .unwrap();
+    let id = campaign_id(4);
+    h.invoke(h.client.create_campaign(
+        id,
+        "Wells".to_string(),
+        h.address(),
+        1_000,
+        86_400,
+        token_address.clone(),
+        0,
+    ))
+    .unwrap();
+
+    let metadata = CampaignMetadata {
+        description: "Wells for the valley.".to_string(),
+        external_url: String::new(),
+        image_hash: "QmWells".to_string(),
+    };
+    h.invoke(
+        h.client
+            .update_campaign_details(id, "Clean water".to_string(), metadata.clone()),
+    )
+    .unwrap();
+    let campaign = h.invoke(h.client.get_campaign(id)).unwrap();
+    assert_eq!(campaign.title, "Clean water");
+    assert_eq!(campaign.metadata, metadata);
+
+    let donor = Address::generate(&h.env);
+    StellarAssetClient::new(&h.env, &token).mint(&donor, &100);
+    h.invoke(
+        h.client
+            .donate(id, sc_address(&h.env, &donor), token_address, 100),
+    )
+    .unwrap();
+    assert!(matches!(
+        h.invoke(h.client.update_campaign_details(
+            id,
+            "Clean water".to_string(),
+            CampaignMetadata {
+                description: "Wells for the hills.".to_string(),
+                ..metadata
+            },
+        )),
+        Err(ClientError::Contract(ContractError::InvalidMetadata))
+    ));
+}
+
+#[test]
+fn pool_calls_round_trip() {
+    let h = Harness::new();
+    h.env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    let config = PoolConfig {
+        name: "Community Garden".to_string(),
+        description: "Seeds and tools".to_string(),
+        target_amount: 5_000,
+        is_private: false,
+        duration: 7 * 86_400,
+        created_at: 0,
+    };
+    let pool_id = h
+        .invoke(h.client.create_pool(h.address(), config.clone(), 0))
+        .unwrap();
+
+    let stored = h.invoke(h.client.get_pool(pool_id)).unwrap();
+    assert_eq!(
+        stored,
+        Some(PoolConfig {
+            created_at: 1_000,
+            ..config
+        })
+    );
+    assert_eq!(h.invoke(h.client.get_pool(pool_id + 1)).unwrap(), None);
+
+    let metadata = PoolMetadata {
+        description: "A shared pool".to_string(),
+        external_url: "https://example.org".to_string(),
+        image_hash: "abc".to_string(),
+    };
+    let signers = vec![h.address(), h.address()];
+    let saved = h
+        .invoke(h.client.save_pool(
+            "Shared".to_string(),
+            metadata.clone(),
+            h.address(),
+            1_000,
+            1_000 + 86_400,
+            Some(2),
+            Some(signers),
+            0,
+        ))
+        .unwrap();
+    assert_eq!(
+        h.invoke(h.client.get_pool_metadata(saved)).unwrap(),
+        (
+            metadata.description,
+            metadata.external_url,
+            metadata.image_hash
+        )
+    );
+
+    h.invoke(h.client.update_pool_state(pool_id, PoolState::Paused))
+        .unwrap();
+    assert!(!h.invoke(h.client.is_closed(pool_id)).unwrap());
+}
+
+#[test]
+fn admin_transfer_round_trip() {
+    let h = Harness::new();
+    let (_, token_address) = h.token();
+    let admin = h.address();
+    h.invoke(h.client.initialize(admin.clone(), token_address, 0))
+        .unwrap();
+    assert_eq!(h.invoke(h.client.get_pending_admin()).unwrap(), None);
+
+    let new_admin = h.address();
+    h.invoke(h.client.propose_admin(new_admin.clone())).unwrap();
+    assert_eq!(
+        h.invoke(h.client.get_pending_admin()).unwrap(),
+        Some(new_admin.clone())
+    );
+    assert_eq!(h.invoke(h.client.get_admin()).unwrap(), admin);
+
+    h.invoke(h.client.accept_admin()).unwrap();
+    assert_eq!(h.invoke(h.client.get_admin()).unwrap(), new_admin);
+    assert!(matches!(
+        h.invoke(h.client.cancel_admin_proposal()),
+        Err(ClientError::Contract(ContractError::NotFound))
+    ));
+}
+
+#[test]
+fn ownership_transfer_round_trip() {
+    let h = Harness::new();
+    let (_, token_address) = h.token();
+    h.invoke(h.client.initialize(h.address(), token_address.clone(), 0))
+        .unwrap();
+    let creator = h.address();
+    let id = campaign_id(5);
+    h.invoke(h.client.create_campaign(
+        id,
+        "Handed over".to_string(),
+        creator.clone(),
+        1_000,
+        86_400,
+        token_address,
+        0,
+    ))
+    .unwrap();
+    let pool_id = h
+        .invoke(h.client.create_pool(
+            creator.clone(),
+            PoolConfig {
+                name: "Handed over".to_string(),
+                description: "Pool".to_string(),
+                target_amount: 1_000,
+                is_private: false,
+                duration: 86_400,
+                created_at: 0,
+            },
+            0,
+        ))
+        .unwrap();
+
+    let new_owner = h.address();
+    h.invoke(h.client.transfer_campaign_ownership(id, new_owner.clone()))
+        .unwrap();
+    assert_eq!(
+        h.invoke(h.client.get_pending_campaign_owner(id)).unwrap(),
+        Some(new_owner.clone())
+    );
+    h.invoke(h.client.accept_campaign_ownership(id)).unwrap();
+    assert_eq!(
+        h.invoke(h.client.get_campaign(id)).unwrap().creator,
+        new_owner
+    );
+
+    assert_eq!(
+        h.invoke(h.client.get_pool_creator(pool_id)).unwrap(),
+        Some(creator)
+    );
+    h.invoke(h.client.transfer_pool_ownership(pool_id, new_owner.clone()))
+        .unwrap();
+    h.invoke(h.client.cancel_pool_transfer(pool_id)).unwrap();
+    assert_eq!(
+        h.invoke(h.client.get_pending_pool_owner(pool_id)).unwrap(),
+        None
+    );
+    assert!(matches!(
+        h.invoke(h.client.accept_pool_ownership(pool_id)),
+        Err(ClientError::Contract(ContractError::NotFound))
+    ));
+}
+
+#[test]
+fn role_calls_round_trip() {
+    let h = Harness::new();
+    let (_, token_address) = h.token();
+    h.invoke(h.client.initialize(h.address(), token_address, 0))
+        .unwrap();
+
+    let operator = h.address();
+    h.invoke(h.client.grant_role(Role::Treasury, operator.clone()))
+        .unwrap();
+    h.invoke(h.client.grant_role(Role::Pauser, operator.clone()))
+        .unwrap();
+    assert!(h
+        .invoke(h.client.has_role(Role::Pauser, operator.clone()))
+        .unwrap());
+    assert_eq!(
+        h.invoke(h.client.get_roles(operator.clone())).unwrap(),
+        vec![Role::Pauser, Role::Treasury]
+    );
+
+    h.invoke(h.client.pause(operator.clone())).unwrap();
+    assert!(h.invoke(h.client.is_paused()).unwrap());
+    assert!(matches!(
+        h.invoke(h.client.revoke_role(Role::Verifier, operator)),
+        Err(ClientError::Contract(ContractError::NotFound))
+    ));
+}
+
+#[test]
+fn upgrade_calls_round_trip() {
+    let h = Harness::new();
+    let (_, token_address) = h.token();
+    h.invoke(h.client.initialize(h.address(), token_address, 0))
+        .unwrap();
+    assert_eq!(
+        h.invoke(h.client.version()).unwrap(),
+        types::CONTRACT_VERSION
+    );
+
+    h.invoke(h.client.set_upgrade_timelock(types::MIN_UPGRADE_TIMELOCK))
+        .unwrap();
+    assert_eq!(
+        h.invoke(h.client.get_upgrade_timelock()).unwrap(),
+        types::MIN_UPGRADE_TIMELOCK
+    );
+
+    h.env.ledger().set_timestamp(100);
+    h.invoke(h.client.propose_upgrade([3; 32])).unwrap();
+    assert_eq!(
+        h.invoke(h.client.get_pending_upgrade()).unwrap(),
+        Some(PendingUpgrade {
+            wasm_hash: [3; 32],
+            proposed_at: 100,
+            executable_at: 100 + types::MIN_UPGRADE_TIMELOCK,
+        })
+    );
+    assert!(matches!(
+        h.invoke(h.client.execute_upgrade()),
+        Err(ClientError::Contract(ContractError::TimelockNotPassed))
+    ));
+
+    h.invoke(h.client.cancel_upgrade()).unwrap();
+    assert_eq!(h.invoke(h.client.get_pending_upgrade()).unwrap(), None);
+
+    assert_eq!(
+        h.invoke(h.client.get_schema_version()).unwrap(),
+        types::STORAGE_SCHEMA_VERSION
+    );
+    assert!(h.invoke(h.client.migrate(10)).unwrap());
+    assert_eq!(h.invoke(h.client.get_migration_cursor()).unwrap(), None);
+    assert!(matches!(
+        h.invoke(h.client.migrate_pool_contributions(1, vec![])),
+        Err(ClientError::Contract(ContractError::NotFound))
+    ));
+}
+
+#[test]
+fn emergency_withdrawal_round_trip() {
+    let h = Harness::new();
+    let (token, token_address) = h.token();
+    let admin = h.address();
+    h.invoke(h.client.initialize(admin.clone(), token_address.clone(), 0))
+        .unwrap();
+    StellarAssetClient::new(&h.env, &token).mint(&h.contract_id, &300);
+    assert_eq!(
+        h.invoke(h.client.get_withdrawable_balance(token_address.clone()))
+            .unwrap(),
+        300
+    );
+    assert_eq!(
+        h.invoke(h.client.get_escrowed_balance(token_address.clone()))
+            .unwrap(),
+        0
+    );
+
+    let recipient = h.address();
+    h.env.ledger().set_timestamp(50);
+    h.invoke(h.client.request_emergency_withdraw(
+        admin.clone(),
+        token_address.clone(),
+        300,
+        Some(recipient.clone()),
+    ))
+    .unwrap();
+    assert_eq!(
+        h.invoke(h.client.get_emergency_withdrawal()).unwrap(),
+        Some(EmergencyWithdrawal {
+            recipient,
+            amount: 300,
+            token: token_address,
+            requested_at: 50,
+            executed: false,
+            unlock_time: 50 + types::DEFAULT_EMERGENCY_WITHDRAW_DELAY,
+        })
+    );
+
+    h.invoke(h.client.cancel_emergency_withdraw(admin)).unwrap();
+    assert_eq!(h.invoke(h.client.get_emergency_withdrawal()).unwrap(), None);
+    h.invoke(
+        h.client
+            .set_emergency_withdraw_delay(types::MIN_EMERGENCY_WITHDRAW_DELAY),
+    )
+    .unwrap();
+    assert_eq!(
+        h.invoke(h.client.get_emergency_withdraw_delay()).unwrap(),
+        types::MIN_EMERGENCY_WITHDRAW_DELAY
+    );
+}
+
+#[test]
+fn parameter_timelock_round_trip() {
+    let h = Harness::new();
+    let (_, token_address) = h.token();
+    let admin = h.address();
+    h.invoke(
+        h.client
+            .initialize(admin.clone(), token_address.clone(), 10),
+    )
+    .unwrap();
+
+    h.env.ledger().set_timestamp(100);
+    h.invoke(h.client.set_creation_fee(admin.clone(), 40))
+        .unwrap();
+    let pending = PendingParameters {
+        creation_fee: Some(40),
+        crowdfunding_token: None,
+        queued_by: admin,
+        effective_at: 100 + types::PARAMETER_CHANGE_DELAY,
+    };
+    assert_eq!(
+        h.invoke(h.client.get_pending_parameters()).unwrap(),
+        Some(pending)
+    );
+    assert_eq!(h.invoke(h.client.get_creation_fee()).unwrap(), 10);
+
+    // Creation is refused once the fee exceeds the caller's bound
+    h.env
+        .ledger()
+        .set_timestamp(100 + types::PARAMETER_CHANGE_DELAY);
+    assert_eq!(h.invoke(h.client.get_creation_fee()).unwrap(), 40);
+    assert!(matches!(
+        h.invoke(h.client.create_campaign(
+            campaign_id(1),
+            "Capped".to_string(),
+            h.address(),
+            1_000,
+            h.env.ledger().timestamp() + 86_400,
+            token_address,
+            10,
+        )),
+        Err(ClientError::Contract(ContractError::InvalidFee))
+    ));
+}
+
+#[test]
+fn entity_fee_round_trip() {
+    let h = Harness::new();
+    let (token, token_address) = h.token();
+    let admin = h.address();
+    h.invoke(h.client.initialize(admin.clone(), token_address, 0))
+        .unwrap();
+
+    h.env.ledger().set_timestamp(100);
+    h.invoke(
+        h.client
+            .set_entity_creation_fee(admin.clone(), FeeEntity::PrivatePool, 15),
+    )
+    .unwrap();
+    let effective_at = 100 + types::PARAMETER_CHANGE_DELAY;
+    assert_eq!(
+        h.invoke(
+            h.client
+                .get_pending_entity_creation_fee(FeeEntity::PrivatePool)
+        )
+        .unwrap(),
+        Some(PendingEntityFee {
+            fee: 15,
+            queued_by: admin,
+            effective_at,
+        })
+    );
+
+    h.env.ledger().set_timestamp(effective_at);
+    assert_eq!(
+        h.invoke(h.client.get_entity_creation_fee(FeeEntity::PrivatePool))
+            .unwrap(),
+        15
+    );
+    let creator = Address::generate(&h.env);
+    StellarAssetClient::new(&h.env, &token).mint(&creator, &15);
+    let config = PoolConfig {
+        name: "Private".to_string(),
+        description: "Invite only".to_string(),
+        target_amount: 5_000,
+        is_private: true,
+        duration: 7 * 86_400,
+        created_at: 0,
+    };
+    assert!(matches!(
+        h.invoke(
+            h.client
+                .create_pool(sc_address(&h.env, &creator), config.clone(), 14)
+        ),
+        Err(ClientError::Contract(ContractError::InvalidFee))
+    ));
+    h.invoke(
+        h.client
+            .create_pool(sc_address(&h.env, &creator), config, 15),
+    )
+    .unwrap();
+}
+
+#[test]
+fn platform_fee_round_trip() {
+    let h = Harness::new();
+    let (token, token_address) = h.token();
+    let admin = h.address();
+    h.invoke(h.client.initialize(admin.clone(), token_address.clone(), 0))
+        .unwrap();
+
+    h.env.ledger().set_timestamp(100);
+    h.invoke(h.client.set_platform_fee(admin.clone(), 200))
+        .unwrap();
+    let effective_at = 100 + types::PARAMETER_CHANGE_DELAY;
+    assert_eq!(
+        h.invoke(h.client.get_pending_platform_fee()).unwrap(),
+        Some(PendingPlatformFee {
+            bps: 200,
+            queued_by: admin,
+            effective_at,
+        })
+    );
+
+    h.env.ledger().set_timestamp(effective_at);
+    assert_eq!(h.invoke(h.client.get_platform_fee()).unwrap(), 200);
+    let pool_id = h
+        .invoke(h.client.create_pool(
+            h.address(),
+            PoolConfig {
+                name: "Fees".to_string(),
+                description: "Pays the platform".to_string(),
+                target_amount: 5_000,
+                is_private: false,
+                duration: 7 * 86_400,
+                created_at: 0,
+            },
+            0,
+        ))
+        .unwrap();
+    h.invoke(h.client.set_pool_platform_fee(pool_id, Some(500)))
+        .unwrap();
+    assert_eq!(
+        h.invoke(h.client.get_pool_platform_fee(pool_id)).unwrap(),
+        500
+    );
+    assert_eq!(
+        h.invoke(h.client.quote_contribution(pool_id, 1_000))
+            .unwrap(),
+        ContributionQuote {
+            fee_bps: 500,
+            fee: 50,
+            net: 950,
+        }
+    );
+
+    let contributor = Address::generate(&h.env);
+    StellarAssetClient::new(&h.env, &token).mint(&contributor, &1_000);
+    h.invoke(h.client.contribute(
+        pool_id,
+        sc_address(&h.env, &contributor),
+        token_address.clone(),
+        1_000,
+        false,
+    ))
+    .unwrap();
+    assert_eq!(
+        h.invoke(h.client.get_fee_balance(token_address)).unwrap(),
+        50
+    );
+}
+
+#[test]
+fn fee_tier_round_trip() {
+    let h = Harness::new();
+    let (token, token_address) = h.token();
+    let admin = h.address();
+    h.invoke(
+        h.client
+            .initialize(admin.clone(), token_address.clone(), 40),
+    )
+    .unwrap();
+
+    h.env.ledger().set_timestamp(100);
+    let creator = Address::generate(&h.env);
+    let creator_address = sc_address(&h.env, &creator);
+    h.invoke(h.client.assign_fee_tier(
+        admin.clone(),
+        creator_address.clone(),
+        FeeTier::Discount(2_500),
+        200,
+    ))
+    .unwrap();
+    assert_eq!(
+        h.invoke(h.client.get_fee_tier(creator_address.clone()))
+            .unwrap(),
+        Some(CreatorFeeTier {
+            tier: FeeTier::Discount(2_500),
+            assigned_by: admin.clone(),
+            expires_at: 200,
+        })
+    );
+
+    StellarAssetClient::new(&h.env, &token).mint(&creator, &30);
+    h.invoke(h.client.create_campaign(
+        campaign_id(1),
+        "Discounted".to_string(),
+        creator_address.clone(),
+        1_000,
+        86_400,
+        token_address.clone(),
+        30,
+    ))
+    .unwrap();
+    assert_eq!(
+        h.invoke(h.client.get_fee_balance(token_address)).unwrap(),
+        30
+    );
+
+    h.invoke(h.client.assign_fee_tier(
+        admin.clone(),
+        creator_address.clone(),
+        FeeTier::FlatFee(0),
+        0,
+    ))
+    .unwrap();
+    h.invoke(h.client.revoke_fee_tier(admin, creator_address.clone()))
+        .unwrap();
+    assert_eq!(
+        h.invoke(h.client.get_fee_tier(creator_address)).unwrap(),
+        None
+    );
+}
+
+#[test]
+fn accepted_token_round_trip() {
+    let h = Harness::new();
+    let (_, token_address) = h.token();
+    let (usdc, usdc_address) = h.token();
+    let admin = h.address();
+    h.invoke(h.client.initialize(admin.clone(), token_address.clone(), 0))
+        .unwrap();
+
+    h.invoke(
+        h.client
+            .set_accepted_token(admin.clone(), usdc_address.clone(), 100),
+    )
+    .unwrap();
+    let usdc_client = soroban_sdk::token::Client::new(&h.env, &usdc);
+    assert_eq!(
+        h.invoke(h.client.get_accepted_token(usdc_address.clone()))
+            .unwrap(),
+        Some(AcceptedToken {
+            symbol: usdc_client.symbol().to_string(),
+            decimals: usdc_client.decimals(),
+            min_amount: 100,
+        })
+    );
+    assert_eq!(
+        h.invoke(h.client.get_accepted_tokens()).unwrap(),
+        vec![token_address, usdc_address.clone()]
+    );
+
+    h.invoke(h.client.remove_accepted_token(admin, usdc_address.clone()))
+        .unwrap();
+    assert_eq!(
+        h.invoke(h.client.get_accepted_token(usdc_address)).unwrap(),
+        None
+    );
+}
+
+#[test]
+fn fee_treasury_round_trip() {
+    let h = Harness::new();
+    let (token, token_address) = h.token();
+    let admin = h.address();
+    h.invoke(
+        h.client
+            .initialize(admin.clone(), token_address.clone(), 25),
+    )
+    .unwrap();
+
+    let creator = Address::generate(&h.env);
+    StellarAssetClient::new(&h.env, &token).mint(&creator, &25);
+    h.invoke(h.client.create_campaign(
+        campaign_id(1),
+        "Fees".to_string(),
+        sc_address(&h.env, &creator),
+        1_000,
+        h.env.ledger().timestamp() + 86_400,
+        token_address.clone(),
+        25,
+    ))
+    .unwrap();
+    assert_eq!(
+        h.invoke(h.client.get_fee_balance(token_address.clone()))
+            .unwrap(),
+        25
+    );
+
+    let to = h.address();
+    assert!(matches!(
+        h.invoke(
+            h.client
+                .withdraw_fees(admin.clone(), token_address.clone(), 26, to.clone())
+        ),
+        Err(ClientError::Contract(ContractError::InsufficientBalance))
+    ));
+    h.invoke(h.client.withdraw_fees(admin, token_address.clone(), 25, to))
+        .unwrap();
+    assert_eq!(
+        h.invoke(h.client.get_fee_balance(token_address)).unwrap(),
+        0
+    );
+}
+
+#[test]
+fn escape_hatch_round_trip() {
+    let h = Harness::new();
+    let (_, token_address) = h.token();
+    let admin = h.address();
+    h.invoke(h.client.initialize(admin.clone(), token_address, 0))
+        .unwrap();
+    h.invoke(
+        h.client
+            .set_max_pause_duration(types::MIN_MAX_PAUSE_DURATION),
+    )
+    .unwrap();
+    assert_eq!(
+        h.invoke(h.client.get_max_pause_duration()).unwrap(),
+        types::MIN_MAX_PAUSE_DURATION
+    );
+
+    h.env.ledger().set_timestamp(100);
+    h.invoke(h.client.pause(admin)).unwrap();
+    assert_eq!(h.invoke(h.client.get_paused_at()).unwrap(), Some(100));
+    assert!(!h.invoke(h.client.is_escape_hatch_open()).unwrap());
+
+    h.env
+        .ledger()
+        .set_timestamp(100 + types::MIN_MAX_PAUSE_DURATION);
+    assert!(h.invoke(h.client.is_escape_hatch_open()).unwrap());
+    assert!(matches!(
+        h.invoke(
+            h.client
+                .escape_campaign_contribution(campaign_id(9), h.address())
+        ),
+        Err(ClientError::Contract(ContractError::CampaignNotFound))
+    ));
+    assert!(matches!(
+        h.invoke(h.client.escape_pool_contribution(9, h.address())),
+        Err(ClientError::Contract(ContractError::NoContributionToRefund))
+    ));
+}
+
+#[test]
+fn feature_pause_round_trip() {
+    let h = Harness::new();
+    let (_, token_address) = h.token();
+    let admin = h.address();
+    h.invoke(h.client.initialize(admin.clone(), token_address, 0))
+        .unwrap();
+
+    let mirrored = [
+        (
+            client_types::PAUSE_CAMPAIGN_CREATION,
+            types::PAUSE_CAMPAIGN_CREATION,
+        ),
+        (client_types::PAUSE_DONATIONS, types::PAUSE_DONATIONS),
+        (
+            client_types::PAUSE_POOL_CREATION,
+            types::PAUSE_POOL_CREATION,
+        ),
+        (
+            client_types::PAUSE_CONTRIBUTIONS,
+            types::PAUSE_CONTRIBUTIONS,
+        ),
+        (client_types::PAUSE_REFUNDS, types::PAUSE_REFUNDS),
+        (
+            client_types::PAUSE_POOL_STATE_UPDATES,
+            types::PAUSE_POOL_STATE_UPDATES,
+        ),
+        (client_types::PAUSE_ALL_FEATURES, types::PAUSE_ALL_FEATURES),
+    ];
+    for (client, contract) in mirrored {
+        assert_eq!(client, contract);
+    }
+
+    h.env.ledger().set_timestamp(100);
+    let features = types::PAUSE_DONATIONS | types::PAUSE_REFUNDS;
+    h.invoke(h.client.pause_features(admin.clone(), features, 3, 50))
+        .unwrap();
+    assert_eq!(h.invoke(h.client.get_paused_features()).unwrap(), features);
+    assert_eq!(
+        h.invoke(h.client.get_feature_pause(types::PAUSE_REFUNDS))
+            .unwrap(),
+        Some(FeaturePause {
+            reason: 3,
+            paused_at: 100,
+            expires_at: 150,
+        })
+    );
+
+    h.invoke(h.client.unpause_features(admin, types::PAUSE_REFUNDS))
+        .unwrap();
+    assert_eq!(
+        h.invoke(h.client.get_paused_features()).unwrap(),
+        types::PAUSE_DONATIONS
+    );
+}
+
+#[test]
+fn council_round_trip() {
+    let h = Harness::new();
+    let (_, token_address) = h.token();
+    let admin = h.address();
+    h.invoke(h.client.initialize(admin, token_address.clone(), 0))
+        .unwrap();
+
+    let members = vec![h.address(), h.address()];
+    let council = MultiSigConfig {
+        required_signatures: 2,
+        signers: members.clone(),
+    };
+    h.invoke(h.client.set_council(council.clone())).unwrap();
+    assert_eq!(
+        h.invoke(h.client.get_council()).unwrap(),
+        Some(council.clone())
+    );
+
+    // Every action shape survives encoding to and from the contract
+    let actions = [
+        CouncilAction::SetCreationFee(25),
+        CouncilAction::SetCrowdfundingToken(token_address.clone()),
+        CouncilAction::RequestEmergencyWithdraw(token_address.clone(), 5, members[0].clone()),
+        CouncilAction::ExecuteEmergencyWithdraw,
+        CouncilAction::CancelEmergencyWithdraw,
+        CouncilAction::ProposeUpgrade([3; 32]),
+        CouncilAction::ExecuteUpgrade,
+        CouncilAction::CancelUpgrade,
+        CouncilAction::SetCouncil(council),
+        CouncilAction::SetEntityCreationFee(FeeEntity::MultiSigPool, 7),
+        CouncilAction::SetPlatformFee(100),
+        CouncilAction::SetPoolPlatformFee(1, None),
+        CouncilAction::SetAcceptedToken(token_address.clone(), 10),
+        CouncilAction::RemoveAcceptedToken(token_address.clone()),
+        CouncilAction::WithdrawFees(token_address.clone(), 5, members[1].clone()),
+        CouncilAction::AssignFeeTier(members[1].clone(), FeeTier::Discount(500), 0),
+        CouncilAction::SetEmergencyWithdrawDelay(types::MIN_EMERGENCY_WITHDRAW_DELAY),
+        CouncilAction::SetUpgradeTimelock(types::MIN_UPGRADE_TIMELOCK),
+        CouncilAction::DisableCouncil,
+    ];
+    h.env.ledger().set_timestamp(10);
+    for (i, action) in actions.iter().enumerate() {
+        let proposal_id = h
+            .invoke(
+                h.client
+                    .propose_council_action(members[0].clone(), action.clone()),
+            )
+            .unwrap();
+        assert_eq!(proposal_id, i as u64 + 1);
+        assert_eq!(
+            h.invoke(h.client.get_council_proposal(proposal_id))
+                .unwrap(),
+            Some(CouncilProposal {
+                action: action.clone(),
+                approvals: vec![members[0].clone()],
+                created_at: 10,
+                expires_at: 10 + types::COUNCIL_PROPOSAL_TTL,
+                executed: false,
+            })
+        );
+    }
+
+    h.invoke(h.client.approve_council_action(members[1].clone(), 1))
+        .unwrap();
+    h.invoke(h.client.execute_council_action(members[1].clone(), 1))
+        .unwrap();
+    let pending = h.invoke(h.client.get_pending_parameters()).unwrap();
+    assert_eq!(pending.unwrap().creation_fee, Some(25));
+
+    // Executed proposals are removed
+    assert_eq!(h.invoke(h.client.get_council_proposal(1)).unwrap(), None);
+    let again = h.invoke(h.client.execute_council_action(members[1].clone(), 1));
+    assert!(matches!(
+        again,
+        Err(ClientError::Contract(ContractError::NotFound))
+    ));
+    let missing = h.invoke(h.client.approve_council_action(members[1].clone(), 99));
+    assert!(matches!(
+        missing,
+        Err(ClientError::Contract(ContractError::NotFound))
+    ));
+}
+
+#[test]
+fn contract_errors_are_decoded() {
+    let h = Harness::new();
+    let (_, token_address) = h.token();
+
+    let missing = h.invoke(h.client.get_campaign(campaign_id(9)));
+    assert!(matches!(
+        missing,
+        Err(ClientError::Contract(ContractError::CampaignNotFound))
+    ));
+
+    let admin = h.address();
+    h.invoke(h.client.initialize(admin.clone(), token_address.clone(), 0))
+        .unwrap();
+    let again = h.invoke(h.client.initialize(admin.clone(), token_address, 0));
+    assert!(matches!(
+        again,
+        Err(ClientError::Contract(
+            ContractError::ContractAlreadyInitialized
+        ))
+    ));
+
+    let invalid = h.invoke(h.client.set_creation_fee(admin, -1));
+    assert!(matches!(
+        invalid,
+        Err(ClientError::Contract(ContractError::InvalidFee))
+    ));
+
+    let unauthorized = h.invoke(h.client.set_creation_fee(h.address(), 1));
+    assert!(matches!(
+        unauthorized,
+        Err(ClientError::Contract(ContractError::Unauthorized))
+    ));
+
+    assert!(matches!(
+        ClientError::from(ScError::Contract(9_999)),
+        ClientError::UnknownContractError(9_999)
+    ));
+}
+
+#[test]
+fn builds_unsigned_invocation_envelope() {
+    let h = Harness::new();
+    let call = h.client.pause(h.address()).unwrap();
+
+    let envelope = TransactionBuilder::new(SOURCE, 42)
+        .unwrap()
+        .fee(1_000)
+        .time_bounds(10, 20)
+        .build(&call)
+        .unwrap();
+    let encoded = TransactionBuilder::new(SOURCE, 42)
+        .unwrap()
+        .fee(1_000)
+        .time_bounds(10, 20)
+        .build_base64(&call)
+        .unwrap();
+    assert_eq!(
+        TransactionEnvelope::from_xdr_base64(encoded, Limits::none()).unwrap(),
+        envelope
+    );
+
+    let TransactionEnvelope::Tx(envelope) = envelope else {
+        panic!("expected a v1 envelope");
+    };
+    assert!(envelope.signatures.is_empty());
+    assert_eq!(envelope.tx.fee, 1_000);
+    assert_eq!(envelope.tx.seq_num.0, 42);
+    assert_eq!(envelope.tx.source_account.to_string(), SOURCE);
+    assert!(matches!(envelope.tx.cond, Preconditions::Time(_)));
+
+    let [operation] = envelope.tx.operations.as_slice() else {
+        panic!("expected one operation");
+    };
+    let OperationBody::InvokeHostFunction(op) = &operation.body else {
+        panic!("expected an invoke host function operation");
+    };
+    assert!(op.auth.is_empty());
+    assert_eq!(
+        op.host_function,
+        HostFunction::InvokeContract(call.args().clone())
+    );
+    assert_eq!(call.args().function_name.to_utf8_string_lossy(), "pause");
+    assert_eq!(&call.args().contract_address, h.client.contract());
+
+    assert!(TransactionBuilder::new("not a strkey", 1).is_err());
+    assert!(CrowdfundingClient::from_strkey(SOURCE).is_err());
+    let contract = h.client.contract().to_string();
+    assert_eq!(
+        CrowdfundingClient::from_strkey(&contract).unwrap(),
+        h.client
+    );
+}
+
+#[test]
+fn arguments_encode_like_the_sdk() {
+    let env = Env::default();
+    let config = types::PoolConfig {
+        name: soroban_sdk::String::from_str(&env, "Pool"),
+        description: soroban_sdk::String::from_str(&env, "Desc"),
+        target_amount: -5,
+        is_private: true,
+        duration: 3_600,
+        created_at: 7,
+    };
+    let expected =
+        ScVal::try_from_val(&env, &IntoVal::<Env, Val>::into_val(&config, &env)).unwrap();
+    let mirror = PoolConfig {
+        name: "Pool".to_string(),
+        description: "Desc".to_string(),
+        target_amount: -5,
+        is_private: true,
+        duration: 3_600,
+        created_at: 7,
+    };
+    assert_eq!(mirror.to_sc_val().unwrap(), expected);
+    assert_eq!(PoolConfig::from_sc_val(&expected).unwrap(), mirror);
+
+    let state: Val = types::PoolState::Disbursed.into_val(&env);
+    let state = ScVal::try_from_val(&env, &state).unwrap();
+    assert_eq!(PoolState::Disbursed.to_sc_val().unwrap(), state);
+
+    for value in [i128::MIN, -1, 0, 1, i128::MAX] {
+        let sdk = ScVal::try_from_val(&env, &IntoVal::<Env, Val>::into_val(&value, &env)).unwrap();
+        assert_eq!(value.to_sc_val().unwrap(), sdk);
+        assert_eq!(i128::from_sc_val(&sdk).unwrap(), value);
+    }
+}
+
+fn spec(xdr: &[u8]) -> ScSpecEntry {
+    ScSpecEntry::from_xdr(xdr, Limits::none()).unwrap()
+}
+
+#[test]
+fn functions_match_contract_spec() {
+    let specs = [
+        &CrowdfundingContract::spec_xdr_create_campaign()[..],
+        &CrowdfundingContract::spec_xdr_get_campaign(),
+        &CrowdfundingContract::spec_xdr_get_all_campaigns(),
+        &CrowdfundingContract::spec_xdr_get_donor_count(),
+        &CrowdfundingContract::spec_xdr_get_campaign_balance(),
+        &CrowdfundingContract::spec_xdr_get_total_raised(),
+        &CrowdfundingContract::spec_xdr_get_contribution(),
+        &CrowdfundingContract::spec_xdr_get_campaign_goal(),
+        &CrowdfundingContract::spec_xdr_is_campaign_completed(),
+        &CrowdfundingContract::spec_xdr_donate(),
+        &CrowdfundingContract::spec_xdr_cancel_campaign(),
+        &CrowdfundingContract::spec_xdr_refund_campaign(),
+        &CrowdfundingContract::spec_xdr_extend_campaign_deadline(),
+        &CrowdfundingContract::spec_xdr_update_campaign_goal(),
+        &CrowdfundingContract::spec_xdr_update_campaign_details(),
+        &CrowdfundingContract::spec_xdr_get_campaign_amendments(),
+        &CrowdfundingContract::spec_xdr_set_campaign_amendment_rules(),
+        &CrowdfundingContract::spec_xdr_get_campaign_amendment_rules(),
+        &CrowdfundingContract::spec_xdr_transfer_campaign_ownership(),
+        &CrowdfundingContract::spec_xdr_accept_campaign_ownership(),
+        &CrowdfundingContract::spec_xdr_cancel_campaign_transfer(),
+        &CrowdfundingContract::spec_xdr_get_pending_campaign_owner(),
+        &CrowdfundingContract::spec_xdr_create_pool(),
+        &CrowdfundingContract::spec_xdr_save_pool(),
+        &CrowdfundingContract::spec_xdr_get_pool(),
+        &CrowdfundingContract::spec_xdr_get_pool_metadata(),
+        &CrowdfundingContract::spec_xdr_get_pool_creator(),
+        &CrowdfundingContract::spec_xdr_transfer_pool_ownership(),
+        &CrowdfundingContract::spec_xdr_accept_pool_ownership(),
+        &CrowdfundingContract::spec_xdr_cancel_pool_transfer(),
+        &CrowdfundingContract::spec_xdr_get_pending_pool_owner(),
+        &CrowdfundingContract::spec_xdr_update_pool_state(),
+        &CrowdfundingContract::spec_xdr_set_crowdfunding_token(),
+        &CrowdfundingContract::spec_xdr_get_crowdfunding_token(),
+        &CrowdfundingContract::spec_xdr_set_creation_fee(),
+        &CrowdfundingContract::spec_xdr_get_creation_fee(),
+        &CrowdfundingContract::spec_xdr_get_pending_parameters(),
+        &CrowdfundingContract::spec_xdr_set_entity_creation_fee(),
+        &CrowdfundingContract::spec_xdr_get_entity_creation_fee(),
+        &CrowdfundingContract::spec_xdr_get_pending_entity_creation_fee(),
+        &CrowdfundingContract::spec_xdr_set_platform_fee(),
+        &CrowdfundingContract::spec_xdr_get_platform_fee(),
+        &CrowdfundingContract::spec_xdr_get_pending_platform_fee(),
+        &CrowdfundingContract::spec_xdr_set_pool_platform_fee(),
+        &CrowdfundingContract::spec_xdr_get_pool_platform_fee(),
+        &CrowdfundingContract::spec_xdr_quote_contribution(),
+        &CrowdfundingContract::spec_xdr_assign_fee_tier(),
+        &CrowdfundingContract::spec_xdr_revoke_fee_tier(),
+        &CrowdfundingContract::spec_xdr_get_fee_tier(),
+        &CrowdfundingContract::spec_xdr_set_accepted_token(),
+        &CrowdfundingContract::spec_xdr_remove_accepted_token(),
+        &CrowdfundingContract::spec_xdr_get_accepted_token(),
+        &CrowdfundingContract::spec_xdr_get_accepted_tokens(),
+        &CrowdfundingContract::spec_xdr_initialize(),
+        &CrowdfundingContract::spec_xdr_pause(),
+        &CrowdfundingContract::spec_xdr_unpause(),
+        &CrowdfundingContract::spec_xdr_is_paused(),
+        &CrowdfundingContract::spec_xdr_get_paused_at(),
+        &CrowdfundingContract::spec_xdr_pause_features(),
+        &CrowdfundingContract::spec_xdr_unpause_features(),
+        &CrowdfundingContract::spec_xdr_get_paused_features(),
+        &CrowdfundingContract::spec_xdr_get_feature_pause(),
+        &CrowdfundingContract::spec_xdr_set_max_pause_duration(),
+        &CrowdfundingContract::spec_xdr_get_max_pause_duration(),
+        &CrowdfundingContract::spec_xdr_is_escape_hatch_open(),
+        &CrowdfundingContract::spec_xdr_escape_pool_contribution(),
+        &CrowdfundingContract::spec_xdr_escape_campaign_contribution(),
+        &CrowdfundingContract::spec_xdr_get_admin(),
+        &CrowdfundingContract::spec_xdr_get_pending_admin(),
+        &CrowdfundingContract::spec_xdr_propose_admin(),
+        &CrowdfundingContract::spec_xdr_accept_admin(),
+        &CrowdfundingContract::spec_xdr_cancel_admin_proposal(),
+        &CrowdfundingContract::spec_xdr_grant_role(),
+        &CrowdfundingContract::spec_xdr_revoke_role(),
+        &CrowdfundingContract::spec_xdr_has_role(),
+        &CrowdfundingContract::spec_xdr_get_roles(),
+        &CrowdfundingContract::spec_xdr_set_council(),
+        &CrowdfundingContract::spec_xdr_get_council(),
+        &CrowdfundingContract::spec_xdr_propose_council_action(),
+        &CrowdfundingContract::spec_xdr_approve_council_action(),
+        &CrowdfundingContract::spec_xdr_execute_council_action(),
+        &CrowdfundingContract::spec_xdr_get_council_proposal(),
+        &CrowdfundingContract::spec_xdr_version(),
+        &CrowdfundingContract::spec_xdr_set_upgrade_timelock(),
+        &CrowdfundingContract::spec_xdr_get_upgrade_timelock(),
+        &CrowdfundingContract::spec_xdr_propose_upgrade(),
+        &CrowdfundingContract::spec_xdr_execute_upgrade(),
+        &CrowdfundingContract::spec_xdr_cancel_upgrade(),
+        &CrowdfundingContract::spec_xdr_get_pending_upgrade(),
+        &CrowdfundingContract::spec_xdr_get_schema_version(),
+        &CrowdfundingContract::spec_xdr_get_migration_cursor(),
+        &CrowdfundingContract::spec_xdr_migrate(),
+        &CrowdfundingContract::spec_xdr_migrate_pool_contributions(),
+        &CrowdfundingContract::spec_xdr_contribute(),
+        &CrowdfundingContract::spec_xdr_refund(),
+        &CrowdfundingContract::spec_xdr_request_emergency_withdraw(),
+        &CrowdfundingContract::spec_xdr_execute_emergency_withdraw(),
+        &CrowdfundingContract::spec_xdr_cancel_emergency_withdraw(),
+        &CrowdfundingContract::spec_xdr_get_emergency_withdrawal(),
+        &CrowdfundingContract::spec_xdr_set_emergency_withdraw_delay(),
+        &CrowdfundingContract::spec_xdr_get_emergency_withdraw_delay(),
+        &CrowdfundingContract::spec_xdr_get_escrowed_balance(),
+        &CrowdfundingContract::spec_xdr_get_withdrawable_balance(),
+        &CrowdfundingContract::spec_xdr_withdraw_fees(),
+        &CrowdfundingContract::spec_xdr_get_fee_balance(),
+        &CrowdfundingContract::spec_xdr_close_pool(),
+        &CrowdfundingContract::spec_xdr_is_closed(),
+    ];
+    assert_eq!(specs.len(), FUNCTIONS.len());
+
+    for xdr in specs {
+        let ScSpecEntry::FunctionV0(function) = spec(xdr) else {
+            panic!("expected a function spec");
+        };
+        let name = function.name.to_utf8_string_lossy();
+        let wrapped = FUNCTIONS
+            .iter()
+            .find(|f| f.name == name)
+            .unwrap_or_else(|| panic!("`{name}` has no client wrapper"));
+        let inputs: Vec<String> = function
+            .inputs
+            .iter()
+            .map(|input| input.name.to_utf8_string_lossy())
+            .collect();
+        assert_eq!(inputs, wrapped.inputs, "inputs of `{name}`");
+    }
+}
+
+#[test]
+fn errors_match_contract_spec() {
+    let ScSpecEntry::UdtErrorEnumV0(errors) = spec(&CrowdfundingError::spec_xdr()) else {
+        panic!("expected an error enum spec");
+    };
+    assert_eq!(errors.cases.len(), ContractError::ALL.len());
+    for case in errors.cases.iter() {
+        let error = ContractError::from_code(case.value)
+            .unwrap_or_else(|| panic!("code {} has no client error", case.value));
+        assert_eq!(error.name(), case.name.to_utf8_string_lossy());
+    }
+}
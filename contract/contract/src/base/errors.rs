@@ -50,4 +50,12 @@ pub enum CrowdfundingError {
     RefundGracePeriodNotPassed = 44,
     PoolAlreadyClosed = 45,
     PoolNotDisbursedOrRefunded = 46,
+    ArithmeticOverflow = 47,
+    /// The referenced proposal, request or grant does not exist.
+    NotFound = 48,
+    /// The proposal, request or grant being created already exists, or the
+    /// proposal was already executed.
+    AlreadyExists = 49,
+    /// A timelocked action was attempted before its delay elapsed.
+    TimelockNotPassed = 50,
 }
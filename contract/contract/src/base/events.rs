@@ -1,7 +1,703 @@
-#![allow(deprecated)]
-use soroban_sdk::{Address, BytesN, Env, String, Symbol};
+//! Contract events.
+//!
+//! Every event is a `#[contractevent]` struct, so its layout is published in
+//! the contract spec. Topics are `[event_name, subject, ...]` where the
+//! subject is the pool or campaign id for pool/campaign events and the acting
+//! address for platform-wide events. Every payload carries `version`, which
+//! is bumped whenever a field is added, removed or changes meaning.
+use soroban_sdk::{contractevent, Address, BytesN, Env, String, Vec};
 
-use crate::base::types::PoolState;
+use crate::base::types::{
+    CampaignAmendmentRules, CampaignMetadata, CouncilAction, FeeEntity, FeeTier, PoolState, Role,
+};
+
+/// Version of the event payload layout emitted by this contract.
+pub const EVENT_SCHEMA_VERSION: u32 = 1;
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractInitialized {
+    #[topic]
+    pub admin: Address,
+    pub token: Address,
+    pub creation_fee: i128,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CampaignCreated {
+    #[topic]
+    pub campaign_id: BytesN<32>,
+    #[topic]
+    pub creator: Address,
+    pub title: String,
+    pub goal: i128,
+    pub deadline: u64,
+    pub token: Address,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CampaignGoalReached {
+    #[topic]
+    pub campaign_id: BytesN<32>,
+    pub total_raised: i128,
+    pub timestamp: u64,
+    pub version: u32,
+}
+
+/// A campaign stopped before its deadline. Donations are closed and donors
+/// can reclaim them with `refund_campaign`.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CampaignCancelled {
+    #[topic]
+    pub campaign_id: BytesN<32>,
+    #[topic]
+    pub caller: Address,
+    pub reason: String,
+    pub timestamp: u64,
+    pub version: u32,
+}
+
+/// A creator extended a campaign's deadline or changed its goal. `revision`
+/// changes only with material amendments.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CampaignAmended {
+    #[topic]
+    pub campaign_id: BytesN<32>,
+    pub goal: i128,
+    pub deadline: u64,
+    pub revision: u32,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CampaignDetailsUpdated {
+    #[topic]
+    pub campaign_id: BytesN<32>,
+    pub title: String,
+    pub description: String,
+    pub external_url: String,
+    pub image_hash: String,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CampaignOwnershipProposed {
+    #[topic]
+    pub campaign_id: BytesN<32>,
+    pub owner: Address,
+    pub proposed_owner: Address,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CampaignTransferCancelled {
+    #[topic]
+    pub campaign_id: BytesN<32>,
+    pub owner: Address,
+    pub proposed_owner: Address,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CampaignOwnershipTransferred {
+    #[topic]
+    pub campaign_id: BytesN<32>,
+    pub new_owner: Address,
+    pub previous_owner: Address,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DonationMade {
+    #[topic]
+    pub campaign_id: BytesN<32>,
+    #[topic]
+    pub contributor: Address,
+    pub asset: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolCreated {
+    #[topic]
+    pub pool_id: u64,
+    #[topic]
+    pub creator: Address,
+    pub name: String,
+    pub description: String,
+    pub target_amount: i128,
+    pub is_private: bool,
+    pub deadline: u64,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolMetadataSet {
+    #[topic]
+    pub pool_id: u64,
+    pub description: String,
+    pub external_url: String,
+    pub image_hash: String,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolMultiSigSet {
+    #[topic]
+    pub pool_id: u64,
+    pub required_signatures: u32,
+    pub signers: Vec<Address>,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolOwnershipProposed {
+    #[topic]
+    pub pool_id: u64,
+    pub owner: Address,
+    pub proposed_owner: Address,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolTransferCancelled {
+    #[topic]
+    pub pool_id: u64,
+    pub owner: Address,
+    pub proposed_owner: Address,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolOwnershipTransferred {
+    #[topic]
+    pub pool_id: u64,
+    pub new_owner: Address,
+    pub previous_owner: Address,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolStateUpdated {
+    #[topic]
+    pub pool_id: u64,
+    pub old_state: PoolState,
+    pub new_state: PoolState,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractPaused {
+    #[topic]
+    pub admin: Address,
+    pub timestamp: u64,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractUnpaused {
+    #[topic]
+    pub admin: Address,
+    pub timestamp: u64,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Contribution {
+    #[topic]
+    pub pool_id: u64,
+    #[topic]
+    pub contributor: Address,
+    pub asset: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+    pub is_private: bool,
+    pub version: u32,
+}
+
+/// `recipient` is the account the withdrawal pays, or `None` when it pays
+/// `admin`.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyWithdrawRequested {
+    #[topic]
+    pub admin: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub recipient: Option<Address>,
+    pub unlock_time: u64,
+    pub version: u32,
+}
+
+/// `recipient` is the account paid, or `None` when it is `admin`.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyWithdrawExecuted {
+    #[topic]
+    pub admin: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub recipient: Option<Address>,
+    pub version: u32,
+}
+
+/// Collected fees paid out of the fee treasury by a treasury role holder.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeesWithdrawn {
+    #[topic]
+    pub admin: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub to: Address,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CampaignAmendmentRulesSet {
+    #[topic]
+    pub admin: Address,
+    pub max_extension: u64,
+    pub single_extension: bool,
+    pub material_goal_change_bps: u32,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaxPauseDurationSet {
+    #[topic]
+    pub admin: Address,
+    pub duration: u64,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeaturesPaused {
+    #[topic]
+    pub admin: Address,
+    pub features: u32,
+    pub reason: u32,
+    pub timestamp: u64,
+    pub expires_at: u64,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeaturesUnpaused {
+    #[topic]
+    pub admin: Address,
+    pub features: u32,
+    pub timestamp: u64,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CouncilSet {
+    #[topic]
+    pub admin: Address,
+    pub required_signatures: u32,
+    pub signers: Vec<Address>,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CouncilDisabled {
+    #[topic]
+    pub executor: Address,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CouncilActionProposed {
+    #[topic]
+    pub proposal_id: u64,
+    #[topic]
+    pub proposer: Address,
+    pub action: CouncilAction,
+    pub expires_at: u64,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CouncilActionApproved {
+    #[topic]
+    pub proposal_id: u64,
+    #[topic]
+    pub signer: Address,
+    pub approvals: u32,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CouncilActionExecuted {
+    #[topic]
+    pub proposal_id: u64,
+    #[topic]
+    pub executor: Address,
+    pub version: u32,
+}
+
+/// A donation paid back to its donor.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CampaignRefunded {
+    #[topic]
+    pub campaign_id: BytesN<32>,
+    #[topic]
+    pub contributor: Address,
+    pub asset: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyWithdrawCancelled {
+    #[topic]
+    pub admin: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyWithdrawDelaySet {
+    #[topic]
+    pub admin: Address,
+    pub delay: u64,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CrowdfundingTokenSet {
+    #[topic]
+    pub admin: Address,
+    pub token: Address,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreationFeeSet {
+    #[topic]
+    pub admin: Address,
+    pub fee: i128,
+    pub version: u32,
+}
+
+/// A creation fee change queued to take effect at `effective_at`, when
+/// `CreationFeeSet` is emitted.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreationFeeQueued {
+    #[topic]
+    pub admin: Address,
+    pub fee: i128,
+    pub effective_at: u64,
+    pub version: u32,
+}
+
+/// A platform token change queued to take effect at `effective_at`, when
+/// `CrowdfundingTokenSet` is emitted.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CrowdfundingTokenQueued {
+    #[topic]
+    pub admin: Address,
+    pub token: Address,
+    pub effective_at: u64,
+    pub version: u32,
+}
+
+/// The creation fee of a pool entity changed. Campaign fee changes keep
+/// emitting `CreationFeeSet`.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EntityCreationFeeSet {
+    #[topic]
+    pub admin: Address,
+    pub entity: FeeEntity,
+    pub fee: i128,
+    pub version: u32,
+}
+
+/// A pool entity fee change queued to take effect at `effective_at`, when
+/// `EntityCreationFeeSet` is emitted.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EntityCreationFeeQueued {
+    #[topic]
+    pub admin: Address,
+    pub entity: FeeEntity,
+    pub fee: i128,
+    pub effective_at: u64,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreationFeePaid {
+    #[topic]
+    pub creator: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlatformFeeSet {
+    #[topic]
+    pub admin: Address,
+    pub bps: u32,
+    pub version: u32,
+}
+
+/// A platform fee rate change queued to take effect at `effective_at`, when
+/// `PlatformFeeSet` is emitted.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlatformFeeQueued {
+    #[topic]
+    pub admin: Address,
+    pub bps: u32,
+    pub effective_at: u64,
+    pub version: u32,
+}
+
+/// A pool's platform fee override set, or cleared when `bps` is `None`.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolPlatformFeeSet {
+    #[topic]
+    pub pool_id: u64,
+    pub admin: Address,
+    pub bps: Option<u32>,
+    pub version: u32,
+}
+
+/// A fee tier assigned to `creator`, replacing any earlier one. An
+/// `expires_at` of 0 never expires.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeTierAssigned {
+    #[topic]
+    pub admin: Address,
+    pub creator: Address,
+    pub tier: FeeTier,
+    pub expires_at: u64,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeTierRevoked {
+    #[topic]
+    pub admin: Address,
+    pub creator: Address,
+    pub version: u32,
+}
+
+/// A token added to the accepted-token registry, or its minimum amount
+/// changed.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AcceptedTokenSet {
+    #[topic]
+    pub admin: Address,
+    pub token: Address,
+    pub symbol: String,
+    pub decimals: u32,
+    pub min_amount: i128,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AcceptedTokenRemoved {
+    #[topic]
+    pub admin: Address,
+    pub token: Address,
+    pub version: u32,
+}
+
+/// Platform fee taken from a donation or contribution. The `amount` of the
+/// matching `DonationMade` or `Contribution` event is net of this fee.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlatformFeePaid {
+    #[topic]
+    pub payer: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Refund {
+    #[topic]
+    pub pool_id: u64,
+    #[topic]
+    pub contributor: Address,
+    pub asset: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolClosed {
+    #[topic]
+    pub pool_id: u64,
+    #[topic]
+    pub closed_by: Address,
+    pub timestamp: u64,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminProposed {
+    #[topic]
+    pub admin: Address,
+    pub proposed_admin: Address,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminProposalCancelled {
+    #[topic]
+    pub admin: Address,
+    pub proposed_admin: Address,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminTransferred {
+    #[topic]
+    pub new_admin: Address,
+    pub previous_admin: Address,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleGranted {
+    #[topic]
+    pub admin: Address,
+    pub account: Address,
+    pub role: Role,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleRevoked {
+    #[topic]
+    pub admin: Address,
+    pub account: Address,
+    pub role: Role,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeTimelockSet {
+    #[topic]
+    pub admin: Address,
+    pub delay: u64,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeProposed {
+    #[topic]
+    pub admin: Address,
+    pub wasm_hash: BytesN<32>,
+    pub executable_at: u64,
+    pub version: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeCancelled {
+    #[topic]
+    pub admin: Address,
+    pub wasm_hash: BytesN<32>,
+    pub version: u32,
+}
+
+/// `from_version` is the `version()` of the code being replaced.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeExecuted {
+    #[topic]
+    pub admin: Address,
+    pub wasm_hash: BytesN<32>,
+    pub from_version: u32,
+    pub version: u32,
+}
+
+/// Emitted when `migrate` has rewritten every indexed record.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StorageMigrated {
+    #[topic]
+    pub admin: Address,
+    pub from_version: u32,
+    pub to_version: u32,
+    pub version: u32,
+}
+
+pub fn contract_initialized(env: &Env, admin: Address, token: Address, creation_fee: i128) {
+    ContractInitialized {
+        admin,
+        token,
+        creation_fee,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
 
 pub fn campaign_created(
     env: &Env,
@@ -10,11 +706,31 @@ pub fn campaign_created(
     creator: Address,
     goal: i128,
     deadline: u64,
+    token: Address,
 ) {
-    let topics = (Symbol::new(env, "campaign_created"), id, creator);
-    env.events().publish(topics, (title, goal, deadline));
+    CampaignCreated {
+        campaign_id: id,
+        creator,
+        title,
+        goal,
+        deadline,
+        token,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn campaign_goal_reached(env: &Env, campaign_id: BytesN<32>, total_raised: i128) {
+    CampaignGoalReached {
+        campaign_id,
+        total_raised,
+        timestamp: env.ledger().timestamp(),
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn pool_created(
     env: &Env,
     pool_id: u64,
@@ -22,31 +738,93 @@ pub fn pool_created(
     description: String,
     creator: Address,
     target_amount: i128,
+    is_private: bool,
     deadline: u64,
 ) {
-    let topics = (Symbol::new(env, "pool_created"), pool_id, creator);
-    env.events()
-        .publish(topics, (name, description, target_amount, deadline));
+    PoolCreated {
+        pool_id,
+        creator,
+        name,
+        description,
+        target_amount,
+        is_private,
+        deadline,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn pool_metadata_set(
+    env: &Env,
+    pool_id: u64,
+    description: String,
+    external_url: String,
+    image_hash: String,
+) {
+    PoolMetadataSet {
+        pool_id,
+        description,
+        external_url,
+        image_hash,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn pool_multisig_set(env: &Env, pool_id: u64, required_signatures: u32, signers: Vec<Address>) {
+    PoolMultiSigSet {
+        pool_id,
+        required_signatures,
+        signers,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
 }
 
-pub fn pool_state_updated(env: &Env, pool_id: u64, new_state: PoolState) {
-    let topics = (Symbol::new(env, "pool_state_updated"), pool_id);
-    env.events().publish(topics, new_state);
+pub fn pool_state_updated(env: &Env, pool_id: u64, old_state: PoolState, new_state: PoolState) {
+    PoolStateUpdated {
+        pool_id,
+        old_state,
+        new_state,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
 }
 
 pub fn contract_paused(env: &Env, admin: Address, timestamp: u64) {
-    let topics = (Symbol::new(env, "contract_paused"), admin);
-    env.events().publish(topics, timestamp);
+    ContractPaused {
+        admin,
+        timestamp,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
 }
 
 pub fn contract_unpaused(env: &Env, admin: Address, timestamp: u64) {
-    let topics = (Symbol::new(env, "contract_unpaused"), admin);
-    env.events().publish(topics, timestamp);
+    ContractUnpaused {
+        admin,
+        timestamp,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
 }
 
-pub fn donation_made(env: &Env, campaign_id: BytesN<32>, contributor: Address, amount: i128) {
-    let topics = (Symbol::new(env, "donation_made"), campaign_id);
-    env.events().publish(topics, (contributor, amount));
+pub fn donation_made(
+    env: &Env,
+    campaign_id: BytesN<32>,
+    contributor: Address,
+    asset: Address,
+    amount: i128,
+) {
+    DonationMade {
+        campaign_id,
+        contributor,
+        asset,
+        amount,
+        timestamp: env.ledger().timestamp(),
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
 }
 
 pub fn contribution(
@@ -58,9 +836,16 @@ pub fn contribution(
     timestamp: u64,
     is_private: bool,
 ) {
-    let topics = (Symbol::new(env, "contribution"), pool_id, contributor);
-    env.events()
-        .publish(topics, (asset, amount, timestamp, is_private));
+    Contribution {
+        pool_id,
+        contributor,
+        asset,
+        amount,
+        timestamp,
+        is_private,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
 }
 
 pub fn emergency_withdraw_requested(
@@ -68,30 +853,141 @@ pub fn emergency_withdraw_requested(
     admin: Address,
     token: Address,
     amount: i128,
+    recipient: Option<Address>,
     unlock_time: u64,
 ) {
-    let topics = (Symbol::new(env, "emergency_withdraw_requested"), admin);
-    env.events().publish(topics, (token, amount, unlock_time));
+    EmergencyWithdrawRequested {
+        admin,
+        token,
+        amount,
+        recipient,
+        unlock_time,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn emergency_withdraw_executed(
+    env: &Env,
+    admin: Address,
+    token: Address,
+    amount: i128,
+    recipient: Option<Address>,
+) {
+    EmergencyWithdrawExecuted {
+        admin,
+        token,
+        amount,
+        recipient,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
 }
 
-pub fn emergency_withdraw_executed(env: &Env, admin: Address, token: Address, amount: i128) {
-    let topics = (Symbol::new(env, "emergency_withdraw_executed"), admin);
-    env.events().publish(topics, (token, amount));
+pub fn fees_withdrawn(env: &Env, admin: Address, token: Address, amount: i128, to: Address) {
+    FeesWithdrawn {
+        admin,
+        token,
+        amount,
+        to,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn max_pause_duration_set(env: &Env, admin: Address, duration: u64) {
+    MaxPauseDurationSet {
+        admin,
+        duration,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn campaign_refunded(
+    env: &Env,
+    campaign_id: BytesN<32>,
+    contributor: Address,
+    asset: Address,
+    amount: i128,
+    timestamp: u64,
+) {
+    CampaignRefunded {
+        campaign_id,
+        contributor,
+        asset,
+        amount,
+        timestamp,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn emergency_withdraw_cancelled(env: &Env, admin: Address, token: Address, amount: i128) {
+    EmergencyWithdrawCancelled {
+        admin,
+        token,
+        amount,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn emergency_withdraw_delay_set(env: &Env, admin: Address, delay: u64) {
+    EmergencyWithdrawDelaySet {
+        admin,
+        delay,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
 }
 
 pub fn crowdfunding_token_set(env: &Env, admin: Address, token: Address) {
-    let topics = (Symbol::new(env, "crowdfunding_token_set"), admin);
-    env.events().publish(topics, token);
+    CrowdfundingTokenSet {
+        admin,
+        token,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
 }
 
 pub fn creation_fee_set(env: &Env, admin: Address, fee: i128) {
-    let topics = (Symbol::new(env, "creation_fee_set"), admin);
-    env.events().publish(topics, fee);
+    CreationFeeSet {
+        admin,
+        fee,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
 }
 
-pub fn creation_fee_paid(env: &Env, creator: Address, amount: i128) {
-    let topics = (Symbol::new(env, "creation_fee_paid"), creator);
-    env.events().publish(topics, amount);
+pub fn creation_fee_queued(env: &Env, admin: Address, fee: i128, effective_at: u64) {
+    CreationFeeQueued {
+        admin,
+        fee,
+        effective_at,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn crowdfunding_token_queued(env: &Env, admin: Address, token: Address, effective_at: u64) {
+    CrowdfundingTokenQueued {
+        admin,
+        token,
+        effective_at,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn creation_fee_paid(env: &Env, creator: Address, token: Address, amount: i128) {
+    CreationFeePaid {
+        creator,
+        token,
+        amount,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
 }
 
 pub fn refund(
@@ -102,11 +998,457 @@ pub fn refund(
     amount: i128,
     timestamp: u64,
 ) {
-    let topics = (Symbol::new(env, "refund"), pool_id, contributor);
-    env.events().publish(topics, (asset, amount, timestamp));
+    Refund {
+        pool_id,
+        contributor,
+        asset,
+        amount,
+        timestamp,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
 }
 
 pub fn pool_closed(env: &Env, pool_id: u64, closed_by: Address, timestamp: u64) {
-    let topics = (Symbol::new(env, "pool_closed"), pool_id, closed_by);
-    env.events().publish(topics, timestamp);
+    PoolClosed {
+        pool_id,
+        closed_by,
+        timestamp,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn admin_proposed(env: &Env, admin: Address, proposed_admin: Address) {
+    AdminProposed {
+        admin,
+        proposed_admin,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn admin_proposal_cancelled(env: &Env, admin: Address, proposed_admin: Address) {
+    AdminProposalCancelled {
+        admin,
+        proposed_admin,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn admin_transferred(env: &Env, new_admin: Address, previous_admin: Address) {
+    AdminTransferred {
+        new_admin,
+        previous_admin,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn role_granted(env: &Env, admin: Address, account: Address, role: Role) {
+    RoleGranted {
+        admin,
+        account,
+        role,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn role_revoked(env: &Env, admin: Address, account: Address, role: Role) {
+    RoleRevoked {
+        admin,
+        account,
+        role,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn upgrade_timelock_set(env: &Env, admin: Address, delay: u64) {
+    UpgradeTimelockSet {
+        admin,
+        delay,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn upgrade_proposed(env: &Env, admin: Address, wasm_hash: BytesN<32>, executable_at: u64) {
+    UpgradeProposed {
+        admin,
+        wasm_hash,
+        executable_at,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn upgrade_cancelled(env: &Env, admin: Address, wasm_hash: BytesN<32>) {
+    UpgradeCancelled {
+        admin,
+        wasm_hash,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn upgrade_executed(env: &Env, admin: Address, wasm_hash: BytesN<32>, from_version: u32) {
+    UpgradeExecuted {
+        admin,
+        wasm_hash,
+        from_version,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn storage_migrated(env: &Env, admin: Address, from_version: u32, to_version: u32) {
+    StorageMigrated {
+        admin,
+        from_version,
+        to_version,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn features_paused(
+    env: &Env,
+    admin: Address,
+    features: u32,
+    reason: u32,
+    timestamp: u64,
+    expires_at: u64,
+) {
+    FeaturesPaused {
+        admin,
+        features,
+        reason,
+        timestamp,
+        expires_at,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn features_unpaused(env: &Env, admin: Address, features: u32, timestamp: u64) {
+    FeaturesUnpaused {
+        admin,
+        features,
+        timestamp,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn council_set(env: &Env, admin: Address, required_signatures: u32, signers: Vec<Address>) {
+    CouncilSet {
+        admin,
+        required_signatures,
+        signers,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn council_disabled(env: &Env, executor: Address) {
+    CouncilDisabled {
+        executor,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn council_action_proposed(
+    env: &Env,
+    proposal_id: u64,
+    proposer: Address,
+    action: CouncilAction,
+    expires_at: u64,
+) {
+    CouncilActionProposed {
+        proposal_id,
+        proposer,
+        action,
+        expires_at,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn council_action_approved(env: &Env, proposal_id: u64, signer: Address, approvals: u32) {
+    CouncilActionApproved {
+        proposal_id,
+        signer,
+        approvals,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn council_action_executed(env: &Env, proposal_id: u64, executor: Address) {
+    CouncilActionExecuted {
+        proposal_id,
+        executor,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn entity_creation_fee_set(env: &Env, admin: Address, entity: FeeEntity, fee: i128) {
+    EntityCreationFeeSet {
+        admin,
+        entity,
+        fee,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn entity_creation_fee_queued(
+    env: &Env,
+    admin: Address,
+    entity: FeeEntity,
+    fee: i128,
+    effective_at: u64,
+) {
+    EntityCreationFeeQueued {
+        admin,
+        entity,
+        fee,
+        effective_at,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn platform_fee_set(env: &Env, admin: Address, bps: u32) {
+    PlatformFeeSet {
+        admin,
+        bps,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn platform_fee_queued(env: &Env, admin: Address, bps: u32, effective_at: u64) {
+    PlatformFeeQueued {
+        admin,
+        bps,
+        effective_at,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn pool_platform_fee_set(env: &Env, pool_id: u64, admin: Address, bps: Option<u32>) {
+    PoolPlatformFeeSet {
+        pool_id,
+        admin,
+        bps,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn platform_fee_paid(env: &Env, payer: Address, token: Address, amount: i128) {
+    PlatformFeePaid {
+        payer,
+        token,
+        amount,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn fee_tier_assigned(
+    env: &Env,
+    admin: Address,
+    creator: Address,
+    tier: FeeTier,
+    expires_at: u64,
+) {
+    FeeTierAssigned {
+        admin,
+        creator,
+        tier,
+        expires_at,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn fee_tier_revoked(env: &Env, admin: Address, creator: Address) {
+    FeeTierRevoked {
+        admin,
+        creator,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn accepted_token_set(
+    env: &Env,
+    admin: Address,
+    token: Address,
+    symbol: String,
+    decimals: u32,
+    min_amount: i128,
+) {
+    AcceptedTokenSet {
+        admin,
+        token,
+        symbol,
+        decimals,
+        min_amount,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn accepted_token_removed(env: &Env, admin: Address, token: Address) {
+    AcceptedTokenRemoved {
+        admin,
+        token,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn campaign_cancelled(env: &Env, campaign_id: BytesN<32>, caller: Address, reason: String) {
+    CampaignCancelled {
+        campaign_id,
+        caller,
+        reason,
+        timestamp: env.ledger().timestamp(),
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn campaign_amended(
+    env: &Env,
+    campaign_id: BytesN<32>,
+    goal: i128,
+    deadline: u64,
+    revision: u32,
+) {
+    CampaignAmended {
+        campaign_id,
+        goal,
+        deadline,
+        revision,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn campaign_amendment_rules_set(env: &Env, admin: Address, rules: CampaignAmendmentRules) {
+    CampaignAmendmentRulesSet {
+        admin,
+        max_extension: rules.max_extension,
+        single_extension: rules.single_extension,
+        material_goal_change_bps: rules.material_goal_change_bps,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn campaign_details_updated(
+    env: &Env,
+    campaign_id: BytesN<32>,
+    title: String,
+    metadata: CampaignMetadata,
+) {
+    CampaignDetailsUpdated {
+        campaign_id,
+        title,
+        description: metadata.description,
+        external_url: metadata.external_url,
+        image_hash: metadata.image_hash,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn campaign_ownership_proposed(
+    env: &Env,
+    campaign_id: BytesN<32>,
+    owner: Address,
+    proposed_owner: Address,
+) {
+    CampaignOwnershipProposed {
+        campaign_id,
+        owner,
+        proposed_owner,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn campaign_transfer_cancelled(
+    env: &Env,
+    campaign_id: BytesN<32>,
+    owner: Address,
+    proposed_owner: Address,
+) {
+    CampaignTransferCancelled {
+        campaign_id,
+        owner,
+        proposed_owner,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn campaign_ownership_transferred(
+    env: &Env,
+    campaign_id: BytesN<32>,
+    new_owner: Address,
+    previous_owner: Address,
+) {
+    CampaignOwnershipTransferred {
+        campaign_id,
+        new_owner,
+        previous_owner,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn pool_ownership_proposed(env: &Env, pool_id: u64, owner: Address, proposed_owner: Address) {
+    PoolOwnershipProposed {
+        pool_id,
+        owner,
+        proposed_owner,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn pool_transfer_cancelled(env: &Env, pool_id: u64, owner: Address, proposed_owner: Address) {
+    PoolTransferCancelled {
+        pool_id,
+        owner,
+        proposed_owner,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
+}
+
+pub fn pool_ownership_transferred(
+    env: &Env,
+    pool_id: u64,
+    new_owner: Address,
+    previous_owner: Address,
+) {
+    PoolOwnershipTransferred {
+        pool_id,
+        new_owner,
+        previous_owner,
+        version: EVENT_SCHEMA_VERSION,
+    }
+    .publish(env);
 }
@@ -1,3 +1,4 @@
 pub mod errors;
 pub mod events;
+pub mod storage;
 pub mod types;
@@ -0,0 +1,69 @@
+//! Versioned access to records whose layout may change between upgrades.
+//!
+//! Records are written as the latest variant of their `*Record` enum. Reads
+//! also accept older variants and the bare structs stored before schema
+//! version 1, so the contract keeps working while `migrate` is still
+//! rewriting old entries.
+use soroban_sdk::{BytesN, Env, FromVal, TryFromVal, Val};
+
+use crate::base::types::{
+    CampaignDetails, CampaignDetailsV1, CampaignRecord, PoolConfig, PoolContribution,
+    PoolContributionRecord, PoolRecord, StorageKey,
+};
+
+/// Storage key of a campaign. Campaigns predate `StorageKey` and are keyed by
+/// a one-element tuple of their id.
+pub fn campaign_key(id: &BytesN<32>) -> (BytesN<32>,) {
+    (id.clone(),)
+}
+
+pub fn read_campaign(env: &Env, id: &BytesN<32>) -> Option<CampaignDetails> {
+    let value: Val = env.storage().instance().get(&campaign_key(id))?;
+    match CampaignRecord::try_from_val(env, &value) {
+        Ok(CampaignRecord::V3(campaign)) => Some(campaign),
+        Ok(CampaignRecord::V2(campaign)) => Some(campaign.upgrade()),
+        Ok(CampaignRecord::V1(campaign)) => Some(campaign.upgrade().upgrade()),
+        Err(_) => Some(legacy::<CampaignDetailsV1>(env, value).upgrade().upgrade()),
+    }
+}
+
+pub fn write_campaign(env: &Env, campaign: &CampaignDetails) {
+    env.storage().instance().set(
+        &campaign_key(&campaign.id),
+        &CampaignRecord::V3(campaign.clone()),
+    );
+}
+
+pub fn read_pool(env: &Env, pool_id: u64) -> Option<PoolConfig> {
+    let value: Val = env.storage().instance().get(&StorageKey::Pool(pool_id))?;
+    match PoolRecord::try_from_val(env, &value) {
+        Ok(PoolRecord::V1(pool)) => Some(pool),
+        Err(_) => Some(legacy(env, value)),
+    }
+}
+
+pub fn write_pool(env: &Env, pool_id: u64, pool: &PoolConfig) {
+    env.storage()
+        .instance()
+        .set(&StorageKey::Pool(pool_id), &PoolRecord::V1(pool.clone()));
+}
+
+pub fn read_pool_contribution(env: &Env, key: &StorageKey) -> Option<PoolContribution> {
+    let value: Val = env.storage().instance().get(key)?;
+    match PoolContributionRecord::try_from_val(env, &value) {
+        Ok(PoolContributionRecord::V1(contribution)) => Some(contribution),
+        Err(_) => Some(legacy(env, value)),
+    }
+}
+
+pub fn write_pool_contribution(env: &Env, key: &StorageKey, contribution: &PoolContribution) {
+    env.storage()
+        .instance()
+        .set(key, &PoolContributionRecord::V1(contribution.clone()));
+}
+
+/// Decode a record stored in the unversioned layout, trapping like a plain
+/// `get` would if it is neither that nor a known variant.
+fn legacy<T: FromVal<Env, Val>>(env: &Env, value: Val) -> T {
+    T::from_val(env, &value)
+}
@@ -1,4 +1,6 @@
-use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, Env, String, Vec};
+
+use crate::base::errors::CrowdfundingError;
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -10,6 +12,90 @@ pub struct CampaignDetails {
     pub deadline: u64,
     pub total_raised: i128,
     pub token_address: Address,
+    pub status: CampaignStatus,
+    pub metadata: CampaignMetadata,
+}
+
+/// Descriptive details of a campaign, limited like [`PoolMetadata`]. Freely
+/// editable until the first donation; afterwards fields can only be extended.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CampaignMetadata {
+    pub description: String,
+    pub external_url: String,
+    pub image_hash: String,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum CampaignStatus {
+    Active = 0,
+    /// Cancelled by its creator or a moderator; donors can reclaim their
+    /// contributions.
+    Cancelled = 1,
+}
+
+/// Campaign layout stored before schema version 2 added `status`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CampaignDetailsV1 {
+    pub id: BytesN<32>,
+    pub title: String,
+    pub creator: Address,
+    pub goal: i128,
+    pub deadline: u64,
+    pub total_raised: i128,
+    pub token_address: Address,
+}
+
+impl CampaignDetailsV1 {
+    /// Campaigns stored in this layout could not be cancelled.
+    pub fn upgrade(self) -> CampaignDetailsV2 {
+        CampaignDetailsV2 {
+            id: self.id,
+            title: self.title,
+            creator: self.creator,
+            goal: self.goal,
+            deadline: self.deadline,
+            total_raised: self.total_raised,
+            token_address: self.token_address,
+            status: CampaignStatus::Active,
+        }
+    }
+}
+
+/// Campaign layout of schema version 2, with `status` but stored before
+/// version 3 added `metadata`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CampaignDetailsV2 {
+    pub id: BytesN<32>,
+    pub title: String,
+    pub creator: Address,
+    pub goal: i128,
+    pub deadline: u64,
+    pub total_raised: i128,
+    pub token_address: Address,
+    pub status: CampaignStatus,
+}
+
+impl CampaignDetailsV2 {
+    /// Campaigns stored in this layout had no metadata.
+    pub fn upgrade(self) -> CampaignDetails {
+        let metadata = CampaignMetadata::empty(self.title.env());
+        CampaignDetails {
+            id: self.id,
+            title: self.title,
+            creator: self.creator,
+            goal: self.goal,
+            deadline: self.deadline,
+            total_raised: self.total_raised,
+            token_address: self.token_address,
+            status: self.status,
+            metadata,
+        }
+    }
 }
 
 #[contracttype]
@@ -51,28 +137,164 @@ pub const MAX_DESCRIPTION_LENGTH: u32 = 500;
 pub const MAX_URL_LENGTH: u32 = 200;
 pub const MAX_HASH_LENGTH: u32 = 100;
 
+/// Shortest funding window a pool may be opened with (1 hour).
+pub const MIN_POOL_DURATION: u64 = 60 * 60;
+/// Longest funding window a pool may be opened with (365 days).
+pub const MAX_POOL_DURATION: u64 = 365 * 24 * 60 * 60;
+
+/// Version reported by `version()`; bumped with every released upgrade.
+pub const CONTRACT_VERSION: u32 = 1;
+/// Delay between `propose_upgrade` and `execute_upgrade` unless configured (7 days).
+pub const DEFAULT_UPGRADE_TIMELOCK: u64 = 7 * 24 * 60 * 60;
+/// Shortest upgrade timelock the admin may configure (1 day).
+pub const MIN_UPGRADE_TIMELOCK: u64 = 24 * 60 * 60;
+/// Longest upgrade timelock the admin may configure (30 days), so upgrades
+/// cannot be locked out.
+pub const MAX_UPGRADE_TIMELOCK: u64 = 30 * 24 * 60 * 60;
+
+/// Delay between requesting and executing an emergency withdrawal unless
+/// configured (24 hours).
+pub const DEFAULT_EMERGENCY_WITHDRAW_DELAY: u64 = 24 * 60 * 60;
+/// Shortest emergency withdrawal delay the admin may configure (1 hour).
+pub const MIN_EMERGENCY_WITHDRAW_DELAY: u64 = 60 * 60;
+/// Longest emergency withdrawal delay the admin may configure (30 days), so
+/// requests can always be executed.
+pub const MAX_EMERGENCY_WITHDRAW_DELAY: u64 = 30 * 24 * 60 * 60;
+
+/// How long the contract may stay paused before contributors can withdraw
+/// their funds through the escape hatch, unless configured (30 days).
+pub const DEFAULT_MAX_PAUSE_DURATION: u64 = 30 * 24 * 60 * 60;
+/// Bounds on the configurable maximum pause duration (1 to 90 days), so the
+/// escape hatch can never be disabled.
+pub const MIN_MAX_PAUSE_DURATION: u64 = 24 * 60 * 60;
+pub const MAX_MAX_PAUSE_DURATION: u64 = 90 * 24 * 60 * 60;
+/// How long the contract, or a single feature, must stay unpaused before a
+/// new pause restarts its clock (7 days). Pausing again sooner continues the
+/// last pause, so neither can be kept paused past the maximum duration.
+pub const MIN_UNPAUSED_PERIOD: u64 = 7 * 24 * 60 * 60;
+
+/// Delay between queuing a creation fee or platform token change and it
+/// taking effect (24 hours).
+pub const PARAMETER_CHANGE_DELAY: u64 = 24 * 60 * 60;
+
+/// Platform fees are given in basis points of the amount contributed; the
+/// rate can be at most the whole amount.
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+/// How long a council proposal can gather approvals and be executed (7 days).
+pub const COUNCIL_PROPOSAL_TTL: u64 = 7 * 24 * 60 * 60;
+
+/// Longest total extension of a campaign's deadline unless configured (30 days).
+pub const DEFAULT_MAX_CAMPAIGN_EXTENSION: u64 = 30 * 24 * 60 * 60;
+/// Goal changes of at least this share of the goal, in basis points, let
+/// earlier donors claim a refund unless configured (10%).
+pub const DEFAULT_MATERIAL_GOAL_CHANGE_BPS: u32 = 1_000;
+
+/// Features that can be paused on their own through `pause_features`, as
+/// bits of a `u32` mask. `pause` still freezes all of them at once.
+pub const PAUSE_CAMPAIGN_CREATION: u32 = 1 << 0;
+pub const PAUSE_DONATIONS: u32 = 1 << 1;
+pub const PAUSE_POOL_CREATION: u32 = 1 << 2;
+pub const PAUSE_CONTRIBUTIONS: u32 = 1 << 3;
+pub const PAUSE_REFUNDS: u32 = 1 << 4;
+pub const PAUSE_POOL_STATE_UPDATES: u32 = 1 << 5;
+pub const PAUSE_ALL_FEATURES: u32 = (1 << 6) - 1;
+
+/// Storage layout written by this code. Version 0 is the unversioned layout
+/// that stored records as bare structs; version 2 added `CampaignDetails::status`
+/// and version 3 added `CampaignDetails::metadata`.
+pub const STORAGE_SCHEMA_VERSION: u32 = 3;
+
 impl PoolConfig {
     /// Validate pool configuration according to Nevo invariants.
     ///
-    /// Follows Soroban best practices by failing fast with `panic!` when
-    /// invariants are violated. Callers should validate user input before
-    /// persisting configuration on-chain.
-    pub fn validate(&self) {
-        // Name must not be empty
-        assert!(!self.name.is_empty(), "pool name must not be empty");
+    /// Returns the matching `CrowdfundingError` instead of panicking so that
+    /// entrypoints can surface a typed error to the caller.
+    pub fn validate(&self) -> Result<(), CrowdfundingError> {
+        if self.name.is_empty() {
+            return Err(CrowdfundingError::InvalidPoolName);
+        }
+
+        if self.description.len() > MAX_DESCRIPTION_LENGTH {
+            return Err(CrowdfundingError::InvalidMetadata);
+        }
+
+        if self.target_amount <= 0 {
+            return Err(CrowdfundingError::InvalidPoolTarget);
+        }
+
+        if !(MIN_POOL_DURATION..=MAX_POOL_DURATION).contains(&self.duration) {
+            return Err(CrowdfundingError::InvalidPoolDeadline);
+        }
+
+        Ok(())
+    }
+
+    /// Timestamp at which the pool stops accepting contributions.
+    ///
+    /// Saturates rather than overflowing so an oversized `duration` can never
+    /// abort the refund path.
+    pub fn deadline(&self) -> u64 {
+        self.created_at.saturating_add(self.duration)
+    }
+}
+
+impl PoolMetadata {
+    /// Validate metadata field lengths against the storage limits.
+    pub fn validate(&self) -> Result<(), CrowdfundingError> {
+        validate_metadata(&self.description, &self.external_url, &self.image_hash)
+    }
+}
+
+impl CampaignMetadata {
+    pub fn empty(env: &Env) -> Self {
+        Self {
+            description: String::from_str(env, ""),
+            external_url: String::from_str(env, ""),
+            image_hash: String::from_str(env, ""),
+        }
+    }
+
+    /// Validate metadata field lengths against the same limits as pools.
+    pub fn validate(&self) -> Result<(), CrowdfundingError> {
+        validate_metadata(&self.description, &self.external_url, &self.image_hash)
+    }
+
+    /// Whether `self` only adds to `previous`: the description is appended
+    /// to, and the URL and image hash are unchanged or were unset.
+    pub fn extends(&self, previous: &Self) -> bool {
+        starts_with(&self.description, &previous.description)
+            && (previous.external_url.is_empty() || self.external_url == previous.external_url)
+            && (previous.image_hash.is_empty() || self.image_hash == previous.image_hash)
+    }
+}
 
-        // Description validation
-        assert!(
-            self.description.len() <= MAX_DESCRIPTION_LENGTH,
-            "description too long"
-        );
+fn validate_metadata(
+    description: &String,
+    external_url: &String,
+    image_hash: &String,
+) -> Result<(), CrowdfundingError> {
+    if description.len() > MAX_DESCRIPTION_LENGTH
+        || external_url.len() > MAX_URL_LENGTH
+        || image_hash.len() > MAX_HASH_LENGTH
+    {
+        return Err(CrowdfundingError::InvalidMetadata);
+    }
 
-        // Target amount must be strictly positive
-        assert!(self.target_amount > 0, "target_amount must be > 0");
+    Ok(())
+}
 
-        // Duration must be strictly positive (non-zero)
-        assert!(self.duration > 0, "duration must be > 0");
+/// Byte-wise prefix check for descriptions within `MAX_DESCRIPTION_LENGTH`.
+fn starts_with(value: &String, prefix: &String) -> bool {
+    let (len, prefix_len) = (value.len() as usize, prefix.len() as usize);
+    if prefix_len > len || len > MAX_DESCRIPTION_LENGTH as usize {
+        return false;
     }
+    let mut value_bytes = [0u8; MAX_DESCRIPTION_LENGTH as usize];
+    let mut prefix_bytes = [0u8; MAX_DESCRIPTION_LENGTH as usize];
+    value.copy_into_slice(&mut value_bytes[..len]);
+    prefix.copy_into_slice(&mut prefix_bytes[..prefix_len]);
+    value_bytes[..prefix_len] == prefix_bytes[..prefix_len]
 }
 
 #[contracttype]
@@ -87,6 +309,57 @@ pub enum PoolState {
     Closed = 5,
 }
 
+/// Operational roles granted by the admin. The admin implicitly holds every
+/// role.
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum Role {
+    /// May pause and unpause the contract.
+    Pauser = 0,
+    /// May change the campaign and entity creation fees, the platform fee,
+    /// the platform token and the accepted tokens.
+    FeeManager = 1,
+    /// May request and execute emergency withdrawals.
+    Treasury = 2,
+    /// May close pools and cancel campaigns.
+    PoolModerator = 3,
+    /// May assign and revoke creator fee tiers.
+    Verifier = 4,
+}
+
+impl Role {
+    pub const ALL: [Role; 5] = [
+        Role::Pauser,
+        Role::FeeManager,
+        Role::Treasury,
+        Role::PoolModerator,
+        Role::Verifier,
+    ];
+}
+
+/// Kinds of records charged a creation fee. Each has its own fee; the
+/// campaign fee is the one set through `set_creation_fee`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum FeeEntity {
+    Campaign = 0,
+    PublicPool = 1,
+    PrivatePool = 2,
+    /// A pool created through `save_pool` with a multi-sig configuration.
+    MultiSigPool = 3,
+}
+
+impl FeeEntity {
+    pub const ALL: [FeeEntity; 4] = [
+        FeeEntity::Campaign,
+        FeeEntity::PublicPool,
+        FeeEntity::PrivatePool,
+        FeeEntity::MultiSigPool,
+    ];
+}
+
 #[contracttype]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CampaignMetrics {
@@ -147,6 +420,52 @@ pub struct DisbursementRequest {
     pub executed: bool,
 }
 
+/// A privileged action that, once a council is set, can only be carried out
+/// through an approved council proposal.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CouncilAction {
+    SetCreationFee(i128),
+    SetCrowdfundingToken(Address),
+    /// Token, amount and recipient of the withdrawal.
+    RequestEmergencyWithdraw(Address, i128, Address),
+    ExecuteEmergencyWithdraw,
+    CancelEmergencyWithdraw,
+    ProposeUpgrade(BytesN<32>),
+    ExecuteUpgrade,
+    CancelUpgrade,
+    SetCouncil(MultiSigConfig),
+    SetEntityCreationFee(FeeEntity, i128),
+    SetPlatformFee(u32),
+    /// Pool id and its platform fee override; `None` clears the override.
+    SetPoolPlatformFee(u64, Option<u32>),
+    /// Token and its minimum amount.
+    SetAcceptedToken(Address, i128),
+    RemoveAcceptedToken(Address),
+    /// Token, amount and recipient of collected fees to withdraw.
+    WithdrawFees(Address, i128, Address),
+    /// Creator, tier and expiry of the assignment; an expiry of 0 never lapses.
+    AssignFeeTier(Address, FeeTier, u64),
+    SetEmergencyWithdrawDelay(u64),
+    SetUpgradeTimelock(u64),
+    /// Return privileged actions to the admin and role holders.
+    DisableCouncil,
+}
+
+/// A council proposal, approved like a `DisbursementRequest`. It can be
+/// executed once approved by `required_signatures` current council members,
+/// until `expires_at`. Executed proposals are removed, and expired ones are
+/// removed when the next proposal is made.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CouncilProposal {
+    pub action: CouncilAction,
+    pub approvals: Vec<Address>,
+    pub created_at: u64,
+    pub expires_at: u64,
+    pub executed: bool,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct EmergencyWithdrawal {
@@ -155,6 +474,126 @@ pub struct EmergencyWithdrawal {
     pub token: Address,
     pub requested_at: u64,
     pub executed: bool,
+    pub unlock_time: u64,
+}
+
+/// Platform parameter changes queued by `set_creation_fee` and
+/// `set_crowdfunding_token`. They take effect together at `effective_at`;
+/// queuing another change restarts the delay.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingParameters {
+    pub creation_fee: Option<i128>,
+    pub crowdfunding_token: Option<Address>,
+    pub queued_by: Address,
+    pub effective_at: u64,
+}
+
+/// A creation fee change for one entity, queued like `PendingParameters`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingEntityFee {
+    pub fee: i128,
+    pub queued_by: Address,
+    pub effective_at: u64,
+}
+
+/// Fee treatment of a creator, such as a partner charity, assigned through
+/// `assign_fee_tier`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FeeTier {
+    /// No creation fee, and no platform fee on funds raised.
+    Waived,
+    /// Creation fees and the platform fee rate reduced by this many basis
+    /// points of themselves.
+    Discount(u32),
+    /// This creation fee for every entity in place of the fee schedule. The
+    /// platform fee rate is unchanged.
+    FlatFee(i128),
+}
+
+impl FeeTier {
+    pub fn validate(&self) -> Result<(), CrowdfundingError> {
+        match self {
+            FeeTier::Discount(bps) if *bps > BPS_DENOMINATOR => Err(CrowdfundingError::InvalidFee),
+            FeeTier::FlatFee(fee) if *fee < 0 => Err(CrowdfundingError::InvalidFee),
+            _ => Ok(()),
+        }
+    }
+
+    /// The creation fee charged instead of the scheduled `fee`.
+    pub fn creation_fee(&self, fee: i128) -> i128 {
+        match self {
+            FeeTier::Waived => 0,
+            FeeTier::Discount(bps) => {
+                // Split so a large fee cannot overflow
+                let (bps, denominator) = (*bps as i128, BPS_DENOMINATOR as i128);
+                fee - (fee / denominator * bps + fee % denominator * bps / denominator)
+            }
+            FeeTier::FlatFee(flat) => *flat,
+        }
+    }
+
+    /// The platform fee rate charged instead of `fee_bps`.
+    pub fn platform_fee_bps(&self, fee_bps: u32) -> u32 {
+        match self {
+            FeeTier::Waived => 0,
+            FeeTier::Discount(bps) => {
+                fee_bps - (fee_bps as u64 * *bps as u64 / BPS_DENOMINATOR as u64) as u32
+            }
+            FeeTier::FlatFee(_) => fee_bps,
+        }
+    }
+}
+
+/// A creator's fee tier. An `expires_at` of 0 never expires; otherwise the
+/// tier lapses once the ledger reaches `expires_at`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreatorFeeTier {
+    pub tier: FeeTier,
+    pub assigned_by: Address,
+    pub expires_at: u64,
+}
+
+/// A token campaigns and pools may raise in. `symbol` and `decimals` are
+/// read from the token contract when it is registered.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AcceptedToken {
+    pub symbol: String,
+    pub decimals: u32,
+    /// Smallest donation or contribution accepted, before the platform fee.
+    pub min_amount: i128,
+}
+
+/// A platform fee rate change, queued like `PendingParameters`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingPlatformFee {
+    pub bps: u32,
+    pub queued_by: Address,
+    pub effective_at: u64,
+}
+
+/// Split of a contribution between the platform fee and the net amount
+/// credited to the pool, as returned by `quote_contribution`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContributionQuote {
+    pub fee_bps: u32,
+    pub fee: i128,
+    pub net: i128,
+}
+
+/// An upgrade proposed by the admin, executable from `executable_at`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingUpgrade {
+    pub wasm_hash: BytesN<32>,
+    pub proposed_at: u64,
+    pub executable_at: u64,
 }
 
 #[contracttype]
@@ -166,6 +605,96 @@ pub struct PoolContribution {
     pub asset: Address,
 }
 
+/// Stored form of a campaign. A layout change adds a variant holding the new
+/// struct; readers convert older variants and `migrate` rewrites them.
+// Contract types cannot be boxed, and records only live briefly on the stack
+#[allow(clippy::large_enum_variant)]
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CampaignRecord {
+    V1(CampaignDetailsV1),
+    V2(CampaignDetailsV2),
+    V3(CampaignDetails),
+}
+
+/// Stored form of a pool configuration; see [`CampaignRecord`].
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PoolRecord {
+    V1(PoolConfig),
+}
+
+/// Stored form of a pool contribution; see [`CampaignRecord`]. Contributions
+/// are not indexed, so `migrate` waits for the admin to list each pool's
+/// contributors through `migrate_pool_contributions`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PoolContributionRecord {
+    V1(PoolContribution),
+}
+
+/// Progress of an unfinished `migrate`. Campaigns are rewritten in
+/// `get_all_campaigns` order, then pools by id, then the contributions of
+/// each pool by id.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrationCursor {
+    pub campaign_index: u32,
+    pub next_pool_id: u64,
+    pub next_contribution_pool_id: u64,
+}
+
+/// A pause on a single feature. It lapses once the ledger reaches
+/// `expires_at`, at most the maximum pause duration after `paused_at`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeaturePause {
+    pub reason: u32,
+    pub paused_at: u64,
+    pub expires_at: u64,
+}
+
+impl FeaturePause {
+    pub fn is_active(&self, now: u64) -> bool {
+        now < self.expires_at
+    }
+}
+
+/// Limits on how creators may amend a campaign after creating it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CampaignAmendmentRules {
+    /// Longest total extension of the original deadline, in seconds.
+    pub max_extension: u64,
+    /// Whether the deadline may be extended only once.
+    pub single_extension: bool,
+    /// Smallest goal change, in basis points of the current goal, that
+    /// counts as material. Deadline extensions are always material.
+    pub material_goal_change_bps: u32,
+}
+
+impl Default for CampaignAmendmentRules {
+    fn default() -> Self {
+        Self {
+            max_extension: DEFAULT_MAX_CAMPAIGN_EXTENSION,
+            single_extension: true,
+            material_goal_change_bps: DEFAULT_MATERIAL_GOAL_CHANGE_BPS,
+        }
+    }
+}
+
+/// Amendments made to a campaign since it was created.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CampaignAmendments {
+    pub extensions: u32,
+    /// Total seconds added to the original deadline.
+    pub extended_by: u64,
+    /// Bumped by every material change. Donors whose last donation was made
+    /// under an earlier revision may claim a refund.
+    pub revision: u32,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum StorageKey {
@@ -188,6 +717,65 @@ pub enum StorageKey {
     EmergencyWithdrawal,
     CrowdfundingToken,
     CreationFee,
+    PendingAdmin,
+    Role(Role, Address),
+    UpgradeTimelock,
+    PendingUpgrade,
+    SchemaVersion,
+    MigrationCursor,
+    EmergencyWithdrawDelay,
+    /// Donations and contributions held for campaigns and pools, per token.
+    /// Only funds received since this key was introduced are counted.
+    EscrowedBalance(Address),
+    /// When the latest pause started. It is kept after unpausing, as a pause
+    /// within `MIN_UNPAUSED_PERIOD` of `UnpausedAt` continues from it. A pause
+    /// begun before this key existed is not timed.
+    PausedAt,
+    /// When the latest pause ended; absent while paused.
+    UnpausedAt,
+    MaxPauseDuration,
+    /// Pause of one feature, keyed by its `PAUSE_*` bit. It is kept once it
+    /// lapses or is unpaused, as a pause within `MIN_UNPAUSED_PERIOD` of its
+    /// expiry continues from its start.
+    FeaturePause(u32),
+    /// Council approving privileged actions; absent unless council mode is on.
+    Council,
+    CouncilProposal(u64),
+    NextCouncilProposalId,
+    /// Lowest id of a council proposal that may still be stored.
+    FirstCouncilProposalId,
+    PendingParameters,
+    /// Creation and platform fees collected and not yet withdrawn, per token. Only fees
+    /// collected since this key was introduced are counted.
+    FeeBalance(Address),
+    /// Creation fee of a pool entity; the campaign fee stays in `CreationFee`.
+    EntityCreationFee(FeeEntity),
+    /// Queued pool entity fee change; campaign fee changes stay in
+    /// `PendingParameters`.
+    PendingEntityFee(FeeEntity),
+    /// Platform fee rate in basis points taken from every donation and
+    /// contribution.
+    PlatformFeeBps,
+    PendingPlatformFee,
+    /// Platform fee rate of one pool, overriding `PlatformFeeBps`.
+    PoolPlatformFeeBps(u64),
+    /// Fee tier assigned to a creator through `assign_fee_tier`.
+    CreatorFeeTier(Address),
+    /// Creator of a pool. Only pools created since this key was introduced
+    /// have one.
+    PoolCreator(u64),
+    AcceptedToken(Address),
+    /// Addresses of the `AcceptedToken` entries, in registration order.
+    AcceptedTokens,
+    CampaignAmendmentRules,
+    CampaignAmendments(BytesN<32>),
+    /// `CampaignAmendments::revision` of a campaign when a donor last gave.
+    /// Kept in persistent storage.
+    DonationRevision(BytesN<32>, Address),
+    /// Account a campaign's creator has offered ownership to.
+    PendingCampaignOwner(BytesN<32>),
+    /// Account a pool's creator has offered ownership to.
+    PendingPoolOwner(u64),
 }
 
 #[cfg(test)]
@@ -207,12 +795,11 @@ mod tests {
             created_at: 1,
         };
 
-        cfg.validate();
+        assert_eq!(cfg.validate(), Ok(()));
     }
 
     #[test]
-    #[should_panic]
-    fn pool_config_invalid_target_amount_panics() {
+    fn pool_config_invalid_target_amount_is_rejected() {
         let env = Env::default();
         let cfg = PoolConfig {
             name: String::from_str(&env, "Invalid Target"),
@@ -223,7 +810,45 @@ mod tests {
             created_at: 1,
         };
 
-        cfg.validate();
+        assert_eq!(cfg.validate(), Err(CrowdfundingError::InvalidPoolTarget));
+    }
+
+    #[test]
+    fn pool_config_duration_bounds_are_enforced() {
+        let env = Env::default();
+        let mut cfg = PoolConfig {
+            name: String::from_str(&env, "Bounded Pool"),
+            description: String::from_str(&env, "Description"),
+            target_amount: 1_000,
+            is_private: false,
+            duration: MIN_POOL_DURATION - 1,
+            created_at: 1,
+        };
+        assert_eq!(cfg.validate(), Err(CrowdfundingError::InvalidPoolDeadline));
+
+        cfg.duration = MAX_POOL_DURATION + 1;
+        assert_eq!(cfg.validate(), Err(CrowdfundingError::InvalidPoolDeadline));
+
+        cfg.duration = MIN_POOL_DURATION;
+        assert_eq!(cfg.validate(), Ok(()));
+
+        cfg.duration = MAX_POOL_DURATION;
+        assert_eq!(cfg.validate(), Ok(()));
+    }
+
+    #[test]
+    fn pool_config_deadline_saturates() {
+        let env = Env::default();
+        let cfg = PoolConfig {
+            name: String::from_str(&env, "Overflowing Pool"),
+            description: String::from_str(&env, "Description"),
+            target_amount: 1_000,
+            is_private: false,
+            duration: u64::MAX,
+            created_at: 10,
+        };
+
+        assert_eq!(cfg.deadline(), u64::MAX);
     }
 
     #[test]
@@ -243,4 +868,21 @@ mod tests {
         assert_eq!(metrics.contributor_count, 0);
         assert_eq!(metrics.last_donation_at, 0);
     }
+
+    #[test]
+    fn campaign_metadata_extends_only_by_appending() {
+        let env = Env::default();
+        let metadata = |description, external_url, image_hash| CampaignMetadata {
+            description: String::from_str(&env, description),
+            external_url: String::from_str(&env, external_url),
+            image_hash: String::from_str(&env, image_hash),
+        };
+        let previous = metadata("Clean water", "", "Qm1");
+
+        assert!(metadata("Clean water", "", "Qm1").extends(&previous));
+        assert!(metadata("Clean water for all", "https://x.org", "Qm1").extends(&previous));
+        assert!(!metadata("Clean air", "", "Qm1").extends(&previous));
+        assert!(!metadata("Clean", "", "Qm1").extends(&previous));
+        assert!(!metadata("Clean water", "", "Qm2").extends(&previous));
+    }
 }
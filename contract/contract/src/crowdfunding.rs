@@ -1,13 +1,25 @@
-#![allow(deprecated)]
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Vec};
+// `save_pool` takes eight arguments, which the SDK also generates argument
+// builders for outside the impl block.
+#![allow(clippy::too_many_arguments)]
+
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Map, String, Vec};
 
 use crate::base::{
     errors::CrowdfundingError,
-    events,
+    events, storage,
     types::{
-        CampaignDetails, CampaignMetrics, Contribution, EmergencyWithdrawal, MultiSigConfig,
-        PoolConfig, PoolContribution, PoolMetadata, PoolMetrics, PoolState, StorageKey,
-        MAX_DESCRIPTION_LENGTH, MAX_HASH_LENGTH, MAX_URL_LENGTH,
+        AcceptedToken, CampaignAmendmentRules, CampaignAmendments, CampaignDetails,
+        CampaignMetadata, CampaignMetrics, CampaignStatus, Contribution, ContributionQuote,
+        CouncilAction, CouncilProposal, CreatorFeeTier, EmergencyWithdrawal, FeaturePause,
+        FeeEntity, FeeTier, MigrationCursor, MultiSigConfig, PendingEntityFee, PendingParameters,
+        PendingPlatformFee, PendingUpgrade, PoolConfig, PoolContribution, PoolMetadata,
+        PoolMetrics, PoolState, Role, StorageKey, BPS_DENOMINATOR, CONTRACT_VERSION,
+        COUNCIL_PROPOSAL_TTL, DEFAULT_EMERGENCY_WITHDRAW_DELAY, DEFAULT_MAX_PAUSE_DURATION,
+        DEFAULT_UPGRADE_TIMELOCK, MAX_DESCRIPTION_LENGTH, MAX_EMERGENCY_WITHDRAW_DELAY,
+        MAX_MAX_PAUSE_DURATION, MAX_UPGRADE_TIMELOCK, MIN_EMERGENCY_WITHDRAW_DELAY,
+        MIN_MAX_PAUSE_DURATION, MIN_UNPAUSED_PERIOD, MIN_UPGRADE_TIMELOCK, PARAMETER_CHANGE_DELAY,
+        PAUSE_ALL_FEATURES, PAUSE_CAMPAIGN_CREATION, PAUSE_CONTRIBUTIONS, PAUSE_DONATIONS,
+        PAUSE_POOL_CREATION, PAUSE_POOL_STATE_UPDATES, PAUSE_REFUNDS, STORAGE_SCHEMA_VERSION,
     },
 };
 use crate::interfaces::crowdfunding::CrowdfundingTrait;
@@ -16,8 +28,8 @@ use crate::interfaces::crowdfunding::CrowdfundingTrait;
 pub struct CrowdfundingContract;
 
 #[contractimpl]
-#[allow(clippy::too_many_arguments)]
 impl CrowdfundingTrait for CrowdfundingContract {
+    #[allow(clippy::too_many_arguments)]
     fn create_campaign(
         env: Env,
         id: BytesN<32>,
@@ -25,11 +37,10 @@ impl CrowdfundingTrait for CrowdfundingContract {
         creator: Address,
         goal: i128,
         deadline: u64,
-        _token_address: Address,
+        token_address: Address,
+        max_fee: i128,
     ) -> Result<(), CrowdfundingError> {
-        if Self::is_paused(env.clone()) {
-            return Err(CrowdfundingError::ContractPaused);
-        }
+        require_feature_unpaused(&env, PAUSE_CAMPAIGN_CREATION)?;
         creator.require_auth();
 
         if title.is_empty() {
@@ -44,30 +55,18 @@ impl CrowdfundingTrait for CrowdfundingContract {
             return Err(CrowdfundingError::InvalidDeadline);
         }
 
-        let token_key = StorageKey::CrowdfundingToken;
-        if !env.storage().instance().has(&token_key) {
+        if !env.storage().instance().has(&StorageKey::CrowdfundingToken) {
             return Err(CrowdfundingError::NotInitialized);
         }
-        let token_address: Address = env.storage().instance().get(&token_key).unwrap();
-
-        let fee_key = StorageKey::CreationFee;
-        let creation_fee: i128 = env.storage().instance().get(&fee_key).unwrap_or(0);
-
-        if creation_fee > 0 {
-            use soroban_sdk::token;
-            let token_client = token::Client::new(&env, &token_address);
-
-            let balance = token_client.balance(&creator);
-            if balance < creation_fee {
-                return Err(CrowdfundingError::InsufficientBalance);
-            }
-
-            token_client.transfer(&creator, env.current_contract_address(), &creation_fee);
-            events::creation_fee_paid(&env, creator.clone(), creation_fee);
+        // Campaigns may raise in any registered token; the creation fee
+        // is still charged in the platform token
+        if Self::get_accepted_token(env.clone(), token_address.clone()).is_none() {
+            return Err(CrowdfundingError::InvalidToken);
         }
 
-        let campaign_key = (id.clone(),);
-        if env.storage().instance().has(&campaign_key) {
+        charge_creation_fee(&env, &creator, FeeEntity::Campaign, max_fee)?;
+
+        if env.storage().instance().has(&storage::campaign_key(&id)) {
             return Err(CrowdfundingError::CampaignAlreadyExists);
         }
 
@@ -79,9 +78,11 @@ impl CrowdfundingTrait for CrowdfundingContract {
             deadline,
             total_raised: 0,
             token_address: token_address.clone(),
+            status: CampaignStatus::Active,
+            metadata: CampaignMetadata::empty(&env),
         };
 
-        env.storage().instance().set(&campaign_key, &campaign);
+        storage::write_campaign(&env, &campaign);
 
         // Initialize metrics
         let metrics_key = StorageKey::CampaignMetrics(id.clone());
@@ -100,51 +101,43 @@ impl CrowdfundingTrait for CrowdfundingContract {
             .instance()
             .set(&StorageKey::AllCampaigns, &all_campaigns);
 
-        events::campaign_created(&env, id, title, creator, goal, deadline);
+        events::campaign_created(&env, id, title, creator, goal, deadline, token_address);
 
         Ok(())
     }
 
-    fn set_crowdfunding_token(env: Env, token: Address) -> Result<(), CrowdfundingError> {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&StorageKey::Admin)
-            .ok_or(CrowdfundingError::NotInitialized)?;
-        admin.require_auth();
+    fn set_crowdfunding_token(
+        env: Env,
+        caller: Address,
+        token: Address,
+    ) -> Result<(), CrowdfundingError> {
+        require_no_council(&env)?;
+        require_role(&env, &caller, Role::FeeManager)?;
 
-        env.storage()
-            .instance()
-            .set(&StorageKey::CrowdfundingToken, &token);
-        events::crowdfunding_token_set(&env, admin, token);
-        Ok(())
+        apply_set_crowdfunding_token(&env, caller, token)
     }
 
     fn get_crowdfunding_token(env: Env) -> Result<Address, CrowdfundingError> {
+        if let Some(token) = due_parameters(&env).and_then(|pending| pending.crowdfunding_token) {
+            return Ok(token);
+        }
         env.storage()
             .instance()
             .get(&StorageKey::CrowdfundingToken)
             .ok_or(CrowdfundingError::NotInitialized)
     }
 
-    fn set_creation_fee(env: Env, fee: i128) -> Result<(), CrowdfundingError> {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&StorageKey::Admin)
-            .ok_or(CrowdfundingError::NotInitialized)?;
-        admin.require_auth();
-
-        if fee < 0 {
-            return Err(CrowdfundingError::InvalidFee);
-        }
+    fn set_creation_fee(env: Env, caller: Address, fee: i128) -> Result<(), CrowdfundingError> {
+        require_no_council(&env)?;
+        require_role(&env, &caller, Role::FeeManager)?;
 
-        env.storage().instance().set(&StorageKey::CreationFee, &fee);
-        events::creation_fee_set(&env, admin, fee);
-        Ok(())
+        apply_set_creation_fee(&env, caller, fee)
     }
 
     fn get_creation_fee(env: Env) -> Result<i128, CrowdfundingError> {
+        if let Some(fee) = due_parameters(&env).and_then(|pending| pending.creation_fee) {
+            return Ok(fee);
+        }
         Ok(env
             .storage()
             .instance()
@@ -152,6 +145,196 @@ impl CrowdfundingTrait for CrowdfundingContract {
             .unwrap_or(0))
     }
 
+    fn get_pending_parameters(env: Env) -> Option<PendingParameters> {
+        env.storage()
+            .instance()
+            .get(&StorageKey::PendingParameters)
+            .filter(|pending: &PendingParameters| env.ledger().timestamp() < pending.effective_at)
+    }
+
+    fn set_entity_creation_fee(
+        env: Env,
+        caller: Address,
+        entity: FeeEntity,
+        fee: i128,
+    ) -> Result<(), CrowdfundingError> {
+        require_no_council(&env)?;
+        require_role(&env, &caller, Role::FeeManager)?;
+
+        apply_set_entity_creation_fee(&env, caller, entity, fee)
+    }
+
+    fn get_entity_creation_fee(env: Env, entity: FeeEntity) -> i128 {
+        if entity == FeeEntity::Campaign {
+            return Self::get_creation_fee(env).unwrap_or(0);
+        }
+        if let Some(pending) = due_entity_fee(&env, entity) {
+            return pending.fee;
+        }
+        env.storage()
+            .instance()
+            .get(&StorageKey::EntityCreationFee(entity))
+            .unwrap_or(0)
+    }
+
+    fn get_pending_entity_creation_fee(env: Env, entity: FeeEntity) -> Option<PendingEntityFee> {
+        if entity == FeeEntity::Campaign {
+            let pending = Self::get_pending_parameters(env)?;
+            return pending.creation_fee.map(|fee| PendingEntityFee {
+                fee,
+                queued_by: pending.queued_by,
+                effective_at: pending.effective_at,
+            });
+        }
+        env.storage()
+            .instance()
+            .get(&StorageKey::PendingEntityFee(entity))
+            .filter(|pending: &PendingEntityFee| env.ledger().timestamp() < pending.effective_at)
+    }
+
+    fn set_platform_fee(env: Env, caller: Address, bps: u32) -> Result<(), CrowdfundingError> {
+        require_no_council(&env)?;
+        require_role(&env, &caller, Role::FeeManager)?;
+
+        apply_set_platform_fee(&env, caller, bps)
+    }
+
+    fn get_platform_fee(env: Env) -> u32 {
+        if let Some(pending) = due_platform_fee(&env) {
+            return pending.bps;
+        }
+        env.storage()
+            .instance()
+            .get(&StorageKey::PlatformFeeBps)
+            .unwrap_or(0)
+    }
+
+    fn get_pending_platform_fee(env: Env) -> Option<PendingPlatformFee> {
+        env.storage()
+            .instance()
+            .get(&StorageKey::PendingPlatformFee)
+            .filter(|pending: &PendingPlatformFee| env.ledger().timestamp() < pending.effective_at)
+    }
+
+    fn set_pool_platform_fee(
+        env: Env,
+        pool_id: u64,
+        bps: Option<u32>,
+    ) -> Result<(), CrowdfundingError> {
+        require_no_council(&env)?;
+        let admin = require_admin(&env)?;
+
+        apply_set_pool_platform_fee(&env, admin, pool_id, bps)
+    }
+
+    fn get_pool_platform_fee(env: Env, pool_id: u64) -> Result<u32, CrowdfundingError> {
+        if !env.storage().instance().has(&StorageKey::Pool(pool_id)) {
+            return Err(CrowdfundingError::PoolNotFound);
+        }
+        // An override set for the pool is taken as is; the platform rate is
+        // subject to the creator's fee tier
+        if let Some(bps) = env
+            .storage()
+            .instance()
+            .get(&StorageKey::PoolPlatformFeeBps(pool_id))
+        {
+            return Ok(bps);
+        }
+        let bps = Self::get_platform_fee(env.clone());
+        let creator: Option<Address> = env
+            .storage()
+            .instance()
+            .get(&StorageKey::PoolCreator(pool_id));
+        Ok(match creator {
+            Some(creator) => creator_platform_fee_bps(&env, &creator, bps),
+            None => bps,
+        })
+    }
+
+    fn quote_contribution(
+        env: Env,
+        pool_id: u64,
+        amount: i128,
+    ) -> Result<ContributionQuote, CrowdfundingError> {
+        let fee_bps = Self::get_pool_platform_fee(env, pool_id)?;
+        quote_platform_fee(amount, fee_bps)
+    }
+
+    fn assign_fee_tier(
+        env: Env,
+        caller: Address,
+        creator: Address,
+        tier: FeeTier,
+        expires_at: u64,
+    ) -> Result<(), CrowdfundingError> {
+        require_no_council(&env)?;
+        require_role(&env, &caller, Role::Verifier)?;
+
+        apply_assign_fee_tier(&env, caller, creator, tier, expires_at)
+    }
+
+    fn revoke_fee_tier(
+        env: Env,
+        caller: Address,
+        creator: Address,
+    ) -> Result<(), CrowdfundingError> {
+        require_role(&env, &caller, Role::Verifier)?;
+
+        // A lapsed tier can still be revoked to clear it from storage
+        let key = StorageKey::CreatorFeeTier(creator.clone());
+        if !env.storage().instance().has(&key) {
+            return Err(CrowdfundingError::NotFound);
+        }
+        env.storage().instance().remove(&key);
+        events::fee_tier_revoked(&env, caller, creator);
+        Ok(())
+    }
+
+    fn get_fee_tier(env: Env, creator: Address) -> Option<CreatorFeeTier> {
+        env.storage()
+            .instance()
+            .get(&StorageKey::CreatorFeeTier(creator))
+            .filter(|tier: &CreatorFeeTier| {
+                tier.expires_at == 0 || env.ledger().timestamp() < tier.expires_at
+            })
+    }
+
+    fn set_accepted_token(
+        env: Env,
+        caller: Address,
+        token: Address,
+        min_amount: i128,
+    ) -> Result<(), CrowdfundingError> {
+        require_no_council(&env)?;
+        require_role(&env, &caller, Role::FeeManager)?;
+
+        apply_set_accepted_token(&env, caller, token, min_amount)
+    }
+
+    fn remove_accepted_token(
+        env: Env,
+        caller: Address,
+        token: Address,
+    ) -> Result<(), CrowdfundingError> {
+        require_no_council(&env)?;
+        require_role(&env, &caller, Role::FeeManager)?;
+
+        apply_remove_accepted_token(&env, caller, token)
+    }
+
+    fn get_accepted_token(env: Env, token: Address) -> Option<AcceptedToken> {
+        env.storage()
+            .instance()
+            .get(&StorageKey::AcceptedToken(token))
+    }
+
+    fn get_accepted_tokens(env: Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&StorageKey::AcceptedTokens)
+            .unwrap_or(Vec::new(&env))
+    }
+
     fn get_all_campaigns(env: Env) -> Vec<BytesN<32>> {
         env.storage()
             .instance()
@@ -160,8 +343,11 @@ impl CrowdfundingTrait for CrowdfundingContract {
     }
 
     fn get_donor_count(env: Env, campaign_id: BytesN<32>) -> Result<u32, CrowdfundingError> {
-        let campaign_key = (campaign_id.clone(),);
-        if !env.storage().instance().has(&campaign_key) {
+        if !env
+            .storage()
+            .instance()
+            .has(&storage::campaign_key(&campaign_id))
+        {
             return Err(CrowdfundingError::CampaignNotFound);
         }
 
@@ -175,8 +361,11 @@ impl CrowdfundingTrait for CrowdfundingContract {
     }
 
     fn get_campaign_balance(env: Env, campaign_id: BytesN<32>) -> Result<i128, CrowdfundingError> {
-        let campaign_key = (campaign_id.clone(),);
-        if !env.storage().instance().has(&campaign_key) {
+        if !env
+            .storage()
+            .instance()
+            .has(&storage::campaign_key(&campaign_id))
+        {
             return Err(CrowdfundingError::CampaignNotFound);
         }
 
@@ -233,9 +422,7 @@ impl CrowdfundingTrait for CrowdfundingContract {
         asset: Address,
         amount: i128,
     ) -> Result<(), CrowdfundingError> {
-        if Self::is_paused(env.clone()) {
-            return Err(CrowdfundingError::ContractPaused);
-        }
+        require_feature_unpaused(&env, PAUSE_DONATIONS)?;
         donor.require_auth();
 
         // Validate donation amount
@@ -246,8 +433,10 @@ impl CrowdfundingTrait for CrowdfundingContract {
         // Get campaign and validate it exists
         let mut campaign = Self::get_campaign(env.clone(), campaign_id.clone())?;
 
-        // Check if campaign is still active (deadline hasn't passed)
-        if env.ledger().timestamp() >= campaign.deadline {
+        // Check if campaign is still active (not cancelled, deadline hasn't passed)
+        if campaign.status != CampaignStatus::Active
+            || env.ledger().timestamp() >= campaign.deadline
+        {
             return Err(CrowdfundingError::CampaignExpired);
         }
 
@@ -258,18 +447,30 @@ impl CrowdfundingTrait for CrowdfundingContract {
 
         // Verify the asset matches the campaign's token
         if asset != campaign.token_address {
-            return Err(CrowdfundingError::TokenTransferFailed);
+            return Err(CrowdfundingError::InvalidToken);
+        }
+        // A campaign keeps its token if it is removed from the registry
+        if let Some(accepted) = Self::get_accepted_token(env.clone(), asset.clone()) {
+            if amount < accepted.min_amount {
+                return Err(CrowdfundingError::InvalidDonationAmount);
+            }
         }
 
         // Transfer tokens from donor to contract
         use soroban_sdk::token;
         let token_client = token::Client::new(&env, &asset);
         token_client.transfer(&donor, env.current_contract_address(), &amount);
+        apply_due_platform_fee(&env);
+        let fee_bps =
+            creator_platform_fee_bps(&env, &campaign.creator, Self::get_platform_fee(env.clone()));
+        let amount = escrow_net_of_platform_fee(&env, &donor, &asset, amount, fee_bps)?;
 
         // Update campaign's total_raised
-        campaign.total_raised += amount;
-        let campaign_key = (campaign_id.clone(),);
-        env.storage().instance().set(&campaign_key, &campaign);
+        campaign.total_raised = campaign
+            .total_raised
+            .checked_add(amount)
+            .ok_or(CrowdfundingError::ArithmeticOverflow)?;
+        storage::write_campaign(&env, &campaign);
 
         // Update metrics
         let metrics_key = StorageKey::CampaignMetrics(campaign_id.clone());
@@ -279,13 +480,19 @@ impl CrowdfundingTrait for CrowdfundingContract {
             .get(&metrics_key)
             .unwrap_or_default();
 
-        metrics.total_raised += amount;
+        metrics.total_raised = metrics
+            .total_raised
+            .checked_add(amount)
+            .ok_or(CrowdfundingError::ArithmeticOverflow)?;
         metrics.last_donation_at = env.ledger().timestamp();
 
         // Track unique donor
         let donor_key = StorageKey::CampaignDonor(campaign_id.clone(), donor.clone());
         if !env.storage().instance().has(&donor_key) {
-            metrics.contributor_count += 1;
+            metrics.contributor_count = metrics
+                .contributor_count
+                .checked_add(1)
+                .ok_or(CrowdfundingError::ArithmeticOverflow)?;
             env.storage().instance().set(&donor_key, &true);
         }
 
@@ -306,123 +513,437 @@ impl CrowdfundingTrait for CrowdfundingContract {
         let updated_contribution = Contribution {
             campaign_id: campaign_id.clone(),
             contributor: donor.clone(),
-            amount: existing_contribution.amount + amount,
+            amount: existing_contribution
+                .amount
+                .checked_add(amount)
+                .ok_or(CrowdfundingError::ArithmeticOverflow)?,
         };
         env.storage()
             .instance()
             .set(&contribution_key, &updated_contribution);
 
+        // Remember the terms the donor last gave under, in persistent storage
+        // as there is an entry per donor
+        let revision = Self::get_campaign_amendments(env.clone(), campaign_id.clone())?.revision;
+        if revision > 0 {
+            env.storage().persistent().set(
+                &StorageKey::DonationRevision(campaign_id.clone(), donor.clone()),
+                &revision,
+            );
+        }
+
         // Emit DonationMade event
-        events::donation_made(&env, campaign_id, donor, amount);
+        events::donation_made(&env, campaign_id.clone(), donor, asset, amount);
 
-        Ok(())
-    }
+        if campaign.total_raised >= campaign.goal {
+            events::campaign_goal_reached(&env, campaign_id, campaign.total_raised);
+        }
 
-    fn get_campaign(env: Env, id: BytesN<32>) -> Result<CampaignDetails, CrowdfundingError> {
-        let campaign_key = (id,);
-        env.storage()
-            .instance()
-            .get(&campaign_key)
-            .ok_or(CrowdfundingError::CampaignNotFound)
+        Ok(())
     }
 
-    fn create_pool(
+    fn cancel_campaign(
         env: Env,
-        creator: Address,
-        config: PoolConfig,
-    ) -> Result<u64, CrowdfundingError> {
-        if Self::is_paused(env.clone()) {
-            return Err(CrowdfundingError::ContractPaused);
+        campaign_id: BytesN<32>,
+        caller: Address,
+        reason: String,
+    ) -> Result<(), CrowdfundingError> {
+        let mut campaign = Self::get_campaign(env.clone(), campaign_id.clone())?;
+        if caller == campaign.creator {
+            caller.require_auth();
+        } else {
+            require_role(&env, &caller, Role::PoolModerator)?;
         }
-        creator.require_auth();
 
-        // Validate config
-        config.validate();
+        if campaign.status != CampaignStatus::Active
+            || env.ledger().timestamp() >= campaign.deadline
+        {
+            return Err(CrowdfundingError::CampaignExpired);
+        }
+        if reason.len() > MAX_DESCRIPTION_LENGTH {
+            return Err(CrowdfundingError::InvalidMetadata);
+        }
 
-        // Extra validation (if any, e.g. duration checks not covered by validate)
-        // For now relying on PoolConfig::validate
+        campaign.status = CampaignStatus::Cancelled;
+        storage::write_campaign(&env, &campaign);
 
-        // Generate unique pool ID
-        let next_id_key = StorageKey::NextPoolId;
-        let pool_id = env.storage().instance().get(&next_id_key).unwrap_or(1u64);
-        let new_next_id = pool_id + 1;
+        events::campaign_cancelled(&env, campaign_id, caller, reason);
+        Ok(())
+    }
 
-        // Check uniqueness (redundant with sequential IDs but safe)
-        let pool_key = StorageKey::Pool(pool_id);
-        if env.storage().instance().has(&pool_key) {
-            return Err(CrowdfundingError::PoolAlreadyExists);
-        }
+    fn refund_campaign(
+        env: Env,
+        campaign_id: BytesN<32>,
+        contributor: Address,
+    ) -> Result<(), CrowdfundingError> {
+        require_feature_unpaused(&env, PAUSE_REFUNDS)?;
+        contributor.require_auth();
 
-        // Store config
-        env.storage().instance().set(&pool_key, &config);
+        let campaign = Self::get_campaign(env.clone(), campaign_id.clone())?;
+        let donated_under: u32 = env
+            .storage()
+            .persistent()
+            .get(&StorageKey::DonationRevision(
+                campaign_id.clone(),
+                contributor.clone(),
+            ))
+            .unwrap_or(0);
+        let revision = Self::get_campaign_amendments(env.clone(), campaign_id.clone())?.revision;
+        if campaign.status != CampaignStatus::Cancelled && donated_under >= revision {
+            return Err(CrowdfundingError::RefundNotAvailable);
+        }
 
-        // Initialize state
-        let state_key = StorageKey::PoolState(pool_id);
-        env.storage().instance().set(&state_key, &PoolState::Active);
+        refund_campaign_contribution(&env, campaign_id, contributor)
+    }
 
-        // Initialize metrics
-        let metrics_key = StorageKey::PoolMetrics(pool_id);
-        env.storage()
-            .instance()
-            .set(&metrics_key, &PoolMetrics::new());
+    fn extend_campaign_deadline(
+        env: Env,
+        campaign_id: BytesN<32>,
+        new_deadline: u64,
+    ) -> Result<(), CrowdfundingError> {
+        let mut campaign = amendable_campaign(&env, &campaign_id)?;
+        let rules = Self::get_campaign_amendment_rules(env.clone());
+        let mut amendments = Self::get_campaign_amendments(env.clone(), campaign_id.clone())?;
 
-        // Update ID counter
-        env.storage().instance().set(&next_id_key, &new_next_id);
+        if new_deadline <= campaign.deadline
+            || (rules.single_extension && amendments.extensions > 0)
+        {
+            return Err(CrowdfundingError::InvalidDeadline);
+        }
+        amendments.extended_by = amendments
+            .extended_by
+            .checked_add(new_deadline - campaign.deadline)
+            .filter(|extended_by| *extended_by <= rules.max_extension)
+            .ok_or(CrowdfundingError::InvalidDeadline)?;
+        amendments.extensions = amendments.extensions.saturating_add(1);
+        amendments.revision = amendments
+            .revision
+            .checked_add(1)
+            .ok_or(CrowdfundingError::ArithmeticOverflow)?;
+
+        campaign.deadline = new_deadline;
+        storage::write_campaign(&env, &campaign);
+        env.storage().instance().set(
+            &StorageKey::CampaignAmendments(campaign_id.clone()),
+            &amendments,
+        );
 
-        // Emit event
-        // Calculate deadline from creation time and duration for the event
-        let deadline = config.created_at + config.duration;
-        events::pool_created(
+        events::campaign_amended(
             &env,
-            pool_id,
-            config.name,
-            config.description,
-            creator,
-            config.target_amount,
-            deadline,
+            campaign_id,
+            campaign.goal,
+            new_deadline,
+            amendments.revision,
         );
-
-        Ok(pool_id)
+        Ok(())
     }
 
-    #[allow(clippy::too_many_arguments)]
-    fn save_pool(
+    fn update_campaign_goal(
         env: Env,
-        name: String,
-        metadata: PoolMetadata,
-        creator: Address,
-        target_amount: i128,
-        deadline: u64,
-        required_signatures: Option<u32>,
-        signers: Option<Vec<Address>>,
-    ) -> Result<u64, CrowdfundingError> {
-        if Self::is_paused(env.clone()) {
-            return Err(CrowdfundingError::ContractPaused);
-        }
-        creator.require_auth();
+        campaign_id: BytesN<32>,
+        new_goal: i128,
+    ) -> Result<(), CrowdfundingError> {
+        let mut campaign = amendable_campaign(&env, &campaign_id)?;
+        let rules = Self::get_campaign_amendment_rules(env.clone());
+        let mut amendments = Self::get_campaign_amendments(env.clone(), campaign_id.clone())?;
 
-        // Validate inputs
-        if name.is_empty() {
-            return Err(CrowdfundingError::InvalidPoolName);
+        if new_goal <= 0 || new_goal == campaign.goal || new_goal < campaign.total_raised {
+            return Err(CrowdfundingError::InvalidGoal);
+        }
+        let change = new_goal
+            .abs_diff(campaign.goal)
+            .checked_mul(BPS_DENOMINATOR as u128)
+            .ok_or(CrowdfundingError::ArithmeticOverflow)?;
+        let threshold = (campaign.goal as u128)
+            .checked_mul(rules.material_goal_change_bps as u128)
+            .ok_or(CrowdfundingError::ArithmeticOverflow)?;
+        if change >= threshold {
+            amendments.revision = amendments
+                .revision
+                .checked_add(1)
+                .ok_or(CrowdfundingError::ArithmeticOverflow)?;
+            env.storage().instance().set(
+                &StorageKey::CampaignAmendments(campaign_id.clone()),
+                &amendments,
+            );
         }
 
-        if target_amount <= 0 {
-            return Err(CrowdfundingError::InvalidPoolTarget);
+        campaign.goal = new_goal;
+        storage::write_campaign(&env, &campaign);
+
+        events::campaign_amended(
+            &env,
+            campaign_id.clone(),
+            new_goal,
+            campaign.deadline,
+            amendments.revision,
+        );
+        if campaign.total_raised >= new_goal {
+            events::campaign_goal_reached(&env, campaign_id, campaign.total_raised);
         }
+        Ok(())
+    }
 
-        if deadline <= env.ledger().timestamp() {
-            return Err(CrowdfundingError::InvalidPoolDeadline);
+    fn update_campaign_details(
+        env: Env,
+        campaign_id: BytesN<32>,
+        title: String,
+        metadata: CampaignMetadata,
+    ) -> Result<(), CrowdfundingError> {
+        let mut campaign = amendable_campaign(&env, &campaign_id)?;
+
+        if title.is_empty() {
+            return Err(CrowdfundingError::InvalidTitle);
         }
+        metadata.validate()?;
 
-        // Validate metadata lengths
-        if metadata.description.len() > MAX_DESCRIPTION_LENGTH
-            || metadata.external_url.len() > MAX_URL_LENGTH
-            || metadata.image_hash.len() > MAX_HASH_LENGTH
-        {
-            return Err(CrowdfundingError::InvalidMetadata);
+        // Donors gave under the published details, so once anyone has
+        // donated the creator can only add to them
+        let metrics: CampaignMetrics = env
+            .storage()
+            .instance()
+            .get(&StorageKey::CampaignMetrics(campaign_id.clone()))
+            .unwrap_or_default();
+        if metrics.contributor_count > 0 {
+            if title != campaign.title {
+                return Err(CrowdfundingError::InvalidTitle);
+            }
+            if !metadata.extends(&campaign.metadata) {
+                return Err(CrowdfundingError::InvalidMetadata);
+            }
         }
 
-        // Validate multi-sig configuration if provided
+        campaign.title = title.clone();
+        campaign.metadata = metadata.clone();
+        storage::write_campaign(&env, &campaign);
+
+        events::campaign_details_updated(&env, campaign_id, title, metadata);
+        Ok(())
+    }
+
+    fn get_campaign_amendments(
+        env: Env,
+        campaign_id: BytesN<32>,
+    ) -> Result<CampaignAmendments, CrowdfundingError> {
+        if !env
+            .storage()
+            .instance()
+            .has(&storage::campaign_key(&campaign_id))
+        {
+            return Err(CrowdfundingError::CampaignNotFound);
+        }
+        Ok(env
+            .storage()
+            .instance()
+            .get(&StorageKey::CampaignAmendments(campaign_id))
+            .unwrap_or_default())
+    }
+
+    fn set_campaign_amendment_rules(
+        env: Env,
+        rules: CampaignAmendmentRules,
+    ) -> Result<(), CrowdfundingError> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&StorageKey::Admin)
+            .ok_or(CrowdfundingError::NotInitialized)?;
+        admin.require_auth();
+
+        if rules.material_goal_change_bps > BPS_DENOMINATOR {
+            return Err(CrowdfundingError::InvalidAmount);
+        }
+
+        env.storage()
+            .instance()
+            .set(&StorageKey::CampaignAmendmentRules, &rules);
+        events::campaign_amendment_rules_set(&env, admin, rules);
+        Ok(())
+    }
+
+    fn get_campaign_amendment_rules(env: Env) -> CampaignAmendmentRules {
+        env.storage()
+            .instance()
+            .get(&StorageKey::CampaignAmendmentRules)
+            .unwrap_or_default()
+    }
+
+    fn transfer_campaign_ownership(
+        env: Env,
+        campaign_id: BytesN<32>,
+        new_owner: Address,
+    ) -> Result<(), CrowdfundingError> {
+        let campaign = Self::get_campaign(env.clone(), campaign_id.clone())?;
+        campaign.creator.require_auth();
+        if new_owner == campaign.creator {
+            return Err(CrowdfundingError::AlreadyExists);
+        }
+
+        env.storage().instance().set(
+            &StorageKey::PendingCampaignOwner(campaign_id.clone()),
+            &new_owner,
+        );
+        events::campaign_ownership_proposed(&env, campaign_id, campaign.creator, new_owner);
+        Ok(())
+    }
+
+    fn accept_campaign_ownership(
+        env: Env,
+        campaign_id: BytesN<32>,
+    ) -> Result<(), CrowdfundingError> {
+        let mut campaign = Self::get_campaign(env.clone(), campaign_id.clone())?;
+        let pending_key = StorageKey::PendingCampaignOwner(campaign_id.clone());
+        let new_owner: Address = env
+            .storage()
+            .instance()
+            .get(&pending_key)
+            .ok_or(CrowdfundingError::NotFound)?;
+        new_owner.require_auth();
+
+        let previous_owner = campaign.creator;
+        campaign.creator = new_owner.clone();
+        storage::write_campaign(&env, &campaign);
+        env.storage().instance().remove(&pending_key);
+
+        events::campaign_ownership_transferred(&env, campaign_id, new_owner, previous_owner);
+        Ok(())
+    }
+
+    fn cancel_campaign_transfer(
+        env: Env,
+        campaign_id: BytesN<32>,
+    ) -> Result<(), CrowdfundingError> {
+        let campaign = Self::get_campaign(env.clone(), campaign_id.clone())?;
+        campaign.creator.require_auth();
+
+        let pending_key = StorageKey::PendingCampaignOwner(campaign_id.clone());
+        let proposed_owner: Address = env
+            .storage()
+            .instance()
+            .get(&pending_key)
+            .ok_or(CrowdfundingError::NotFound)?;
+
+        env.storage().instance().remove(&pending_key);
+        events::campaign_transfer_cancelled(&env, campaign_id, campaign.creator, proposed_owner);
+        Ok(())
+    }
+
+    fn get_pending_campaign_owner(env: Env, campaign_id: BytesN<32>) -> Option<Address> {
+        env.storage()
+            .instance()
+            .get(&StorageKey::PendingCampaignOwner(campaign_id))
+    }
+
+    fn get_campaign(env: Env, id: BytesN<32>) -> Result<CampaignDetails, CrowdfundingError> {
+        storage::read_campaign(&env, &id).ok_or(CrowdfundingError::CampaignNotFound)
+    }
+
+    fn create_pool(
+        env: Env,
+        creator: Address,
+        config: PoolConfig,
+        max_fee: i128,
+    ) -> Result<u64, CrowdfundingError> {
+        require_feature_unpaused(&env, PAUSE_POOL_CREATION)?;
+        creator.require_auth();
+
+        // The creation time is always taken from the ledger so a caller cannot
+        // backdate a pool into its refund window.
+        let mut config = config;
+        config.created_at = env.ledger().timestamp();
+        config.validate()?;
+
+        let entity = if config.is_private {
+            FeeEntity::PrivatePool
+        } else {
+            FeeEntity::PublicPool
+        };
+        charge_creation_fee(&env, &creator, entity, max_fee)?;
+
+        // Generate unique pool ID
+        let next_id_key = StorageKey::NextPoolId;
+        let pool_id = env.storage().instance().get(&next_id_key).unwrap_or(1u64);
+        let new_next_id = pool_id
+            .checked_add(1)
+            .ok_or(CrowdfundingError::ArithmeticOverflow)?;
+
+        // Check uniqueness (redundant with sequential IDs but safe)
+        let pool_key = StorageKey::Pool(pool_id);
+        if env.storage().instance().has(&pool_key) {
+            return Err(CrowdfundingError::PoolAlreadyExists);
+        }
+
+        // Store config
+        storage::write_pool(&env, pool_id, &config);
+        env.storage()
+            .instance()
+            .set(&StorageKey::PoolCreator(pool_id), &creator);
+
+        // Initialize state
+        let state_key = StorageKey::PoolState(pool_id);
+        env.storage().instance().set(&state_key, &PoolState::Active);
+
+        // Initialize metrics
+        let metrics_key = StorageKey::PoolMetrics(pool_id);
+        env.storage()
+            .instance()
+            .set(&metrics_key, &PoolMetrics::new());
+
+        // Update ID counter
+        env.storage().instance().set(&next_id_key, &new_next_id);
+
+        // Emit event
+        // Calculate deadline from creation time and duration for the event
+        let deadline = config.deadline();
+        events::pool_created(
+            &env,
+            pool_id,
+            config.name,
+            config.description,
+            creator,
+            config.target_amount,
+            config.is_private,
+            deadline,
+        );
+
+        Ok(pool_id)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn save_pool(
+        env: Env,
+        name: String,
+        metadata: PoolMetadata,
+        creator: Address,
+        target_amount: i128,
+        deadline: u64,
+        required_signatures: Option<u32>,
+        signers: Option<Vec<Address>>,
+        max_fee: i128,
+    ) -> Result<u64, CrowdfundingError> {
+        require_feature_unpaused(&env, PAUSE_POOL_CREATION)?;
+        creator.require_auth();
+
+        // Derive pool duration from requested deadline and current timestamp;
+        // a deadline in the past yields zero and fails the duration bounds.
+        let now = env.ledger().timestamp();
+        let duration = deadline.saturating_sub(now);
+
+        // Create pool configuration (persistent view)
+        let pool_config = PoolConfig {
+            name: name.clone(),
+            description: metadata.description.clone(),
+            target_amount,
+            is_private: false,
+            duration,
+            created_at: now,
+        };
+
+        // Apply the same invariants as `create_pool`, then metadata lengths
+        pool_config.validate()?;
+        metadata.validate()?;
+
+        // Validate multi-sig configuration if provided
         let multi_sig_config = match (required_signatures, signers) {
             (Some(req_sigs), Some(signer_list)) => {
                 let signer_count = signer_list.len();
@@ -441,10 +962,19 @@ impl CrowdfundingTrait for CrowdfundingContract {
             _ => return Err(CrowdfundingError::InvalidMultiSigConfig),
         };
 
+        let entity = if multi_sig_config.is_some() {
+            FeeEntity::MultiSigPool
+        } else {
+            FeeEntity::PublicPool
+        };
+        charge_creation_fee(&env, &creator, entity, max_fee)?;
+
         // Generate unique pool ID
         let next_id_key = StorageKey::NextPoolId;
         let pool_id = env.storage().instance().get(&next_id_key).unwrap_or(1u64);
-        let new_next_id = pool_id + 1;
+        let new_next_id = pool_id
+            .checked_add(1)
+            .ok_or(CrowdfundingError::ArithmeticOverflow)?;
 
         // Check if pool already exists (shouldn't happen with auto-increment)
         let pool_key = StorageKey::Pool(pool_id);
@@ -452,31 +982,20 @@ impl CrowdfundingTrait for CrowdfundingContract {
             return Err(CrowdfundingError::PoolAlreadyExists);
         }
 
-        // Derive pool duration from requested deadline and current timestamp
-        let now = env.ledger().timestamp();
-        let duration = deadline.saturating_sub(now);
-
-        // Create pool configuration (persistent view)
-        let pool_config = PoolConfig {
-            name: name.clone(),
-            description: metadata.description.clone(),
-            target_amount,
-            is_private: false,
-            duration,
-            created_at: now,
-        };
-
         // Store pool configuration
-        env.storage().instance().set(&pool_key, &pool_config);
+        storage::write_pool(&env, pool_id, &pool_config);
+        env.storage()
+            .instance()
+            .set(&StorageKey::PoolCreator(pool_id), &creator);
 
         // Store pool metadata in persistent storage
         let metadata_key = StorageKey::PoolMetadata(pool_id);
         env.storage().persistent().set(&metadata_key, &metadata);
 
         // Store multi-sig config separately if provided
-        if let Some(config) = multi_sig_config {
+        if let Some(config) = &multi_sig_config {
             let multi_sig_key = StorageKey::MultiSigConfig(pool_id);
-            env.storage().instance().set(&multi_sig_key, &config);
+            env.storage().instance().set(&multi_sig_key, config);
         }
 
         // Initialize pool state as Active
@@ -491,7 +1010,7 @@ impl CrowdfundingTrait for CrowdfundingContract {
         // Update next pool ID
         env.storage().instance().set(&next_id_key, &new_next_id);
 
-        // Emit event (assuming events module has pool_created function)
+        // Emit events
         events::pool_created(
             &env,
             pool_id,
@@ -499,15 +1018,25 @@ impl CrowdfundingTrait for CrowdfundingContract {
             metadata.description.clone(),
             creator,
             target_amount,
+            false,
             deadline,
         );
+        events::pool_metadata_set(
+            &env,
+            pool_id,
+            metadata.description,
+            metadata.external_url,
+            metadata.image_hash,
+        );
+        if let Some(config) = multi_sig_config {
+            events::pool_multisig_set(&env, pool_id, config.required_signatures, config.signers);
+        }
 
         Ok(pool_id)
     }
 
     fn get_pool(env: Env, pool_id: u64) -> Option<PoolConfig> {
-        let pool_key = StorageKey::Pool(pool_id);
-        env.storage().instance().get(&pool_key)
+        storage::read_pool(&env, pool_id)
     }
 
     fn get_pool_metadata(env: Env, pool_id: u64) -> (String, String, String) {
@@ -531,14 +1060,77 @@ impl CrowdfundingTrait for CrowdfundingContract {
         }
     }
 
+    fn get_pool_creator(env: Env, pool_id: u64) -> Option<Address> {
+        env.storage()
+            .instance()
+            .get(&StorageKey::PoolCreator(pool_id))
+    }
+
+    fn transfer_pool_ownership(
+        env: Env,
+        pool_id: u64,
+        new_owner: Address,
+    ) -> Result<(), CrowdfundingError> {
+        let owner = pool_owner(&env, pool_id)?;
+        owner.require_auth();
+        if new_owner == owner {
+            return Err(CrowdfundingError::AlreadyExists);
+        }
+
+        env.storage()
+            .instance()
+            .set(&StorageKey::PendingPoolOwner(pool_id), &new_owner);
+        events::pool_ownership_proposed(&env, pool_id, owner, new_owner);
+        Ok(())
+    }
+
+    fn accept_pool_ownership(env: Env, pool_id: u64) -> Result<(), CrowdfundingError> {
+        let previous_owner = pool_owner(&env, pool_id)?;
+        let pending_key = StorageKey::PendingPoolOwner(pool_id);
+        let new_owner: Address = env
+            .storage()
+            .instance()
+            .get(&pending_key)
+            .ok_or(CrowdfundingError::NotFound)?;
+        new_owner.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&StorageKey::PoolCreator(pool_id), &new_owner);
+        env.storage().instance().remove(&pending_key);
+
+        events::pool_ownership_transferred(&env, pool_id, new_owner, previous_owner);
+        Ok(())
+    }
+
+    fn cancel_pool_transfer(env: Env, pool_id: u64) -> Result<(), CrowdfundingError> {
+        let owner = pool_owner(&env, pool_id)?;
+        owner.require_auth();
+
+        let pending_key = StorageKey::PendingPoolOwner(pool_id);
+        let proposed_owner: Address = env
+            .storage()
+            .instance()
+            .get(&pending_key)
+            .ok_or(CrowdfundingError::NotFound)?;
+
+        env.storage().instance().remove(&pending_key);
+        events::pool_transfer_cancelled(&env, pool_id, owner, proposed_owner);
+        Ok(())
+    }
+
+    fn get_pending_pool_owner(env: Env, pool_id: u64) -> Option<Address> {
+        env.storage()
+            .instance()
+            .get(&StorageKey::PendingPoolOwner(pool_id))
+    }
+
     fn update_pool_state(
         env: Env,
         pool_id: u64,
         new_state: PoolState,
     ) -> Result<(), CrowdfundingError> {
-        if Self::is_paused(env.clone()) {
-            return Err(CrowdfundingError::ContractPaused);
-        }
+        require_feature_unpaused(&env, PAUSE_POOL_STATE_UPDATES)?;
         let pool_key = StorageKey::Pool(pool_id);
         if !env.storage().instance().has(&pool_key) {
             return Err(CrowdfundingError::PoolNotFound);
@@ -564,7 +1156,7 @@ impl CrowdfundingTrait for CrowdfundingContract {
         env.storage().instance().set(&state_key, &new_state);
 
         // Emit event
-        events::pool_state_updated(&env, pool_id, new_state);
+        events::pool_state_updated(&env, pool_id, current_state, new_state);
 
         Ok(())
     }
@@ -591,40 +1183,56 @@ impl CrowdfundingTrait for CrowdfundingContract {
             .instance()
             .set(&StorageKey::CreationFee, &creation_fee);
         env.storage().instance().set(&StorageKey::IsPaused, &false);
-        Ok(())
+        // A fresh deployment only ever writes the current layout
+        env.storage()
+            .instance()
+            .set(&StorageKey::SchemaVersion, &STORAGE_SCHEMA_VERSION);
+
+        events::contract_initialized(&env, admin.clone(), token.clone(), creation_fee);
+        // The platform token is the first accepted token
+        apply_set_accepted_token(&env, admin, token, 0)
     }
 
-    fn pause(env: Env) -> Result<(), CrowdfundingError> {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&StorageKey::Admin)
-            .ok_or(CrowdfundingError::NotInitialized)?;
-        admin.require_auth();
+    fn pause(env: Env, caller: Address) -> Result<(), CrowdfundingError> {
+        require_role(&env, &caller, Role::Pauser)?;
 
         if Self::is_paused(env.clone()) {
             return Err(CrowdfundingError::ContractAlreadyPaused);
         }
 
+        // Unpausing for less than the minimum period does not reset the
+        // escape hatch clock
+        let now = env.ledger().timestamp();
+        let last_pause: Option<u64> = env.storage().instance().get(&StorageKey::PausedAt);
+        let unpaused_at: Option<u64> = env.storage().instance().get(&StorageKey::UnpausedAt);
+        let paused_at = match (last_pause, unpaused_at) {
+            (Some(paused_at), Some(unpaused_at))
+                if now < unpaused_at.saturating_add(MIN_UNPAUSED_PERIOD) =>
+            {
+                paused_at
+            }
+            _ => now,
+        };
         env.storage().instance().set(&StorageKey::IsPaused, &true);
-        events::contract_paused(&env, admin, env.ledger().timestamp());
+        env.storage()
+            .instance()
+            .set(&StorageKey::PausedAt, &paused_at);
+        env.storage().instance().remove(&StorageKey::UnpausedAt);
+        events::contract_paused(&env, caller, now);
         Ok(())
     }
 
-    fn unpause(env: Env) -> Result<(), CrowdfundingError> {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&StorageKey::Admin)
-            .ok_or(CrowdfundingError::NotInitialized)?;
-        admin.require_auth();
+    fn unpause(env: Env, caller: Address) -> Result<(), CrowdfundingError> {
+        require_role(&env, &caller, Role::Pauser)?;
 
         if !Self::is_paused(env.clone()) {
             return Err(CrowdfundingError::ContractAlreadyUnpaused);
         }
 
+        let now = env.ledger().timestamp();
         env.storage().instance().set(&StorageKey::IsPaused, &false);
-        events::contract_unpaused(&env, admin, env.ledger().timestamp());
+        env.storage().instance().set(&StorageKey::UnpausedAt, &now);
+        events::contract_unpaused(&env, caller, now);
         Ok(())
     }
 
@@ -635,20 +1243,714 @@ impl CrowdfundingTrait for CrowdfundingContract {
             .unwrap_or(false)
     }
 
-    fn contribute(
+    fn get_paused_at(env: Env) -> Option<u64> {
+        if !Self::is_paused(env.clone()) {
+            return None;
+        }
+        env.storage().instance().get(&StorageKey::PausedAt)
+    }
+
+    fn pause_features(
         env: Env,
-        pool_id: u64,
-        contributor: Address,
-        asset: Address,
-        amount: i128,
-        is_private: bool,
+        caller: Address,
+        features: u32,
+        reason: u32,
+        duration: u64,
     ) -> Result<(), CrowdfundingError> {
-        if Self::is_paused(env.clone()) {
-            return Err(CrowdfundingError::ContractPaused);
-        }
-        contributor.require_auth();
+        require_role(&env, &caller, Role::Pauser)?;
 
-        if amount <= 0 {
+        if features == 0 || features & !PAUSE_ALL_FEATURES != 0 {
+            return Err(CrowdfundingError::InvalidAmount);
+        }
+        // Every feature pause lapses on its own, so refunds and contributions
+        // cannot be frozen for longer than the contract itself may be paused
+        let max_duration = Self::get_max_pause_duration(env.clone());
+        if duration == 0 || duration > max_duration {
+            return Err(CrowdfundingError::InvalidDeadline);
+        }
+
+        let now = env.ledger().timestamp();
+        let expires_at = now
+            .checked_add(duration)
+            .ok_or(CrowdfundingError::ArithmeticOverflow)?;
+        // Re-pausing a feature replaces its reason and expiry, but the pause
+        // still counts from when it started and cannot outlast the maximum.
+        // A pause that ended less than the minimum unpaused period ago counts
+        // as the same pause.
+        let paused_at = |feature: u32| {
+            let last_pause: Option<FeaturePause> = env
+                .storage()
+                .instance()
+                .get(&StorageKey::FeaturePause(feature));
+            match last_pause {
+                Some(pause) if now < pause.expires_at.saturating_add(MIN_UNPAUSED_PERIOD) => {
+                    pause.paused_at
+                }
+                _ => now,
+            }
+        };
+        for bit in 0..PAUSE_ALL_FEATURES.count_ones() {
+            let feature = 1 << bit;
+            if features & feature != 0 && expires_at - paused_at(feature) > max_duration {
+                return Err(CrowdfundingError::InvalidDeadline);
+            }
+        }
+        for bit in 0..PAUSE_ALL_FEATURES.count_ones() {
+            let feature = 1 << bit;
+            if features & feature != 0 {
+                let pause = FeaturePause {
+                    reason,
+                    paused_at: paused_at(feature),
+                    expires_at,
+                };
+                env.storage()
+                    .instance()
+                    .set(&StorageKey::FeaturePause(feature), &pause);
+            }
+        }
+
+        events::features_paused(&env, caller, features, reason, now, expires_at);
+        Ok(())
+    }
+
+    fn unpause_features(env: Env, caller: Address, features: u32) -> Result<(), CrowdfundingError> {
+        require_role(&env, &caller, Role::Pauser)?;
+
+        if features == 0 || features & !PAUSE_ALL_FEATURES != 0 {
+            return Err(CrowdfundingError::InvalidAmount);
+        }
+        if Self::get_paused_features(env.clone()) & features == 0 {
+            return Err(CrowdfundingError::ContractAlreadyUnpaused);
+        }
+
+        // Ended pauses are kept so that pausing again soon continues them
+        let now = env.ledger().timestamp();
+        for bit in 0..PAUSE_ALL_FEATURES.count_ones() {
+            let feature = 1 << bit;
+            if features & feature != 0 {
+                if let Some(mut pause) = Self::get_feature_pause(env.clone(), feature) {
+                    pause.expires_at = now;
+                    env.storage()
+                        .instance()
+                        .set(&StorageKey::FeaturePause(feature), &pause);
+                }
+            }
+        }
+
+        events::features_unpaused(&env, caller, features, now);
+        Ok(())
+    }
+
+    fn get_paused_features(env: Env) -> u32 {
+        (0..PAUSE_ALL_FEATURES.count_ones())
+            .map(|bit| 1 << bit)
+            .filter(|feature| Self::get_feature_pause(env.clone(), *feature).is_some())
+            .fold(0, |features, feature| features | feature)
+    }
+
+    fn get_feature_pause(env: Env, feature: u32) -> Option<FeaturePause> {
+        env.storage()
+            .instance()
+            .get(&StorageKey::FeaturePause(feature))
+            .filter(|pause: &FeaturePause| pause.is_active(env.ledger().timestamp()))
+    }
+
+    fn set_max_pause_duration(env: Env, duration: u64) -> Result<(), CrowdfundingError> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&StorageKey::Admin)
+            .ok_or(CrowdfundingError::NotInitialized)?;
+        admin.require_auth();
+
+        if !(MIN_MAX_PAUSE_DURATION..=MAX_MAX_PAUSE_DURATION).contains(&duration) {
+            return Err(CrowdfundingError::InvalidDeadline);
+        }
+
+        env.storage()
+            .instance()
+            .set(&StorageKey::MaxPauseDuration, &duration);
+        events::max_pause_duration_set(&env, admin, duration);
+        Ok(())
+    }
+
+    fn get_max_pause_duration(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&StorageKey::MaxPauseDuration)
+            .unwrap_or(DEFAULT_MAX_PAUSE_DURATION)
+    }
+
+    fn is_escape_hatch_open(env: Env) -> bool {
+        match Self::get_paused_at(env.clone()) {
+            Some(paused_at) => {
+                Self::is_paused(env.clone())
+                    && env.ledger().timestamp()
+                        >= paused_at.saturating_add(Self::get_max_pause_duration(env))
+            }
+            None => false,
+        }
+    }
+
+    fn escape_pool_contribution(
+        env: Env,
+        pool_id: u64,
+        contributor: Address,
+    ) -> Result<(), CrowdfundingError> {
+        require_escape_hatch(&env)?;
+        contributor.require_auth();
+
+        // Unlike `refund`, the pool's deadline and state are not consulted
+        let contribution_key = StorageKey::PoolContribution(pool_id, contributor.clone());
+        let contribution = storage::read_pool_contribution(&env, &contribution_key)
+            .filter(|contribution| contribution.amount > 0)
+            .ok_or(CrowdfundingError::NoContributionToRefund)?;
+
+        use soroban_sdk::token;
+        let token_client = token::Client::new(&env, &contribution.asset);
+        token_client.transfer(
+            &env.current_contract_address(),
+            &contributor,
+            &contribution.amount,
+        );
+        release_escrow(&env, &contribution.asset, contribution.amount);
+
+        let metrics_key = StorageKey::PoolMetrics(pool_id);
+        let mut metrics: PoolMetrics = env
+            .storage()
+            .instance()
+            .get(&metrics_key)
+            .unwrap_or_default();
+        metrics.total_raised = metrics
+            .total_raised
+            .checked_sub(contribution.amount)
+            .ok_or(CrowdfundingError::ArithmeticOverflow)?;
+        env.storage().instance().set(&metrics_key, &metrics);
+
+        let zeroed_contribution = PoolContribution {
+            amount: 0,
+            ..contribution.clone()
+        };
+        storage::write_pool_contribution(&env, &contribution_key, &zeroed_contribution);
+
+        events::refund(
+            &env,
+            pool_id,
+            contributor,
+            contribution.asset,
+            contribution.amount,
+            env.ledger().timestamp(),
+        );
+        Ok(())
+    }
+
+    fn escape_campaign_contribution(
+        env: Env,
+        campaign_id: BytesN<32>,
+        contributor: Address,
+    ) -> Result<(), CrowdfundingError> {
+        require_escape_hatch(&env)?;
+        contributor.require_auth();
+
+        refund_campaign_contribution(&env, campaign_id, contributor)
+    }
+
+    fn get_admin(env: Env) -> Result<Address, CrowdfundingError> {
+        env.storage()
+            .instance()
+            .get(&StorageKey::Admin)
+            .ok_or(CrowdfundingError::NotInitialized)
+    }
+
+    fn get_pending_admin(env: Env) -> Option<Address> {
+        env.storage().instance().get(&StorageKey::PendingAdmin)
+    }
+
+    fn propose_admin(env: Env, new_admin: Address) -> Result<(), CrowdfundingError> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&StorageKey::Admin)
+            .ok_or(CrowdfundingError::NotInitialized)?;
+        admin.require_auth();
+
+        // A new proposal replaces any earlier one
+        env.storage()
+            .instance()
+            .set(&StorageKey::PendingAdmin, &new_admin);
+        events::admin_proposed(&env, admin, new_admin);
+        Ok(())
+    }
+
+    fn accept_admin(env: Env) -> Result<(), CrowdfundingError> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&StorageKey::Admin)
+            .ok_or(CrowdfundingError::NotInitialized)?;
+        let new_admin: Address = env
+            .storage()
+            .instance()
+            .get(&StorageKey::PendingAdmin)
+            .ok_or(CrowdfundingError::NotFound)?;
+        new_admin.require_auth();
+
+        env.storage().instance().set(&StorageKey::Admin, &new_admin);
+        env.storage().instance().remove(&StorageKey::PendingAdmin);
+        events::admin_transferred(&env, new_admin, admin);
+        Ok(())
+    }
+
+    fn cancel_admin_proposal(env: Env) -> Result<(), CrowdfundingError> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&StorageKey::Admin)
+            .ok_or(CrowdfundingError::NotInitialized)?;
+        admin.require_auth();
+
+        let proposed_admin: Address = env
+            .storage()
+            .instance()
+            .get(&StorageKey::PendingAdmin)
+            .ok_or(CrowdfundingError::NotFound)?;
+
+        env.storage().instance().remove(&StorageKey::PendingAdmin);
+        events::admin_proposal_cancelled(&env, admin, proposed_admin);
+        Ok(())
+    }
+
+    fn grant_role(env: Env, role: Role, account: Address) -> Result<(), CrowdfundingError> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&StorageKey::Admin)
+            .ok_or(CrowdfundingError::NotInitialized)?;
+        admin.require_auth();
+
+        let key = StorageKey::Role(role, account.clone());
+        if env.storage().instance().has(&key) {
+            return Err(CrowdfundingError::AlreadyExists);
+        }
+
+        env.storage().instance().set(&key, &true);
+        events::role_granted(&env, admin, account, role);
+        Ok(())
+    }
+
+    fn revoke_role(env: Env, role: Role, account: Address) -> Result<(), CrowdfundingError> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&StorageKey::Admin)
+            .ok_or(CrowdfundingError::NotInitialized)?;
+        admin.require_auth();
+
+        let key = StorageKey::Role(role, account.clone());
+        if !env.storage().instance().has(&key) {
+            return Err(CrowdfundingError::NotFound);
+        }
+
+        env.storage().instance().remove(&key);
+        events::role_revoked(&env, admin, account, role);
+        Ok(())
+    }
+
+    fn has_role(env: Env, role: Role, account: Address) -> bool {
+        env.storage()
+            .instance()
+            .has(&StorageKey::Role(role, account))
+    }
+
+    fn get_roles(env: Env, account: Address) -> Vec<Role> {
+        let mut roles = Vec::new(&env);
+        for role in Role::ALL {
+            if Self::has_role(env.clone(), role, account.clone()) {
+                roles.push_back(role);
+            }
+        }
+        roles
+    }
+
+    fn set_council(env: Env, config: MultiSigConfig) -> Result<(), CrowdfundingError> {
+        let admin = require_admin(&env)?;
+
+        // Once set, the council can only be changed through its own proposals
+        if env.storage().instance().has(&StorageKey::Council) {
+            return Err(CrowdfundingError::AlreadyExists);
+        }
+        validate_council(&config)?;
+
+        env.storage().instance().set(&StorageKey::Council, &config);
+        events::council_set(&env, admin, config.required_signatures, config.signers);
+        Ok(())
+    }
+
+    fn get_council(env: Env) -> Option<MultiSigConfig> {
+        env.storage().instance().get(&StorageKey::Council)
+    }
+
+    fn propose_council_action(
+        env: Env,
+        proposer: Address,
+        action: CouncilAction,
+    ) -> Result<u64, CrowdfundingError> {
+        require_council_member(&env, &proposer)?;
+        remove_expired_council_proposals(&env);
+
+        let next_id_key = StorageKey::NextCouncilProposalId;
+        let proposal_id: u64 = env.storage().instance().get(&next_id_key).unwrap_or(1);
+        let next_id = proposal_id
+            .checked_add(1)
+            .ok_or(CrowdfundingError::ArithmeticOverflow)?;
+
+        // The proposer's approval is recorded with the proposal
+        let now = env.ledger().timestamp();
+        let proposal = CouncilProposal {
+            action: action.clone(),
+            approvals: Vec::from_array(&env, [proposer.clone()]),
+            created_at: now,
+            expires_at: now.saturating_add(COUNCIL_PROPOSAL_TTL),
+            executed: false,
+        };
+        env.storage()
+            .instance()
+            .set(&StorageKey::CouncilProposal(proposal_id), &proposal);
+        env.storage().instance().set(&next_id_key, &next_id);
+
+        events::council_action_proposed(&env, proposal_id, proposer, action, proposal.expires_at);
+        Ok(proposal_id)
+    }
+
+    fn approve_council_action(
+        env: Env,
+        signer: Address,
+        proposal_id: u64,
+    ) -> Result<(), CrowdfundingError> {
+        require_council_member(&env, &signer)?;
+        let mut proposal = open_council_proposal(&env, proposal_id)?;
+
+        if proposal.approvals.contains(&signer) {
+            return Err(CrowdfundingError::AlreadyApproved);
+        }
+        proposal.approvals.push_back(signer.clone());
+        env.storage()
+            .instance()
+            .set(&StorageKey::CouncilProposal(proposal_id), &proposal);
+
+        events::council_action_approved(&env, proposal_id, signer, proposal.approvals.len());
+        Ok(())
+    }
+
+    fn execute_council_action(
+        env: Env,
+        executor: Address,
+        proposal_id: u64,
+    ) -> Result<(), CrowdfundingError> {
+        let council = require_council_member(&env, &executor)?;
+        let proposal = open_council_proposal(&env, proposal_id)?;
+
+        // Approvals from members removed since they approved no longer count
+        let approvals = proposal
+            .approvals
+            .iter()
+            .filter(|signer| council.signers.contains(signer))
+            .count() as u32;
+        if approvals < council.required_signatures {
+            return Err(CrowdfundingError::InsufficientApprovals);
+        }
+
+        env.storage()
+            .instance()
+            .remove(&StorageKey::CouncilProposal(proposal_id));
+
+        match proposal.action {
+            CouncilAction::SetCreationFee(fee) => {
+                apply_set_creation_fee(&env, executor.clone(), fee)?
+            }
+            CouncilAction::SetCrowdfundingToken(token) => {
+                apply_set_crowdfunding_token(&env, executor.clone(), token)?
+            }
+            CouncilAction::RequestEmergencyWithdraw(token, amount, recipient) => {
+                apply_request_emergency_withdraw(&env, executor.clone(), token, amount, recipient)?
+            }
+            CouncilAction::ExecuteEmergencyWithdraw => {
+                apply_execute_emergency_withdraw(&env, executor.clone())?
+            }
+            CouncilAction::CancelEmergencyWithdraw => {
+                apply_cancel_emergency_withdraw(&env, executor.clone())?
+            }
+            CouncilAction::ProposeUpgrade(wasm_hash) => {
+                apply_propose_upgrade(&env, executor.clone(), wasm_hash)?
+            }
+            CouncilAction::ExecuteUpgrade => apply_execute_upgrade(&env, executor.clone())?,
+            CouncilAction::CancelUpgrade => apply_cancel_upgrade(&env, executor.clone())?,
+            CouncilAction::SetCouncil(config) => {
+                validate_council(&config)?;
+                env.storage().instance().set(&StorageKey::Council, &config);
+                events::council_set(
+                    &env,
+                    executor.clone(),
+                    config.required_signatures,
+                    config.signers,
+                );
+            }
+            CouncilAction::SetEntityCreationFee(entity, fee) => {
+                apply_set_entity_creation_fee(&env, executor.clone(), entity, fee)?
+            }
+            CouncilAction::SetPlatformFee(bps) => {
+                apply_set_platform_fee(&env, executor.clone(), bps)?
+            }
+            CouncilAction::SetPoolPlatformFee(pool_id, bps) => {
+                apply_set_pool_platform_fee(&env, executor.clone(), pool_id, bps)?
+            }
+            CouncilAction::SetAcceptedToken(token, min_amount) => {
+                apply_set_accepted_token(&env, executor.clone(), token, min_amount)?
+            }
+            CouncilAction::RemoveAcceptedToken(token) => {
+                apply_remove_accepted_token(&env, executor.clone(), token)?
+            }
+            CouncilAction::WithdrawFees(token, amount, to) => {
+                apply_withdraw_fees(&env, executor.clone(), token, amount, to)?
+            }
+            CouncilAction::AssignFeeTier(creator, tier, expires_at) => {
+                apply_assign_fee_tier(&env, executor.clone(), creator, tier, expires_at)?
+            }
+            CouncilAction::SetEmergencyWithdrawDelay(delay) => {
+                apply_set_emergency_withdraw_delay(&env, executor.clone(), delay)?
+            }
+            CouncilAction::SetUpgradeTimelock(delay) => {
+                apply_set_upgrade_timelock(&env, executor.clone(), delay)?
+            }
+            CouncilAction::DisableCouncil => {
+                env.storage().instance().remove(&StorageKey::Council);
+                events::council_disabled(&env, executor.clone());
+            }
+        }
+
+        events::council_action_executed(&env, proposal_id, executor);
+        Ok(())
+    }
+
+    fn get_council_proposal(env: Env, proposal_id: u64) -> Option<CouncilProposal> {
+        env.storage()
+            .instance()
+            .get(&StorageKey::CouncilProposal(proposal_id))
+    }
+
+    fn version(_env: Env) -> u32 {
+        CONTRACT_VERSION
+    }
+
+    fn set_upgrade_timelock(env: Env, delay: u64) -> Result<(), CrowdfundingError> {
+        require_no_council(&env)?;
+        let admin = require_admin(&env)?;
+
+        apply_set_upgrade_timelock(&env, admin, delay)
+    }
+
+    fn get_upgrade_timelock(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&StorageKey::UpgradeTimelock)
+            .unwrap_or(DEFAULT_UPGRADE_TIMELOCK)
+    }
+
+    fn propose_upgrade(env: Env, wasm_hash: BytesN<32>) -> Result<(), CrowdfundingError> {
+        require_no_council(&env)?;
+        let admin = require_admin(&env)?;
+
+        apply_propose_upgrade(&env, admin, wasm_hash)
+    }
+
+    fn execute_upgrade(env: Env) -> Result<(), CrowdfundingError> {
+        require_no_council(&env)?;
+        let admin = require_admin(&env)?;
+
+        apply_execute_upgrade(&env, admin)
+    }
+
+    fn cancel_upgrade(env: Env) -> Result<(), CrowdfundingError> {
+        require_no_council(&env)?;
+        let admin = require_admin(&env)?;
+
+        apply_cancel_upgrade(&env, admin)
+    }
+
+    fn get_pending_upgrade(env: Env) -> Option<PendingUpgrade> {
+        env.storage().instance().get(&StorageKey::PendingUpgrade)
+    }
+
+    fn get_schema_version(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&StorageKey::SchemaVersion)
+            .unwrap_or(0)
+    }
+
+    fn get_migration_cursor(env: Env) -> Option<MigrationCursor> {
+        env.storage().instance().get(&StorageKey::MigrationCursor)
+    }
+
+    fn migrate(env: Env, batch_size: u32) -> Result<bool, CrowdfundingError> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&StorageKey::Admin)
+            .ok_or(CrowdfundingError::NotInitialized)?;
+        admin.require_auth();
+
+        if batch_size == 0 {
+            return Err(CrowdfundingError::InvalidAmount);
+        }
+
+        let from_version = Self::get_schema_version(env.clone());
+        if from_version >= STORAGE_SCHEMA_VERSION {
+            return Ok(true);
+        }
+
+        let mut cursor = Self::get_migration_cursor(env.clone()).unwrap_or(MigrationCursor {
+            campaign_index: 0,
+            next_pool_id: 1,
+            next_contribution_pool_id: 1,
+        });
+        let mut remaining = batch_size;
+
+        // Reading accepts either layout, so rewriting is a read followed by a write
+        let campaigns = Self::get_all_campaigns(env.clone());
+        while remaining > 0 && cursor.campaign_index < campaigns.len() {
+            let id = campaigns.get_unchecked(cursor.campaign_index);
+            if let Some(campaign) = storage::read_campaign(&env, &id) {
+                storage::write_campaign(&env, &campaign);
+            }
+            cursor.campaign_index += 1;
+            remaining -= 1;
+        }
+
+        let pool_end: u64 = env
+            .storage()
+            .instance()
+            .get(&StorageKey::NextPoolId)
+            .unwrap_or(1);
+        while remaining > 0 && cursor.next_pool_id < pool_end {
+            if let Some(pool) = storage::read_pool(&env, cursor.next_pool_id) {
+                storage::write_pool(&env, cursor.next_pool_id, &pool);
+            }
+            cursor.next_pool_id += 1;
+            remaining -= 1;
+        }
+
+        // A pool that never had a contributor has no records to rewrite; the
+        // first one that did waits for `migrate_pool_contributions`
+        while remaining > 0
+            && cursor.next_pool_id >= pool_end
+            && cursor.next_contribution_pool_id < pool_end
+        {
+            let metrics: PoolMetrics = env
+                .storage()
+                .instance()
+                .get(&StorageKey::PoolMetrics(cursor.next_contribution_pool_id))
+                .unwrap_or_default();
+            if metrics.contributor_count > 0 {
+                break;
+            }
+            cursor.next_contribution_pool_id += 1;
+            remaining -= 1;
+        }
+
+        if cursor.campaign_index < campaigns.len()
+            || cursor.next_pool_id < pool_end
+            || cursor.next_contribution_pool_id < pool_end
+        {
+            env.storage()
+                .instance()
+                .set(&StorageKey::MigrationCursor, &cursor);
+            return Ok(false);
+        }
+
+        env.storage()
+            .instance()
+            .remove(&StorageKey::MigrationCursor);
+        env.storage()
+            .instance()
+            .set(&StorageKey::SchemaVersion, &STORAGE_SCHEMA_VERSION);
+        events::storage_migrated(&env, admin, from_version, STORAGE_SCHEMA_VERSION);
+        Ok(true)
+    }
+
+    fn migrate_pool_contributions(
+        env: Env,
+        pool_id: u64,
+        contributors: Vec<Address>,
+    ) -> Result<(), CrowdfundingError> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&StorageKey::Admin)
+            .ok_or(CrowdfundingError::NotInitialized)?;
+        admin.require_auth();
+
+        // Only the pool `migrate` is waiting on, once campaigns and pools are done
+        let mut cursor =
+            Self::get_migration_cursor(env.clone()).ok_or(CrowdfundingError::NotFound)?;
+        let pool_end: u64 = env
+            .storage()
+            .instance()
+            .get(&StorageKey::NextPoolId)
+            .unwrap_or(1);
+        if cursor.campaign_index < Self::get_all_campaigns(env.clone()).len()
+            || cursor.next_pool_id < pool_end
+            || cursor.next_contribution_pool_id != pool_id
+        {
+            return Err(CrowdfundingError::NotFound);
+        }
+
+        // Every record adds to the pool's total, so matching it shows none
+        // holding funds was left out
+        let mut seen: Map<Address, ()> = Map::new(&env);
+        let mut listed: i128 = 0;
+        for contributor in contributors.iter() {
+            if seen.contains_key(contributor.clone()) {
+                return Err(CrowdfundingError::AlreadyExists);
+            }
+            seen.set(contributor.clone(), ());
+            let key = StorageKey::PoolContribution(pool_id, contributor);
+            let contribution =
+                storage::read_pool_contribution(&env, &key).ok_or(CrowdfundingError::NotFound)?;
+            listed = listed
+                .checked_add(contribution.amount)
+                .ok_or(CrowdfundingError::ArithmeticOverflow)?;
+            storage::write_pool_contribution(&env, &key, &contribution);
+        }
+
+        let metrics: PoolMetrics = env
+            .storage()
+            .instance()
+            .get(&StorageKey::PoolMetrics(pool_id))
+            .unwrap_or_default();
+        if listed != metrics.total_raised {
+            return Err(CrowdfundingError::InvalidAmount);
+        }
+
+        cursor.next_contribution_pool_id += 1;
+        env.storage()
+            .instance()
+            .set(&StorageKey::MigrationCursor, &cursor);
+        Ok(())
+    }
+
+    fn contribute(
+        env: Env,
+        pool_id: u64,
+        contributor: Address,
+        asset: Address,
+        amount: i128,
+        is_private: bool,
+    ) -> Result<(), CrowdfundingError> {
+        require_feature_unpaused(&env, PAUSE_CONTRIBUTIONS)?;
+        contributor.require_auth();
+
+        if amount <= 0 {
             return Err(CrowdfundingError::InvalidAmount);
         }
 
@@ -667,6 +1969,20 @@ impl CrowdfundingTrait for CrowdfundingContract {
         if state != PoolState::Active {
             return Err(CrowdfundingError::InvalidPoolState);
         }
+        require_accepted_amount(&env, &asset, amount)?;
+
+        // Track unique contributor; a pool position is held in a single asset
+        let contributor_key = StorageKey::PoolContribution(pool_id, contributor.clone());
+        let existing_contribution = storage::read_pool_contribution(&env, &contributor_key)
+            .unwrap_or(PoolContribution {
+                pool_id,
+                contributor: contributor.clone(),
+                amount: 0,
+                asset: asset.clone(),
+            });
+        if existing_contribution.amount > 0 && existing_contribution.asset != asset {
+            return Err(CrowdfundingError::InvalidToken);
+        }
 
         // Transfer tokens
         // Note: In a real implementation we would use the token client.
@@ -674,34 +1990,30 @@ impl CrowdfundingTrait for CrowdfundingContract {
         use soroban_sdk::token;
         let token_client = token::Client::new(&env, &asset);
         token_client.transfer(&contributor, env.current_contract_address(), &amount);
+        apply_due_platform_fee(&env);
+        let fee_bps = Self::get_pool_platform_fee(env.clone(), pool_id)?;
+        let amount = escrow_net_of_platform_fee(&env, &contributor, &asset, amount, fee_bps)?;
 
         // Update metrics
         let metrics_key = StorageKey::PoolMetrics(pool_id);
-        let mut metrics: PoolMetrics = env
-            .storage()
-            .instance()
-            .get(&metrics_key)
-            .unwrap_or_default();
-
-        // Track unique contributor
-        let contributor_key = StorageKey::PoolContribution(pool_id, contributor.clone());
-        let existing_contribution: PoolContribution = env
+        let mut metrics: PoolMetrics = env
             .storage()
             .instance()
-            .get(&contributor_key)
-            .unwrap_or(PoolContribution {
-                pool_id,
-                contributor: contributor.clone(),
-                amount: 0,
-                asset: asset.clone(),
-            });
+            .get(&metrics_key)
+            .unwrap_or_default();
 
         // Only increment contributor_count if this is a new contributor
         if existing_contribution.amount == 0 {
-            metrics.contributor_count += 1;
+            metrics.contributor_count = metrics
+                .contributor_count
+                .checked_add(1)
+                .ok_or(CrowdfundingError::ArithmeticOverflow)?;
         }
 
-        metrics.total_raised += amount;
+        metrics.total_raised = metrics
+            .total_raised
+            .checked_add(amount)
+            .ok_or(CrowdfundingError::ArithmeticOverflow)?;
         metrics.last_donation_at = env.ledger().timestamp();
 
         env.storage().instance().set(&metrics_key, &metrics);
@@ -710,12 +2022,13 @@ impl CrowdfundingTrait for CrowdfundingContract {
         let updated_contribution = PoolContribution {
             pool_id,
             contributor: contributor.clone(),
-            amount: existing_contribution.amount + amount,
+            amount: existing_contribution
+                .amount
+                .checked_add(amount)
+                .ok_or(CrowdfundingError::ArithmeticOverflow)?,
             asset: asset.clone(),
         };
-        env.storage()
-            .instance()
-            .set(&contributor_key, &updated_contribution);
+        storage::write_pool_contribution(&env, &contributor_key, &updated_contribution);
 
         // Emit event
         events::contribution(
@@ -732,18 +2045,11 @@ impl CrowdfundingTrait for CrowdfundingContract {
     }
 
     fn refund(env: Env, pool_id: u64, contributor: Address) -> Result<(), CrowdfundingError> {
-        if Self::is_paused(env.clone()) {
-            return Err(CrowdfundingError::ContractPaused);
-        }
+        require_feature_unpaused(&env, PAUSE_REFUNDS)?;
         contributor.require_auth();
 
         // Validate pool exists
-        let pool_key = StorageKey::Pool(pool_id);
-        let pool: PoolConfig = env
-            .storage()
-            .instance()
-            .get(&pool_key)
-            .ok_or(CrowdfundingError::PoolNotFound)?;
+        let pool = storage::read_pool(&env, pool_id).ok_or(CrowdfundingError::PoolNotFound)?;
 
         // Check if pool has a deadline (duration > 0)
         if pool.duration == 0 {
@@ -751,7 +2057,7 @@ impl CrowdfundingTrait for CrowdfundingContract {
         }
 
         // Calculate deadline: created_at + duration
-        let deadline = pool.created_at + pool.duration;
+        let deadline = pool.deadline();
         let now = env.ledger().timestamp();
 
         // Check if deadline has passed
@@ -773,7 +2079,7 @@ impl CrowdfundingTrait for CrowdfundingContract {
 
         // Grace period: 7 days (604800 seconds)
         const REFUND_GRACE_PERIOD: u64 = 604800;
-        let refund_available_after = deadline + REFUND_GRACE_PERIOD;
+        let refund_available_after = deadline.saturating_add(REFUND_GRACE_PERIOD);
 
         // Check if grace period has passed
         if now < refund_available_after {
@@ -782,10 +2088,7 @@ impl CrowdfundingTrait for CrowdfundingContract {
 
         // Get contributor's contribution
         let contribution_key = StorageKey::PoolContribution(pool_id, contributor.clone());
-        let contribution: PoolContribution = env
-            .storage()
-            .instance()
-            .get(&contribution_key)
+        let contribution = storage::read_pool_contribution(&env, &contribution_key)
             .ok_or(CrowdfundingError::NoContributionToRefund)?;
 
         if contribution.amount <= 0 {
@@ -800,6 +2103,7 @@ impl CrowdfundingTrait for CrowdfundingContract {
             &contributor,
             &contribution.amount,
         );
+        release_escrow(&env, &contribution.asset, contribution.amount);
 
         // Update pool metrics
         let metrics_key = StorageKey::PoolMetrics(pool_id);
@@ -807,9 +2111,12 @@ impl CrowdfundingTrait for CrowdfundingContract {
             .storage()
             .instance()
             .get(&metrics_key)
-            .unwrap_or(PoolMetrics::new());
+            .unwrap_or_default();
 
-        metrics.total_raised -= contribution.amount;
+        metrics.total_raised = metrics
+            .total_raised
+            .checked_sub(contribution.amount)
+            .ok_or(CrowdfundingError::ArithmeticOverflow)?;
         // Note: We don't decrement contributor_count as the contributor may have other contributions
         // or we want to keep historical data
 
@@ -823,9 +2130,7 @@ impl CrowdfundingTrait for CrowdfundingContract {
             amount: 0,
             asset: contribution.asset.clone(),
         };
-        env.storage()
-            .instance()
-            .set(&contribution_key, &zeroed_contribution);
+        storage::write_pool_contribution(&env, &contribution_key, &zeroed_contribution);
 
         // Emit refund event
         events::refund(
@@ -842,94 +2147,90 @@ impl CrowdfundingTrait for CrowdfundingContract {
 
     fn request_emergency_withdraw(
         env: Env,
+        caller: Address,
         token: Address,
         amount: i128,
+        recipient: Option<Address>,
     ) -> Result<(), CrowdfundingError> {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&StorageKey::Admin)
-            .ok_or(CrowdfundingError::CampaignNotFound)?;
-        admin.require_auth();
+        require_no_council(&env)?;
+        require_role(&env, &caller, Role::Treasury)?;
 
-        if env
-            .storage()
-            .instance()
-            .has(&StorageKey::EmergencyWithdrawal)
-        {
-            return Err(CrowdfundingError::EmergencyWithdrawalAlreadyRequested);
-        }
+        let recipient = recipient.unwrap_or(caller.clone());
+        apply_request_emergency_withdraw(&env, caller, token, amount, recipient)
+    }
 
-        let now = env.ledger().timestamp();
-        let grace_period = 86400; // 24 hours
+    fn execute_emergency_withdraw(env: Env, caller: Address) -> Result<(), CrowdfundingError> {
+        require_no_council(&env)?;
+        require_role(&env, &caller, Role::Treasury)?;
 
-        let request = EmergencyWithdrawal {
-            recipient: admin.clone(),
-            amount,
-            token: token.clone(),
-            requested_at: now,
-            executed: false,
-        };
+        apply_execute_emergency_withdraw(&env, caller)
+    }
+
+    fn cancel_emergency_withdraw(env: Env, caller: Address) -> Result<(), CrowdfundingError> {
+        require_no_council(&env)?;
+        require_role(&env, &caller, Role::Treasury)?;
+
+        apply_cancel_emergency_withdraw(&env, caller)
+    }
 
+    fn get_emergency_withdrawal(env: Env) -> Option<EmergencyWithdrawal> {
         env.storage()
             .instance()
-            .set(&StorageKey::EmergencyWithdrawal, &request);
+            .get(&StorageKey::EmergencyWithdrawal)
+    }
 
-        events::emergency_withdraw_requested(&env, admin, token, amount, now + grace_period);
+    fn set_emergency_withdraw_delay(env: Env, delay: u64) -> Result<(), CrowdfundingError> {
+        require_no_council(&env)?;
+        let admin = require_admin(&env)?;
 
-        Ok(())
+        apply_set_emergency_withdraw_delay(&env, admin, delay)
     }
 
-    fn execute_emergency_withdraw(env: Env) -> Result<(), CrowdfundingError> {
-        let admin: Address = env
-            .storage()
+    fn get_emergency_withdraw_delay(env: Env) -> u64 {
+        env.storage()
             .instance()
-            .get(&StorageKey::Admin)
-            .ok_or(CrowdfundingError::CampaignNotFound)?;
-        admin.require_auth();
+            .get(&StorageKey::EmergencyWithdrawDelay)
+            .unwrap_or(DEFAULT_EMERGENCY_WITHDRAW_DELAY)
+    }
 
-        let key = StorageKey::EmergencyWithdrawal;
-        let request: EmergencyWithdrawal = env
-            .storage()
+    fn get_escrowed_balance(env: Env, token: Address) -> i128 {
+        env.storage()
             .instance()
-            .get(&key)
-            .ok_or(CrowdfundingError::EmergencyWithdrawalNotRequested)?;
-
-        // If for some reason it's already executed but not removed (shouldn't happen with remove)
-        if request.executed {
-            return Err(CrowdfundingError::EmergencyWithdrawalAlreadyRequested);
-        }
-
-        let now = env.ledger().timestamp();
-        let grace_period = 86400; // 24 hours
-        if now < request.requested_at + grace_period {
-            return Err(CrowdfundingError::EmergencyWithdrawalPeriodNotPassed);
-        }
+            .get(&StorageKey::EscrowedBalance(token))
+            .unwrap_or(0)
+    }
 
+    fn get_withdrawable_balance(env: Env, token: Address) -> i128 {
         use soroban_sdk::token;
-        let token_client = token::Client::new(&env, &request.token);
-        token_client.transfer(&env.current_contract_address(), &admin, &request.amount);
+        let balance = token::Client::new(&env, &token).balance(&env.current_contract_address());
+        let escrowed = Self::get_escrowed_balance(env.clone(), token.clone());
+        let fees = Self::get_fee_balance(env, token);
+        balance.saturating_sub(escrowed).saturating_sub(fees).max(0)
+    }
 
-        // Remove the request to allow future requests (or keep it as history? Requirement says "Define clear rules in storage to prevent abuse".
-        // Removing it clears the storage. If we want history, we should use a map or log events.
-        // Events are logged. Clearing storage prevents double withdrawal and clutter.
-        env.storage().instance().remove(&key);
+    fn withdraw_fees(
+        env: Env,
+        caller: Address,
+        token: Address,
+        amount: i128,
+        to: Address,
+    ) -> Result<(), CrowdfundingError> {
+        require_no_council(&env)?;
+        require_role(&env, &caller, Role::Treasury)?;
 
-        events::emergency_withdraw_executed(&env, admin, request.token, request.amount);
+        apply_withdraw_fees(&env, caller, token, amount, to)
+    }
 
-        Ok(())
+    fn get_fee_balance(env: Env, token: Address) -> i128 {
+        env.storage()
+            .instance()
+            .get(&StorageKey::FeeBalance(token))
+            .unwrap_or(0)
     }
 
     fn close_pool(env: Env, pool_id: u64, caller: Address) -> Result<(), CrowdfundingError> {
-        caller.require_auth();
-
         // Validate pool exists
-        let pool_key = StorageKey::Pool(pool_id);
-        let _pool: PoolConfig = env
-            .storage()
-            .instance()
-            .get(&pool_key)
-            .ok_or(CrowdfundingError::PoolNotFound)?;
+        storage::read_pool(&env, pool_id).ok_or(CrowdfundingError::PoolNotFound)?;
 
         // Get current pool state
         let state_key = StorageKey::PoolState(pool_id);
@@ -949,19 +2250,9 @@ impl CrowdfundingTrait for CrowdfundingContract {
             return Err(CrowdfundingError::PoolNotDisbursedOrRefunded);
         }
 
-        // Verify caller is admin or pool creator
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&StorageKey::Admin)
-            .ok_or(CrowdfundingError::NotInitialized)?;
-
-        // For now, we'll check if there's a creator stored separately
-        // Since PoolConfig doesn't have creator field, we'll allow admin only
-        // In a real implementation, you might want to add creator to PoolConfig or store it separately
-        if caller != admin {
-            return Err(CrowdfundingError::Unauthorized);
-        }
+        // Closing is a moderation action, so only the admin or a pool
+        // moderator can close a pool, not its recorded owner
+        require_role(&env, &caller, Role::PoolModerator)?;
 
         // Update state to Closed
         env.storage().instance().set(&state_key, &PoolState::Closed);
@@ -991,3 +2282,903 @@ impl CrowdfundingTrait for CrowdfundingContract {
         Ok(current_state == PoolState::Closed)
     }
 }
+
+/// Require the stored admin's auth and return it.
+fn require_admin(env: &Env) -> Result<Address, CrowdfundingError> {
+    let admin: Address = env
+        .storage()
+        .instance()
+        .get(&StorageKey::Admin)
+        .ok_or(CrowdfundingError::NotInitialized)?;
+    admin.require_auth();
+    Ok(admin)
+}
+
+/// Fail while a council is set; privileged actions then go through
+/// `propose_council_action`.
+fn require_no_council(env: &Env) -> Result<(), CrowdfundingError> {
+    if env.storage().instance().has(&StorageKey::Council) {
+        return Err(CrowdfundingError::Unauthorized);
+    }
+    Ok(())
+}
+
+/// Require `signer`'s auth and that it sits on the council, returning the
+/// council.
+fn require_council_member(
+    env: &Env,
+    signer: &Address,
+) -> Result<MultiSigConfig, CrowdfundingError> {
+    let council: MultiSigConfig = env
+        .storage()
+        .instance()
+        .get(&StorageKey::Council)
+        .ok_or(CrowdfundingError::NotFound)?;
+    signer.require_auth();
+
+    if !council.signers.contains(signer) {
+        return Err(CrowdfundingError::NotAuthorizedSigner);
+    }
+    Ok(council)
+}
+
+/// Same rules as a pool's multi-sig config, with distinct signers.
+fn validate_council(config: &MultiSigConfig) -> Result<(), CrowdfundingError> {
+    if config.required_signatures == 0 || config.required_signatures > config.signers.len() {
+        return Err(CrowdfundingError::InvalidMultiSigConfig);
+    }
+    if config.signers.is_empty() {
+        return Err(CrowdfundingError::InvalidSignerCount);
+    }
+    for (i, signer) in config.signers.iter().enumerate() {
+        if config
+            .signers
+            .iter()
+            .skip(i + 1)
+            .any(|other| other == signer)
+        {
+            return Err(CrowdfundingError::SignerAlreadyExists);
+        }
+    }
+    Ok(())
+}
+
+/// Load a proposal that can still be approved or executed. Expired proposals
+/// are treated as missing.
+fn open_council_proposal(
+    env: &Env,
+    proposal_id: u64,
+) -> Result<CouncilProposal, CrowdfundingError> {
+    let proposal: CouncilProposal = env
+        .storage()
+        .instance()
+        .get(&StorageKey::CouncilProposal(proposal_id))
+        .filter(|proposal: &CouncilProposal| env.ledger().timestamp() < proposal.expires_at)
+        .ok_or(CrowdfundingError::NotFound)?;
+    if proposal.executed {
+        return Err(CrowdfundingError::AlreadyExists);
+    }
+    Ok(proposal)
+}
+
+/// Remove expired proposals, oldest first. Proposals expire in the order they
+/// were made, so this stops at the first one still open.
+fn remove_expired_council_proposals(env: &Env) {
+    let next_id: u64 = env
+        .storage()
+        .instance()
+        .get(&StorageKey::NextCouncilProposalId)
+        .unwrap_or(1);
+    let mut id: u64 = env
+        .storage()
+        .instance()
+        .get(&StorageKey::FirstCouncilProposalId)
+        .unwrap_or(1);
+    let now = env.ledger().timestamp();
+    while id < next_id {
+        let key = StorageKey::CouncilProposal(id);
+        let proposal: Option<CouncilProposal> = env.storage().instance().get(&key);
+        match proposal {
+            Some(proposal) if now < proposal.expires_at => break,
+            Some(_) => env.storage().instance().remove(&key),
+            None => {}
+        }
+        id += 1;
+    }
+    env.storage()
+        .instance()
+        .set(&StorageKey::FirstCouncilProposalId, &id);
+}
+
+// The `apply_*` helpers carry out a privileged action once its caller has
+// been authorized, either directly or through an executed council proposal.
+
+fn apply_set_crowdfunding_token(
+    env: &Env,
+    caller: Address,
+    token: Address,
+) -> Result<(), CrowdfundingError> {
+    if !env
+        .storage()
+        .instance()
+        .has(&StorageKey::AcceptedToken(token.clone()))
+    {
+        return Err(CrowdfundingError::InvalidToken);
+    }
+
+    let mut pending = queue_parameters(env, caller.clone());
+    pending.crowdfunding_token = Some(token.clone());
+    env.storage()
+        .instance()
+        .set(&StorageKey::PendingParameters, &pending);
+    events::crowdfunding_token_queued(env, caller, token, pending.effective_at);
+    Ok(())
+}
+
+fn apply_set_accepted_token(
+    env: &Env,
+    caller: Address,
+    token: Address,
+    min_amount: i128,
+) -> Result<(), CrowdfundingError> {
+    if min_amount < 0 {
+        return Err(CrowdfundingError::InvalidAmount);
+    }
+
+    // Reading the metadata also checks that `token` is a token contract
+    use soroban_sdk::token;
+    let token_client = token::Client::new(env, &token);
+    let (Ok(Ok(symbol)), Ok(Ok(decimals))) =
+        (token_client.try_symbol(), token_client.try_decimals())
+    else {
+        return Err(CrowdfundingError::InvalidToken);
+    };
+
+    let key = StorageKey::AcceptedToken(token.clone());
+    if !env.storage().instance().has(&key) {
+        let mut tokens = CrowdfundingContract::get_accepted_tokens(env.clone());
+        tokens.push_back(token.clone());
+        env.storage()
+            .instance()
+            .set(&StorageKey::AcceptedTokens, &tokens);
+    }
+    env.storage().instance().set(
+        &key,
+        &AcceptedToken {
+            symbol: symbol.clone(),
+            decimals,
+            min_amount,
+        },
+    );
+    events::accepted_token_set(env, caller, token, symbol, decimals, min_amount);
+    Ok(())
+}
+
+fn apply_remove_accepted_token(
+    env: &Env,
+    caller: Address,
+    token: Address,
+) -> Result<(), CrowdfundingError> {
+    let key = StorageKey::AcceptedToken(token.clone());
+    if !env.storage().instance().has(&key) {
+        return Err(CrowdfundingError::NotFound);
+    }
+    // Creation fees are charged in the platform token, including one queued
+    // to replace it
+    let queued = env
+        .storage()
+        .instance()
+        .get::<_, PendingParameters>(&StorageKey::PendingParameters)
+        .and_then(|pending| pending.crowdfunding_token);
+    let current: Option<Address> = env.storage().instance().get(&StorageKey::CrowdfundingToken);
+    if current.as_ref() == Some(&token) || queued.as_ref() == Some(&token) {
+        return Err(CrowdfundingError::InvalidToken);
+    }
+
+    env.storage().instance().remove(&key);
+    let mut tokens = CrowdfundingContract::get_accepted_tokens(env.clone());
+    if let Some(index) = tokens.first_index_of(&token) {
+        tokens.remove(index);
+    }
+    env.storage()
+        .instance()
+        .set(&StorageKey::AcceptedTokens, &tokens);
+    events::accepted_token_removed(env, caller, token);
+    Ok(())
+}
+
+/// Fail unless `token` is accepted and `amount` meets its minimum.
+fn require_accepted_amount(
+    env: &Env,
+    token: &Address,
+    amount: i128,
+) -> Result<(), CrowdfundingError> {
+    let accepted = CrowdfundingContract::get_accepted_token(env.clone(), token.clone())
+        .ok_or(CrowdfundingError::InvalidToken)?;
+    if amount < accepted.min_amount {
+        return Err(CrowdfundingError::InvalidAmount);
+    }
+    Ok(())
+}
+
+fn apply_set_creation_fee(env: &Env, caller: Address, fee: i128) -> Result<(), CrowdfundingError> {
+    if fee < 0 {
+        return Err(CrowdfundingError::InvalidFee);
+    }
+
+    let mut pending = queue_parameters(env, caller.clone());
+    pending.creation_fee = Some(fee);
+    env.storage()
+        .instance()
+        .set(&StorageKey::PendingParameters, &pending);
+    events::creation_fee_queued(env, caller, fee, pending.effective_at);
+    Ok(())
+}
+
+fn apply_set_entity_creation_fee(
+    env: &Env,
+    caller: Address,
+    entity: FeeEntity,
+    fee: i128,
+) -> Result<(), CrowdfundingError> {
+    if entity == FeeEntity::Campaign {
+        return apply_set_creation_fee(env, caller, fee);
+    }
+    if fee < 0 {
+        return Err(CrowdfundingError::InvalidFee);
+    }
+
+    // A due change is written first; one still waiting is replaced
+    apply_due_entity_fee(env, entity);
+    let pending = PendingEntityFee {
+        fee,
+        queued_by: caller.clone(),
+        effective_at: env
+            .ledger()
+            .timestamp()
+            .saturating_add(PARAMETER_CHANGE_DELAY),
+    };
+    env.storage()
+        .instance()
+        .set(&StorageKey::PendingEntityFee(entity), &pending);
+    events::entity_creation_fee_queued(env, caller, entity, fee, pending.effective_at);
+    Ok(())
+}
+
+/// The queued fee change of a pool entity if its delay has passed but it has
+/// not been written yet.
+fn due_entity_fee(env: &Env, entity: FeeEntity) -> Option<PendingEntityFee> {
+    env.storage()
+        .instance()
+        .get(&StorageKey::PendingEntityFee(entity))
+        .filter(|pending: &PendingEntityFee| env.ledger().timestamp() >= pending.effective_at)
+}
+
+fn apply_due_entity_fee(env: &Env, entity: FeeEntity) {
+    let Some(pending) = due_entity_fee(env, entity) else {
+        return;
+    };
+    env.storage()
+        .instance()
+        .remove(&StorageKey::PendingEntityFee(entity));
+    env.storage()
+        .instance()
+        .set(&StorageKey::EntityCreationFee(entity), &pending.fee);
+    events::entity_creation_fee_set(env, pending.queued_by, entity, pending.fee);
+}
+
+fn apply_set_platform_fee(env: &Env, caller: Address, bps: u32) -> Result<(), CrowdfundingError> {
+    if bps > BPS_DENOMINATOR {
+        return Err(CrowdfundingError::InvalidFee);
+    }
+
+    // A due change is written first; one still waiting is replaced
+    apply_due_platform_fee(env);
+    let pending = PendingPlatformFee {
+        bps,
+        queued_by: caller.clone(),
+        effective_at: env
+            .ledger()
+            .timestamp()
+            .saturating_add(PARAMETER_CHANGE_DELAY),
+    };
+    env.storage()
+        .instance()
+        .set(&StorageKey::PendingPlatformFee, &pending);
+    events::platform_fee_queued(env, caller, bps, pending.effective_at);
+    Ok(())
+}
+
+fn apply_set_pool_platform_fee(
+    env: &Env,
+    caller: Address,
+    pool_id: u64,
+    bps: Option<u32>,
+) -> Result<(), CrowdfundingError> {
+    if !env.storage().instance().has(&StorageKey::Pool(pool_id)) {
+        return Err(CrowdfundingError::PoolNotFound);
+    }
+    let key = StorageKey::PoolPlatformFeeBps(pool_id);
+    match bps {
+        Some(bps) if bps > BPS_DENOMINATOR => return Err(CrowdfundingError::InvalidFee),
+        Some(bps) => env.storage().instance().set(&key, &bps),
+        None => env.storage().instance().remove(&key),
+    }
+    events::pool_platform_fee_set(env, pool_id, caller, bps);
+    Ok(())
+}
+
+/// The queued platform fee change if its delay has passed but it has not
+/// been written yet.
+fn due_platform_fee(env: &Env) -> Option<PendingPlatformFee> {
+    env.storage()
+        .instance()
+        .get(&StorageKey::PendingPlatformFee)
+        .filter(|pending: &PendingPlatformFee| env.ledger().timestamp() >= pending.effective_at)
+}
+
+fn apply_due_platform_fee(env: &Env) {
+    let Some(pending) = due_platform_fee(env) else {
+        return;
+    };
+    env.storage()
+        .instance()
+        .remove(&StorageKey::PendingPlatformFee);
+    env.storage()
+        .instance()
+        .set(&StorageKey::PlatformFeeBps, &pending.bps);
+    events::platform_fee_set(env, pending.queued_by, pending.bps);
+}
+
+/// The platform fee rate `bps` after `creator`'s fee tier, if any.
+fn creator_platform_fee_bps(env: &Env, creator: &Address, bps: u32) -> u32 {
+    match CrowdfundingContract::get_fee_tier(env.clone(), creator.clone()) {
+        Some(tier) => tier.tier.platform_fee_bps(bps),
+        None => bps,
+    }
+}
+
+/// Split `amount` into the platform fee at `fee_bps`, rounded down, and the
+/// net amount left for escrow.
+fn quote_platform_fee(amount: i128, fee_bps: u32) -> Result<ContributionQuote, CrowdfundingError> {
+    if amount <= 0 {
+        return Err(CrowdfundingError::InvalidAmount);
+    }
+    let fee = amount
+        .checked_mul(fee_bps as i128)
+        .ok_or(CrowdfundingError::ArithmeticOverflow)?
+        / BPS_DENOMINATOR as i128;
+    Ok(ContributionQuote {
+        fee_bps,
+        fee,
+        net: amount - fee,
+    })
+}
+
+/// Take the platform fee at `fee_bps` out of `amount` received from `payer`
+/// into the fee treasury, and escrow the rest. Returns the escrowed amount,
+/// which is what a refund pays back.
+fn escrow_net_of_platform_fee(
+    env: &Env,
+    payer: &Address,
+    token: &Address,
+    amount: i128,
+    fee_bps: u32,
+) -> Result<i128, CrowdfundingError> {
+    let quote = quote_platform_fee(amount, fee_bps)?;
+    if quote.net <= 0 {
+        return Err(CrowdfundingError::InvalidAmount);
+    }
+    escrow(env, token, quote.net)?;
+    if quote.fee > 0 {
+        collect_fee(env, token, quote.fee)?;
+        events::platform_fee_paid(env, payer.clone(), token.clone(), quote.fee);
+    }
+    Ok(quote.net)
+}
+
+/// Charge `creator` the creation fee of `entity` in the platform token,
+/// refusing a fee above the `max_fee` they agreed to.
+fn charge_creation_fee(
+    env: &Env,
+    creator: &Address,
+    entity: FeeEntity,
+    max_fee: i128,
+) -> Result<(), CrowdfundingError> {
+    apply_due_parameters(env);
+    apply_due_entity_fee(env, entity);
+
+    let fee_key = match entity {
+        FeeEntity::Campaign => StorageKey::CreationFee,
+        _ => StorageKey::EntityCreationFee(entity),
+    };
+    let creation_fee: i128 = env.storage().instance().get(&fee_key).unwrap_or(0);
+    let creation_fee = match CrowdfundingContract::get_fee_tier(env.clone(), creator.clone()) {
+        Some(tier) => tier.tier.creation_fee(creation_fee),
+        None => creation_fee,
+    };
+    if creation_fee > max_fee {
+        return Err(CrowdfundingError::InvalidFee);
+    }
+    if creation_fee == 0 {
+        return Ok(());
+    }
+
+    use soroban_sdk::token;
+    let token_address: Address = env
+        .storage()
+        .instance()
+        .get(&StorageKey::CrowdfundingToken)
+        .ok_or(CrowdfundingError::NotInitialized)?;
+    let token_client = token::Client::new(env, &token_address);
+
+    let balance = token_client.balance(creator);
+    if balance < creation_fee {
+        return Err(CrowdfundingError::InsufficientBalance);
+    }
+
+    token_client.transfer(creator, env.current_contract_address(), &creation_fee);
+    collect_fee(env, &token_address, creation_fee)?;
+    events::creation_fee_paid(env, creator.clone(), token_address, creation_fee);
+    Ok(())
+}
+
+/// The queued parameter changes if their delay has passed but they have not
+/// been written yet.
+fn due_parameters(env: &Env) -> Option<PendingParameters> {
+    env.storage()
+        .instance()
+        .get(&StorageKey::PendingParameters)
+        .filter(|pending: &PendingParameters| env.ledger().timestamp() >= pending.effective_at)
+}
+
+/// Write queued parameter changes whose delay has passed, emitting the
+/// regular `*_set` events on behalf of whoever last queued a change.
+fn apply_due_parameters(env: &Env) {
+    let Some(pending) = due_parameters(env) else {
+        return;
+    };
+    env.storage()
+        .instance()
+        .remove(&StorageKey::PendingParameters);
+
+    if let Some(fee) = pending.creation_fee {
+        env.storage().instance().set(&StorageKey::CreationFee, &fee);
+        events::creation_fee_set(env, pending.queued_by.clone(), fee);
+    }
+    if let Some(token) = pending.crowdfunding_token {
+        env.storage()
+            .instance()
+            .set(&StorageKey::CrowdfundingToken, &token);
+        events::crowdfunding_token_set(env, pending.queued_by, token);
+    }
+}
+
+/// The queued changes to extend with another one, restarting the delay.
+fn queue_parameters(env: &Env, caller: Address) -> PendingParameters {
+    apply_due_parameters(env);
+    let effective_at = env
+        .ledger()
+        .timestamp()
+        .saturating_add(PARAMETER_CHANGE_DELAY);
+    match env.storage().instance().get(&StorageKey::PendingParameters) {
+        Some(pending) => PendingParameters {
+            queued_by: caller,
+            effective_at,
+            ..pending
+        },
+        None => PendingParameters {
+            creation_fee: None,
+            crowdfunding_token: None,
+            queued_by: caller,
+            effective_at,
+        },
+    }
+}
+
+fn apply_request_emergency_withdraw(
+    env: &Env,
+    caller: Address,
+    token: Address,
+    amount: i128,
+    recipient: Address,
+) -> Result<(), CrowdfundingError> {
+    if env
+        .storage()
+        .instance()
+        .has(&StorageKey::EmergencyWithdrawal)
+    {
+        return Err(CrowdfundingError::EmergencyWithdrawalAlreadyRequested);
+    }
+
+    if amount <= 0 {
+        return Err(CrowdfundingError::InvalidAmount);
+    }
+    // Escrowed donations and contributions are never withdrawable
+    if amount > CrowdfundingContract::get_withdrawable_balance(env.clone(), token.clone()) {
+        return Err(CrowdfundingError::InsufficientBalance);
+    }
+
+    let now = env.ledger().timestamp();
+    let unlock_time = now.saturating_add(CrowdfundingContract::get_emergency_withdraw_delay(
+        env.clone(),
+    ));
+
+    let request = EmergencyWithdrawal {
+        recipient: recipient.clone(),
+        amount,
+        token: token.clone(),
+        requested_at: now,
+        executed: false,
+        unlock_time,
+    };
+
+    env.storage()
+        .instance()
+        .set(&StorageKey::EmergencyWithdrawal, &request);
+
+    let recipient = (recipient != caller).then_some(recipient);
+    events::emergency_withdraw_requested(env, caller, token, amount, recipient, unlock_time);
+
+    Ok(())
+}
+
+fn apply_execute_emergency_withdraw(env: &Env, caller: Address) -> Result<(), CrowdfundingError> {
+    let key = StorageKey::EmergencyWithdrawal;
+    let request: EmergencyWithdrawal = env
+        .storage()
+        .instance()
+        .get(&key)
+        .ok_or(CrowdfundingError::EmergencyWithdrawalNotRequested)?;
+
+    // If for some reason it's already executed but not removed (shouldn't happen with remove)
+    if request.executed {
+        return Err(CrowdfundingError::EmergencyWithdrawalAlreadyRequested);
+    }
+
+    if env.ledger().timestamp() < request.unlock_time {
+        return Err(CrowdfundingError::EmergencyWithdrawalPeriodNotPassed);
+    }
+
+    // Funds escrowed since the request was made stay out of reach
+    if request.amount
+        > CrowdfundingContract::get_withdrawable_balance(env.clone(), request.token.clone())
+    {
+        return Err(CrowdfundingError::InsufficientBalance);
+    }
+
+    use soroban_sdk::token;
+    let token_client = token::Client::new(env, &request.token);
+    token_client.transfer(
+        &env.current_contract_address(),
+        &request.recipient,
+        &request.amount,
+    );
+
+    // Remove the request to allow future requests (or keep it as history? Requirement says "Define clear rules in storage to prevent abuse".
+    // Removing it clears the storage. If we want history, we should use a map or log events.
+    // Events are logged. Clearing storage prevents double withdrawal and clutter.
+    env.storage().instance().remove(&key);
+
+    let recipient = (request.recipient != caller).then_some(request.recipient);
+    events::emergency_withdraw_executed(env, caller, request.token, request.amount, recipient);
+
+    Ok(())
+}
+
+fn apply_cancel_emergency_withdraw(env: &Env, caller: Address) -> Result<(), CrowdfundingError> {
+    let request: EmergencyWithdrawal = env
+        .storage()
+        .instance()
+        .get(&StorageKey::EmergencyWithdrawal)
+        .ok_or(CrowdfundingError::EmergencyWithdrawalNotRequested)?;
+
+    env.storage()
+        .instance()
+        .remove(&StorageKey::EmergencyWithdrawal);
+    events::emergency_withdraw_cancelled(env, caller, request.token, request.amount);
+    Ok(())
+}
+
+fn apply_withdraw_fees(
+    env: &Env,
+    caller: Address,
+    token: Address,
+    amount: i128,
+    to: Address,
+) -> Result<(), CrowdfundingError> {
+    use soroban_sdk::token;
+
+    if amount <= 0 {
+        return Err(CrowdfundingError::InvalidAmount);
+    }
+    let balance: i128 = env
+        .storage()
+        .instance()
+        .get(&StorageKey::FeeBalance(token.clone()))
+        .unwrap_or(0);
+    if amount > balance {
+        return Err(CrowdfundingError::InsufficientBalance);
+    }
+
+    env.storage()
+        .instance()
+        .set(&StorageKey::FeeBalance(token.clone()), &(balance - amount));
+    token::Client::new(env, &token).transfer(&env.current_contract_address(), &to, &amount);
+
+    events::fees_withdrawn(env, caller, token, amount, to);
+    Ok(())
+}
+
+fn apply_assign_fee_tier(
+    env: &Env,
+    caller: Address,
+    creator: Address,
+    tier: FeeTier,
+    expires_at: u64,
+) -> Result<(), CrowdfundingError> {
+    tier.validate()?;
+    if expires_at != 0 && expires_at <= env.ledger().timestamp() {
+        return Err(CrowdfundingError::InvalidDeadline);
+    }
+
+    env.storage().instance().set(
+        &StorageKey::CreatorFeeTier(creator.clone()),
+        &CreatorFeeTier {
+            tier: tier.clone(),
+            assigned_by: caller.clone(),
+            expires_at,
+        },
+    );
+    events::fee_tier_assigned(env, caller, creator, tier, expires_at);
+    Ok(())
+}
+
+fn apply_set_emergency_withdraw_delay(
+    env: &Env,
+    admin: Address,
+    delay: u64,
+) -> Result<(), CrowdfundingError> {
+    if !(MIN_EMERGENCY_WITHDRAW_DELAY..=MAX_EMERGENCY_WITHDRAW_DELAY).contains(&delay) {
+        return Err(CrowdfundingError::InvalidDeadline);
+    }
+
+    // A pending request keeps the unlock time it was created with
+    env.storage()
+        .instance()
+        .set(&StorageKey::EmergencyWithdrawDelay, &delay);
+    events::emergency_withdraw_delay_set(env, admin, delay);
+    Ok(())
+}
+
+fn apply_set_upgrade_timelock(
+    env: &Env,
+    admin: Address,
+    delay: u64,
+) -> Result<(), CrowdfundingError> {
+    if !(MIN_UPGRADE_TIMELOCK..=MAX_UPGRADE_TIMELOCK).contains(&delay) {
+        return Err(CrowdfundingError::InvalidDeadline);
+    }
+
+    // Only affects later proposals; a pending upgrade keeps its unlock time
+    env.storage()
+        .instance()
+        .set(&StorageKey::UpgradeTimelock, &delay);
+    events::upgrade_timelock_set(env, admin, delay);
+    Ok(())
+}
+
+fn apply_propose_upgrade(
+    env: &Env,
+    admin: Address,
+    wasm_hash: BytesN<32>,
+) -> Result<(), CrowdfundingError> {
+    if env.storage().instance().has(&StorageKey::PendingUpgrade) {
+        return Err(CrowdfundingError::AlreadyExists);
+    }
+
+    let now = env.ledger().timestamp();
+    let executable_at = now.saturating_add(CrowdfundingContract::get_upgrade_timelock(env.clone()));
+    let upgrade = PendingUpgrade {
+        wasm_hash: wasm_hash.clone(),
+        proposed_at: now,
+        executable_at,
+    };
+    env.storage()
+        .instance()
+        .set(&StorageKey::PendingUpgrade, &upgrade);
+
+    events::upgrade_proposed(env, admin, wasm_hash, executable_at);
+    Ok(())
+}
+
+fn apply_execute_upgrade(env: &Env, admin: Address) -> Result<(), CrowdfundingError> {
+    let upgrade: PendingUpgrade = env
+        .storage()
+        .instance()
+        .get(&StorageKey::PendingUpgrade)
+        .ok_or(CrowdfundingError::NotFound)?;
+    if env.ledger().timestamp() < upgrade.executable_at {
+        return Err(CrowdfundingError::TimelockNotPassed);
+    }
+
+    env.storage().instance().remove(&StorageKey::PendingUpgrade);
+    env.deployer()
+        .update_current_contract_wasm(upgrade.wasm_hash.clone());
+
+    events::upgrade_executed(env, admin, upgrade.wasm_hash, CONTRACT_VERSION);
+    Ok(())
+}
+
+fn apply_cancel_upgrade(env: &Env, admin: Address) -> Result<(), CrowdfundingError> {
+    let upgrade: PendingUpgrade = env
+        .storage()
+        .instance()
+        .get(&StorageKey::PendingUpgrade)
+        .ok_or(CrowdfundingError::NotFound)?;
+
+    env.storage().instance().remove(&StorageKey::PendingUpgrade);
+    events::upgrade_cancelled(env, admin, upgrade.wasm_hash);
+    Ok(())
+}
+
+/// Require `caller`'s auth and that it is the admin or holds `role`.
+fn require_role(env: &Env, caller: &Address, role: Role) -> Result<(), CrowdfundingError> {
+    let admin: Address = env
+        .storage()
+        .instance()
+        .get(&StorageKey::Admin)
+        .ok_or(CrowdfundingError::NotInitialized)?;
+    caller.require_auth();
+
+    if *caller == admin
+        || env
+            .storage()
+            .instance()
+            .has(&StorageKey::Role(role, caller.clone()))
+    {
+        Ok(())
+    } else {
+        Err(CrowdfundingError::Unauthorized)
+    }
+}
+
+/// Fail if the whole contract, or `feature` on its own, is paused. Feature
+/// pauses lapse at their expiry without a transaction.
+fn require_feature_unpaused(env: &Env, feature: u32) -> Result<(), CrowdfundingError> {
+    if CrowdfundingContract::is_paused(env.clone())
+        || CrowdfundingContract::get_feature_pause(env.clone(), feature).is_some()
+    {
+        return Err(CrowdfundingError::ContractPaused);
+    }
+    Ok(())
+}
+
+/// Fail unless the contract has been paused for longer than the maximum pause
+/// duration. An unpaused contract has its regular refund paths instead.
+fn require_escape_hatch(env: &Env) -> Result<(), CrowdfundingError> {
+    if !CrowdfundingContract::is_paused(env.clone()) {
+        return Err(CrowdfundingError::RefundNotAvailable);
+    }
+    if !CrowdfundingContract::is_escape_hatch_open(env.clone()) {
+        return Err(CrowdfundingError::TimelockNotPassed);
+    }
+    Ok(())
+}
+
+/// Record `amount` of `token` as held on behalf of contributors.
+fn escrow(env: &Env, token: &Address, amount: i128) -> Result<(), CrowdfundingError> {
+    let key = StorageKey::EscrowedBalance(token.clone());
+    let escrowed: i128 = env.storage().instance().get(&key).unwrap_or(0);
+    let escrowed = escrowed
+        .checked_add(amount)
+        .ok_or(CrowdfundingError::ArithmeticOverflow)?;
+    env.storage().instance().set(&key, &escrowed);
+    Ok(())
+}
+
+/// Credit a collected fee to the fee treasury.
+fn collect_fee(env: &Env, token: &Address, amount: i128) -> Result<(), CrowdfundingError> {
+    let key = StorageKey::FeeBalance(token.clone());
+    let balance: i128 = env.storage().instance().get(&key).unwrap_or(0);
+    let balance = balance
+        .checked_add(amount)
+        .ok_or(CrowdfundingError::ArithmeticOverflow)?;
+    env.storage().instance().set(&key, &balance);
+    Ok(())
+}
+
+/// Release `amount` of `token` paid back out of escrow. Saturates at zero
+/// since funds received before escrow was tracked were never counted.
+fn release_escrow(env: &Env, token: &Address, amount: i128) {
+    let key = StorageKey::EscrowedBalance(token.clone());
+    let escrowed: i128 = env.storage().instance().get(&key).unwrap_or(0);
+    env.storage()
+        .instance()
+        .set(&key, &escrowed.saturating_sub(amount).max(0));
+}
+
+/// The recorded creator of an existing pool.
+fn pool_owner(env: &Env, pool_id: u64) -> Result<Address, CrowdfundingError> {
+    if !env.storage().instance().has(&StorageKey::Pool(pool_id)) {
+        return Err(CrowdfundingError::PoolNotFound);
+    }
+    CrowdfundingContract::get_pool_creator(env.clone(), pool_id)
+        .ok_or(CrowdfundingError::Unauthorized)
+}
+
+/// The campaign `campaign_id` after checking its creator's authorization and
+/// that it is still open to amendments.
+fn amendable_campaign(
+    env: &Env,
+    campaign_id: &BytesN<32>,
+) -> Result<CampaignDetails, CrowdfundingError> {
+    let campaign = CrowdfundingContract::get_campaign(env.clone(), campaign_id.clone())?;
+    campaign.creator.require_auth();
+
+    if campaign.status != CampaignStatus::Active || env.ledger().timestamp() >= campaign.deadline {
+        return Err(CrowdfundingError::CampaignExpired);
+    }
+    Ok(campaign)
+}
+
+/// Pay a donor's whole campaign contribution back out of escrow.
+fn refund_campaign_contribution(
+    env: &Env,
+    campaign_id: BytesN<32>,
+    contributor: Address,
+) -> Result<(), CrowdfundingError> {
+    let mut campaign = CrowdfundingContract::get_campaign(env.clone(), campaign_id.clone())?;
+    let contribution_key = StorageKey::Contribution(campaign_id.clone(), contributor.clone());
+    let contribution: Contribution = env
+        .storage()
+        .instance()
+        .get(&contribution_key)
+        .filter(|contribution: &Contribution| contribution.amount > 0)
+        .ok_or(CrowdfundingError::NoContributionToRefund)?;
+
+    use soroban_sdk::token;
+    let token_client = token::Client::new(env, &campaign.token_address);
+    token_client.transfer(
+        &env.current_contract_address(),
+        &contributor,
+        &contribution.amount,
+    );
+    release_escrow(env, &campaign.token_address, contribution.amount);
+
+    campaign.total_raised = campaign
+        .total_raised
+        .checked_sub(contribution.amount)
+        .ok_or(CrowdfundingError::ArithmeticOverflow)?;
+    storage::write_campaign(env, &campaign);
+
+    let metrics_key = StorageKey::CampaignMetrics(campaign_id.clone());
+    let mut metrics: CampaignMetrics = env
+        .storage()
+        .instance()
+        .get(&metrics_key)
+        .unwrap_or_default();
+    metrics.total_raised = metrics
+        .total_raised
+        .checked_sub(contribution.amount)
+        .ok_or(CrowdfundingError::ArithmeticOverflow)?;
+    env.storage().instance().set(&metrics_key, &metrics);
+
+    env.storage().instance().set(
+        &contribution_key,
+        &Contribution {
+            amount: 0,
+            ..contribution.clone()
+        },
+    );
+
+    events::campaign_refunded(
+        env,
+        campaign_id,
+        contributor,
+        campaign.token_address,
+        contribution.amount,
+        env.ledger().timestamp(),
+    );
+    Ok(())
+}
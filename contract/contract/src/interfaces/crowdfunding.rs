@@ -2,10 +2,17 @@ use soroban_sdk::{Address, BytesN, Env, String, Vec};
 
 use crate::base::{
     errors::CrowdfundingError,
-    types::{CampaignDetails, PoolConfig, PoolMetadata, PoolState},
+    types::{
+        AcceptedToken, CampaignAmendmentRules, CampaignAmendments, CampaignDetails,
+        CampaignMetadata, ContributionQuote, CouncilAction, CouncilProposal, CreatorFeeTier,
+        EmergencyWithdrawal, FeaturePause, FeeEntity, FeeTier, MigrationCursor, MultiSigConfig,
+        PendingEntityFee, PendingParameters, PendingPlatformFee, PendingUpgrade, PoolConfig,
+        PoolMetadata, PoolState, Role,
+    },
 };
 
 pub trait CrowdfundingTrait {
+    #[allow(clippy::too_many_arguments)]
     fn create_campaign(
         env: Env,
         id: BytesN<32>,
@@ -14,6 +21,7 @@ pub trait CrowdfundingTrait {
         goal: i128,
         deadline: u64,
         token_address: Address,
+        max_fee: i128,
     ) -> Result<(), CrowdfundingError>;
 
     fn get_campaign(env: Env, id: BytesN<32>) -> Result<CampaignDetails, CrowdfundingError>;
@@ -44,10 +52,84 @@ pub trait CrowdfundingTrait {
         amount: i128,
     ) -> Result<(), CrowdfundingError>;
 
+    /// Stop a campaign before its deadline. The creator or a pool moderator
+    /// may cancel; donations are then refused and donors can call
+    /// `refund_campaign`.
+    fn cancel_campaign(
+        env: Env,
+        campaign_id: BytesN<32>,
+        caller: Address,
+        reason: String,
+    ) -> Result<(), CrowdfundingError>;
+
+    /// Available once the campaign is cancelled, or to donors whose last
+    /// donation predates a material amendment.
+    fn refund_campaign(
+        env: Env,
+        campaign_id: BytesN<32>,
+        contributor: Address,
+    ) -> Result<(), CrowdfundingError>;
+
+    /// Push the deadline back within the `CampaignAmendmentRules`, failing
+    /// with `InvalidDeadline` where they forbid it.
+    fn extend_campaign_deadline(
+        env: Env,
+        campaign_id: BytesN<32>,
+        new_deadline: u64,
+    ) -> Result<(), CrowdfundingError>;
+
+    /// Fails with `InvalidGoal` if `new_goal` is below the amount raised.
+    fn update_campaign_goal(
+        env: Env,
+        campaign_id: BytesN<32>,
+        new_goal: i128,
+    ) -> Result<(), CrowdfundingError>;
+
+    /// Until the first donation the title and metadata can be replaced;
+    /// afterwards the title is fixed and metadata may only be extended.
+    fn update_campaign_details(
+        env: Env,
+        campaign_id: BytesN<32>,
+        title: String,
+        metadata: CampaignMetadata,
+    ) -> Result<(), CrowdfundingError>;
+
+    fn get_campaign_amendments(
+        env: Env,
+        campaign_id: BytesN<32>,
+    ) -> Result<CampaignAmendments, CrowdfundingError>;
+
+    fn set_campaign_amendment_rules(
+        env: Env,
+        rules: CampaignAmendmentRules,
+    ) -> Result<(), CrowdfundingError>;
+
+    fn get_campaign_amendment_rules(env: Env) -> CampaignAmendmentRules;
+
+    /// Offer the campaign to `new_owner`, replacing any earlier offer; the
+    /// creator is unchanged until `new_owner` accepts. Fails with
+    /// `AlreadyExists` if `new_owner` is already the creator.
+    fn transfer_campaign_ownership(
+        env: Env,
+        campaign_id: BytesN<32>,
+        new_owner: Address,
+    ) -> Result<(), CrowdfundingError>;
+
+    fn accept_campaign_ownership(
+        env: Env,
+        campaign_id: BytesN<32>,
+    ) -> Result<(), CrowdfundingError>;
+
+    fn cancel_campaign_transfer(env: Env, campaign_id: BytesN<32>)
+        -> Result<(), CrowdfundingError>;
+
+    fn get_pending_campaign_owner(env: Env, campaign_id: BytesN<32>) -> Option<Address>;
+
     fn create_pool(
         env: Env,
         creator: Address,
         config: PoolConfig,
+        max_fee: i128,
     ) -> Result<u64, CrowdfundingError>;
 
     #[allow(clippy::too_many_arguments)]
@@ -60,26 +142,115 @@ pub trait CrowdfundingTrait {
         deadline: u64,
         required_signatures: Option<u32>,
         signers: Option<Vec<Address>>,
+        max_fee: i128,
     ) -> Result<u64, CrowdfundingError>;
 
     fn get_pool(env: Env, pool_id: u64) -> Option<PoolConfig>;
 
     fn get_pool_metadata(env: Env, pool_id: u64) -> (String, String, String);
 
+    /// `None` for pools created before creators were recorded.
+    fn get_pool_creator(env: Env, pool_id: u64) -> Option<Address>;
+
+    /// Offer the pool to `new_owner`, replacing any earlier offer. Fails with
+    /// `Unauthorized` for pools without a recorded creator and with
+    /// `AlreadyExists` if `new_owner` is already the creator.
+    fn transfer_pool_ownership(
+        env: Env,
+        pool_id: u64,
+        new_owner: Address,
+    ) -> Result<(), CrowdfundingError>;
+
+    fn accept_pool_ownership(env: Env, pool_id: u64) -> Result<(), CrowdfundingError>;
+
+    fn cancel_pool_transfer(env: Env, pool_id: u64) -> Result<(), CrowdfundingError>;
+
+    fn get_pending_pool_owner(env: Env, pool_id: u64) -> Option<Address>;
+
     fn update_pool_state(
         env: Env,
         pool_id: u64,
         new_state: PoolState,
     ) -> Result<(), CrowdfundingError>;
 
-    fn set_crowdfunding_token(env: Env, token: Address) -> Result<(), CrowdfundingError>;
+    fn set_crowdfunding_token(
+        env: Env,
+        caller: Address,
+        token: Address,
+    ) -> Result<(), CrowdfundingError>;
 
     fn get_crowdfunding_token(env: Env) -> Result<Address, CrowdfundingError>;
 
-    fn set_creation_fee(env: Env, fee: i128) -> Result<(), CrowdfundingError>;
+    fn set_creation_fee(env: Env, caller: Address, fee: i128) -> Result<(), CrowdfundingError>;
 
     fn get_creation_fee(env: Env) -> Result<i128, CrowdfundingError>;
 
+    fn get_pending_parameters(env: Env) -> Option<PendingParameters>;
+
+    fn set_entity_creation_fee(
+        env: Env,
+        caller: Address,
+        entity: FeeEntity,
+        fee: i128,
+    ) -> Result<(), CrowdfundingError>;
+
+    fn get_entity_creation_fee(env: Env, entity: FeeEntity) -> i128;
+
+    fn get_pending_entity_creation_fee(env: Env, entity: FeeEntity) -> Option<PendingEntityFee>;
+
+    fn set_platform_fee(env: Env, caller: Address, bps: u32) -> Result<(), CrowdfundingError>;
+
+    fn get_platform_fee(env: Env) -> u32;
+
+    fn get_pending_platform_fee(env: Env) -> Option<PendingPlatformFee>;
+
+    fn set_pool_platform_fee(
+        env: Env,
+        pool_id: u64,
+        bps: Option<u32>,
+    ) -> Result<(), CrowdfundingError>;
+
+    fn get_pool_platform_fee(env: Env, pool_id: u64) -> Result<u32, CrowdfundingError>;
+
+    fn quote_contribution(
+        env: Env,
+        pool_id: u64,
+        amount: i128,
+    ) -> Result<ContributionQuote, CrowdfundingError>;
+
+    fn assign_fee_tier(
+        env: Env,
+        caller: Address,
+        creator: Address,
+        tier: FeeTier,
+        expires_at: u64,
+    ) -> Result<(), CrowdfundingError>;
+
+    fn revoke_fee_tier(
+        env: Env,
+        caller: Address,
+        creator: Address,
+    ) -> Result<(), CrowdfundingError>;
+
+    fn get_fee_tier(env: Env, creator: Address) -> Option<CreatorFeeTier>;
+
+    fn set_accepted_token(
+        env: Env,
+        caller: Address,
+        token: Address,
+        min_amount: i128,
+    ) -> Result<(), CrowdfundingError>;
+
+    fn remove_accepted_token(
+        env: Env,
+        caller: Address,
+        token: Address,
+    ) -> Result<(), CrowdfundingError>;
+
+    fn get_accepted_token(env: Env, token: Address) -> Option<AcceptedToken>;
+
+    fn get_accepted_tokens(env: Env) -> Vec<Address>;
+
     fn initialize(
         env: Env,
         admin: Address,
@@ -87,12 +258,114 @@ pub trait CrowdfundingTrait {
         creation_fee: i128,
     ) -> Result<(), CrowdfundingError>;
 
-    fn pause(env: Env) -> Result<(), CrowdfundingError>;
+    fn pause(env: Env, caller: Address) -> Result<(), CrowdfundingError>;
 
-    fn unpause(env: Env) -> Result<(), CrowdfundingError>;
+    fn unpause(env: Env, caller: Address) -> Result<(), CrowdfundingError>;
 
     fn is_paused(env: Env) -> bool;
 
+    fn get_paused_at(env: Env) -> Option<u64>;
+
+    fn pause_features(
+        env: Env,
+        caller: Address,
+        features: u32,
+        reason: u32,
+        duration: u64,
+    ) -> Result<(), CrowdfundingError>;
+
+    fn unpause_features(env: Env, caller: Address, features: u32) -> Result<(), CrowdfundingError>;
+
+    fn get_paused_features(env: Env) -> u32;
+
+    fn get_feature_pause(env: Env, feature: u32) -> Option<FeaturePause>;
+
+    fn set_max_pause_duration(env: Env, duration: u64) -> Result<(), CrowdfundingError>;
+
+    fn get_max_pause_duration(env: Env) -> u64;
+
+    fn is_escape_hatch_open(env: Env) -> bool;
+
+    fn escape_pool_contribution(
+        env: Env,
+        pool_id: u64,
+        contributor: Address,
+    ) -> Result<(), CrowdfundingError>;
+
+    fn escape_campaign_contribution(
+        env: Env,
+        campaign_id: BytesN<32>,
+        contributor: Address,
+    ) -> Result<(), CrowdfundingError>;
+
+    fn get_admin(env: Env) -> Result<Address, CrowdfundingError>;
+
+    fn get_pending_admin(env: Env) -> Option<Address>;
+
+    fn propose_admin(env: Env, new_admin: Address) -> Result<(), CrowdfundingError>;
+
+    fn accept_admin(env: Env) -> Result<(), CrowdfundingError>;
+
+    fn cancel_admin_proposal(env: Env) -> Result<(), CrowdfundingError>;
+
+    fn grant_role(env: Env, role: Role, account: Address) -> Result<(), CrowdfundingError>;
+
+    fn revoke_role(env: Env, role: Role, account: Address) -> Result<(), CrowdfundingError>;
+
+    fn has_role(env: Env, role: Role, account: Address) -> bool;
+
+    fn get_roles(env: Env, account: Address) -> Vec<Role>;
+
+    fn set_council(env: Env, config: MultiSigConfig) -> Result<(), CrowdfundingError>;
+
+    fn get_council(env: Env) -> Option<MultiSigConfig>;
+
+    fn propose_council_action(
+        env: Env,
+        proposer: Address,
+        action: CouncilAction,
+    ) -> Result<u64, CrowdfundingError>;
+
+    fn approve_council_action(
+        env: Env,
+        signer: Address,
+        proposal_id: u64,
+    ) -> Result<(), CrowdfundingError>;
+
+    fn execute_council_action(
+        env: Env,
+        executor: Address,
+        proposal_id: u64,
+    ) -> Result<(), CrowdfundingError>;
+
+    fn get_council_proposal(env: Env, proposal_id: u64) -> Option<CouncilProposal>;
+
+    fn version(env: Env) -> u32;
+
+    fn set_upgrade_timelock(env: Env, delay: u64) -> Result<(), CrowdfundingError>;
+
+    fn get_upgrade_timelock(env: Env) -> u64;
+
+    fn propose_upgrade(env: Env, wasm_hash: BytesN<32>) -> Result<(), CrowdfundingError>;
+
+    fn execute_upgrade(env: Env) -> Result<(), CrowdfundingError>;
+
+    fn cancel_upgrade(env: Env) -> Result<(), CrowdfundingError>;
+
+    fn get_pending_upgrade(env: Env) -> Option<PendingUpgrade>;
+
+    fn get_schema_version(env: Env) -> u32;
+
+    fn get_migration_cursor(env: Env) -> Option<MigrationCursor>;
+
+    fn migrate(env: Env, batch_size: u32) -> Result<bool, CrowdfundingError>;
+
+    fn migrate_pool_contributions(
+        env: Env,
+        pool_id: u64,
+        contributors: Vec<Address>,
+    ) -> Result<(), CrowdfundingError>;
+
     fn contribute(
         env: Env,
         pool_id: u64,
@@ -106,11 +379,35 @@ pub trait CrowdfundingTrait {
 
     fn request_emergency_withdraw(
         env: Env,
+        caller: Address,
+        token: Address,
+        amount: i128,
+        recipient: Option<Address>,
+    ) -> Result<(), CrowdfundingError>;
+
+    fn execute_emergency_withdraw(env: Env, caller: Address) -> Result<(), CrowdfundingError>;
+
+    fn cancel_emergency_withdraw(env: Env, caller: Address) -> Result<(), CrowdfundingError>;
+
+    fn get_emergency_withdrawal(env: Env) -> Option<EmergencyWithdrawal>;
+
+    fn set_emergency_withdraw_delay(env: Env, delay: u64) -> Result<(), CrowdfundingError>;
+
+    fn get_emergency_withdraw_delay(env: Env) -> u64;
+
+    fn get_escrowed_balance(env: Env, token: Address) -> i128;
+
+    fn get_withdrawable_balance(env: Env, token: Address) -> i128;
+
+    fn withdraw_fees(
+        env: Env,
+        caller: Address,
         token: Address,
         amount: i128,
+        to: Address,
     ) -> Result<(), CrowdfundingError>;
 
-    fn execute_emergency_withdraw(env: Env) -> Result<(), CrowdfundingError>;
+    fn get_fee_balance(env: Env, token: Address) -> i128;
 
     fn close_pool(env: Env, pool_id: u64, caller: Address) -> Result<(), CrowdfundingError>;
 
@@ -0,0 +1,350 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, BytesN, Env, Event, String, Vec,
+};
+
+use crate::{
+    base::{
+        errors::CrowdfundingError,
+        events::{AcceptedTokenRemoved, AcceptedTokenSet, EVENT_SCHEMA_VERSION},
+        types::{
+            AcceptedToken, CouncilAction, MultiSigConfig, PoolConfig, Role, PARAMETER_CHANGE_DELAY,
+        },
+    },
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+    test::contract_events,
+};
+
+struct Setup<'a> {
+    env: &'a Env,
+    client: CrowdfundingContractClient<'a>,
+    admin: Address,
+    token: Address,
+}
+
+fn setup_test(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token = new_token(env);
+    client.initialize(&admin, &token, &0);
+
+    Setup {
+        env,
+        client,
+        admin,
+        token,
+    }
+}
+
+fn new_token(env: &Env) -> Address {
+    env.register_stellar_asset_contract_v2(Address::generate(env))
+        .address()
+}
+
+impl Setup<'_> {
+    fn metadata(&self, token: &Address, min_amount: i128) -> AcceptedToken {
+        let token_client = token::Client::new(self.env, token);
+        AcceptedToken {
+            symbol: token_client.symbol(),
+            decimals: token_client.decimals(),
+            min_amount,
+        }
+    }
+
+    fn funded(&self, token: &Address, amount: i128) -> Address {
+        let account = Address::generate(self.env);
+        token::StellarAssetClient::new(self.env, token).mint(&account, &amount);
+        account
+    }
+}
+
+#[test]
+fn test_initialize_registers_platform_token() {
+    let env = Env::default();
+    let s = setup_test(&env);
+
+    assert_eq!(s.client.get_accepted_tokens(), vec![&env, s.token.clone()]);
+    assert_eq!(
+        s.client.get_accepted_token(&s.token),
+        Some(s.metadata(&s.token, 0))
+    );
+
+    // An address that is not a token contract is refused
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+    assert_eq!(
+        client.try_initialize(&s.admin, &Address::generate(&env), &0),
+        Err(Ok(CrowdfundingError::InvalidToken))
+    );
+}
+
+#[test]
+fn test_set_and_remove_accepted_token() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    let usdc = new_token(&env);
+    let expected = s.metadata(&usdc, 50);
+
+    s.client.set_accepted_token(&s.admin, &usdc, &50);
+    let event = AcceptedTokenSet {
+        admin: s.admin.clone(),
+        token: usdc.clone(),
+        symbol: expected.symbol.clone(),
+        decimals: expected.decimals,
+        min_amount: 50,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        contract_events(&env, &s.client.address),
+        vec![
+            &env,
+            (
+                s.client.address.clone(),
+                event.topics(&env),
+                event.data(&env)
+            ),
+        ]
+    );
+    assert_eq!(s.client.get_accepted_token(&usdc), Some(expected));
+
+    // Updating the minimum keeps the registration order
+    let fee_manager = Address::generate(&env);
+    s.client.grant_role(&Role::FeeManager, &fee_manager);
+    s.client.set_accepted_token(&fee_manager, &usdc, &0);
+    assert_eq!(s.client.get_accepted_token(&usdc).unwrap().min_amount, 0);
+    assert_eq!(
+        s.client.get_accepted_tokens(),
+        vec![&env, s.token.clone(), usdc.clone()]
+    );
+
+    s.client.remove_accepted_token(&fee_manager, &usdc);
+    let event = AcceptedTokenRemoved {
+        admin: fee_manager.clone(),
+        token: usdc.clone(),
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        contract_events(&env, &s.client.address),
+        vec![
+            &env,
+            (
+                s.client.address.clone(),
+                event.topics(&env),
+                event.data(&env)
+            ),
+        ]
+    );
+    assert_eq!(s.client.get_accepted_token(&usdc), None);
+    assert_eq!(s.client.get_accepted_tokens(), vec![&env, s.token.clone()]);
+
+    // A token queued to become the platform token cannot be removed either
+    s.client.set_accepted_token(&s.admin, &usdc, &0);
+    s.client.set_crowdfunding_token(&s.admin, &usdc);
+    for token in [&s.token, &usdc] {
+        assert_eq!(
+            s.client.try_remove_accepted_token(&s.admin, token),
+            Err(Ok(CrowdfundingError::InvalidToken))
+        );
+    }
+}
+
+#[test]
+fn test_accepted_token_validation() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    let usdc = new_token(&env);
+
+    assert_eq!(
+        s.client.try_set_accepted_token(&s.admin, &usdc, &-1),
+        Err(Ok(CrowdfundingError::InvalidAmount))
+    );
+    assert_eq!(
+        s.client
+            .try_set_accepted_token(&s.admin, &Address::generate(&env), &0),
+        Err(Ok(CrowdfundingError::InvalidToken))
+    );
+    assert_eq!(
+        s.client.try_remove_accepted_token(&s.admin, &usdc),
+        Err(Ok(CrowdfundingError::NotFound))
+    );
+    assert_eq!(
+        s.client.try_set_crowdfunding_token(&s.admin, &usdc),
+        Err(Ok(CrowdfundingError::InvalidToken))
+    );
+
+    let treasury = Address::generate(&env);
+    s.client.grant_role(&Role::Treasury, &treasury);
+    assert_eq!(
+        s.client.try_set_accepted_token(&treasury, &usdc, &0),
+        Err(Ok(CrowdfundingError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_contributions_are_checked_against_registry() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    let usdc = new_token(&env);
+    let pool_id = s.client.create_pool(
+        &Address::generate(&env),
+        &PoolConfig {
+            name: String::from_str(&env, "Pool"),
+            description: String::from_str(&env, "Description"),
+            target_amount: 10_000,
+            is_private: false,
+            duration: 86_400,
+            created_at: 0,
+        },
+        &0,
+    );
+
+    let contributor = s.funded(&usdc, 100);
+    assert_eq!(
+        s.client
+            .try_contribute(&pool_id, &contributor, &usdc, &100, &false),
+        Err(Ok(CrowdfundingError::InvalidToken))
+    );
+    s.client.set_accepted_token(&s.admin, &usdc, &100);
+    assert_eq!(
+        s.client
+            .try_contribute(&pool_id, &contributor, &usdc, &99, &false),
+        Err(Ok(CrowdfundingError::InvalidAmount))
+    );
+    s.client
+        .contribute(&pool_id, &contributor, &usdc, &100, &false);
+
+    let campaign_id = BytesN::from_array(&env, &[1; 32]);
+    s.client.create_campaign(
+        &campaign_id,
+        &String::from_str(&env, "Campaign"),
+        &Address::generate(&env),
+        &10_000,
+        &86_400,
+        &s.token,
+        &0,
+    );
+    s.client.set_accepted_token(&s.admin, &s.token, &10);
+    let donor = s.funded(&s.token, 10);
+    assert_eq!(
+        s.client.try_donate(&campaign_id, &donor, &s.token, &9),
+        Err(Ok(CrowdfundingError::InvalidDonationAmount))
+    );
+    s.client.donate(&campaign_id, &donor, &s.token, &10);
+}
+
+#[test]
+fn test_pool_contributions_cannot_mix_assets() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    let usdc = new_token(&env);
+    s.client.set_accepted_token(&s.admin, &usdc, &0);
+    let config = PoolConfig {
+        name: String::from_str(&env, "Pool"),
+        description: String::from_str(&env, "Description"),
+        target_amount: 10_000,
+        is_private: false,
+        duration: 86_400,
+        created_at: 0,
+    };
+    let first_pool = s.client.create_pool(&Address::generate(&env), &config, &0);
+    let second_pool = s.client.create_pool(&Address::generate(&env), &config, &0);
+
+    let attacker = s.funded(&s.token, 1_000);
+    token::StellarAssetClient::new(&env, &usdc).mint(&attacker, &1);
+    s.client
+        .contribute(&first_pool, &attacker, &s.token, &1_000, &false);
+    assert_eq!(
+        s.client
+            .try_contribute(&first_pool, &attacker, &usdc, &1, &false),
+        Err(Ok(CrowdfundingError::InvalidToken))
+    );
+    assert_eq!(token::Client::new(&env, &usdc).balance(&attacker), 1);
+
+    let victim = s.funded(&usdc, 1_000);
+    s.client
+        .contribute(&second_pool, &victim, &usdc, &1_000, &false);
+
+    env.ledger().with_mut(|li| li.timestamp += 86_400 + 604_800);
+    s.client.refund(&first_pool, &attacker);
+    s.client.refund(&second_pool, &victim);
+    assert_eq!(token::Client::new(&env, &s.token).balance(&attacker), 1_000);
+    assert_eq!(token::Client::new(&env, &usdc).balance(&attacker), 1);
+    assert_eq!(token::Client::new(&env, &usdc).balance(&victim), 1_000);
+}
+
+#[test]
+fn test_council_manages_accepted_tokens() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    let usdc = new_token(&env);
+    let members = [Address::generate(&env), Address::generate(&env)];
+    s.client.set_council(&MultiSigConfig {
+        required_signatures: 1,
+        signers: Vec::from_slice(&env, &members),
+    });
+
+    assert_eq!(
+        s.client.try_set_accepted_token(&s.admin, &usdc, &0),
+        Err(Ok(CrowdfundingError::Unauthorized))
+    );
+    let proposal_id = s.client.propose_council_action(
+        &members[0],
+        &CouncilAction::SetAcceptedToken(usdc.clone(), 25),
+    );
+    s.client.execute_council_action(&members[0], &proposal_id);
+    assert_eq!(s.client.get_accepted_token(&usdc).unwrap().min_amount, 25);
+
+    let proposal_id = s.client.propose_council_action(
+        &members[1],
+        &CouncilAction::RemoveAcceptedToken(usdc.clone()),
+    );
+    s.client.execute_council_action(&members[1], &proposal_id);
+    assert_eq!(s.client.get_accepted_token(&usdc), None);
+}
+
+#[test]
+fn test_campaigns_raise_in_selected_token() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    let usdc = new_token(&env);
+    s.client.set_creation_fee(&s.admin, &10);
+    env.ledger().set_timestamp(PARAMETER_CHANGE_DELAY);
+
+    let creator = s.funded(&s.token, 10);
+    let campaign_id = BytesN::from_array(&env, &[1; 32]);
+    let create = |token: &Address| {
+        s.client.try_create_campaign(
+            &campaign_id,
+            &String::from_str(&env, "Campaign"),
+            &creator,
+            &10_000,
+            &(PARAMETER_CHANGE_DELAY + 86_400),
+            token,
+            &10,
+        )
+    };
+    assert_eq!(create(&usdc), Err(Ok(CrowdfundingError::InvalidToken)));
+
+    // The creation fee is still paid in the platform token
+    s.client.set_accepted_token(&s.admin, &usdc, &0);
+    create(&usdc).unwrap().unwrap();
+    assert_eq!(s.client.get_campaign(&campaign_id).token_address, usdc);
+    assert_eq!(s.client.get_fee_balance(&s.token), 10);
+
+    let donor = s.funded(&usdc, 500);
+    assert_eq!(
+        s.client.try_donate(&campaign_id, &donor, &s.token, &500),
+        Err(Ok(CrowdfundingError::InvalidToken))
+    );
+    s.client.donate(&campaign_id, &donor, &usdc, &500);
+    assert_eq!(s.client.get_campaign(&campaign_id).total_raised, 500);
+    assert_eq!(
+        token::Client::new(&env, &usdc).balance(&s.client.address),
+        500
+    );
+}
@@ -0,0 +1,177 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Events as _, MockAuth, MockAuthInvoke},
+    vec, Address, Env, Event,
+};
+
+use crate::{
+    base::{
+        errors::CrowdfundingError,
+        events::{AdminProposalCancelled, AdminProposed, AdminTransferred, EVENT_SCHEMA_VERSION},
+    },
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+
+fn setup_test(env: &Env) -> (CrowdfundingContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token_contract = env.register_stellar_asset_contract_v2(token_admin.clone());
+    client.initialize(&admin, &token_contract.address(), &0);
+
+    (client, admin)
+}
+
+/// Assert that the last invocation emitted exactly `event`.
+fn assert_emitted(env: &Env, client: &CrowdfundingContractClient, event: &impl Event) {
+    assert_eq!(
+        env.events().all(),
+        vec![
+            env,
+            (client.address.clone(), event.topics(env), event.data(env))
+        ]
+    );
+}
+
+#[test]
+fn test_admin_transfer_two_steps() {
+    let env = Env::default();
+    let (client, admin) = setup_test(&env);
+    let new_admin = Address::generate(&env);
+
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.get_pending_admin(), None);
+
+    client.propose_admin(&new_admin);
+    assert_emitted(
+        &env,
+        &client,
+        &AdminProposed {
+            admin: admin.clone(),
+            proposed_admin: new_admin.clone(),
+            version: EVENT_SCHEMA_VERSION,
+        },
+    );
+    // Proposing does not hand over control yet
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+
+    client.accept_admin();
+    assert_eq!(env.auths()[0].0, new_admin);
+    assert_emitted(
+        &env,
+        &client,
+        &AdminTransferred {
+            new_admin: new_admin.clone(),
+            previous_admin: admin,
+            version: EVENT_SCHEMA_VERSION,
+        },
+    );
+    assert_eq!(client.get_admin(), new_admin);
+    assert_eq!(client.get_pending_admin(), None);
+
+    // The new admin now authorizes admin actions
+    client.pause(&new_admin);
+    assert_eq!(env.auths()[0].0, new_admin);
+}
+
+#[test]
+fn test_accept_admin_requires_new_admin_auth() {
+    let env = Env::default();
+    let (client, admin) = setup_test(&env);
+    let new_admin = Address::generate(&env);
+    client.propose_admin(&new_admin);
+
+    let result = client
+        .mock_auths(&[MockAuth {
+            address: &admin,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "accept_admin",
+                args: vec![&env],
+                sub_invokes: &[],
+            },
+        }])
+        .try_accept_admin();
+    assert!(result.is_err());
+    assert_eq!(client.get_admin(), admin);
+}
+
+#[test]
+fn test_accept_admin_without_proposal() {
+    let env = Env::default();
+    let (client, _) = setup_test(&env);
+
+    let result = client.try_accept_admin();
+    assert_eq!(result, Err(Ok(CrowdfundingError::NotFound)));
+}
+
+#[test]
+fn test_new_proposal_replaces_previous() {
+    let env = Env::default();
+    let (client, _) = setup_test(&env);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+
+    client.propose_admin(&first);
+    client.propose_admin(&second);
+    client.accept_admin();
+
+    assert_eq!(env.auths()[0].0, second);
+    assert_eq!(client.get_admin(), second);
+}
+
+#[test]
+fn test_cancel_admin_proposal() {
+    let env = Env::default();
+    let (client, admin) = setup_test(&env);
+    let new_admin = Address::generate(&env);
+
+    client.propose_admin(&new_admin);
+    client.cancel_admin_proposal();
+    assert_emitted(
+        &env,
+        &client,
+        &AdminProposalCancelled {
+            admin: admin.clone(),
+            proposed_admin: new_admin,
+            version: EVENT_SCHEMA_VERSION,
+        },
+    );
+    assert_eq!(client.get_pending_admin(), None);
+
+    assert_eq!(
+        client.try_accept_admin(),
+        Err(Ok(CrowdfundingError::NotFound))
+    );
+    assert_eq!(
+        client.try_cancel_admin_proposal(),
+        Err(Ok(CrowdfundingError::NotFound))
+    );
+    assert_eq!(client.get_admin(), admin);
+}
+
+#[test]
+fn test_admin_transfer_uninitialized() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    assert_eq!(
+        client.try_get_admin(),
+        Err(Ok(CrowdfundingError::NotInitialized))
+    );
+    assert_eq!(
+        client.try_propose_admin(&Address::generate(&env)),
+        Err(Ok(CrowdfundingError::NotInitialized))
+    );
+    assert_eq!(
+        client.try_accept_admin(),
+        Err(Ok(CrowdfundingError::NotInitialized))
+    );
+}
@@ -0,0 +1,308 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, BytesN, Env, Event, String,
+};
+
+use crate::{
+    base::{
+        errors::CrowdfundingError,
+        events::{
+            CampaignAmended, CampaignAmendmentRulesSet, CampaignGoalReached, EVENT_SCHEMA_VERSION,
+        },
+        types::{
+            CampaignAmendmentRules, CampaignAmendments, StorageKey, DEFAULT_MAX_CAMPAIGN_EXTENSION,
+        },
+    },
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+    test::contract_events,
+};
+
+const DEADLINE: u64 = 86_400;
+const GOAL: i128 = 10_000;
+
+struct Setup<'a> {
+    env: &'a Env,
+    client: CrowdfundingContractClient<'a>,
+    admin: Address,
+    token: Address,
+    campaign_id: BytesN<32>,
+}
+
+fn setup_test(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    client.initialize(&admin, &token, &0);
+
+    let campaign_id = BytesN::from_array(env, &[1; 32]);
+    client.create_campaign(
+        &campaign_id,
+        &String::from_str(env, "Campaign"),
+        &Address::generate(env),
+        &GOAL,
+        &DEADLINE,
+        &token,
+        &0,
+    );
+
+    Setup {
+        env,
+        client,
+        admin,
+        token,
+        campaign_id,
+    }
+}
+
+impl Setup<'_> {
+    fn donate(&self, donor: &Address, amount: i128) {
+        token::StellarAssetClient::new(self.env, &self.token).mint(donor, &amount);
+        self.client
+            .donate(&self.campaign_id, donor, &self.token, &amount);
+    }
+
+    fn donor(&self, amount: i128) -> Address {
+        let donor = Address::generate(self.env);
+        self.donate(&donor, amount);
+        donor
+    }
+}
+
+#[test]
+fn test_extension_lets_earlier_donors_refund() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    let early = s.donor(300);
+
+    s.client
+        .extend_campaign_deadline(&s.campaign_id, &(DEADLINE + 3_600));
+    let event = CampaignAmended {
+        campaign_id: s.campaign_id.clone(),
+        goal: GOAL,
+        deadline: DEADLINE + 3_600,
+        revision: 1,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        contract_events(&env, &s.client.address),
+        vec![
+            &env,
+            (
+                s.client.address.clone(),
+                event.topics(&env),
+                event.data(&env)
+            ),
+        ]
+    );
+    assert_eq!(
+        s.client.get_campaign(&s.campaign_id).deadline,
+        DEADLINE + 3_600
+    );
+    assert_eq!(
+        s.client.get_campaign_amendments(&s.campaign_id),
+        CampaignAmendments {
+            extensions: 1,
+            extended_by: 3_600,
+            revision: 1,
+        }
+    );
+
+    // By default a campaign is extended only once
+    assert_eq!(
+        s.client
+            .try_extend_campaign_deadline(&s.campaign_id, &(DEADLINE + 7_200)),
+        Err(Ok(CrowdfundingError::InvalidDeadline))
+    );
+
+    // Donors who gave under the new terms accepted them
+    let late = s.donor(200);
+    let key = StorageKey::DonationRevision(s.campaign_id.clone(), late.clone());
+    env.as_contract(&s.client.address, || {
+        assert!(!env.storage().instance().has(&key));
+        assert_eq!(env.storage().persistent().get(&key), Some(1u32));
+    });
+    assert_eq!(
+        s.client.try_refund_campaign(&s.campaign_id, &late),
+        Err(Ok(CrowdfundingError::RefundNotAvailable))
+    );
+    s.client.refund_campaign(&s.campaign_id, &early);
+    assert_eq!(token::Client::new(&env, &s.token).balance(&early), 300);
+    assert_eq!(s.client.get_total_raised(&s.campaign_id), 200);
+}
+
+#[test]
+fn test_configured_extension_rules() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    assert_eq!(
+        s.client.get_campaign_amendment_rules(),
+        CampaignAmendmentRules {
+            max_extension: DEFAULT_MAX_CAMPAIGN_EXTENSION,
+            single_extension: true,
+            material_goal_change_bps: 1_000,
+        }
+    );
+
+    let rules = CampaignAmendmentRules {
+        max_extension: 1_000,
+        single_extension: false,
+        material_goal_change_bps: 0,
+    };
+    s.client.set_campaign_amendment_rules(&rules);
+    assert_eq!(env.auths()[0].0, s.admin);
+    let event = CampaignAmendmentRulesSet {
+        admin: s.admin.clone(),
+        max_extension: 1_000,
+        single_extension: false,
+        material_goal_change_bps: 0,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        contract_events(&env, &s.client.address),
+        vec![
+            &env,
+            (
+                s.client.address.clone(),
+                event.topics(&env),
+                event.data(&env)
+            ),
+        ]
+    );
+    assert_eq!(s.client.get_campaign_amendment_rules(), rules);
+
+    s.client
+        .extend_campaign_deadline(&s.campaign_id, &(DEADLINE + 600));
+    assert_eq!(
+        s.client
+            .try_extend_campaign_deadline(&s.campaign_id, &(DEADLINE + 1_001)),
+        Err(Ok(CrowdfundingError::InvalidDeadline))
+    );
+    assert_eq!(
+        s.client
+            .try_extend_campaign_deadline(&s.campaign_id, &(DEADLINE + 600)),
+        Err(Ok(CrowdfundingError::InvalidDeadline))
+    );
+    s.client
+        .extend_campaign_deadline(&s.campaign_id, &(DEADLINE + 1_000));
+    assert_eq!(
+        s.client.get_campaign_amendments(&s.campaign_id),
+        CampaignAmendments {
+            extensions: 2,
+            extended_by: 1_000,
+            revision: 2,
+        }
+    );
+
+    assert_eq!(
+        s.client
+            .try_set_campaign_amendment_rules(&CampaignAmendmentRules {
+                material_goal_change_bps: 10_001,
+                ..rules
+            }),
+        Err(Ok(CrowdfundingError::InvalidAmount))
+    );
+}
+
+#[test]
+fn test_goal_changes() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    let donor = s.donor(4_000);
+
+    assert_eq!(
+        s.client.try_update_campaign_goal(&s.campaign_id, &3_999),
+        Err(Ok(CrowdfundingError::InvalidGoal))
+    );
+    assert_eq!(
+        s.client.try_update_campaign_goal(&s.campaign_id, &GOAL),
+        Err(Ok(CrowdfundingError::InvalidGoal))
+    );
+
+    // A change under 10% of the goal is not material
+    s.client.update_campaign_goal(&s.campaign_id, &(GOAL + 999));
+    assert_eq!(s.client.get_campaign_amendments(&s.campaign_id).revision, 0);
+    assert_eq!(
+        s.client.try_refund_campaign(&s.campaign_id, &donor),
+        Err(Ok(CrowdfundingError::RefundNotAvailable))
+    );
+
+    // Lowering the goal to the amount raised completes the campaign
+    s.client.update_campaign_goal(&s.campaign_id, &4_000);
+    let amended = CampaignAmended {
+        campaign_id: s.campaign_id.clone(),
+        goal: 4_000,
+        deadline: DEADLINE,
+        revision: 1,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    let reached = CampaignGoalReached {
+        campaign_id: s.campaign_id.clone(),
+        total_raised: 4_000,
+        timestamp: 0,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        contract_events(&env, &s.client.address),
+        vec![
+            &env,
+            (
+                s.client.address.clone(),
+                amended.topics(&env),
+                amended.data(&env)
+            ),
+            (
+                s.client.address.clone(),
+                reached.topics(&env),
+                reached.data(&env)
+            ),
+        ]
+    );
+    assert!(s.client.is_campaign_completed(&s.campaign_id));
+    s.client.refund_campaign(&s.campaign_id, &donor);
+}
+
+#[test]
+fn test_amendments_require_open_campaign() {
+    let env = Env::default();
+    let s = setup_test(&env);
+
+    assert_eq!(
+        s.client
+            .try_extend_campaign_deadline(&s.campaign_id, &DEADLINE),
+        Err(Ok(CrowdfundingError::InvalidDeadline))
+    );
+    assert_eq!(
+        s.client
+            .try_get_campaign_amendments(&BytesN::from_array(&env, &[2; 32])),
+        Err(Ok(CrowdfundingError::CampaignNotFound))
+    );
+
+    env.ledger().set_timestamp(DEADLINE);
+    assert_eq!(
+        s.client
+            .try_extend_campaign_deadline(&s.campaign_id, &(DEADLINE + 60)),
+        Err(Ok(CrowdfundingError::CampaignExpired))
+    );
+    assert_eq!(
+        s.client
+            .try_update_campaign_goal(&s.campaign_id, &(GOAL * 2)),
+        Err(Ok(CrowdfundingError::CampaignExpired))
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_amendments_require_creator_auth() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    env.mock_auths(&[]);
+
+    s.client.update_campaign_goal(&s.campaign_id, &(GOAL * 2));
+}
@@ -0,0 +1,212 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, BytesN, Env, Event, String,
+};
+
+use crate::{
+    base::{
+        errors::CrowdfundingError,
+        events::{CampaignCancelled, CampaignRefunded, EVENT_SCHEMA_VERSION},
+        types::{CampaignStatus, Role, MAX_DESCRIPTION_LENGTH, PAUSE_REFUNDS},
+    },
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+    test::contract_events,
+};
+
+const DEADLINE: u64 = 86_400;
+
+struct Setup<'a> {
+    env: &'a Env,
+    client: CrowdfundingContractClient<'a>,
+    admin: Address,
+    token: Address,
+    creator: Address,
+    campaign_id: BytesN<32>,
+}
+
+fn setup_test(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    client.initialize(&admin, &token, &0);
+
+    let creator = Address::generate(env);
+    let campaign_id = BytesN::from_array(env, &[1; 32]);
+    client.create_campaign(
+        &campaign_id,
+        &String::from_str(env, "Campaign"),
+        &creator,
+        &10_000,
+        &DEADLINE,
+        &token,
+        &0,
+    );
+
+    Setup {
+        env,
+        client,
+        admin,
+        token,
+        creator,
+        campaign_id,
+    }
+}
+
+impl Setup<'_> {
+    fn donor(&self, amount: i128) -> Address {
+        let donor = Address::generate(self.env);
+        token::StellarAssetClient::new(self.env, &self.token).mint(&donor, &amount);
+        self.client
+            .donate(&self.campaign_id, &donor, &self.token, &amount);
+        donor
+    }
+
+    fn reason(&self) -> String {
+        String::from_str(self.env, "Venue fell through")
+    }
+}
+
+#[test]
+fn test_creator_cancels_and_donors_are_refunded() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    let donor = s.donor(600);
+
+    s.client
+        .cancel_campaign(&s.campaign_id, &s.creator, &s.reason());
+    let event = CampaignCancelled {
+        campaign_id: s.campaign_id.clone(),
+        caller: s.creator.clone(),
+        reason: s.reason(),
+        timestamp: 0,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        contract_events(&env, &s.client.address),
+        vec![
+            &env,
+            (
+                s.client.address.clone(),
+                event.topics(&env),
+                event.data(&env)
+            ),
+        ]
+    );
+    assert_eq!(
+        s.client.get_campaign(&s.campaign_id).status,
+        CampaignStatus::Cancelled
+    );
+
+    let late_donor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &s.token).mint(&late_donor, &100);
+    assert_eq!(
+        s.client
+            .try_donate(&s.campaign_id, &late_donor, &s.token, &100),
+        Err(Ok(CrowdfundingError::CampaignExpired))
+    );
+
+    // Refunds open immediately, without waiting for the deadline
+    s.client.refund_campaign(&s.campaign_id, &donor);
+    let event = CampaignRefunded {
+        campaign_id: s.campaign_id.clone(),
+        contributor: donor.clone(),
+        asset: s.token.clone(),
+        amount: 600,
+        timestamp: 0,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        contract_events(&env, &s.client.address),
+        vec![
+            &env,
+            (
+                s.client.address.clone(),
+                event.topics(&env),
+                event.data(&env)
+            ),
+        ]
+    );
+    assert_eq!(token::Client::new(&env, &s.token).balance(&donor), 600);
+    assert_eq!(s.client.get_total_raised(&s.campaign_id), 0);
+    assert_eq!(s.client.get_escrowed_balance(&s.token), 0);
+    assert_eq!(
+        s.client.try_refund_campaign(&s.campaign_id, &donor),
+        Err(Ok(CrowdfundingError::NoContributionToRefund))
+    );
+}
+
+#[test]
+fn test_moderator_can_cancel() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    let moderator = Address::generate(&env);
+    s.client.grant_role(&Role::PoolModerator, &moderator);
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        s.client
+            .try_cancel_campaign(&s.campaign_id, &stranger, &s.reason()),
+        Err(Ok(CrowdfundingError::Unauthorized))
+    );
+
+    s.client
+        .cancel_campaign(&s.campaign_id, &moderator, &s.reason());
+    assert_eq!(
+        s.client
+            .try_cancel_campaign(&s.campaign_id, &s.admin, &s.reason()),
+        Err(Ok(CrowdfundingError::CampaignExpired))
+    );
+}
+
+#[test]
+fn test_cancel_validation() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    let donor = s.donor(100);
+
+    assert_eq!(
+        s.client.try_refund_campaign(&s.campaign_id, &donor),
+        Err(Ok(CrowdfundingError::RefundNotAvailable))
+    );
+    let reason = "x".repeat((MAX_DESCRIPTION_LENGTH + 1) as usize);
+    assert_eq!(
+        s.client
+            .try_cancel_campaign(&s.campaign_id, &s.creator, &String::from_str(&env, &reason)),
+        Err(Ok(CrowdfundingError::InvalidMetadata))
+    );
+    assert_eq!(
+        s.client
+            .try_cancel_campaign(&BytesN::from_array(&env, &[2; 32]), &s.creator, &s.reason()),
+        Err(Ok(CrowdfundingError::CampaignNotFound))
+    );
+
+    // A refund pause also holds back cancellation refunds
+    s.client
+        .cancel_campaign(&s.campaign_id, &s.creator, &s.reason());
+    s.client
+        .pause_features(&s.admin, &PAUSE_REFUNDS, &0, &3_600);
+    assert_eq!(
+        s.client.try_refund_campaign(&s.campaign_id, &donor),
+        Err(Ok(CrowdfundingError::ContractPaused))
+    );
+}
+
+#[test]
+fn test_cannot_cancel_after_deadline() {
+    let env = Env::default();
+    let s = setup_test(&env);
+
+    env.ledger().set_timestamp(DEADLINE);
+    assert_eq!(
+        s.client
+            .try_cancel_campaign(&s.campaign_id, &s.creator, &s.reason()),
+        Err(Ok(CrowdfundingError::CampaignExpired))
+    );
+}
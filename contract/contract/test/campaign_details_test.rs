@@ -0,0 +1,223 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, BytesN, Env, Event, String,
+};
+
+use crate::{
+    base::{
+        errors::CrowdfundingError,
+        events::{CampaignDetailsUpdated, EVENT_SCHEMA_VERSION},
+        types::{CampaignMetadata, MAX_URL_LENGTH},
+    },
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+    test::contract_events,
+};
+
+const DEADLINE: u64 = 86_400;
+
+struct Setup<'a> {
+    env: &'a Env,
+    client: CrowdfundingContractClient<'a>,
+    token: Address,
+    campaign_id: BytesN<32>,
+}
+
+fn setup_test(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    client.initialize(&admin, &token, &0);
+
+    let campaign_id = BytesN::from_array(env, &[1; 32]);
+    client.create_campaign(
+        &campaign_id,
+        &String::from_str(env, "Campaign"),
+        &Address::generate(env),
+        &10_000,
+        &DEADLINE,
+        &token,
+        &0,
+    );
+
+    Setup {
+        env,
+        client,
+        token,
+        campaign_id,
+    }
+}
+
+impl Setup<'_> {
+    fn donate(&self, amount: i128) {
+        let donor = Address::generate(self.env);
+        token::StellarAssetClient::new(self.env, &self.token).mint(&donor, &amount);
+        self.client
+            .donate(&self.campaign_id, &donor, &self.token, &amount);
+    }
+
+    fn title(&self, title: &str) -> String {
+        String::from_str(self.env, title)
+    }
+
+    fn metadata(
+        &self,
+        description: &str,
+        external_url: &str,
+        image_hash: &str,
+    ) -> CampaignMetadata {
+        CampaignMetadata {
+            description: String::from_str(self.env, description),
+            external_url: String::from_str(self.env, external_url),
+            image_hash: String::from_str(self.env, image_hash),
+        }
+    }
+}
+
+#[test]
+fn test_details_editable_before_first_donation() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    assert_eq!(
+        s.client.get_campaign(&s.campaign_id).metadata,
+        CampaignMetadata::empty(&env)
+    );
+
+    let metadata = s.metadata("Wells for the valley", "https://wells.org", "QmWells");
+    s.client
+        .update_campaign_details(&s.campaign_id, &s.title("Clean water"), &metadata);
+    let event = CampaignDetailsUpdated {
+        campaign_id: s.campaign_id.clone(),
+        title: s.title("Clean water"),
+        description: metadata.description.clone(),
+        external_url: metadata.external_url.clone(),
+        image_hash: metadata.image_hash.clone(),
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        contract_events(&env, &s.client.address),
+        vec![
+            &env,
+            (
+                s.client.address.clone(),
+                event.topics(&env),
+                event.data(&env)
+            ),
+        ]
+    );
+    let campaign = s.client.get_campaign(&s.campaign_id);
+    assert_eq!(campaign.title, s.title("Clean water"));
+    assert_eq!(campaign.metadata, metadata);
+
+    // Anything may still be replaced, including clearing fields
+    let metadata = s.metadata("Wells", "", "");
+    s.client
+        .update_campaign_details(&s.campaign_id, &s.title("Water"), &metadata);
+    assert_eq!(s.client.get_campaign(&s.campaign_id).metadata, metadata);
+}
+
+#[test]
+fn test_details_only_extended_after_first_donation() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    let metadata = s.metadata("Wells for the valley.", "", "QmWells");
+    s.client
+        .update_campaign_details(&s.campaign_id, &s.title("Clean water"), &metadata);
+    s.donate(100);
+
+    assert_eq!(
+        s.client
+            .try_update_campaign_details(&s.campaign_id, &s.title("Dirty water"), &metadata),
+        Err(Ok(CrowdfundingError::InvalidTitle))
+    );
+    for rewrite in [
+        s.metadata("Wells for the hills.", "", "QmWells"),
+        s.metadata("Wells for the valley.", "", "QmOther"),
+        s.metadata("Wells for the valley.", "", ""),
+    ] {
+        assert_eq!(
+            s.client
+                .try_update_campaign_details(&s.campaign_id, &s.title("Clean water"), &rewrite),
+            Err(Ok(CrowdfundingError::InvalidMetadata))
+        );
+    }
+
+    // Appending to the description and filling an unset URL are allowed
+    let extended = s.metadata(
+        "Wells for the valley. Update: drilling starts in May.",
+        "https://wells.org",
+        "QmWells",
+    );
+    s.client
+        .update_campaign_details(&s.campaign_id, &s.title("Clean water"), &extended);
+    assert_eq!(s.client.get_campaign(&s.campaign_id).metadata, extended);
+    assert_eq!(
+        s.client.try_update_campaign_details(
+            &s.campaign_id,
+            &s.title("Clean water"),
+            &s.metadata(
+                "Wells for the valley. Update: drilling starts in May.",
+                "https://other.org",
+                "QmWells",
+            ),
+        ),
+        Err(Ok(CrowdfundingError::InvalidMetadata))
+    );
+}
+
+#[test]
+fn test_details_validation() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    let metadata = s.metadata("Description", "", "");
+
+    assert_eq!(
+        s.client
+            .try_update_campaign_details(&s.campaign_id, &s.title(""), &metadata),
+        Err(Ok(CrowdfundingError::InvalidTitle))
+    );
+    let url = "x".repeat((MAX_URL_LENGTH + 1) as usize);
+    assert_eq!(
+        s.client.try_update_campaign_details(
+            &s.campaign_id,
+            &s.title("Campaign"),
+            &s.metadata("Description", &url, ""),
+        ),
+        Err(Ok(CrowdfundingError::InvalidMetadata))
+    );
+    assert_eq!(
+        s.client.try_update_campaign_details(
+            &BytesN::from_array(&env, &[2; 32]),
+            &s.title("Campaign"),
+            &metadata,
+        ),
+        Err(Ok(CrowdfundingError::CampaignNotFound))
+    );
+
+    env.ledger().set_timestamp(DEADLINE);
+    assert_eq!(
+        s.client
+            .try_update_campaign_details(&s.campaign_id, &s.title("Campaign"), &metadata),
+        Err(Ok(CrowdfundingError::CampaignExpired))
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_details_require_creator_auth() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    env.mock_auths(&[]);
+
+    s.client.update_campaign_details(
+        &s.campaign_id,
+        &s.title("Campaign"),
+        &s.metadata("Description", "", ""),
+    );
+}
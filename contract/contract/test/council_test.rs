@@ -0,0 +1,373 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Events as _, Ledger},
+    token, vec, Address, BytesN, Env, Event, Vec,
+};
+
+use crate::{
+    base::{
+        errors::CrowdfundingError,
+        events::{
+            CouncilActionApproved, CouncilActionExecuted, CouncilActionProposed, CouncilSet,
+            CreationFeeQueued, EVENT_SCHEMA_VERSION,
+        },
+        types::{
+            CouncilAction, CouncilProposal, MultiSigConfig, COUNCIL_PROPOSAL_TTL,
+            DEFAULT_EMERGENCY_WITHDRAW_DELAY, PARAMETER_CHANGE_DELAY,
+        },
+    },
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+
+struct Setup<'a> {
+    client: CrowdfundingContractClient<'a>,
+    admin: Address,
+    token: Address,
+    members: [Address; 3],
+}
+
+/// Initialize and install a 2-of-3 council.
+fn setup_test(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin.clone())
+        .address();
+    client.initialize(&admin, &token, &0);
+
+    let members = [
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env),
+    ];
+    client.set_council(&council(env, 2, &members));
+
+    Setup {
+        client,
+        admin,
+        token,
+        members,
+    }
+}
+
+fn council(env: &Env, required_signatures: u32, members: &[Address]) -> MultiSigConfig {
+    MultiSigConfig {
+        required_signatures,
+        signers: Vec::from_slice(env, members),
+    }
+}
+
+/// Propose `action` from the first member and approve it with the second.
+fn approved(s: &Setup, action: &CouncilAction) -> u64 {
+    let proposal_id = s.client.propose_council_action(&s.members[0], action);
+    s.client.approve_council_action(&s.members[1], &proposal_id);
+    proposal_id
+}
+
+#[test]
+fn test_set_council() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.initialize(&admin, &token, &0);
+    let members = [Address::generate(&env), Address::generate(&env)];
+
+    assert_eq!(client.get_council(), None);
+    for config in [
+        council(&env, 0, &members),
+        council(&env, 3, &members),
+        council(&env, 1, &[]),
+    ] {
+        assert_eq!(
+            client.try_set_council(&config),
+            Err(Ok(CrowdfundingError::InvalidMultiSigConfig))
+        );
+    }
+    assert_eq!(
+        client.try_set_council(&council(&env, 1, &[members[0].clone(), members[0].clone()])),
+        Err(Ok(CrowdfundingError::SignerAlreadyExists))
+    );
+
+    let config = council(&env, 2, &members);
+    client.set_council(&config);
+    assert_eq!(env.auths()[0].0, admin);
+    let event = CouncilSet {
+        admin: admin.clone(),
+        required_signatures: 2,
+        signers: config.signers.clone(),
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (client.address.clone(), event.topics(&env), event.data(&env))
+        ]
+    );
+    assert_eq!(client.get_council(), Some(config.clone()));
+
+    // Further changes need the council's approval
+    assert_eq!(
+        client.try_set_council(&config),
+        Err(Ok(CrowdfundingError::AlreadyExists))
+    );
+}
+
+#[test]
+fn test_council_blocks_direct_privileged_calls() {
+    let env = Env::default();
+    let s = setup_test(&env);
+
+    assert_eq!(
+        s.client.try_set_creation_fee(&s.admin, &10),
+        Err(Ok(CrowdfundingError::Unauthorized))
+    );
+    assert_eq!(
+        s.client.try_set_crowdfunding_token(&s.admin, &s.token),
+        Err(Ok(CrowdfundingError::Unauthorized))
+    );
+    assert_eq!(
+        s.client
+            .try_request_emergency_withdraw(&s.admin, &s.token, &1, &None),
+        Err(Ok(CrowdfundingError::Unauthorized))
+    );
+    assert_eq!(
+        s.client
+            .try_propose_upgrade(&BytesN::from_array(&env, &[1; 32])),
+        Err(Ok(CrowdfundingError::Unauthorized))
+    );
+    assert_eq!(
+        s.client.try_cancel_upgrade(),
+        Err(Ok(CrowdfundingError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_council_action_needs_threshold_approvals() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    env.ledger().set_timestamp(100);
+
+    let action = CouncilAction::SetCreationFee(50);
+    let proposal_id = s.client.propose_council_action(&s.members[0], &action);
+    assert_eq!(proposal_id, 1);
+    assert_eq!(env.auths()[0].0, s.members[0]);
+    let event = CouncilActionProposed {
+        proposal_id,
+        proposer: s.members[0].clone(),
+        action: action.clone(),
+        expires_at: 100 + COUNCIL_PROPOSAL_TTL,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                s.client.address.clone(),
+                event.topics(&env),
+                event.data(&env)
+            )
+        ]
+    );
+
+    assert_eq!(
+        s.client
+            .try_execute_council_action(&s.members[0], &proposal_id),
+        Err(Ok(CrowdfundingError::InsufficientApprovals))
+    );
+    assert_eq!(
+        s.client
+            .try_approve_council_action(&s.members[0], &proposal_id),
+        Err(Ok(CrowdfundingError::AlreadyApproved))
+    );
+
+    s.client.approve_council_action(&s.members[1], &proposal_id);
+    let event = CouncilActionApproved {
+        proposal_id,
+        signer: s.members[1].clone(),
+        approvals: 2,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                s.client.address.clone(),
+                event.topics(&env),
+                event.data(&env)
+            )
+        ]
+    );
+
+    // Any member can execute once the threshold is met
+    s.client.execute_council_action(&s.members[2], &proposal_id);
+    let fee_queued = CreationFeeQueued {
+        admin: s.members[2].clone(),
+        fee: 50,
+        effective_at: 100 + PARAMETER_CHANGE_DELAY,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    let executed = CouncilActionExecuted {
+        proposal_id,
+        executor: s.members[2].clone(),
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                s.client.address.clone(),
+                fee_queued.topics(&env),
+                fee_queued.data(&env)
+            ),
+            (
+                s.client.address.clone(),
+                executed.topics(&env),
+                executed.data(&env)
+            ),
+        ]
+    );
+    env.ledger().set_timestamp(100 + PARAMETER_CHANGE_DELAY);
+    assert_eq!(s.client.get_creation_fee(), 50);
+
+    // Executed proposals are removed
+    assert_eq!(s.client.get_council_proposal(&proposal_id), None);
+    assert_eq!(
+        s.client
+            .try_execute_council_action(&s.members[0], &proposal_id),
+        Err(Ok(CrowdfundingError::NotFound))
+    );
+}
+
+#[test]
+fn test_only_members_take_part() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    let outsider = Address::generate(&env);
+
+    assert_eq!(
+        s.client
+            .try_propose_council_action(&outsider, &CouncilAction::CancelUpgrade),
+        Err(Ok(CrowdfundingError::NotAuthorizedSigner))
+    );
+    let proposal_id = s
+        .client
+        .propose_council_action(&s.members[0], &CouncilAction::CancelUpgrade);
+    assert_eq!(
+        s.client.try_approve_council_action(&outsider, &proposal_id),
+        Err(Ok(CrowdfundingError::NotAuthorizedSigner))
+    );
+    assert_eq!(
+        s.client
+            .try_approve_council_action(&s.members[1], &(proposal_id + 1)),
+        Err(Ok(CrowdfundingError::NotFound))
+    );
+}
+
+#[test]
+fn test_council_proposal_expires() {
+    let env = Env::default();
+    let s = setup_test(&env);
+
+    let proposal_id = approved(&s, &CouncilAction::SetCreationFee(5));
+    env.ledger()
+        .with_mut(|li| li.timestamp += COUNCIL_PROPOSAL_TTL);
+    assert_eq!(
+        s.client
+            .try_execute_council_action(&s.members[0], &proposal_id),
+        Err(Ok(CrowdfundingError::NotFound))
+    );
+    assert_eq!(s.client.get_creation_fee(), 0);
+
+    // Making the next proposal removes the expired ones
+    let open = s
+        .client
+        .propose_council_action(&s.members[0], &CouncilAction::CancelUpgrade);
+    assert_eq!(s.client.get_council_proposal(&proposal_id), None);
+    assert_eq!(
+        s.client.get_council_proposal(&open),
+        Some(CouncilProposal {
+            action: CouncilAction::CancelUpgrade,
+            approvals: Vec::from_array(&env, [s.members[0].clone()]),
+            created_at: env.ledger().timestamp(),
+            expires_at: env.ledger().timestamp() + COUNCIL_PROPOSAL_TTL,
+            executed: false,
+        })
+    );
+}
+
+#[test]
+fn test_council_emergency_withdrawal() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    token::StellarAssetClient::new(&env, &s.token).mint(&s.client.address, &300);
+    let recipient = Address::generate(&env);
+
+    let request = CouncilAction::RequestEmergencyWithdraw(s.token.clone(), 300, recipient.clone());
+    let proposal_id = approved(&s, &request);
+    s.client.execute_council_action(&s.members[0], &proposal_id);
+    assert_eq!(
+        s.client.get_emergency_withdrawal().unwrap().recipient,
+        recipient
+    );
+
+    env.ledger()
+        .with_mut(|li| li.timestamp += DEFAULT_EMERGENCY_WITHDRAW_DELAY);
+    let proposal_id = approved(&s, &CouncilAction::ExecuteEmergencyWithdraw);
+    s.client.execute_council_action(&s.members[0], &proposal_id);
+    assert_eq!(token::Client::new(&env, &s.token).balance(&recipient), 300);
+    assert_eq!(s.client.get_emergency_withdrawal(), None);
+}
+
+#[test]
+fn test_council_changes_and_disables_itself() {
+    let env = Env::default();
+    let s = setup_test(&env);
+
+    // A proposal approved by a member who is then removed falls short
+    let pending = approved(&s, &CouncilAction::SetCreationFee(7));
+    let newcomer = Address::generate(&env);
+    let reduced = council(
+        &env,
+        2,
+        &[s.members[0].clone(), s.members[2].clone(), newcomer.clone()],
+    );
+    let proposal_id = approved(&s, &CouncilAction::SetCouncil(reduced.clone()));
+    s.client.execute_council_action(&s.members[2], &proposal_id);
+    assert_eq!(s.client.get_council(), Some(reduced));
+    assert_eq!(
+        s.client.try_execute_council_action(&s.members[0], &pending),
+        Err(Ok(CrowdfundingError::InsufficientApprovals))
+    );
+
+    let proposal_id = s
+        .client
+        .propose_council_action(&newcomer, &CouncilAction::DisableCouncil);
+    s.client.approve_council_action(&s.members[0], &proposal_id);
+    s.client.execute_council_action(&newcomer, &proposal_id);
+    assert_eq!(s.client.get_council(), None);
+
+    s.client.set_creation_fee(&s.admin, &10);
+    assert_eq!(
+        s.client.get_pending_parameters().unwrap().creation_fee,
+        Some(10)
+    );
+    assert_eq!(
+        s.client
+            .try_propose_council_action(&newcomer, &CouncilAction::CancelUpgrade),
+        Err(Ok(CrowdfundingError::NotFound))
+    );
+}
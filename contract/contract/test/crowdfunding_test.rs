@@ -19,7 +19,7 @@ fn create_test_campaign_id(env: &Env, seed: u8) -> BytesN<32> {
     BytesN::from_array(env, &bytes)
 }
 
-fn setup_test(env: &Env) -> (CrowdfundingContractClient, Address, Address) {
+fn setup_test(env: &Env) -> (CrowdfundingContractClient<'_>, Address, Address) {
     env.mock_all_auths();
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(env, &contract_id);
@@ -38,40 +38,53 @@ fn setup_test(env: &Env) -> (CrowdfundingContractClient, Address, Address) {
 #[test]
 fn test_create_campaign() {
     let env = Env::default();
-    let (client, _, token_address) = setup_test(&env);
+    let (client, admin, _) = setup_test(&env);
 
-    let admin = Address::generate(&env);
+    // Campaigns raise in the token they choose, if it is accepted
     let token_id = env
-        .register_stellar_asset_contract_v2(admin.clone())
+        .register_stellar_asset_contract_v2(Address::generate(&env))
         .address();
+    client.set_accepted_token(&admin, &token_id, &0);
     let creator = Address::generate(&env);
     let campaign_id = create_test_campaign_id(&env, 1);
     let title = String::from_str(&env, "Save the Whales");
     let goal = 1_000_000i128;
     let deadline = env.ledger().timestamp() + 86400;
 
-    client.create_campaign(&campaign_id, &title, &creator, &goal, &deadline, &token_id);
+    client.create_campaign(
+        &campaign_id,
+        &title,
+        &creator,
+        &goal,
+        &deadline,
+        &token_id,
+        &0,
+    );
 
     let campaign = client.get_campaign(&campaign_id);
-    assert_eq!(campaign.token_address, token_address);
+    assert_eq!(campaign.token_address, token_id);
 }
 
 #[test]
 fn test_get_campaign() {
     let env = Env::default();
-    let (client, _, _) = setup_test(&env);
+    let (client, _, token_id) = setup_test(&env);
 
-    let admin = Address::generate(&env);
-    let token_id = env
-        .register_stellar_asset_contract_v2(admin.clone())
-        .address();
     let creator = Address::generate(&env);
     let campaign_id = create_test_campaign_id(&env, 2);
     let title = String::from_str(&env, "Build a School");
     let goal = 500_000i128;
     let deadline = env.ledger().timestamp() + 172800;
 
-    client.create_campaign(&campaign_id, &title, &creator, &goal, &deadline, &token_id);
+    client.create_campaign(
+        &campaign_id,
+        &title,
+        &creator,
+        &goal,
+        &deadline,
+        &token_id,
+        &0,
+    );
 
     let campaign = client.get_campaign(&campaign_id);
 
@@ -109,8 +122,15 @@ fn test_create_campaign_with_empty_title() {
     let goal = 100_000i128;
     let deadline = env.ledger().timestamp() + 86400;
 
-    let result =
-        client.try_create_campaign(&campaign_id, &title, &creator, &goal, &deadline, &token_id);
+    let result = client.try_create_campaign(
+        &campaign_id,
+        &title,
+        &creator,
+        &goal,
+        &deadline,
+        &token_id,
+        &0,
+    );
 
     assert_eq!(result, Err(Ok(CrowdfundingError::InvalidTitle)));
 }
@@ -130,8 +150,15 @@ fn test_create_campaign_with_zero_goal() {
     let goal = 0i128;
     let deadline = env.ledger().timestamp() + 86400;
 
-    let result =
-        client.try_create_campaign(&campaign_id, &title, &creator, &goal, &deadline, &token_id);
+    let result = client.try_create_campaign(
+        &campaign_id,
+        &title,
+        &creator,
+        &goal,
+        &deadline,
+        &token_id,
+        &0,
+    );
 
     assert_eq!(result, Err(Ok(CrowdfundingError::InvalidGoal)));
 }
@@ -151,8 +178,15 @@ fn test_create_campaign_with_negative_goal() {
     let goal = -100i128;
     let deadline = env.ledger().timestamp() + 86400;
 
-    let result =
-        client.try_create_campaign(&campaign_id, &title, &creator, &goal, &deadline, &token_id);
+    let result = client.try_create_campaign(
+        &campaign_id,
+        &title,
+        &creator,
+        &goal,
+        &deadline,
+        &token_id,
+        &0,
+    );
 
     assert_eq!(result, Err(Ok(CrowdfundingError::InvalidGoal)));
 }
@@ -176,6 +210,7 @@ fn test_create_campaign_with_past_deadline() {
         &goal,
         &deadline,
         &token_address,
+        &0,
     );
 
     assert_eq!(result, Err(Ok(CrowdfundingError::InvalidDeadline)));
@@ -184,22 +219,33 @@ fn test_create_campaign_with_past_deadline() {
 #[test]
 fn test_create_duplicate_campaign() {
     let env = Env::default();
-    let (client, _, _) = setup_test(&env);
+    let (client, _, token_id) = setup_test(&env);
 
-    let admin = Address::generate(&env);
-    let token_id = env
-        .register_stellar_asset_contract_v2(admin.clone())
-        .address();
     let creator = Address::generate(&env);
     let campaign_id = create_test_campaign_id(&env, 7);
     let title = String::from_str(&env, "Duplicate Campaign");
     let goal = 100_000i128;
     let deadline = env.ledger().timestamp() + 86400;
 
-    client.create_campaign(&campaign_id, &title, &creator, &goal, &deadline, &token_id);
+    client.create_campaign(
+        &campaign_id,
+        &title,
+        &creator,
+        &goal,
+        &deadline,
+        &token_id,
+        &0,
+    );
 
-    let result2 =
-        client.try_create_campaign(&campaign_id, &title, &creator, &goal, &deadline, &token_id);
+    let result2 = client.try_create_campaign(
+        &campaign_id,
+        &title,
+        &creator,
+        &goal,
+        &deadline,
+        &token_id,
+        &0,
+    );
 
     assert_eq!(result2, Err(Ok(CrowdfundingError::CampaignAlreadyExists)));
 }
@@ -223,8 +269,15 @@ fn test_create_campaign_uninitialized() {
         .register_stellar_asset_contract_v2(admin.clone())
         .address();
 
-    let result =
-        client.try_create_campaign(&campaign_id, &title, &creator, &goal, &deadline, &token_id);
+    let result = client.try_create_campaign(
+        &campaign_id,
+        &title,
+        &creator,
+        &goal,
+        &deadline,
+        &token_id,
+        &0,
+    );
     assert_eq!(result, Err(Ok(CrowdfundingError::NotInitialized)));
 }
 
@@ -255,6 +308,7 @@ fn test_multiple_campaigns() {
         &goal1,
         &deadline1,
         &token_address,
+        &0,
     );
     client.create_campaign(
         &campaign_id_2,
@@ -263,6 +317,7 @@ fn test_multiple_campaigns() {
         &goal2,
         &deadline2,
         &token_address,
+        &0,
     );
 
     let campaign1 = client.get_campaign(&campaign_id_1);
@@ -303,6 +358,7 @@ fn test_save_pool() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &0,
     );
 
     assert_eq!(pool_id, 1);
@@ -336,6 +392,7 @@ fn test_save_pool_validation() {
         &deadline,
         &None,
         &None,
+        &0,
     );
     assert_eq!(result, Err(Ok(CrowdfundingError::InvalidPoolName)));
 
@@ -349,6 +406,7 @@ fn test_save_pool_validation() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &0,
     );
     assert_eq!(result, Err(Ok(CrowdfundingError::InvalidPoolTarget)));
 
@@ -362,6 +420,7 @@ fn test_save_pool_validation() {
         &past_deadline,
         &None,
         &None,
+        &0,
     );
     assert_eq!(result, Err(Ok(CrowdfundingError::InvalidPoolDeadline)));
 }
@@ -392,6 +451,7 @@ fn test_get_pool() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &0,
     );
 
     let pool = client.get_pool(&pool_id).unwrap();
@@ -461,6 +521,7 @@ fn test_update_pool_state() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &0,
     );
 
     // Update state to Paused
@@ -508,6 +569,7 @@ fn test_update_pool_state_invalid_transition() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &0,
     );
 
     // First complete the pool
@@ -549,6 +611,7 @@ fn test_multiple_pools() {
         &deadline1,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &0,
     );
 
     // Create second pool
@@ -568,6 +631,7 @@ fn test_multiple_pools() {
         &deadline2,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &0,
     );
 
     assert_eq!(pool_id1, 1);
@@ -605,11 +669,11 @@ fn test_pause_unpause_full_cycle() {
     assert!(!client.is_paused());
 
     // Pause
-    client.pause();
+    client.pause(&admin);
     assert!(client.is_paused());
 
     // Unpause
-    client.unpause();
+    client.unpause(&admin);
     assert!(!client.is_paused());
 }
 
@@ -631,11 +695,11 @@ fn test_admin_auth_for_pause() {
             invoke: &soroban_sdk::testutils::MockAuthInvoke {
                 contract: &contract_id,
                 fn_name: "pause",
-                args: soroban_sdk::vec![&env],
+                args: soroban_sdk::vec![&env, admin.into_val(&env)],
                 sub_invokes: &[],
             },
         }])
-        .pause();
+        .pause(&admin);
     assert!(client.is_paused());
 }
 
@@ -660,11 +724,11 @@ fn test_non_admin_cannot_pause() {
             invoke: &soroban_sdk::testutils::MockAuthInvoke {
                 contract: &contract_id,
                 fn_name: "pause",
-                args: soroban_sdk::vec![&env],
+                args: soroban_sdk::vec![&env, non_admin.into_val(&env)],
                 sub_invokes: &[],
             },
         }])
-        .pause();
+        .pause(&non_admin);
 }
 
 #[test]
@@ -680,7 +744,7 @@ fn test_operations_disabled_when_paused() {
     let token_contract = env.register_stellar_asset_contract_v2(token_admin.clone());
     client.initialize(&admin, &token_contract.address(), &0);
 
-    client.pause();
+    client.pause(&admin);
 
     // Try create campaign - should fail
     let admin = Address::generate(&env);
@@ -694,7 +758,7 @@ fn test_operations_disabled_when_paused() {
     let deadline = env.ledger().timestamp() + 10000;
 
     let result =
-        client.try_create_campaign(&camp_id, &title, &creator, &goal, &deadline, &token_id);
+        client.try_create_campaign(&camp_id, &title, &creator, &goal, &deadline, &token_id, &0);
     assert_eq!(result, Err(Ok(CrowdfundingError::ContractPaused)));
 
     // Try save pool - should fail
@@ -715,6 +779,7 @@ fn test_operations_disabled_when_paused() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &0,
     );
     assert_eq!(result_pool, Err(Ok(CrowdfundingError::ContractPaused)));
 }
@@ -751,17 +816,18 @@ fn test_update_pool_state_blocked_when_paused() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &0,
     );
 
     // Now pause the contract
-    client.pause();
+    client.pause(&admin);
 
     // Try to update pool state - should fail
     let result = client.try_update_pool_state(&pool_id, &PoolState::Paused);
     assert_eq!(result, Err(Ok(CrowdfundingError::ContractPaused)));
 
     // Unpause and verify it works
-    client.unpause();
+    client.unpause(&admin);
     client.update_pool_state(&pool_id, &PoolState::Paused);
 }
 
@@ -779,10 +845,7 @@ fn test_getters_work_when_paused() {
     client.initialize(&admin, &token_contract.address(), &0);
 
     // Create a campaign before pausing
-    let admin = Address::generate(&env);
-    let token_id = env
-        .register_stellar_asset_contract_v2(admin.clone())
-        .address();
+    let token_id = token_contract.address();
     let creator = Address::generate(&env);
     let camp_id = create_test_campaign_id(&env, 11);
     client.create_campaign(
@@ -792,9 +855,10 @@ fn test_getters_work_when_paused() {
         &1000i128,
         &(env.ledger().timestamp() + 10000),
         &token_id,
+        &0,
     );
 
-    client.pause();
+    client.pause(&admin);
 
     // Getters should still work
     let campaign = client.get_campaign(&camp_id);
@@ -815,8 +879,8 @@ fn test_cannot_pause_already_paused() {
     let token_contract = env.register_stellar_asset_contract_v2(token_admin.clone());
     client.initialize(&admin, &token_contract.address(), &0);
 
-    client.pause();
-    let result = client.try_pause();
+    client.pause(&admin);
+    let result = client.try_pause(&admin);
     assert_eq!(result, Err(Ok(CrowdfundingError::ContractAlreadyPaused)));
 }
 
@@ -833,7 +897,7 @@ fn test_cannot_unpause_already_unpaused() {
     let token_contract = env.register_stellar_asset_contract_v2(token_admin.clone());
     client.initialize(&admin, &token_contract.address(), &0);
 
-    let result = client.try_unpause();
+    let result = client.try_unpause(&admin);
     assert_eq!(result, Err(Ok(CrowdfundingError::ContractAlreadyUnpaused)));
 }
 
@@ -850,13 +914,10 @@ fn test_operations_enabled_after_unpause() {
     let token_contract = env.register_stellar_asset_contract_v2(token_admin.clone());
     client.initialize(&admin, &token_contract.address(), &0);
 
-    client.pause();
-    client.unpause();
+    client.pause(&admin);
+    client.unpause(&admin);
 
-    let admin = Address::generate(&env);
-    let token_id = env
-        .register_stellar_asset_contract_v2(admin.clone())
-        .address();
+    let token_id = token_contract.address();
     let creator = Address::generate(&env);
     let camp_id = create_test_campaign_id(&env, 12);
     let title = String::from_str(&env, "After Unpause");
@@ -867,6 +928,7 @@ fn test_operations_enabled_after_unpause() {
         &1000i128,
         &(env.ledger().timestamp() + 10000),
         &token_id,
+        &0,
     );
 
     let campaign = client.get_campaign(&camp_id);
@@ -886,6 +948,7 @@ fn test_contribute_and_event_emission() {
 
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_id.address(), &0);
 
     let creator = Address::generate(&env);
     let contributor = Address::generate(&env);
@@ -906,6 +969,7 @@ fn test_contribute_and_event_emission() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &0,
     );
 
     // Advance ledger time
@@ -927,22 +991,75 @@ fn test_contribute_and_event_emission() {
     // but the snapshot recorder will capture the events if they are emitted.)
 }
 
+#[test]
+fn test_contribute_overflow_returns_error() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(&env);
+    let token1 = env.register_stellar_asset_contract_v2(token_admin.clone());
+    let token2 = env.register_stellar_asset_contract_v2(token_admin.clone());
+    let token1_admin_client = soroban_sdk::token::StellarAssetClient::new(&env, &token1.address());
+    let token2_admin_client = soroban_sdk::token::StellarAssetClient::new(&env, &token2.address());
+
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+    client.initialize(&token_admin, &token1.address(), &0);
+    client.set_accepted_token(&token_admin, &token2.address(), &0);
+
+    let creator = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let metadata = PoolMetadata {
+        description: String::from_str(&env, "Overflow pool"),
+        external_url: String::from_str(&env, ""),
+        image_hash: String::from_str(&env, ""),
+    };
+    let pool_id = client.save_pool(
+        &String::from_str(&env, "Overflow Pool"),
+        &metadata,
+        &creator,
+        &10_000i128,
+        &(env.ledger().timestamp() + 86400),
+        &None::<u32>,
+        &None::<Vec<Address>>,
+        &0,
+    );
+
+    let other_contributor = Address::generate(&env);
+    token1_admin_client.mint(&contributor, &i128::MAX);
+    token2_admin_client.mint(&other_contributor, &1i128);
+
+    client.contribute(
+        &pool_id,
+        &contributor,
+        &token1.address(),
+        &i128::MAX,
+        &false,
+    );
+
+    // Pool totals are tracked across assets, so a second asset pushes them past i128::MAX
+    let result = client.try_contribute(
+        &pool_id,
+        &other_contributor,
+        &token2.address(),
+        &1i128,
+        &false,
+    );
+    assert_eq!(result, Err(Ok(CrowdfundingError::ArithmeticOverflow)));
+}
+
 // Getter & Donation Tests
 
 #[test]
 fn test_get_all_campaigns() {
     let env = Env::default();
-    let (client, _, _) = setup_test(&env);
+    let (client, _, token_id) = setup_test(&env);
 
     // 1. Returns empty list when no campaigns exist
     let campaigns = client.get_all_campaigns();
     assert_eq!(campaigns.len(), 0);
 
     // 2. Returns all campaign IDs after multiple campaigns created
-    let admin = Address::generate(&env);
-    let token_id = env
-        .register_stellar_asset_contract_v2(admin.clone())
-        .address();
     let creator = Address::generate(&env);
     let id1 = create_test_campaign_id(&env, 101);
     let id2 = create_test_campaign_id(&env, 102);
@@ -954,6 +1071,7 @@ fn test_get_all_campaigns() {
         &1000i128,
         &(env.ledger().timestamp() + 100),
         &token_id,
+        &0,
     );
     client.create_campaign(
         &id2,
@@ -962,6 +1080,7 @@ fn test_get_all_campaigns() {
         &1000i128,
         &(env.ledger().timestamp() + 100),
         &token_id,
+        &0,
     );
 
     let campaigns = client.get_all_campaigns();
@@ -986,6 +1105,7 @@ fn test_donate_and_donor_count() {
         &10000i128,
         &(env.ledger().timestamp() + 1000),
         &token_address,
+        &0,
     );
 
     // 1. Returns 0 for campaign with no donors
@@ -1019,12 +1139,8 @@ fn test_donate_and_donor_count() {
 #[test]
 fn test_get_campaign_goal() {
     let env = Env::default();
-    let (client, _, _) = setup_test(&env);
+    let (client, _, token_id) = setup_test(&env);
 
-    let admin = Address::generate(&env);
-    let token_id = env
-        .register_stellar_asset_contract_v2(admin.clone())
-        .address();
     let creator = Address::generate(&env);
     let id = create_test_campaign_id(&env, 104);
     let goal = 5555i128;
@@ -1036,6 +1152,7 @@ fn test_get_campaign_goal() {
         &goal,
         &(env.ledger().timestamp() + 100),
         &token_id,
+        &0,
     );
 
     assert_eq!(client.get_campaign_goal(&id), goal);
@@ -1059,6 +1176,7 @@ fn test_is_campaign_completed() {
         &goal,
         &(env.ledger().timestamp() + 1000),
         &token_address,
+        &0,
     );
 
     // 1. Returns false for new campaign
@@ -1098,6 +1216,7 @@ fn test_donate_deadline_passed() {
         &1000i128,
         &deadline,
         &token_address,
+        &0,
     );
 
     let donor = Address::generate(&env);
@@ -1142,7 +1261,15 @@ fn test_successful_donation() {
     let goal = 10_000i128;
     let deadline = env.ledger().timestamp() + 86400;
 
-    client.create_campaign(&campaign_id, &title, &creator, &goal, &deadline, &token_id);
+    client.create_campaign(
+        &campaign_id,
+        &title,
+        &creator,
+        &goal,
+        &deadline,
+        &token_id,
+        &0,
+    );
 
     // Setup donor
     let donor = Address::generate(&env);
@@ -1191,7 +1318,15 @@ fn test_multiple_donations_same_campaign() {
     let goal = 10_000i128;
     let deadline = env.ledger().timestamp() + 86400;
 
-    client.create_campaign(&campaign_id, &title, &creator, &goal, &deadline, &token_id);
+    client.create_campaign(
+        &campaign_id,
+        &title,
+        &creator,
+        &goal,
+        &deadline,
+        &token_id,
+        &0,
+    );
 
     // Setup donors
     let donor1 = Address::generate(&env);
@@ -1241,7 +1376,15 @@ fn test_donation_updates_total_raised() {
     let goal = 10_000i128;
     let deadline = env.ledger().timestamp() + 86400;
 
-    client.create_campaign(&campaign_id, &title, &creator, &goal, &deadline, &token_id);
+    client.create_campaign(
+        &campaign_id,
+        &title,
+        &creator,
+        &goal,
+        &deadline,
+        &token_id,
+        &0,
+    );
 
     let donor = Address::generate(&env);
     token_admin_client.mint(&donor, &10_000i128);
@@ -1287,7 +1430,15 @@ fn test_contribution_tracked_per_user() {
     let goal = 10_000i128;
     let deadline = env.ledger().timestamp() + 86400;
 
-    client.create_campaign(&campaign_id, &title, &creator, &goal, &deadline, &token_id);
+    client.create_campaign(
+        &campaign_id,
+        &title,
+        &creator,
+        &goal,
+        &deadline,
+        &token_id,
+        &0,
+    );
 
     // Setup multiple donors
     let donor1 = Address::generate(&env);
@@ -1367,7 +1518,15 @@ fn test_donate_after_deadline() {
     let goal = 10_000i128;
     let deadline = 2000u64;
 
-    client.create_campaign(&campaign_id, &title, &creator, &goal, &deadline, &token_id);
+    client.create_campaign(
+        &campaign_id,
+        &title,
+        &creator,
+        &goal,
+        &deadline,
+        &token_id,
+        &0,
+    );
 
     let donor = Address::generate(&env);
     token_admin_client.mint(&donor, &5_000i128);
@@ -1404,7 +1563,15 @@ fn test_donate_zero_amount() {
     let goal = 10_000i128;
     let deadline = env.ledger().timestamp() + 86400;
 
-    client.create_campaign(&campaign_id, &title, &creator, &goal, &deadline, &token_id);
+    client.create_campaign(
+        &campaign_id,
+        &title,
+        &creator,
+        &goal,
+        &deadline,
+        &token_id,
+        &0,
+    );
 
     let donor = Address::generate(&env);
 
@@ -1442,7 +1609,15 @@ fn test_donate_insufficient_balance() {
     let goal = 10_000i128;
     let deadline = env.ledger().timestamp() + 86400;
 
-    client.create_campaign(&campaign_id, &title, &creator, &goal, &deadline, &token_id);
+    client.create_campaign(
+        &campaign_id,
+        &title,
+        &creator,
+        &goal,
+        &deadline,
+        &token_id,
+        &0,
+    );
 
     let donor = Address::generate(&env);
     // Mint only 100 tokens
@@ -1480,7 +1655,15 @@ fn test_donate_campaign_already_funded() {
     let goal = 1_000i128;
     let deadline = env.ledger().timestamp() + 86400;
 
-    client.create_campaign(&campaign_id, &title, &creator, &goal, &deadline, &token_id);
+    client.create_campaign(
+        &campaign_id,
+        &title,
+        &creator,
+        &goal,
+        &deadline,
+        &token_id,
+        &0,
+    );
 
     let donor = Address::generate(&env);
     token_admin_client.mint(&donor, &5_000i128);
@@ -1490,7 +1673,7 @@ fn test_donate_campaign_already_funded() {
 
     // Verify campaign is completed
     assert_eq!(client.get_total_raised(&campaign_id), goal);
-    assert_eq!(client.is_campaign_completed(&campaign_id), true);
+    assert!(client.is_campaign_completed(&campaign_id));
 
     // Try to donate again - should fail
     let result = client.try_donate(&campaign_id, &donor, &token_id, &100i128);
@@ -1526,14 +1709,22 @@ fn test_donate_wrong_token() {
     let goal = 10_000i128;
     let deadline = env.ledger().timestamp() + 86400;
 
-    client.create_campaign(&campaign_id, &title, &creator, &goal, &deadline, &token1_id);
+    client.create_campaign(
+        &campaign_id,
+        &title,
+        &creator,
+        &goal,
+        &deadline,
+        &token1_id,
+        &0,
+    );
 
     let donor = Address::generate(&env);
     token_admin_client.mint(&donor, &5_000i128);
 
     // Try to donate with wrong token - should fail
     let result = client.try_donate(&campaign_id, &donor, &token2_id, &100i128);
-    assert_eq!(result, Err(Ok(CrowdfundingError::TokenTransferFailed)));
+    assert_eq!(result, Err(Ok(CrowdfundingError::InvalidToken)));
 }
 
 #[test]
@@ -1560,13 +1751,22 @@ fn test_configuration_functions() {
     // Update token
     let new_token_contract = env.register_stellar_asset_contract_v2(token_admin.clone());
     let new_token = new_token_contract.address();
-    client.set_crowdfunding_token(&new_token);
-    assert_eq!(client.get_crowdfunding_token(), new_token);
+    client.set_accepted_token(&admin, &new_token, &0);
+    client.set_crowdfunding_token(&admin, &new_token);
+    assert_eq!(client.get_crowdfunding_token(), token);
 
-    // Update fee
+    // Update fee; both changes wait out the delay together
     let new_fee = 200i128;
-    client.set_creation_fee(&new_fee);
+    client.set_creation_fee(&admin, &new_fee);
+    assert_eq!(client.get_creation_fee(), fee);
+    let pending = client.get_pending_parameters().unwrap();
+    assert_eq!(pending.creation_fee, Some(new_fee));
+    assert_eq!(pending.crowdfunding_token, Some(new_token.clone()));
+
+    env.ledger().set_timestamp(pending.effective_at);
+    assert_eq!(client.get_crowdfunding_token(), new_token);
     assert_eq!(client.get_creation_fee(), new_fee);
+    assert_eq!(client.get_pending_parameters(), None);
 
     // Test auth
     let non_admin = Address::generate(&env);
@@ -1575,7 +1775,7 @@ fn test_configuration_functions() {
         invoke: &MockAuthInvoke {
             contract: &contract_id,
             fn_name: "set_creation_fee",
-            args: soroban_sdk::vec![&env, new_fee.into_val(&env)],
+            args: soroban_sdk::vec![&env, non_admin.into_val(&env), new_fee.into_val(&env)],
             sub_invokes: &[],
         },
     }]);
@@ -1611,7 +1811,15 @@ fn test_create_campaign_with_fee() {
     let goal = 10_000i128;
     let deadline = env.ledger().timestamp() + 86400;
 
-    client.create_campaign(&campaign_id, &title, &creator, &goal, &deadline, &token);
+    client.create_campaign(
+        &campaign_id,
+        &title,
+        &creator,
+        &goal,
+        &deadline,
+        &token,
+        &fee,
+    );
 
     // Fee deduction
     assert_eq!(token_client.balance(&creator), 10_000 - fee);
@@ -1633,6 +1841,7 @@ fn test_refund_after_deadline_and_grace_period() {
 
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_id.address(), &0);
 
     // Create pool with deadline
     let creator = Address::generate(&env);
@@ -1656,6 +1865,7 @@ fn test_refund_after_deadline_and_grace_period() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &0,
     );
 
     // Mint tokens to contributor
@@ -1701,6 +1911,7 @@ fn test_refund_partial_contribution() {
 
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_id.address(), &0);
 
     // Create pool
     let creator = Address::generate(&env);
@@ -1724,6 +1935,7 @@ fn test_refund_partial_contribution() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &0,
     );
 
     // Mint tokens
@@ -1780,6 +1992,7 @@ fn test_refund_fails_before_deadline() {
 
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_id.address(), &0);
 
     let creator = Address::generate(&env);
     let contributor = Address::generate(&env);
@@ -1802,6 +2015,7 @@ fn test_refund_fails_before_deadline() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &0,
     );
 
     token_admin_client.mint(&contributor, &5_000i128);
@@ -1829,6 +2043,7 @@ fn test_refund_fails_before_grace_period() {
 
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_id.address(), &0);
 
     let creator = Address::generate(&env);
     let contributor = Address::generate(&env);
@@ -1851,6 +2066,7 @@ fn test_refund_fails_before_grace_period() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &0,
     );
 
     token_admin_client.mint(&contributor, &5_000i128);
@@ -1886,6 +2102,7 @@ fn test_refund_fails_if_disbursed() {
 
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_id.address(), &0);
 
     let creator = Address::generate(&env);
     let contributor = Address::generate(&env);
@@ -1908,6 +2125,7 @@ fn test_refund_fails_if_disbursed() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &0,
     );
 
     token_admin_client.mint(&contributor, &5_000i128);
@@ -1956,8 +2174,15 @@ fn test_create_campaign_insufficient_balance() {
     let goal = 10_000i128;
     let deadline = env.ledger().timestamp() + 86400;
 
-    let result =
-        client.try_create_campaign(&campaign_id, &title, &creator, &goal, &deadline, &token);
+    let result = client.try_create_campaign(
+        &campaign_id,
+        &title,
+        &creator,
+        &goal,
+        &deadline,
+        &token,
+        &fee,
+    );
     assert_eq!(result, Err(Ok(CrowdfundingError::InsufficientBalance)));
 }
 
@@ -1990,6 +2215,7 @@ fn test_refund_fails_no_contribution() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &0,
     );
 
     // Advance time past deadline + grace period
@@ -2014,6 +2240,7 @@ fn test_multiple_contributors_refund_independently() {
 
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_id.address(), &0);
 
     let creator = Address::generate(&env);
     let contributor1 = Address::generate(&env);
@@ -2037,6 +2264,7 @@ fn test_multiple_contributors_refund_independently() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &0,
     );
 
     // Both contributors contribute
@@ -2087,6 +2315,7 @@ fn test_refund_fails_after_already_refunded() {
 
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_id.address(), &0);
 
     let creator = Address::generate(&env);
     let contributor = Address::generate(&env);
@@ -2109,6 +2338,7 @@ fn test_refund_fails_after_already_refunded() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &0,
     );
 
     token_admin_client.mint(&contributor, &5_000i128);
@@ -0,0 +1,320 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Events as _, Ledger},
+    token, vec, Address, BytesN, Env, Event, String, Vec,
+};
+
+use crate::{
+    base::{
+        errors::CrowdfundingError,
+        events::{
+            EmergencyWithdrawCancelled, EmergencyWithdrawDelaySet, EmergencyWithdrawExecuted,
+            EmergencyWithdrawRequested, EVENT_SCHEMA_VERSION,
+        },
+        types::{
+            CouncilAction, MultiSigConfig, PoolConfig, DEFAULT_EMERGENCY_WITHDRAW_DELAY,
+            MAX_EMERGENCY_WITHDRAW_DELAY, MAX_POOL_DURATION, MIN_EMERGENCY_WITHDRAW_DELAY,
+        },
+    },
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+
+fn setup_test(env: &Env) -> (CrowdfundingContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token_contract = env.register_stellar_asset_contract_v2(token_admin.clone());
+    let token_address = token_contract.address();
+
+    client.initialize(&admin, &token_address, &0);
+
+    (client, admin, token_address)
+}
+
+/// Escrow `amount` through a campaign donation and a pool contribution of
+/// `amount` each.
+fn escrow(env: &Env, client: &CrowdfundingContractClient, token: &Address, amount: i128) {
+    let donor = Address::generate(env);
+    token::StellarAssetClient::new(env, token).mint(&donor, &(2 * amount));
+
+    let campaign_id = BytesN::from_array(env, &[1; 32]);
+    client.create_campaign(
+        &campaign_id,
+        &String::from_str(env, "Campaign"),
+        &Address::generate(env),
+        &1_000_000,
+        &(env.ledger().timestamp() + 86_400),
+        token,
+        &0,
+    );
+    client.donate(&campaign_id, &donor, token, &amount);
+
+    let pool_id = client.create_pool(
+        &Address::generate(env),
+        &PoolConfig {
+            name: String::from_str(env, "Pool"),
+            description: String::from_str(env, "Escrowed"),
+            target_amount: 1_000_000,
+            is_private: false,
+            duration: MAX_POOL_DURATION,
+            created_at: 0,
+        },
+        &0,
+    );
+    client.contribute(&pool_id, &donor, token, &amount, &false);
+}
+
+#[test]
+fn test_withdrawal_capped_at_unallocated_balance() {
+    let env = Env::default();
+    let (client, admin, token) = setup_test(&env);
+    escrow(&env, &client, &token, 300);
+    token::StellarAssetClient::new(&env, &token).mint(&client.address, &100);
+
+    assert_eq!(client.get_escrowed_balance(&token), 600);
+    assert_eq!(client.get_withdrawable_balance(&token), 100);
+    assert_eq!(
+        client.try_request_emergency_withdraw(&admin, &token, &101, &None),
+        Err(Ok(CrowdfundingError::InsufficientBalance))
+    );
+    assert_eq!(
+        client.try_request_emergency_withdraw(&admin, &token, &0, &None),
+        Err(Ok(CrowdfundingError::InvalidAmount))
+    );
+
+    client.request_emergency_withdraw(&admin, &token, &100, &None);
+    env.ledger()
+        .with_mut(|li| li.timestamp += DEFAULT_EMERGENCY_WITHDRAW_DELAY);
+    client.execute_emergency_withdraw(&admin);
+
+    let token_client = token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&admin), 100);
+    assert_eq!(token_client.balance(&client.address), 600);
+    assert_eq!(client.get_withdrawable_balance(&token), 0);
+}
+
+#[test]
+fn test_withdrawal_to_recipient_and_pending_view() {
+    let env = Env::default();
+    let (client, admin, token) = setup_test(&env);
+    let recipient = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&client.address, &250);
+    env.ledger().set_timestamp(1_000);
+
+    assert_eq!(client.get_emergency_withdrawal(), None);
+    client.request_emergency_withdraw(&admin, &token, &250, &Some(recipient.clone()));
+    let event = EmergencyWithdrawRequested {
+        admin: admin.clone(),
+        token: token.clone(),
+        amount: 250,
+        recipient: Some(recipient.clone()),
+        unlock_time: 1_000 + DEFAULT_EMERGENCY_WITHDRAW_DELAY,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (client.address.clone(), event.topics(&env), event.data(&env))
+        ]
+    );
+
+    let pending = client.get_emergency_withdrawal().unwrap();
+    assert_eq!(pending.recipient, recipient);
+    assert_eq!(pending.amount, 250);
+    assert_eq!(pending.token, token);
+    assert_eq!(pending.requested_at, 1_000);
+    assert_eq!(
+        pending.unlock_time,
+        1_000 + DEFAULT_EMERGENCY_WITHDRAW_DELAY
+    );
+
+    env.ledger().set_timestamp(pending.unlock_time - 1);
+    assert_eq!(
+        client.try_execute_emergency_withdraw(&admin),
+        Err(Ok(CrowdfundingError::EmergencyWithdrawalPeriodNotPassed))
+    );
+
+    env.ledger().set_timestamp(pending.unlock_time);
+    client.execute_emergency_withdraw(&admin);
+    let event = EmergencyWithdrawExecuted {
+        admin: admin.clone(),
+        token: token.clone(),
+        amount: 250,
+        recipient: Some(recipient.clone()),
+        version: EVENT_SCHEMA_VERSION,
+    };
+    // The token's transfer event comes first
+    let events = env.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![
+            &env,
+            (client.address.clone(), event.topics(&env), event.data(&env))
+        ]
+    );
+    assert_eq!(token::Client::new(&env, &token).balance(&recipient), 250);
+    assert_eq!(token::Client::new(&env, &token).balance(&admin), 0);
+    assert_eq!(client.get_emergency_withdrawal(), None);
+}
+
+#[test]
+fn test_cancel_emergency_withdraw() {
+    let env = Env::default();
+    let (client, admin, token) = setup_test(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&client.address, &100);
+
+    assert_eq!(
+        client.try_cancel_emergency_withdraw(&admin),
+        Err(Ok(CrowdfundingError::EmergencyWithdrawalNotRequested))
+    );
+
+    client.request_emergency_withdraw(&admin, &token, &60, &None);
+    client.cancel_emergency_withdraw(&admin);
+    let event = EmergencyWithdrawCancelled {
+        admin: admin.clone(),
+        token: token.clone(),
+        amount: 60,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (client.address.clone(), event.topics(&env), event.data(&env))
+        ]
+    );
+    assert_eq!(client.get_emergency_withdrawal(), None);
+
+    env.ledger()
+        .with_mut(|li| li.timestamp += DEFAULT_EMERGENCY_WITHDRAW_DELAY);
+    assert_eq!(
+        client.try_execute_emergency_withdraw(&admin),
+        Err(Ok(CrowdfundingError::EmergencyWithdrawalNotRequested))
+    );
+
+    // A new request can be made once the old one is cancelled
+    client.request_emergency_withdraw(&admin, &token, &100, &None);
+}
+
+#[test]
+fn test_configurable_delay() {
+    let env = Env::default();
+    let (client, admin, token) = setup_test(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&client.address, &100);
+
+    assert_eq!(
+        client.get_emergency_withdraw_delay(),
+        DEFAULT_EMERGENCY_WITHDRAW_DELAY
+    );
+    for delay in [
+        MIN_EMERGENCY_WITHDRAW_DELAY - 1,
+        MAX_EMERGENCY_WITHDRAW_DELAY + 1,
+    ] {
+        assert_eq!(
+            client.try_set_emergency_withdraw_delay(&delay),
+            Err(Ok(CrowdfundingError::InvalidDeadline))
+        );
+    }
+
+    client.set_emergency_withdraw_delay(&MIN_EMERGENCY_WITHDRAW_DELAY);
+    let event = EmergencyWithdrawDelaySet {
+        admin: admin.clone(),
+        delay: MIN_EMERGENCY_WITHDRAW_DELAY,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (client.address.clone(), event.topics(&env), event.data(&env))
+        ]
+    );
+
+    client.request_emergency_withdraw(&admin, &token, &100, &None);
+    // Raising the delay afterwards does not hold back the pending request
+    client.set_emergency_withdraw_delay(&DEFAULT_EMERGENCY_WITHDRAW_DELAY);
+    env.ledger()
+        .with_mut(|li| li.timestamp += MIN_EMERGENCY_WITHDRAW_DELAY);
+    client.execute_emergency_withdraw(&admin);
+}
+
+#[test]
+fn test_council_sets_delay() {
+    let env = Env::default();
+    let (client, _, _) = setup_test(&env);
+    let member = Address::generate(&env);
+    client.set_council(&MultiSigConfig {
+        required_signatures: 1,
+        signers: Vec::from_array(&env, [member.clone()]),
+    });
+
+    assert_eq!(
+        client.try_set_emergency_withdraw_delay(&MIN_EMERGENCY_WITHDRAW_DELAY),
+        Err(Ok(CrowdfundingError::Unauthorized))
+    );
+    let proposal_id = client.propose_council_action(
+        &member,
+        &CouncilAction::SetEmergencyWithdrawDelay(MIN_EMERGENCY_WITHDRAW_DELAY),
+    );
+    client.execute_council_action(&member, &proposal_id);
+    assert_eq!(
+        client.get_emergency_withdraw_delay(),
+        MIN_EMERGENCY_WITHDRAW_DELAY
+    );
+}
+
+#[test]
+fn test_refund_releases_escrow() {
+    let env = Env::default();
+    let (client, _, token) = setup_test(&env);
+    let contributor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&contributor, &500);
+
+    let pool_id = client.create_pool(
+        &Address::generate(&env),
+        &PoolConfig {
+            name: String::from_str(&env, "Pool"),
+            description: String::from_str(&env, "Refunded"),
+            target_amount: 1_000,
+            is_private: false,
+            duration: 60 * 60,
+            created_at: 0,
+        },
+        &0,
+    );
+    client.contribute(&pool_id, &contributor, &token, &500, &false);
+    assert_eq!(client.get_escrowed_balance(&token), 500);
+
+    // Past the deadline and the 7-day refund grace period
+    env.ledger()
+        .with_mut(|li| li.timestamp += 60 * 60 + 604_800);
+    client.refund(&pool_id, &contributor);
+    assert_eq!(client.get_escrowed_balance(&token), 0);
+}
+
+#[test]
+fn test_emergency_withdraw_requires_initialization() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = CrowdfundingContractClient::new(&env, &env.register(CrowdfundingContract, ()));
+    let caller = Address::generate(&env);
+    let token = Address::generate(&env);
+
+    assert_eq!(
+        client.try_request_emergency_withdraw(&caller, &token, &1, &None),
+        Err(Ok(CrowdfundingError::NotInitialized))
+    );
+    assert_eq!(
+        client.try_execute_emergency_withdraw(&caller),
+        Err(Ok(CrowdfundingError::NotInitialized))
+    );
+    assert_eq!(
+        client.try_cancel_emergency_withdraw(&caller),
+        Err(Ok(CrowdfundingError::NotInitialized))
+    );
+}
@@ -0,0 +1,282 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Events as _, Ledger},
+    token, vec, Address, Env, Event, String, Vec,
+};
+
+use crate::{
+    base::{
+        errors::CrowdfundingError,
+        events::{
+            CreationFeePaid, EntityCreationFeeQueued, EntityCreationFeeSet, EVENT_SCHEMA_VERSION,
+        },
+        types::{
+            FeeEntity, PendingEntityFee, PoolConfig, PoolMetadata, Role, PARAMETER_CHANGE_DELAY,
+        },
+    },
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+    test::contract_events,
+};
+
+struct Setup<'a> {
+    env: &'a Env,
+    client: CrowdfundingContractClient<'a>,
+    admin: Address,
+    token: Address,
+}
+
+/// When the pool fees queued by `setup_test` take effect.
+const START: u64 = 1_000 + PARAMETER_CHANGE_DELAY;
+
+/// Initialize with a campaign fee of 100 and the pool fees below, all in
+/// effect at `START`.
+fn setup_test(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    client.initialize(&admin, &token, &100);
+    client.set_entity_creation_fee(&admin, &FeeEntity::PublicPool, &10);
+    client.set_entity_creation_fee(&admin, &FeeEntity::PrivatePool, &20);
+    client.set_entity_creation_fee(&admin, &FeeEntity::MultiSigPool, &30);
+    env.ledger().set_timestamp(START);
+
+    Setup {
+        env,
+        client,
+        admin,
+        token,
+    }
+}
+
+impl Setup<'_> {
+    fn funded_creator(&self, amount: i128) -> Address {
+        let creator = Address::generate(self.env);
+        token::StellarAssetClient::new(self.env, &self.token).mint(&creator, &amount);
+        creator
+    }
+
+    fn balance(&self, account: &Address) -> i128 {
+        token::Client::new(self.env, &self.token).balance(account)
+    }
+
+    fn pool_config(&self, is_private: bool) -> PoolConfig {
+        PoolConfig {
+            name: String::from_str(self.env, "Pool"),
+            description: String::from_str(self.env, "Description"),
+            target_amount: 1_000,
+            is_private,
+            duration: 86_400,
+            created_at: 0,
+        }
+    }
+
+    fn try_save_pool(
+        &self,
+        creator: &Address,
+        signers: Option<Vec<Address>>,
+        max_fee: i128,
+    ) -> Result<u64, CrowdfundingError> {
+        let metadata = PoolMetadata {
+            description: String::from_str(self.env, "Description"),
+            external_url: String::from_str(self.env, ""),
+            image_hash: String::from_str(self.env, ""),
+        };
+        self.client
+            .try_save_pool(
+                &String::from_str(self.env, "Pool"),
+                &metadata,
+                creator,
+                &1_000,
+                &(self.env.ledger().timestamp() + 86_400),
+                &signers.as_ref().map(|_| 1u32),
+                &signers,
+                &max_fee,
+            )
+            .map(|id| id.unwrap())
+            .map_err(|err| err.unwrap())
+    }
+}
+
+#[test]
+fn test_each_entity_has_its_own_fee() {
+    let env = Env::default();
+    let s = setup_test(&env);
+
+    assert_eq!(s.client.get_entity_creation_fee(&FeeEntity::Campaign), 100);
+    assert_eq!(s.client.get_entity_creation_fee(&FeeEntity::PublicPool), 10);
+    assert_eq!(
+        s.client.get_entity_creation_fee(&FeeEntity::PrivatePool),
+        20
+    );
+    assert_eq!(
+        s.client.get_entity_creation_fee(&FeeEntity::MultiSigPool),
+        30
+    );
+
+    let creator = s.funded_creator(1_000);
+    s.client.create_pool(&creator, &s.pool_config(false), &10);
+    s.client.create_pool(&creator, &s.pool_config(true), &20);
+    s.try_save_pool(&creator, None, 10).unwrap();
+    s.try_save_pool(&creator, Some(vec![&env, creator.clone()]), 30)
+        .unwrap();
+
+    assert_eq!(s.balance(&creator), 1_000 - 70);
+    assert_eq!(s.client.get_fee_balance(&s.token), 70);
+}
+
+#[test]
+fn test_pool_fee_is_charged_with_the_campaign_checks() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    let creator = s.funded_creator(15);
+
+    s.client.create_pool(&creator, &s.pool_config(false), &10);
+    let set = EntityCreationFeeSet {
+        admin: s.admin.clone(),
+        entity: FeeEntity::PublicPool,
+        fee: 10,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    let paid = CreationFeePaid {
+        creator: creator.clone(),
+        token: s.token.clone(),
+        amount: 10,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        contract_events(&env, &s.client.address).slice(0..2),
+        vec![
+            &env,
+            (s.client.address.clone(), set.topics(&env), set.data(&env)),
+            (s.client.address.clone(), paid.topics(&env), paid.data(&env)),
+        ]
+    );
+
+    assert_eq!(
+        s.client
+            .try_create_pool(&creator, &s.pool_config(false), &9),
+        Err(Ok(CrowdfundingError::InvalidFee))
+    );
+    assert_eq!(
+        s.client
+            .try_create_pool(&creator, &s.pool_config(true), &20),
+        Err(Ok(CrowdfundingError::InsufficientBalance))
+    );
+    assert_eq!(
+        s.try_save_pool(&creator, Some(vec![&env, creator.clone()]), 30),
+        Err(CrowdfundingError::InsufficientBalance)
+    );
+    assert_eq!(s.balance(&creator), 5);
+}
+
+#[test]
+fn test_entity_fee_change_is_queued() {
+    let env = Env::default();
+    let s = setup_test(&env);
+
+    s.client
+        .set_entity_creation_fee(&s.admin, &FeeEntity::PublicPool, &40);
+    let effective_at = START + PARAMETER_CHANGE_DELAY;
+    // The change queued by the setup is due, so it is written first
+    let set = EntityCreationFeeSet {
+        admin: s.admin.clone(),
+        entity: FeeEntity::PublicPool,
+        fee: 10,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    let queued = EntityCreationFeeQueued {
+        admin: s.admin.clone(),
+        entity: FeeEntity::PublicPool,
+        fee: 40,
+        effective_at,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (s.client.address.clone(), set.topics(&env), set.data(&env)),
+            (
+                s.client.address.clone(),
+                queued.topics(&env),
+                queued.data(&env)
+            ),
+        ]
+    );
+    assert_eq!(
+        s.client
+            .get_pending_entity_creation_fee(&FeeEntity::PublicPool),
+        Some(PendingEntityFee {
+            fee: 40,
+            queued_by: s.admin.clone(),
+            effective_at,
+        })
+    );
+    assert_eq!(s.client.get_entity_creation_fee(&FeeEntity::PublicPool), 10);
+
+    env.ledger().set_timestamp(effective_at);
+    assert_eq!(s.client.get_entity_creation_fee(&FeeEntity::PublicPool), 40);
+    assert_eq!(
+        s.client
+            .get_pending_entity_creation_fee(&FeeEntity::PublicPool),
+        None
+    );
+}
+
+#[test]
+fn test_campaign_entity_uses_the_creation_fee() {
+    let env = Env::default();
+    let s = setup_test(&env);
+
+    s.client
+        .set_entity_creation_fee(&s.admin, &FeeEntity::Campaign, &150);
+    assert_eq!(
+        s.client.get_pending_parameters().unwrap().creation_fee,
+        Some(150)
+    );
+    assert_eq!(
+        s.client
+            .get_pending_entity_creation_fee(&FeeEntity::Campaign),
+        Some(PendingEntityFee {
+            fee: 150,
+            queued_by: s.admin.clone(),
+            effective_at: START + PARAMETER_CHANGE_DELAY,
+        })
+    );
+
+    env.ledger().set_timestamp(START + PARAMETER_CHANGE_DELAY);
+    assert_eq!(s.client.get_creation_fee(), 150);
+    assert_eq!(s.client.get_entity_creation_fee(&FeeEntity::Campaign), 150);
+}
+
+#[test]
+fn test_set_entity_creation_fee_validation() {
+    let env = Env::default();
+    let s = setup_test(&env);
+
+    assert_eq!(
+        s.client
+            .try_set_entity_creation_fee(&s.admin, &FeeEntity::PrivatePool, &-1),
+        Err(Ok(CrowdfundingError::InvalidFee))
+    );
+
+    let treasury = Address::generate(&env);
+    s.client.grant_role(&Role::Treasury, &treasury);
+    assert_eq!(
+        s.client
+            .try_set_entity_creation_fee(&treasury, &FeeEntity::PrivatePool, &1),
+        Err(Ok(CrowdfundingError::Unauthorized))
+    );
+    assert_eq!(
+        s.client
+            .get_pending_entity_creation_fee(&FeeEntity::PrivatePool),
+        None
+    );
+}
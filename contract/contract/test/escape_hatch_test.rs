@@ -0,0 +1,308 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Events as _, Ledger},
+    token, vec, Address, BytesN, Env, Event, String,
+};
+
+use crate::{
+    base::{
+        errors::CrowdfundingError,
+        events::{CampaignRefunded, MaxPauseDurationSet, Refund, EVENT_SCHEMA_VERSION},
+        types::{
+            PoolConfig, PoolState, DEFAULT_MAX_PAUSE_DURATION, MAX_MAX_PAUSE_DURATION,
+            MAX_POOL_DURATION, MIN_MAX_PAUSE_DURATION, MIN_UNPAUSED_PERIOD,
+        },
+    },
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+    test::contract_events,
+};
+
+struct Setup<'a> {
+    client: CrowdfundingContractClient<'a>,
+    admin: Address,
+    token: Address,
+    contributor: Address,
+    campaign_id: BytesN<32>,
+    pool_id: u64,
+}
+
+/// Initialize, then have one contributor donate 300 to a campaign and
+/// contribute 200 to a pool.
+fn setup_test(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin.clone())
+        .address();
+    client.initialize(&admin, &token, &0);
+
+    let contributor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token).mint(&contributor, &500);
+
+    let campaign_id = BytesN::from_array(env, &[7; 32]);
+    client.create_campaign(
+        &campaign_id,
+        &String::from_str(env, "Campaign"),
+        &Address::generate(env),
+        &10_000,
+        &(env.ledger().timestamp() + MAX_POOL_DURATION),
+        &token,
+        &0,
+    );
+    client.donate(&campaign_id, &contributor, &token, &300);
+
+    let pool_id = client.create_pool(
+        &Address::generate(env),
+        &PoolConfig {
+            name: String::from_str(env, "Pool"),
+            description: String::from_str(env, "Frozen"),
+            target_amount: 10_000,
+            is_private: false,
+            duration: MAX_POOL_DURATION,
+            created_at: 0,
+        },
+        &0,
+    );
+    client.contribute(&pool_id, &contributor, &token, &200, &false);
+
+    Setup {
+        client,
+        admin,
+        token,
+        contributor,
+        campaign_id,
+        pool_id,
+    }
+}
+
+#[test]
+fn test_pause_records_start_time() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    env.ledger().set_timestamp(5_000);
+
+    assert_eq!(s.client.get_paused_at(), None);
+    s.client.pause(&s.admin);
+    assert_eq!(s.client.get_paused_at(), Some(5_000));
+
+    s.client.unpause(&s.admin);
+    assert_eq!(s.client.get_paused_at(), None);
+    assert!(!s.client.is_escape_hatch_open());
+}
+
+#[test]
+fn test_escape_hatch_opens_after_max_pause_duration() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    s.client.pause(&s.admin);
+
+    env.ledger()
+        .with_mut(|li| li.timestamp += DEFAULT_MAX_PAUSE_DURATION - 1);
+    assert!(!s.client.is_escape_hatch_open());
+    assert_eq!(
+        s.client
+            .try_escape_pool_contribution(&s.pool_id, &s.contributor),
+        Err(Ok(CrowdfundingError::TimelockNotPassed))
+    );
+    assert_eq!(
+        s.client
+            .try_escape_campaign_contribution(&s.campaign_id, &s.contributor),
+        Err(Ok(CrowdfundingError::TimelockNotPassed))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp += 1);
+    assert!(s.client.is_escape_hatch_open());
+}
+
+#[test]
+fn test_repausing_does_not_restart_escape_hatch_clock() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    env.ledger().set_timestamp(5_000);
+    s.client.pause(&s.admin);
+
+    // Unpausing and pausing again at the deadline keeps the original start
+    env.ledger()
+        .with_mut(|li| li.timestamp += DEFAULT_MAX_PAUSE_DURATION - 1);
+    s.client.unpause(&s.admin);
+    s.client.pause(&s.admin);
+    assert_eq!(s.client.get_paused_at(), Some(5_000));
+
+    env.ledger().with_mut(|li| li.timestamp += 1);
+    assert!(s.client.is_escape_hatch_open());
+    s.client
+        .escape_pool_contribution(&s.pool_id, &s.contributor);
+
+    // Only a pause after the minimum unpaused period starts a new clock
+    s.client.unpause(&s.admin);
+    let unpaused_at = env.ledger().timestamp();
+    env.ledger()
+        .set_timestamp(unpaused_at + MIN_UNPAUSED_PERIOD - 1);
+    s.client.pause(&s.admin);
+    assert_eq!(s.client.get_paused_at(), Some(5_000));
+    s.client.unpause(&s.admin);
+    env.ledger()
+        .set_timestamp(unpaused_at + 2 * MIN_UNPAUSED_PERIOD);
+    s.client.pause(&s.admin);
+    assert_eq!(
+        s.client.get_paused_at(),
+        Some(unpaused_at + 2 * MIN_UNPAUSED_PERIOD)
+    );
+    assert!(!s.client.is_escape_hatch_open());
+}
+
+#[test]
+fn test_escape_pool_contribution_ignores_pool_state() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    // Regular refunds are unavailable for a disbursed pool
+    s.client
+        .update_pool_state(&s.pool_id, &PoolState::Disbursed);
+    s.client.pause(&s.admin);
+    env.ledger()
+        .with_mut(|li| li.timestamp += DEFAULT_MAX_PAUSE_DURATION);
+
+    s.client
+        .escape_pool_contribution(&s.pool_id, &s.contributor);
+    assert_eq!(env.auths()[0].0, s.contributor);
+    let event = Refund {
+        pool_id: s.pool_id,
+        contributor: s.contributor.clone(),
+        asset: s.token.clone(),
+        amount: 200,
+        timestamp: env.ledger().timestamp(),
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        contract_events(&env, &s.client.address),
+        vec![
+            &env,
+            (
+                s.client.address.clone(),
+                event.topics(&env),
+                event.data(&env)
+            )
+        ]
+    );
+
+    assert_eq!(
+        token::Client::new(&env, &s.token).balance(&s.contributor),
+        200
+    );
+    assert_eq!(s.client.get_escrowed_balance(&s.token), 300);
+    assert_eq!(
+        s.client
+            .try_escape_pool_contribution(&s.pool_id, &s.contributor),
+        Err(Ok(CrowdfundingError::NoContributionToRefund))
+    );
+}
+
+#[test]
+fn test_escape_campaign_contribution() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    s.client.pause(&s.admin);
+    env.ledger()
+        .with_mut(|li| li.timestamp += DEFAULT_MAX_PAUSE_DURATION);
+
+    s.client
+        .escape_campaign_contribution(&s.campaign_id, &s.contributor);
+    let event = CampaignRefunded {
+        campaign_id: s.campaign_id.clone(),
+        contributor: s.contributor.clone(),
+        asset: s.token.clone(),
+        amount: 300,
+        timestamp: env.ledger().timestamp(),
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        contract_events(&env, &s.client.address),
+        vec![
+            &env,
+            (
+                s.client.address.clone(),
+                event.topics(&env),
+                event.data(&env)
+            )
+        ]
+    );
+
+    assert_eq!(
+        token::Client::new(&env, &s.token).balance(&s.contributor),
+        300
+    );
+    assert_eq!(s.client.get_contribution(&s.campaign_id, &s.contributor), 0);
+    assert_eq!(s.client.get_total_raised(&s.campaign_id), 0);
+    assert_eq!(s.client.get_campaign_balance(&s.campaign_id), 0);
+    assert_eq!(s.client.get_escrowed_balance(&s.token), 200);
+    assert_eq!(
+        s.client
+            .try_escape_campaign_contribution(&s.campaign_id, &s.contributor),
+        Err(Ok(CrowdfundingError::NoContributionToRefund))
+    );
+}
+
+#[test]
+fn test_escape_hatch_closed_while_unpaused() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    env.ledger()
+        .with_mut(|li| li.timestamp += DEFAULT_MAX_PAUSE_DURATION);
+
+    assert_eq!(
+        s.client
+            .try_escape_pool_contribution(&s.pool_id, &s.contributor),
+        Err(Ok(CrowdfundingError::RefundNotAvailable))
+    );
+    assert_eq!(
+        s.client
+            .try_escape_campaign_contribution(&s.campaign_id, &s.contributor),
+        Err(Ok(CrowdfundingError::RefundNotAvailable))
+    );
+}
+
+#[test]
+fn test_set_max_pause_duration() {
+    let env = Env::default();
+    let s = setup_test(&env);
+
+    assert_eq!(
+        s.client.get_max_pause_duration(),
+        DEFAULT_MAX_PAUSE_DURATION
+    );
+    for duration in [MIN_MAX_PAUSE_DURATION - 1, MAX_MAX_PAUSE_DURATION + 1] {
+        assert_eq!(
+            s.client.try_set_max_pause_duration(&duration),
+            Err(Ok(CrowdfundingError::InvalidDeadline))
+        );
+    }
+
+    s.client.set_max_pause_duration(&MIN_MAX_PAUSE_DURATION);
+    let event = MaxPauseDurationSet {
+        admin: s.admin.clone(),
+        duration: MIN_MAX_PAUSE_DURATION,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                s.client.address.clone(),
+                event.topics(&env),
+                event.data(&env)
+            )
+        ]
+    );
+
+    s.client.pause(&s.admin);
+    env.ledger()
+        .with_mut(|li| li.timestamp += MIN_MAX_PAUSE_DURATION);
+    s.client
+        .escape_campaign_contribution(&s.campaign_id, &s.contributor);
+}
@@ -0,0 +1,222 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::Address as _, token, vec, Address, BytesN, Env, Event, String, TryFromVal, Val, Vec,
+};
+
+use crate::{
+    base::{
+        events::{
+            AcceptedTokenSet, CampaignCreated, CampaignGoalReached, ContractInitialized,
+            DonationMade, PoolCreated, PoolMetadataSet, PoolStateUpdated, EVENT_SCHEMA_VERSION,
+        },
+        types::{PoolMetadata, PoolState},
+    },
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+    test::contract_events,
+};
+
+fn as_emitted(env: &Env, contract_id: &Address, event: &impl Event) -> (Address, Vec<Val>, Val) {
+    (contract_id.clone(), event.topics(env), event.data(env))
+}
+
+#[test]
+fn test_initialize_emits_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+
+    let token_client = token::Client::new(&env, &token);
+    let (symbol, decimals) = (token_client.symbol(), token_client.decimals());
+
+    client.initialize(&admin, &token, &25);
+
+    let expected = ContractInitialized {
+        admin: admin.clone(),
+        token: token.clone(),
+        creation_fee: 25,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    // The platform token is registered as the first accepted token
+    let accepted = AcceptedTokenSet {
+        admin,
+        token: token.clone(),
+        symbol,
+        decimals,
+        min_amount: 0,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        contract_events(&env, &contract_id),
+        vec![
+            &env,
+            as_emitted(&env, &contract_id, &expected),
+            as_emitted(&env, &contract_id, &accepted)
+        ]
+    );
+}
+
+#[test]
+fn test_save_pool_emits_creation_and_metadata_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let creator = Address::generate(&env);
+    let name = String::from_str(&env, "Events Pool");
+    let metadata = PoolMetadata {
+        description: String::from_str(&env, "Pool with events"),
+        external_url: String::from_str(&env, "https://example.com"),
+        image_hash: String::from_str(&env, "hash"),
+    };
+    let deadline = env.ledger().timestamp() + 86400;
+
+    let pool_id = client.save_pool(
+        &name,
+        &metadata,
+        &creator,
+        &10_000i128,
+        &deadline,
+        &None::<u32>,
+        &None::<Vec<Address>>,
+        &0,
+    );
+
+    let created = PoolCreated {
+        pool_id,
+        creator,
+        name,
+        description: metadata.description.clone(),
+        target_amount: 10_000,
+        is_private: false,
+        deadline,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    let metadata_set = PoolMetadataSet {
+        pool_id,
+        description: metadata.description,
+        external_url: metadata.external_url,
+        image_hash: metadata.image_hash,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        contract_events(&env, &contract_id),
+        vec![
+            &env,
+            as_emitted(&env, &contract_id, &created),
+            as_emitted(&env, &contract_id, &metadata_set),
+        ]
+    );
+}
+
+#[test]
+fn test_pool_state_update_event_carries_transition() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let metadata = PoolMetadata {
+        description: String::from_str(&env, "Pool"),
+        external_url: String::from_str(&env, ""),
+        image_hash: String::from_str(&env, ""),
+    };
+    let pool_id = client.save_pool(
+        &String::from_str(&env, "State Pool"),
+        &metadata,
+        &Address::generate(&env),
+        &10_000i128,
+        &(env.ledger().timestamp() + 86400),
+        &None::<u32>,
+        &None::<Vec<Address>>,
+        &0,
+    );
+
+    client.update_pool_state(&pool_id, &PoolState::Paused);
+
+    let expected = PoolStateUpdated {
+        pool_id,
+        old_state: PoolState::Active,
+        new_state: PoolState::Paused,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        contract_events(&env, &contract_id),
+        vec![&env, as_emitted(&env, &contract_id, &expected)]
+    );
+}
+
+#[test]
+fn test_donation_reaching_goal_emits_goal_reached() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.initialize(&admin, &token, &0);
+
+    let creator = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let campaign_id = BytesN::from_array(&env, &[7u8; 32]);
+    let title = String::from_str(&env, "Goal Campaign");
+    let deadline = env.ledger().timestamp() + 86400;
+
+    client.create_campaign(&campaign_id, &title, &creator, &500, &deadline, &token, &0);
+    let created = CampaignCreated {
+        campaign_id: campaign_id.clone(),
+        creator,
+        title,
+        goal: 500,
+        deadline,
+        token: token.clone(),
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        contract_events(&env, &contract_id),
+        vec![&env, as_emitted(&env, &contract_id, &created)]
+    );
+
+    soroban_sdk::token::StellarAssetClient::new(&env, &token).mint(&donor, &500);
+    client.donate(&campaign_id, &donor, &token, &500);
+
+    let donated = DonationMade {
+        campaign_id: campaign_id.clone(),
+        contributor: donor,
+        asset: token,
+        amount: 500,
+        timestamp: env.ledger().timestamp(),
+        version: EVENT_SCHEMA_VERSION,
+    };
+    let goal_reached = CampaignGoalReached {
+        campaign_id: campaign_id.clone(),
+        total_raised: 500,
+        timestamp: env.ledger().timestamp(),
+        version: EVENT_SCHEMA_VERSION,
+    };
+    let events = contract_events(&env, &contract_id);
+    assert_eq!(
+        events,
+        vec![
+            &env,
+            as_emitted(&env, &contract_id, &donated),
+            as_emitted(&env, &contract_id, &goal_reached),
+        ]
+    );
+
+    // The campaign id is the first topic after the event name
+    for (_, topics, _) in events.iter() {
+        let subject = BytesN::<32>::try_from_val(&env, &topics.get(1).unwrap()).unwrap();
+        assert_eq!(subject, campaign_id);
+    }
+}
@@ -0,0 +1,346 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Events as _, Ledger},
+    token, vec, Address, BytesN, Env, Event, String,
+};
+
+use crate::{
+    base::{
+        errors::CrowdfundingError,
+        events::{FeaturesPaused, FeaturesUnpaused, EVENT_SCHEMA_VERSION},
+        types::{
+            FeaturePause, PoolConfig, PoolMetadata, PoolState, Role, DEFAULT_MAX_PAUSE_DURATION,
+            MAX_POOL_DURATION, MIN_UNPAUSED_PERIOD, PAUSE_ALL_FEATURES, PAUSE_CAMPAIGN_CREATION,
+            PAUSE_CONTRIBUTIONS, PAUSE_DONATIONS, PAUSE_POOL_CREATION, PAUSE_POOL_STATE_UPDATES,
+            PAUSE_REFUNDS,
+        },
+    },
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+
+fn setup_test(env: &Env) -> (CrowdfundingContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin.clone())
+        .address();
+    client.initialize(&admin, &token, &0);
+
+    (client, admin, token)
+}
+
+fn pool_config(env: &Env) -> PoolConfig {
+    PoolConfig {
+        name: String::from_str(env, "Pool"),
+        description: String::from_str(env, "Desc"),
+        target_amount: 10_000,
+        is_private: false,
+        duration: MAX_POOL_DURATION,
+        created_at: 0,
+    }
+}
+
+#[test]
+fn test_pause_contributions_keeps_refunds_open() {
+    let env = Env::default();
+    let (client, admin, token) = setup_test(&env);
+    let contributor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&contributor, &1_000);
+    let pool_id = client.create_pool(&Address::generate(&env), &pool_config(&env), &0);
+    client.contribute(&pool_id, &contributor, &token, &100, &false);
+
+    client.pause_features(&admin, &PAUSE_CONTRIBUTIONS, &7, &3_600);
+    assert_eq!(client.get_paused_features(), PAUSE_CONTRIBUTIONS);
+    assert!(!client.is_paused());
+    assert_eq!(
+        client.try_contribute(&pool_id, &contributor, &token, &100, &false),
+        Err(Ok(CrowdfundingError::ContractPaused))
+    );
+
+    // Other features are unaffected
+    // Past the deadline and the 7 day refund grace period
+    let pool = client.get_pool(&pool_id).unwrap();
+    env.ledger()
+        .set_timestamp(pool.created_at + pool.duration + 7 * 24 * 60 * 60);
+    client.refund(&pool_id, &contributor);
+    assert_eq!(
+        token::Client::new(&env, &token).balance(&contributor),
+        1_000
+    );
+    client.create_pool(&Address::generate(&env), &pool_config(&env), &0);
+}
+
+#[test]
+fn test_each_entrypoint_checks_its_feature() {
+    let env = Env::default();
+    let (client, admin, token) = setup_test(&env);
+    let creator = Address::generate(&env);
+    let campaign_id = BytesN::from_array(&env, &[1; 32]);
+    let deadline = env.ledger().timestamp() + MAX_POOL_DURATION;
+    client.create_campaign(
+        &campaign_id,
+        &String::from_str(&env, "Campaign"),
+        &creator,
+        &1_000,
+        &deadline,
+        &token,
+        &0,
+    );
+    let pool_id = client.create_pool(&creator, &pool_config(&env), &0);
+
+    client.pause_features(
+        &admin,
+        &(PAUSE_CAMPAIGN_CREATION | PAUSE_DONATIONS | PAUSE_POOL_CREATION),
+        &1,
+        &3_600,
+    );
+    assert_eq!(
+        client.try_create_campaign(
+            &BytesN::from_array(&env, &[2; 32]),
+            &String::from_str(&env, "Campaign"),
+            &creator,
+            &1_000,
+            &deadline,
+            &token,
+            &0,
+        ),
+        Err(Ok(CrowdfundingError::ContractPaused))
+    );
+    assert_eq!(
+        client.try_donate(&campaign_id, &creator, &token, &10),
+        Err(Ok(CrowdfundingError::ContractPaused))
+    );
+    assert_eq!(
+        client.try_create_pool(&creator, &pool_config(&env), &0),
+        Err(Ok(CrowdfundingError::ContractPaused))
+    );
+    assert_eq!(
+        client.try_save_pool(
+            &String::from_str(&env, "Pool"),
+            &PoolMetadata {
+                description: String::from_str(&env, "Desc"),
+                external_url: String::from_str(&env, ""),
+                image_hash: String::from_str(&env, ""),
+            },
+            &creator,
+            &1_000,
+            &deadline,
+            &None,
+            &None,
+            &0,
+        ),
+        Err(Ok(CrowdfundingError::ContractPaused))
+    );
+    client.update_pool_state(&pool_id, &PoolState::Paused);
+
+    client.pause_features(
+        &admin,
+        &(PAUSE_POOL_STATE_UPDATES | PAUSE_REFUNDS),
+        &1,
+        &3_600,
+    );
+    assert_eq!(
+        client.get_paused_features(),
+        PAUSE_ALL_FEATURES & !PAUSE_CONTRIBUTIONS
+    );
+    assert_eq!(
+        client.try_update_pool_state(&pool_id, &PoolState::Active),
+        Err(Ok(CrowdfundingError::ContractPaused))
+    );
+    assert_eq!(
+        client.try_refund(&pool_id, &creator),
+        Err(Ok(CrowdfundingError::ContractPaused))
+    );
+}
+
+#[test]
+fn test_feature_pause_expires() {
+    let env = Env::default();
+    let (client, admin, _) = setup_test(&env);
+    env.ledger().set_timestamp(1_000);
+
+    client.pause_features(&admin, &PAUSE_POOL_CREATION, &42, &3_600);
+    let event = FeaturesPaused {
+        admin: admin.clone(),
+        features: PAUSE_POOL_CREATION,
+        reason: 42,
+        timestamp: 1_000,
+        expires_at: 4_600,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (client.address.clone(), event.topics(&env), event.data(&env))
+        ]
+    );
+    assert_eq!(
+        client.get_feature_pause(&PAUSE_POOL_CREATION),
+        Some(FeaturePause {
+            reason: 42,
+            paused_at: 1_000,
+            expires_at: 4_600,
+        })
+    );
+    assert_eq!(client.get_feature_pause(&PAUSE_DONATIONS), None);
+
+    env.ledger().set_timestamp(4_599);
+    assert_eq!(
+        client.try_create_pool(&Address::generate(&env), &pool_config(&env), &0),
+        Err(Ok(CrowdfundingError::ContractPaused))
+    );
+
+    env.ledger().set_timestamp(4_600);
+    assert_eq!(client.get_paused_features(), 0);
+    assert_eq!(client.get_feature_pause(&PAUSE_POOL_CREATION), None);
+    client.create_pool(&Address::generate(&env), &pool_config(&env), &0);
+}
+
+#[test]
+fn test_feature_pause_cannot_trap_contributors() {
+    let env = Env::default();
+    let (client, admin, token) = setup_test(&env);
+    let contributor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&contributor, &1_000);
+    let pool_id = client.create_pool(&Address::generate(&env), &pool_config(&env), &0);
+    client.contribute(&pool_id, &contributor, &token, &100, &false);
+
+    // Past the deadline and the 7 day refund grace period
+    let pool = client.get_pool(&pool_id).unwrap();
+    let paused_at = pool.created_at + pool.duration + 7 * 24 * 60 * 60;
+    env.ledger().set_timestamp(paused_at);
+
+    let features = PAUSE_CONTRIBUTIONS | PAUSE_REFUNDS;
+    for duration in [0, DEFAULT_MAX_PAUSE_DURATION + 1] {
+        assert_eq!(
+            client.try_pause_features(&admin, &features, &0, &duration),
+            Err(Ok(CrowdfundingError::InvalidDeadline))
+        );
+    }
+    client.pause_features(&admin, &features, &0, &DEFAULT_MAX_PAUSE_DURATION);
+    assert_eq!(
+        client.try_refund(&pool_id, &contributor),
+        Err(Ok(CrowdfundingError::ContractPaused))
+    );
+
+    // Re-pausing cannot push the pause past the maximum duration
+    env.ledger().set_timestamp(paused_at + 3_600);
+    assert_eq!(
+        client.try_pause_features(&admin, &PAUSE_REFUNDS, &0, &DEFAULT_MAX_PAUSE_DURATION),
+        Err(Ok(CrowdfundingError::InvalidDeadline))
+    );
+    client.pause_features(
+        &admin,
+        &PAUSE_REFUNDS,
+        &1,
+        &(DEFAULT_MAX_PAUSE_DURATION - 3_600),
+    );
+    assert_eq!(
+        client.get_feature_pause(&PAUSE_REFUNDS).unwrap().paused_at,
+        paused_at
+    );
+
+    // Neither pausing again once the pause lapses, nor unpausing and pausing
+    // again, restarts it
+    env.ledger()
+        .set_timestamp(paused_at + DEFAULT_MAX_PAUSE_DURATION);
+    assert_eq!(
+        client.try_pause_features(&admin, &PAUSE_REFUNDS, &0, &3_600),
+        Err(Ok(CrowdfundingError::InvalidDeadline))
+    );
+    client.pause_features(&admin, &PAUSE_DONATIONS, &0, &3_600);
+    client.unpause_features(&admin, &PAUSE_DONATIONS);
+    env.ledger().with_mut(|li| li.timestamp += 1);
+    assert_eq!(
+        client.try_pause_features(&admin, &PAUSE_DONATIONS, &0, &DEFAULT_MAX_PAUSE_DURATION),
+        Err(Ok(CrowdfundingError::InvalidDeadline))
+    );
+    client.refund(&pool_id, &contributor);
+    assert_eq!(
+        token::Client::new(&env, &token).balance(&contributor),
+        1_000
+    );
+
+    // Only a pause after the minimum unpaused period starts over
+    env.ledger()
+        .set_timestamp(paused_at + DEFAULT_MAX_PAUSE_DURATION + MIN_UNPAUSED_PERIOD - 1);
+    assert_eq!(
+        client.try_pause_features(&admin, &PAUSE_REFUNDS, &0, &3_600),
+        Err(Ok(CrowdfundingError::InvalidDeadline))
+    );
+    env.ledger()
+        .set_timestamp(paused_at + DEFAULT_MAX_PAUSE_DURATION + MIN_UNPAUSED_PERIOD);
+    client.pause_features(&admin, &PAUSE_REFUNDS, &0, &3_600);
+    assert_eq!(
+        client.get_feature_pause(&PAUSE_REFUNDS).unwrap().paused_at,
+        env.ledger().timestamp()
+    );
+}
+
+#[test]
+fn test_unpause_features() {
+    let env = Env::default();
+    let (client, admin, _) = setup_test(&env);
+
+    assert_eq!(
+        client.try_unpause_features(&admin, &PAUSE_DONATIONS),
+        Err(Ok(CrowdfundingError::ContractAlreadyUnpaused))
+    );
+
+    client.pause_features(&admin, &(PAUSE_DONATIONS | PAUSE_REFUNDS), &3, &3_600);
+    client.unpause_features(&admin, &PAUSE_DONATIONS);
+    let event = FeaturesUnpaused {
+        admin: admin.clone(),
+        features: PAUSE_DONATIONS,
+        timestamp: env.ledger().timestamp(),
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (client.address.clone(), event.topics(&env), event.data(&env))
+        ]
+    );
+    assert_eq!(client.get_paused_features(), PAUSE_REFUNDS);
+}
+
+#[test]
+fn test_unknown_features_are_rejected() {
+    let env = Env::default();
+    let (client, admin, _) = setup_test(&env);
+
+    for features in [0, PAUSE_ALL_FEATURES + 1] {
+        assert_eq!(
+            client.try_pause_features(&admin, &features, &0, &3_600),
+            Err(Ok(CrowdfundingError::InvalidAmount))
+        );
+        assert_eq!(
+            client.try_unpause_features(&admin, &features),
+            Err(Ok(CrowdfundingError::InvalidAmount))
+        );
+    }
+}
+
+#[test]
+fn test_pause_features_requires_pauser_role() {
+    let env = Env::default();
+    let (client, _, _) = setup_test(&env);
+    let pauser = Address::generate(&env);
+
+    assert_eq!(
+        client.try_pause_features(&pauser, &PAUSE_DONATIONS, &0, &3_600),
+        Err(Ok(CrowdfundingError::Unauthorized))
+    );
+
+    client.grant_role(&Role::Pauser, &pauser);
+    client.pause_features(&pauser, &PAUSE_DONATIONS, &0, &3_600);
+    assert_eq!(env.auths()[0].0, pauser);
+    client.unpause_features(&pauser, &PAUSE_DONATIONS);
+}
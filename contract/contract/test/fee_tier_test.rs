@@ -0,0 +1,296 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, BytesN, Env, Event, String, Vec,
+};
+
+use crate::{
+    base::{
+        errors::CrowdfundingError,
+        events::{FeeTierAssigned, FeeTierRevoked, EVENT_SCHEMA_VERSION},
+        types::{
+            CouncilAction, CreatorFeeTier, FeeEntity, FeeTier, MultiSigConfig, PoolConfig, Role,
+            PARAMETER_CHANGE_DELAY,
+        },
+    },
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+    test::contract_events,
+};
+
+/// When the fees queued by `setup_test` take effect.
+const START: u64 = 1_000 + PARAMETER_CHANGE_DELAY;
+/// Platform fee rate queued by `setup_test` (10%).
+const FEE_BPS: u32 = 1_000;
+
+struct Setup<'a> {
+    env: &'a Env,
+    client: CrowdfundingContractClient<'a>,
+    admin: Address,
+    token: Address,
+}
+
+/// Initialize with a campaign fee of 100, a public pool fee of 40 and a
+/// platform fee of `FEE_BPS`, all in effect at `START`.
+fn setup_test(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    client.initialize(&admin, &token, &100);
+    client.set_entity_creation_fee(&admin, &FeeEntity::PublicPool, &40);
+    client.set_platform_fee(&admin, &FEE_BPS);
+    env.ledger().set_timestamp(START);
+
+    Setup {
+        env,
+        client,
+        admin,
+        token,
+    }
+}
+
+impl Setup<'_> {
+    fn funded(&self, amount: i128) -> Address {
+        let account = Address::generate(self.env);
+        token::StellarAssetClient::new(self.env, &self.token).mint(&account, &amount);
+        account
+    }
+
+    fn balance(&self, account: &Address) -> i128 {
+        token::Client::new(self.env, &self.token).balance(account)
+    }
+
+    fn create_campaign(&self, creator: &Address) -> BytesN<32> {
+        let campaign_id = BytesN::from_array(self.env, &[1; 32]);
+        self.client.create_campaign(
+            &campaign_id,
+            &String::from_str(self.env, "Campaign"),
+            creator,
+            &10_000,
+            &(START + 86_400),
+            &self.token,
+            &100,
+        );
+        campaign_id
+    }
+
+    fn create_pool(&self, creator: &Address) -> u64 {
+        self.client.create_pool(
+            creator,
+            &PoolConfig {
+                name: String::from_str(self.env, "Pool"),
+                description: String::from_str(self.env, "Description"),
+                target_amount: 10_000,
+                is_private: false,
+                duration: 86_400,
+                created_at: 0,
+            },
+            &40,
+        )
+    }
+}
+
+#[test]
+fn test_waived_creator_pays_no_fees() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    let creator = Address::generate(&env);
+
+    s.client
+        .assign_fee_tier(&s.admin, &creator, &FeeTier::Waived, &0);
+    let event = FeeTierAssigned {
+        admin: s.admin.clone(),
+        creator: creator.clone(),
+        tier: FeeTier::Waived,
+        expires_at: 0,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        contract_events(&env, &s.client.address),
+        vec![
+            &env,
+            (
+                s.client.address.clone(),
+                event.topics(&env),
+                event.data(&env)
+            ),
+        ]
+    );
+
+    // The creator holds no tokens, so any creation fee would fail
+    let campaign_id = s.create_campaign(&creator);
+    let pool_id = s.create_pool(&creator);
+    assert_eq!(s.client.get_pool_platform_fee(&pool_id), 0);
+
+    let contributor = s.funded(2_000);
+    s.client
+        .contribute(&pool_id, &contributor, &s.token, &1_000, &false);
+    s.client
+        .donate(&campaign_id, &contributor, &s.token, &1_000);
+    assert_eq!(s.client.get_contribution(&campaign_id, &contributor), 1_000);
+    assert_eq!(s.client.get_fee_balance(&s.token), 0);
+}
+
+#[test]
+fn test_discount_reduces_creation_and_platform_fees() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    let creator = s.funded(70);
+
+    s.client
+        .assign_fee_tier(&s.admin, &creator, &FeeTier::Discount(5_000), &0);
+    let campaign_id = s.create_campaign(&creator);
+    let pool_id = s.create_pool(&creator);
+    assert_eq!(s.balance(&creator), 0);
+    assert_eq!(s.client.get_pool_platform_fee(&pool_id), FEE_BPS / 2);
+    assert_eq!(s.client.quote_contribution(&pool_id, &1_000).fee, 50);
+
+    let donor = s.funded(1_000);
+    s.client.donate(&campaign_id, &donor, &s.token, &1_000);
+    assert_eq!(s.client.get_contribution(&campaign_id, &donor), 950);
+    assert_eq!(s.client.get_fee_balance(&s.token), 70 + 50);
+}
+
+#[test]
+fn test_flat_fee_replaces_creation_fees_only() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    let creator = s.funded(14);
+
+    s.client
+        .assign_fee_tier(&s.admin, &creator, &FeeTier::FlatFee(7), &0);
+    s.create_campaign(&creator);
+    let pool_id = s.create_pool(&creator);
+    assert_eq!(s.balance(&creator), 0);
+    assert_eq!(s.client.get_pool_platform_fee(&pool_id), FEE_BPS);
+
+    // A pool override takes precedence over the tier
+    s.client.set_pool_platform_fee(&pool_id, &Some(300));
+    assert_eq!(s.client.get_pool_platform_fee(&pool_id), 300);
+}
+
+#[test]
+fn test_tier_expires_and_is_revoked() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    let creator = s.funded(100);
+    let verifier = Address::generate(&env);
+    s.client.grant_role(&Role::Verifier, &verifier);
+
+    s.client
+        .assign_fee_tier(&verifier, &creator, &FeeTier::Waived, &(START + 100));
+    assert_eq!(
+        s.client.get_fee_tier(&creator),
+        Some(CreatorFeeTier {
+            tier: FeeTier::Waived,
+            assigned_by: verifier.clone(),
+            expires_at: START + 100,
+        })
+    );
+
+    env.ledger().set_timestamp(START + 100);
+    assert_eq!(s.client.get_fee_tier(&creator), None);
+    s.create_campaign(&creator);
+    assert_eq!(s.balance(&creator), 0);
+
+    // A lapsed tier can still be cleared
+    s.client.revoke_fee_tier(&verifier, &creator);
+    let event = FeeTierRevoked {
+        admin: verifier.clone(),
+        creator: creator.clone(),
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        contract_events(&env, &s.client.address),
+        vec![
+            &env,
+            (
+                s.client.address.clone(),
+                event.topics(&env),
+                event.data(&env)
+            ),
+        ]
+    );
+    assert_eq!(
+        s.client.try_revoke_fee_tier(&verifier, &creator),
+        Err(Ok(CrowdfundingError::NotFound))
+    );
+}
+
+#[test]
+fn test_council_assigns_fee_tiers() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    s.client.grant_role(&Role::Verifier, &verifier);
+    let member = Address::generate(&env);
+    s.client.set_council(&MultiSigConfig {
+        required_signatures: 1,
+        signers: Vec::from_array(&env, [member.clone()]),
+    });
+
+    for caller in [&s.admin, &verifier] {
+        assert_eq!(
+            s.client
+                .try_assign_fee_tier(caller, &creator, &FeeTier::Waived, &0),
+            Err(Ok(CrowdfundingError::Unauthorized))
+        );
+    }
+    let proposal_id = s.client.propose_council_action(
+        &member,
+        &CouncilAction::AssignFeeTier(creator.clone(), FeeTier::Discount(500), 0),
+    );
+    s.client.execute_council_action(&member, &proposal_id);
+    assert_eq!(
+        s.client.get_fee_tier(&creator),
+        Some(CreatorFeeTier {
+            tier: FeeTier::Discount(500),
+            assigned_by: member,
+            expires_at: 0,
+        })
+    );
+}
+
+#[test]
+fn test_fee_tier_validation() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    let creator = Address::generate(&env);
+
+    assert_eq!(
+        s.client
+            .try_assign_fee_tier(&s.admin, &creator, &FeeTier::Discount(10_001), &0),
+        Err(Ok(CrowdfundingError::InvalidFee))
+    );
+    assert_eq!(
+        s.client
+            .try_assign_fee_tier(&s.admin, &creator, &FeeTier::FlatFee(-1), &0),
+        Err(Ok(CrowdfundingError::InvalidFee))
+    );
+    assert_eq!(
+        s.client
+            .try_assign_fee_tier(&s.admin, &creator, &FeeTier::Waived, &START),
+        Err(Ok(CrowdfundingError::InvalidDeadline))
+    );
+
+    let treasury = Address::generate(&env);
+    s.client.grant_role(&Role::Treasury, &treasury);
+    assert_eq!(
+        s.client
+            .try_assign_fee_tier(&treasury, &creator, &FeeTier::Waived, &0),
+        Err(Ok(CrowdfundingError::Unauthorized))
+    );
+    s.client
+        .assign_fee_tier(&s.admin, &creator, &FeeTier::Waived, &0);
+    assert_eq!(
+        s.client.try_revoke_fee_tier(&treasury, &creator),
+        Err(Ok(CrowdfundingError::Unauthorized))
+    );
+}
@@ -0,0 +1,170 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, vec, Address, BytesN, Env, Event, String, Vec};
+
+use crate::{
+    base::{
+        errors::CrowdfundingError,
+        events::{FeesWithdrawn, EVENT_SCHEMA_VERSION},
+        types::{CouncilAction, MultiSigConfig, Role},
+    },
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+    test::contract_events,
+};
+
+const FEE: i128 = 100;
+
+struct Setup<'a> {
+    client: CrowdfundingContractClient<'a>,
+    admin: Address,
+    token: Address,
+}
+
+/// Initialize with a creation fee of `FEE` and collect it from `campaigns`
+/// campaigns.
+fn setup_test(env: &Env, campaigns: u8) -> Setup<'_> {
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    client.initialize(&admin, &token, &FEE);
+
+    let creator = Address::generate(env);
+    token::StellarAssetClient::new(env, &token).mint(&creator, &(FEE * campaigns as i128));
+    for seed in 0..campaigns {
+        client.create_campaign(
+            &BytesN::from_array(env, &[seed; 32]),
+            &String::from_str(env, "Campaign"),
+            &creator,
+            &1_000,
+            &(env.ledger().timestamp() + 86_400),
+            &token,
+            &FEE,
+        );
+    }
+
+    Setup {
+        client,
+        admin,
+        token,
+    }
+}
+
+#[test]
+fn test_creation_fees_accrue_to_treasury() {
+    let env = Env::default();
+    let s = setup_test(&env, 3);
+
+    assert_eq!(s.client.get_fee_balance(&s.token), 3 * FEE);
+    assert_eq!(s.client.get_escrowed_balance(&s.token), 0);
+    // Collected fees cannot be taken through an emergency withdrawal
+    assert_eq!(s.client.get_withdrawable_balance(&s.token), 0);
+}
+
+#[test]
+fn test_treasury_withdraws_fees() {
+    let env = Env::default();
+    let s = setup_test(&env, 2);
+    let treasury = Address::generate(&env);
+    s.client.grant_role(&Role::Treasury, &treasury);
+    let to = Address::generate(&env);
+
+    s.client.withdraw_fees(&treasury, &s.token, &150, &to);
+    let event = FeesWithdrawn {
+        admin: treasury.clone(),
+        token: s.token.clone(),
+        amount: 150,
+        to: to.clone(),
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        contract_events(&env, &s.client.address),
+        vec![
+            &env,
+            (
+                s.client.address.clone(),
+                event.topics(&env),
+                event.data(&env)
+            )
+        ]
+    );
+    assert_eq!(token::Client::new(&env, &s.token).balance(&to), 150);
+    assert_eq!(s.client.get_fee_balance(&s.token), 2 * FEE - 150);
+
+    // The admin may withdraw as well
+    s.client
+        .withdraw_fees(&s.admin, &s.token, &(2 * FEE - 150), &to);
+    assert_eq!(s.client.get_fee_balance(&s.token), 0);
+}
+
+#[test]
+fn test_withdraw_fees_validation() {
+    let env = Env::default();
+    let s = setup_test(&env, 1);
+    let to = Address::generate(&env);
+
+    assert_eq!(
+        s.client.try_withdraw_fees(&s.admin, &s.token, &0, &to),
+        Err(Ok(CrowdfundingError::InvalidAmount))
+    );
+    assert_eq!(
+        s.client
+            .try_withdraw_fees(&s.admin, &s.token, &(FEE + 1), &to),
+        Err(Ok(CrowdfundingError::InsufficientBalance))
+    );
+    let other_token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    assert_eq!(
+        s.client.try_withdraw_fees(&s.admin, &other_token, &1, &to),
+        Err(Ok(CrowdfundingError::InsufficientBalance))
+    );
+
+    let fee_manager = Address::generate(&env);
+    s.client.grant_role(&Role::FeeManager, &fee_manager);
+    assert_eq!(
+        s.client.try_withdraw_fees(&fee_manager, &s.token, &1, &to),
+        Err(Ok(CrowdfundingError::Unauthorized))
+    );
+    assert_eq!(s.client.get_fee_balance(&s.token), FEE);
+}
+
+#[test]
+fn test_council_withdraws_fees() {
+    let env = Env::default();
+    let s = setup_test(&env, 1);
+    let to = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    s.client.grant_role(&Role::Treasury, &treasury);
+    let members = [Address::generate(&env), Address::generate(&env)];
+    s.client.set_council(&MultiSigConfig {
+        required_signatures: 2,
+        signers: Vec::from_slice(&env, &members),
+    });
+
+    // Neither the admin nor a treasurer can withdraw alone
+    for caller in [&s.admin, &treasury] {
+        assert_eq!(
+            s.client.try_withdraw_fees(caller, &s.token, &FEE, &to),
+            Err(Ok(CrowdfundingError::Unauthorized))
+        );
+    }
+
+    let proposal_id = s.client.propose_council_action(
+        &members[0],
+        &CouncilAction::WithdrawFees(s.token.clone(), FEE, to.clone()),
+    );
+    assert_eq!(
+        s.client
+            .try_execute_council_action(&members[0], &proposal_id),
+        Err(Ok(CrowdfundingError::InsufficientApprovals))
+    );
+    s.client.approve_council_action(&members[1], &proposal_id);
+    s.client.execute_council_action(&members[1], &proposal_id);
+    assert_eq!(token::Client::new(&env, &s.token).balance(&to), FEE);
+    assert_eq!(s.client.get_fee_balance(&s.token), 0);
+}
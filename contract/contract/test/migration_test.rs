@@ -0,0 +1,478 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Events as _},
+    token::StellarAssetClient,
+    vec, Address, BytesN, Env, Event, String, Vec,
+};
+
+use crate::{
+    base::{
+        errors::CrowdfundingError,
+        events::{StorageMigrated, EVENT_SCHEMA_VERSION},
+        types::{
+            CampaignDetailsV1, CampaignMetadata, CampaignMetrics, CampaignRecord, CampaignStatus,
+            MigrationCursor, PoolConfig, PoolContribution, PoolContributionRecord, PoolMetrics,
+            PoolRecord, PoolState, StorageKey, STORAGE_SCHEMA_VERSION,
+        },
+    },
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+
+struct Legacy {
+    campaigns: Vec<CampaignDetailsV1>,
+    pools: Vec<PoolConfig>,
+    token: Address,
+}
+
+fn setup_test(env: &Env) -> (CrowdfundingContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token_contract = env.register_stellar_asset_contract_v2(token_admin.clone());
+    client.initialize(&admin, &token_contract.address(), &0);
+
+    (client, admin, token_contract.address())
+}
+
+/// Write `campaigns` campaigns and `pools` pools the way the contract stored
+/// them before schema version 1, as bare structs, and forget the version.
+fn seed_legacy(
+    env: &Env,
+    client: &CrowdfundingContractClient,
+    campaigns: u8,
+    pools: u64,
+) -> Legacy {
+    let token = client.get_crowdfunding_token();
+    let mut legacy = Legacy {
+        campaigns: Vec::new(env),
+        pools: Vec::new(env),
+        token: token.clone(),
+    };
+
+    env.as_contract(&client.address, || {
+        let storage = env.storage().instance();
+        let mut ids = Vec::<BytesN<32>>::new(env);
+        for n in 0..campaigns {
+            let campaign = CampaignDetailsV1 {
+                id: BytesN::from_array(env, &[n + 1; 32]),
+                title: String::from_str(env, "Legacy campaign"),
+                creator: Address::generate(env),
+                goal: 1_000,
+                deadline: 10_000,
+                total_raised: n as i128,
+                token_address: token.clone(),
+            };
+            storage.set(&(campaign.id.clone(),), &campaign);
+            storage.set(
+                &StorageKey::CampaignMetrics(campaign.id.clone()),
+                &CampaignMetrics::new(),
+            );
+            ids.push_back(campaign.id.clone());
+            legacy.campaigns.push_back(campaign);
+        }
+        storage.set(&StorageKey::AllCampaigns, &ids);
+
+        for pool_id in 1..=pools {
+            let pool = PoolConfig {
+                name: String::from_str(env, "Legacy pool"),
+                description: String::from_str(env, "Stored before versioning"),
+                target_amount: 5_000,
+                is_private: false,
+                duration: 60 * 60 * 24,
+                created_at: pool_id,
+            };
+            storage.set(&StorageKey::Pool(pool_id), &pool);
+            storage.set(&StorageKey::PoolState(pool_id), &PoolState::Active);
+            storage.set(&StorageKey::PoolMetrics(pool_id), &PoolMetrics::new());
+            legacy.pools.push_back(pool);
+        }
+        storage.set(&StorageKey::NextPoolId, &(pools + 1));
+        storage.remove(&StorageKey::SchemaVersion);
+    });
+
+    legacy
+}
+
+#[test]
+fn test_fresh_deployment_needs_no_migration() {
+    let env = Env::default();
+    let (client, _, _) = setup_test(&env);
+
+    assert_eq!(client.get_schema_version(), STORAGE_SCHEMA_VERSION);
+    assert!(client.migrate(&10));
+    assert_eq!(env.events().all().len(), 0);
+    assert_eq!(client.get_migration_cursor(), None);
+}
+
+#[test]
+fn test_getters_read_legacy_records() {
+    let env = Env::default();
+    let (client, _, _) = setup_test(&env);
+    let legacy = seed_legacy(&env, &client, 2, 2);
+
+    assert_eq!(client.get_schema_version(), 0);
+    for campaign in legacy.campaigns.iter() {
+        assert_eq!(
+            client.get_campaign(&campaign.id),
+            campaign.clone().upgrade().upgrade()
+        );
+        assert_eq!(client.get_total_raised(&campaign.id), campaign.total_raised);
+    }
+    assert_eq!(client.get_pool(&1), Some(legacy.pools.get_unchecked(0)));
+    assert_eq!(client.get_pool(&2), Some(legacy.pools.get_unchecked(1)));
+}
+
+#[test]
+fn test_migrate_in_batches() {
+    let env = Env::default();
+    let (client, admin, _) = setup_test(&env);
+    let legacy = seed_legacy(&env, &client, 3, 2);
+
+    assert!(!client.migrate(&2));
+    assert_eq!(
+        client.get_migration_cursor(),
+        Some(MigrationCursor {
+            campaign_index: 2,
+            next_pool_id: 1,
+            next_contribution_pool_id: 1,
+        })
+    );
+    assert_eq!(client.get_schema_version(), 0);
+
+    assert!(!client.migrate(&2));
+    assert_eq!(
+        client.get_migration_cursor(),
+        Some(MigrationCursor {
+            campaign_index: 3,
+            next_pool_id: 2,
+            next_contribution_pool_id: 1,
+        })
+    );
+
+    // Neither pool has contributors, so their contributions need no listing
+    assert!(!client.migrate(&2));
+    assert_eq!(
+        client.get_migration_cursor(),
+        Some(MigrationCursor {
+            campaign_index: 3,
+            next_pool_id: 3,
+            next_contribution_pool_id: 2,
+        })
+    );
+    assert_eq!(client.get_schema_version(), 0);
+
+    assert!(client.migrate(&2));
+    assert_eq!(env.auths()[0].0, admin);
+    let event = StorageMigrated {
+        admin,
+        from_version: 0,
+        to_version: STORAGE_SCHEMA_VERSION,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (client.address.clone(), event.topics(&env), event.data(&env))
+        ]
+    );
+    assert_eq!(client.get_schema_version(), STORAGE_SCHEMA_VERSION);
+    assert_eq!(client.get_migration_cursor(), None);
+
+    // Every record is now stored in the versioned layout
+    env.as_contract(&client.address, || {
+        let storage = env.storage().instance();
+        for campaign in legacy.campaigns.iter() {
+            let record: CampaignRecord = storage.get(&(campaign.id.clone(),)).unwrap();
+            assert_eq!(record, CampaignRecord::V3(campaign.upgrade().upgrade()));
+        }
+        for (pool, pool_id) in legacy.pools.iter().zip(1u64..) {
+            let record: PoolRecord = storage.get(&StorageKey::Pool(pool_id)).unwrap();
+            assert_eq!(record, PoolRecord::V1(pool));
+        }
+    });
+    for campaign in legacy.campaigns.iter() {
+        assert_eq!(
+            client.get_campaign(&campaign.id),
+            campaign.upgrade().upgrade()
+        );
+    }
+    assert_eq!(client.get_pool(&2), Some(legacy.pools.get_unchecked(1)));
+
+    // Once complete, further calls are no-ops
+    assert!(client.migrate(&1));
+    assert_eq!(env.events().all().len(), 0);
+}
+
+#[test]
+fn test_v1_campaign_records_gain_status() {
+    let env = Env::default();
+    let (client, admin, _) = setup_test(&env);
+    let legacy = seed_legacy(&env, &client, 1, 0);
+    let campaign = legacy.campaigns.get_unchecked(0);
+
+    // Rewrite the campaign as schema version 1 stored it
+    env.as_contract(&client.address, || {
+        let storage = env.storage().instance();
+        storage.set(
+            &(campaign.id.clone(),),
+            &CampaignRecord::V1(campaign.clone()),
+        );
+        storage.set(&StorageKey::SchemaVersion, &1u32);
+    });
+    assert_eq!(
+        client.get_campaign(&campaign.id).status,
+        CampaignStatus::Active
+    );
+
+    assert!(client.migrate(&1));
+    let event = StorageMigrated {
+        admin,
+        from_version: 1,
+        to_version: STORAGE_SCHEMA_VERSION,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (client.address.clone(), event.topics(&env), event.data(&env))
+        ]
+    );
+    env.as_contract(&client.address, || {
+        let record: CampaignRecord = env
+            .storage()
+            .instance()
+            .get(&(campaign.id.clone(),))
+            .unwrap();
+        assert_eq!(
+            record,
+            CampaignRecord::V3(campaign.clone().upgrade().upgrade())
+        );
+    });
+}
+
+#[test]
+fn test_v2_campaign_records_gain_metadata() {
+    let env = Env::default();
+    let (client, admin, _) = setup_test(&env);
+    let legacy = seed_legacy(&env, &client, 1, 0);
+    let campaign = legacy.campaigns.get_unchecked(0).upgrade();
+
+    // Rewrite the campaign as schema version 2 stored it
+    env.as_contract(&client.address, || {
+        let storage = env.storage().instance();
+        storage.set(
+            &(campaign.id.clone(),),
+            &CampaignRecord::V2(campaign.clone()),
+        );
+        storage.set(&StorageKey::SchemaVersion, &2u32);
+    });
+    assert_eq!(
+        client.get_campaign(&campaign.id).metadata,
+        CampaignMetadata::empty(&env)
+    );
+
+    assert!(client.migrate(&1));
+    let event = StorageMigrated {
+        admin,
+        from_version: 2,
+        to_version: STORAGE_SCHEMA_VERSION,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (client.address.clone(), event.topics(&env), event.data(&env))
+        ]
+    );
+    env.as_contract(&client.address, || {
+        let record: CampaignRecord = env
+            .storage()
+            .instance()
+            .get(&(campaign.id.clone(),))
+            .unwrap();
+        assert_eq!(record, CampaignRecord::V3(campaign.clone().upgrade()));
+    });
+}
+
+#[test]
+fn test_legacy_pool_contribution_upgraded_on_write() {
+    let env = Env::default();
+    let (client, _, _) = setup_test(&env);
+    let legacy = seed_legacy(&env, &client, 0, 1);
+    let contributor = Address::generate(&env);
+    let key = StorageKey::PoolContribution(1, contributor.clone());
+
+    env.as_contract(&client.address, || {
+        env.storage().instance().set(
+            &key,
+            &PoolContribution {
+                pool_id: 1,
+                contributor: contributor.clone(),
+                amount: 40,
+                asset: legacy.token.clone(),
+            },
+        );
+    });
+
+    StellarAssetClient::new(&env, &legacy.token).mint(&contributor, &10);
+    client.contribute(&1, &contributor, &legacy.token, &10, &false);
+
+    env.as_contract(&client.address, || {
+        let record: PoolContributionRecord = env.storage().instance().get(&key).unwrap();
+        assert_eq!(
+            record,
+            PoolContributionRecord::V1(PoolContribution {
+                pool_id: 1,
+                contributor: contributor.clone(),
+                amount: 50,
+                asset: legacy.token.clone(),
+            })
+        );
+    });
+}
+
+#[test]
+fn test_migrate_rewrites_listed_pool_contributions() {
+    let env = Env::default();
+    let (client, admin, _) = setup_test(&env);
+    let legacy = seed_legacy(&env, &client, 0, 2);
+    let contributors = [Address::generate(&env), Address::generate(&env)];
+    let contributions = [
+        PoolContribution {
+            pool_id: 1,
+            contributor: contributors[0].clone(),
+            amount: 40,
+            asset: legacy.token.clone(),
+        },
+        PoolContribution {
+            pool_id: 1,
+            contributor: contributors[1].clone(),
+            amount: 15,
+            asset: legacy.token.clone(),
+        },
+    ];
+
+    env.as_contract(&client.address, || {
+        let storage = env.storage().instance();
+        for contribution in contributions.iter() {
+            storage.set(
+                &StorageKey::PoolContribution(1, contribution.contributor.clone()),
+                contribution,
+            );
+        }
+        storage.set(
+            &StorageKey::PoolMetrics(1),
+            &PoolMetrics {
+                total_raised: 55,
+                contributor_count: 2,
+                last_donation_at: 0,
+            },
+        );
+    });
+
+    // Nothing to list until pools are rewritten
+    assert_eq!(
+        client.try_migrate_pool_contributions(&1, &vec![&env]),
+        Err(Ok(CrowdfundingError::NotFound))
+    );
+
+    // The pool with contributors holds the migration back
+    assert!(!client.migrate(&10));
+    assert!(!client.migrate(&10));
+    assert_eq!(
+        client.get_migration_cursor(),
+        Some(MigrationCursor {
+            campaign_index: 0,
+            next_pool_id: 3,
+            next_contribution_pool_id: 1,
+        })
+    );
+    assert_eq!(client.get_schema_version(), 0);
+
+    let stranger = Address::generate(&env);
+    let [first, second] = contributors.clone();
+    assert_eq!(
+        client.try_migrate_pool_contributions(&2, &vec![&env]),
+        Err(Ok(CrowdfundingError::NotFound))
+    );
+    assert_eq!(
+        client.try_migrate_pool_contributions(&1, &vec![&env, first.clone()]),
+        Err(Ok(CrowdfundingError::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_migrate_pool_contributions(&1, &vec![&env, first.clone(), first.clone()]),
+        Err(Ok(CrowdfundingError::AlreadyExists))
+    );
+    assert_eq!(
+        client.try_migrate_pool_contributions(
+            &1,
+            &vec![&env, first.clone(), second.clone(), stranger]
+        ),
+        Err(Ok(CrowdfundingError::NotFound))
+    );
+
+    client.migrate_pool_contributions(&1, &vec![&env, first, second]);
+    assert_eq!(env.auths()[0].0, admin);
+    assert_eq!(
+        client.get_migration_cursor(),
+        Some(MigrationCursor {
+            campaign_index: 0,
+            next_pool_id: 3,
+            next_contribution_pool_id: 2,
+        })
+    );
+    assert_eq!(client.get_schema_version(), 0);
+
+    assert!(client.migrate(&10));
+    assert_eq!(client.get_schema_version(), STORAGE_SCHEMA_VERSION);
+    env.as_contract(&client.address, || {
+        for contribution in contributions.iter() {
+            let record: PoolContributionRecord = env
+                .storage()
+                .instance()
+                .get(&StorageKey::PoolContribution(
+                    1,
+                    contribution.contributor.clone(),
+                ))
+                .unwrap();
+            assert_eq!(record, PoolContributionRecord::V1(contribution.clone()));
+        }
+    });
+}
+
+#[test]
+fn test_migrate_validation() {
+    let env = Env::default();
+    let (client, _, _) = setup_test(&env);
+    seed_legacy(&env, &client, 1, 0);
+
+    assert_eq!(
+        client.try_migrate(&0),
+        Err(Ok(CrowdfundingError::InvalidAmount))
+    );
+
+    let uninitialized =
+        CrowdfundingContractClient::new(&env, &env.register(CrowdfundingContract, ()));
+    assert_eq!(
+        uninitialized.try_migrate(&1),
+        Err(Ok(CrowdfundingError::NotInitialized))
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_migrate_requires_admin_auth() {
+    let env = Env::default();
+    let (client, _, _) = setup_test(&env);
+    seed_legacy(&env, &client, 1, 0);
+    env.mock_auths(&[]);
+
+    client.migrate(&1);
+}
@@ -1,3 +1,36 @@
+use soroban_sdk::{testutils::Events as _, Address, Env, Val, Vec};
+
+mod accepted_token_test;
+mod admin_transfer_test;
+mod campaign_amendment_test;
+mod campaign_cancel_test;
+mod campaign_details_test;
 mod close_pool_test;
+mod council_test;
 mod create_pool;
 mod crowdfunding_test;
+mod emergency_withdraw_test;
+mod entity_fee_test;
+mod escape_hatch_test;
+mod events_test;
+mod feature_pause_test;
+mod fee_tier_test;
+mod fee_treasury_test;
+mod migration_test;
+mod ownership_transfer_test;
+mod parameter_timelock_test;
+mod platform_fee_test;
+mod roles_test;
+mod upgrade_test;
+
+/// Events emitted by `contract_id` in the last invocation, leaving out those
+/// of the token contracts it called.
+fn contract_events(env: &Env, contract_id: &Address) -> Vec<(Address, Vec<Val>, Val)> {
+    let mut events = Vec::new(env);
+    for event in env.events().all().iter() {
+        if &event.0 == contract_id {
+            events.push_back(event);
+        }
+    }
+    events
+}
@@ -0,0 +1,315 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, MockAuth, MockAuthInvoke},
+    vec, Address, BytesN, Env, Event, IntoVal, String,
+};
+
+use crate::{
+    base::{
+        errors::CrowdfundingError,
+        events::{
+            CampaignOwnershipProposed, CampaignOwnershipTransferred, CampaignTransferCancelled,
+            PoolOwnershipProposed, PoolOwnershipTransferred, PoolTransferCancelled,
+            EVENT_SCHEMA_VERSION,
+        },
+        types::{PoolConfig, StorageKey},
+    },
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+    test::contract_events,
+};
+
+struct Setup<'a> {
+    env: &'a Env,
+    client: CrowdfundingContractClient<'a>,
+    creator: Address,
+    campaign_id: BytesN<32>,
+    pool_id: u64,
+}
+
+fn setup_test(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    client.initialize(&admin, &token, &0);
+
+    let creator = Address::generate(env);
+    let campaign_id = BytesN::from_array(env, &[1; 32]);
+    client.create_campaign(
+        &campaign_id,
+        &String::from_str(env, "Campaign"),
+        &creator,
+        &10_000,
+        &86_400,
+        &token,
+        &0,
+    );
+    let pool_id = client.create_pool(
+        &creator,
+        &PoolConfig {
+            name: String::from_str(env, "Pool"),
+            description: String::from_str(env, "Description"),
+            target_amount: 10_000,
+            is_private: false,
+            duration: 86_400,
+            created_at: 0,
+        },
+        &0,
+    );
+
+    Setup {
+        env,
+        client,
+        creator,
+        campaign_id,
+        pool_id,
+    }
+}
+
+#[test]
+fn test_campaign_ownership_transfer() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    let new_owner = Address::generate(&env);
+
+    s.client
+        .transfer_campaign_ownership(&s.campaign_id, &new_owner);
+    assert_eq!(env.auths()[0].0, s.creator);
+    let event = CampaignOwnershipProposed {
+        campaign_id: s.campaign_id.clone(),
+        owner: s.creator.clone(),
+        proposed_owner: new_owner.clone(),
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        contract_events(&env, &s.client.address),
+        vec![
+            &env,
+            (
+                s.client.address.clone(),
+                event.topics(&env),
+                event.data(&env)
+            ),
+        ]
+    );
+    assert_eq!(
+        s.client.get_pending_campaign_owner(&s.campaign_id),
+        Some(new_owner.clone())
+    );
+    assert_eq!(s.client.get_campaign(&s.campaign_id).creator, s.creator);
+
+    s.client.accept_campaign_ownership(&s.campaign_id);
+    assert_eq!(env.auths()[0].0, new_owner);
+    let event = CampaignOwnershipTransferred {
+        campaign_id: s.campaign_id.clone(),
+        new_owner: new_owner.clone(),
+        previous_owner: s.creator.clone(),
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        contract_events(&env, &s.client.address),
+        vec![
+            &env,
+            (
+                s.client.address.clone(),
+                event.topics(&env),
+                event.data(&env)
+            ),
+        ]
+    );
+    assert_eq!(s.client.get_campaign(&s.campaign_id).creator, new_owner);
+    assert_eq!(s.client.get_pending_campaign_owner(&s.campaign_id), None);
+
+    // Creator-only calls now need the new owner
+    s.client.update_campaign_goal(&s.campaign_id, &20_000);
+    assert_eq!(env.auths()[0].0, new_owner);
+}
+
+#[test]
+fn test_pool_ownership_transfer() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    let new_owner = Address::generate(&env);
+    assert_eq!(
+        s.client.get_pool_creator(&s.pool_id),
+        Some(s.creator.clone())
+    );
+
+    s.client.transfer_pool_ownership(&s.pool_id, &new_owner);
+    assert_eq!(env.auths()[0].0, s.creator);
+    let event = PoolOwnershipProposed {
+        pool_id: s.pool_id,
+        owner: s.creator.clone(),
+        proposed_owner: new_owner.clone(),
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        contract_events(&env, &s.client.address),
+        vec![
+            &env,
+            (
+                s.client.address.clone(),
+                event.topics(&env),
+                event.data(&env)
+            ),
+        ]
+    );
+    assert_eq!(
+        s.client.get_pending_pool_owner(&s.pool_id),
+        Some(new_owner.clone())
+    );
+
+    s.client.accept_pool_ownership(&s.pool_id);
+    assert_eq!(env.auths()[0].0, new_owner);
+    let event = PoolOwnershipTransferred {
+        pool_id: s.pool_id,
+        new_owner: new_owner.clone(),
+        previous_owner: s.creator.clone(),
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        contract_events(&env, &s.client.address),
+        vec![
+            &env,
+            (
+                s.client.address.clone(),
+                event.topics(&env),
+                event.data(&env)
+            ),
+        ]
+    );
+    assert_eq!(s.client.get_pool_creator(&s.pool_id), Some(new_owner));
+    assert_eq!(s.client.get_pending_pool_owner(&s.pool_id), None);
+}
+
+#[test]
+fn test_transfers_can_be_cancelled() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    let new_owner = Address::generate(&env);
+
+    s.client
+        .transfer_campaign_ownership(&s.campaign_id, &new_owner);
+    s.client.cancel_campaign_transfer(&s.campaign_id);
+    let event = CampaignTransferCancelled {
+        campaign_id: s.campaign_id.clone(),
+        owner: s.creator.clone(),
+        proposed_owner: new_owner.clone(),
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        contract_events(&env, &s.client.address),
+        vec![
+            &env,
+            (
+                s.client.address.clone(),
+                event.topics(&env),
+                event.data(&env)
+            ),
+        ]
+    );
+    assert_eq!(
+        s.client.try_accept_campaign_ownership(&s.campaign_id),
+        Err(Ok(CrowdfundingError::NotFound))
+    );
+
+    s.client.transfer_pool_ownership(&s.pool_id, &new_owner);
+    s.client.cancel_pool_transfer(&s.pool_id);
+    let event = PoolTransferCancelled {
+        pool_id: s.pool_id,
+        owner: s.creator.clone(),
+        proposed_owner: new_owner.clone(),
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        contract_events(&env, &s.client.address),
+        vec![
+            &env,
+            (
+                s.client.address.clone(),
+                event.topics(&env),
+                event.data(&env)
+            ),
+        ]
+    );
+    assert_eq!(
+        s.client.try_accept_pool_ownership(&s.pool_id),
+        Err(Ok(CrowdfundingError::NotFound))
+    );
+    assert_eq!(
+        s.client.try_cancel_pool_transfer(&s.pool_id),
+        Err(Ok(CrowdfundingError::NotFound))
+    );
+}
+
+#[test]
+fn test_transfer_validation() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    let new_owner = Address::generate(&env);
+
+    assert_eq!(
+        s.client
+            .try_transfer_campaign_ownership(&BytesN::from_array(&env, &[2; 32]), &new_owner),
+        Err(Ok(CrowdfundingError::CampaignNotFound))
+    );
+    assert_eq!(
+        s.client
+            .try_transfer_pool_ownership(&(s.pool_id + 1), &new_owner),
+        Err(Ok(CrowdfundingError::PoolNotFound))
+    );
+    assert_eq!(
+        s.client.try_cancel_campaign_transfer(&s.campaign_id),
+        Err(Ok(CrowdfundingError::NotFound))
+    );
+
+    // Owners cannot hand their campaign or pool to themselves
+    assert_eq!(
+        s.client
+            .try_transfer_campaign_ownership(&s.campaign_id, &s.creator),
+        Err(Ok(CrowdfundingError::AlreadyExists))
+    );
+    assert_eq!(
+        s.client.try_transfer_pool_ownership(&s.pool_id, &s.creator),
+        Err(Ok(CrowdfundingError::AlreadyExists))
+    );
+
+    // Pools created before creators were recorded have no owner to transfer
+    env.as_contract(&s.client.address, || {
+        env.storage()
+            .instance()
+            .remove(&StorageKey::PoolCreator(s.pool_id));
+    });
+    assert_eq!(s.client.get_pool_creator(&s.pool_id), None);
+    assert_eq!(
+        s.client.try_transfer_pool_ownership(&s.pool_id, &new_owner),
+        Err(Ok(CrowdfundingError::Unauthorized))
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_accept_requires_new_owner_auth() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    let new_owner = Address::generate(&env);
+    s.client
+        .transfer_campaign_ownership(&s.campaign_id, &new_owner);
+
+    // Only the current creator signs
+    env.mock_auths(&[MockAuth {
+        address: &s.creator,
+        invoke: &MockAuthInvoke {
+            contract: &s.client.address,
+            fn_name: "accept_campaign_ownership",
+            args: (s.campaign_id.clone(),).into_val(s.env),
+            sub_invokes: &[],
+        },
+    }]);
+    s.client.accept_campaign_ownership(&s.campaign_id);
+}
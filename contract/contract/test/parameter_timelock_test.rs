@@ -0,0 +1,233 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Events as _, Ledger},
+    token, vec, Address, BytesN, Env, Event, String,
+};
+
+use crate::{
+    base::{
+        errors::CrowdfundingError,
+        events::{
+            CreationFeePaid, CreationFeeQueued, CreationFeeSet, CrowdfundingTokenQueued,
+            EVENT_SCHEMA_VERSION,
+        },
+        types::{PendingParameters, PARAMETER_CHANGE_DELAY},
+    },
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+    test::contract_events,
+};
+
+struct Setup<'a> {
+    env: &'a Env,
+    client: CrowdfundingContractClient<'a>,
+    admin: Address,
+    token: Address,
+}
+
+/// Initialize with a creation fee of 100 at timestamp 1000.
+fn setup_test(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    client.initialize(&admin, &token, &100);
+
+    Setup {
+        env,
+        client,
+        admin,
+        token,
+    }
+}
+
+impl Setup<'_> {
+    /// A creator holding enough of the platform token for any fee used here.
+    fn funded_creator(&self) -> Address {
+        let creator = Address::generate(self.env);
+        token::StellarAssetClient::new(self.env, &self.token).mint(&creator, &10_000);
+        creator
+    }
+
+    fn try_create(
+        &self,
+        seed: u8,
+        creator: &Address,
+        max_fee: i128,
+    ) -> Result<(), CrowdfundingError> {
+        let mut id = [0u8; 32];
+        id[0] = seed;
+        self.client
+            .try_create_campaign(
+                &BytesN::from_array(self.env, &id),
+                &String::from_str(self.env, "Campaign"),
+                creator,
+                &1_000,
+                &(self.env.ledger().timestamp() + 86_400),
+                &self.token,
+                &max_fee,
+            )
+            .map(|_| ())
+            .map_err(|err| err.unwrap())
+    }
+}
+
+#[test]
+fn test_fee_change_is_queued() {
+    let env = Env::default();
+    let s = setup_test(&env);
+
+    s.client.set_creation_fee(&s.admin, &300);
+    let effective_at = 1_000 + PARAMETER_CHANGE_DELAY;
+    let event = CreationFeeQueued {
+        admin: s.admin.clone(),
+        fee: 300,
+        effective_at,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                s.client.address.clone(),
+                event.topics(&env),
+                event.data(&env)
+            )
+        ]
+    );
+    assert_eq!(
+        s.client.get_pending_parameters(),
+        Some(PendingParameters {
+            creation_fee: Some(300),
+            crowdfunding_token: None,
+            queued_by: s.admin.clone(),
+            effective_at,
+        })
+    );
+
+    // The old fee is still charged until the delay has passed
+    env.ledger().set_timestamp(effective_at - 1);
+    assert_eq!(s.client.get_creation_fee(), 100);
+    let creator = s.funded_creator();
+    s.try_create(1, &creator, 100).unwrap();
+
+    env.ledger().set_timestamp(effective_at);
+    assert_eq!(s.client.get_creation_fee(), 300);
+    assert_eq!(s.client.get_pending_parameters(), None);
+}
+
+#[test]
+fn test_due_change_is_applied_on_creation() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    let creator = s.funded_creator();
+
+    s.client.set_creation_fee(&s.admin, &300);
+    env.ledger().set_timestamp(1_000 + PARAMETER_CHANGE_DELAY);
+    s.try_create(1, &creator, 300).unwrap();
+
+    // The change is written, and announced, by the first call that needs it
+    let set = CreationFeeSet {
+        admin: s.admin.clone(),
+        fee: 300,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    let paid = CreationFeePaid {
+        creator: creator.clone(),
+        token: s.token.clone(),
+        amount: 300,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        contract_events(&env, &s.client.address).slice(0..2),
+        vec![
+            &env,
+            (s.client.address.clone(), set.topics(&env), set.data(&env)),
+            (s.client.address.clone(), paid.topics(&env), paid.data(&env)),
+        ]
+    );
+    assert_eq!(
+        token::Client::new(&env, &s.token).balance(&creator),
+        10_000 - 300
+    );
+}
+
+#[test]
+fn test_max_fee_bounds_the_creation_fee() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    let creator = s.funded_creator();
+
+    assert_eq!(
+        s.try_create(1, &creator, 99),
+        Err(CrowdfundingError::InvalidFee)
+    );
+
+    // A creator who signed against the old fee is not charged the new one
+    s.client.set_creation_fee(&s.admin, &300);
+    env.ledger().set_timestamp(1_000 + PARAMETER_CHANGE_DELAY);
+    assert_eq!(
+        s.try_create(1, &creator, 100),
+        Err(CrowdfundingError::InvalidFee)
+    );
+    assert_eq!(token::Client::new(&env, &s.token).balance(&creator), 10_000);
+    s.try_create(1, &creator, 300).unwrap();
+}
+
+#[test]
+fn test_queuing_again_restarts_the_delay() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    let new_token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    s.client.set_accepted_token(&s.admin, &new_token, &0);
+
+    s.client.set_creation_fee(&s.admin, &300);
+    env.ledger().set_timestamp(2_000);
+    s.client.set_crowdfunding_token(&s.admin, &new_token);
+    let event = CrowdfundingTokenQueued {
+        admin: s.admin.clone(),
+        token: new_token.clone(),
+        effective_at: 2_000 + PARAMETER_CHANGE_DELAY,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                s.client.address.clone(),
+                event.topics(&env),
+                event.data(&env)
+            )
+        ]
+    );
+
+    // Both changes now wait for the later effective time
+    env.ledger().set_timestamp(1_000 + PARAMETER_CHANGE_DELAY);
+    assert_eq!(s.client.get_creation_fee(), 100);
+    assert_eq!(s.client.get_crowdfunding_token(), s.token);
+
+    env.ledger().set_timestamp(2_000 + PARAMETER_CHANGE_DELAY);
+    assert_eq!(s.client.get_creation_fee(), 300);
+    assert_eq!(s.client.get_crowdfunding_token(), new_token);
+}
+
+#[test]
+fn test_negative_fee_is_rejected_before_queuing() {
+    let env = Env::default();
+    let s = setup_test(&env);
+
+    assert_eq!(
+        s.client.try_set_creation_fee(&s.admin, &-1),
+        Err(Ok(CrowdfundingError::InvalidFee))
+    );
+    assert_eq!(s.client.get_pending_parameters(), None);
+}
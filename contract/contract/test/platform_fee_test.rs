@@ -0,0 +1,275 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, BytesN, Env, Event, String,
+};
+
+use crate::{
+    base::{
+        errors::CrowdfundingError,
+        events::{
+            PlatformFeePaid, PlatformFeeQueued, PlatformFeeSet, PoolPlatformFeeSet,
+            EVENT_SCHEMA_VERSION,
+        },
+        types::{ContributionQuote, PendingPlatformFee, PoolConfig, Role, PARAMETER_CHANGE_DELAY},
+    },
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+    test::contract_events,
+};
+
+/// Platform fee rate queued by `setup_test` (2.5%).
+const FEE_BPS: u32 = 250;
+/// When the rate queued by `setup_test` takes effect.
+const START: u64 = 1_000 + PARAMETER_CHANGE_DELAY;
+const POOL_DURATION: u64 = 86_400;
+
+struct Setup<'a> {
+    env: &'a Env,
+    client: CrowdfundingContractClient<'a>,
+    admin: Address,
+    token: Address,
+    pool_id: u64,
+}
+
+/// Initialize, queue a platform fee of `FEE_BPS` in effect at `START` and
+/// create a public pool.
+fn setup_test(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    client.initialize(&admin, &token, &0);
+    client.set_platform_fee(&admin, &FEE_BPS);
+    env.ledger().set_timestamp(START);
+
+    let pool_id = client.create_pool(
+        &Address::generate(env),
+        &PoolConfig {
+            name: String::from_str(env, "Pool"),
+            description: String::from_str(env, "Description"),
+            target_amount: 10_000,
+            is_private: false,
+            duration: POOL_DURATION,
+            created_at: 0,
+        },
+        &0,
+    );
+
+    Setup {
+        env,
+        client,
+        admin,
+        token,
+        pool_id,
+    }
+}
+
+impl Setup<'_> {
+    fn funded_contributor(&self, amount: i128) -> Address {
+        let contributor = Address::generate(self.env);
+        token::StellarAssetClient::new(self.env, &self.token).mint(&contributor, &amount);
+        contributor
+    }
+
+    fn balance(&self, account: &Address) -> i128 {
+        token::Client::new(self.env, &self.token).balance(account)
+    }
+}
+
+#[test]
+fn test_contribution_fee_is_kept_out_of_escrow() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    let contributor = s.funded_contributor(1_000);
+
+    assert_eq!(
+        s.client.quote_contribution(&s.pool_id, &1_000),
+        ContributionQuote {
+            fee_bps: FEE_BPS,
+            fee: 25,
+            net: 975,
+        }
+    );
+    s.client
+        .contribute(&s.pool_id, &contributor, &s.token, &1_000, &false);
+    let paid = PlatformFeePaid {
+        payer: contributor.clone(),
+        token: s.token.clone(),
+        amount: 25,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        contract_events(&env, &s.client.address).slice(1..2),
+        vec![
+            &env,
+            (s.client.address.clone(), paid.topics(&env), paid.data(&env)),
+        ]
+    );
+
+    assert_eq!(s.balance(&contributor), 0);
+    assert_eq!(s.client.get_fee_balance(&s.token), 25);
+    assert_eq!(s.client.get_escrowed_balance(&s.token), 975);
+
+    // A refund returns the net contribution and leaves the fee collected
+    env.ledger()
+        .set_timestamp(START + POOL_DURATION + 7 * 24 * 60 * 60);
+    s.client.refund(&s.pool_id, &contributor);
+    assert_eq!(s.balance(&contributor), 975);
+    assert_eq!(s.client.get_escrowed_balance(&s.token), 0);
+    assert_eq!(s.client.get_fee_balance(&s.token), 25);
+}
+
+#[test]
+fn test_donation_pays_the_platform_fee() {
+    let env = Env::default();
+    let s = setup_test(&env);
+    let donor = s.funded_contributor(400);
+    let campaign_id = BytesN::from_array(&env, &[1; 32]);
+    s.client.create_campaign(
+        &campaign_id,
+        &String::from_str(&env, "Campaign"),
+        &Address::generate(&env),
+        &10_000,
+        &(START + 86_400),
+        &s.token,
+        &0,
+    );
+
+    s.client.donate(&campaign_id, &donor, &s.token, &400);
+    assert_eq!(s.client.get_contribution(&campaign_id, &donor), 390);
+    assert_eq!(s.client.get_campaign_balance(&campaign_id), 390);
+    assert_eq!(s.client.get_fee_balance(&s.token), 10);
+    assert_eq!(s.client.get_escrowed_balance(&s.token), 390);
+}
+
+#[test]
+fn test_pool_override() {
+    let env = Env::default();
+    let s = setup_test(&env);
+
+    s.client.set_pool_platform_fee(&s.pool_id, &Some(1_000));
+    let event = PoolPlatformFeeSet {
+        pool_id: s.pool_id,
+        admin: s.admin.clone(),
+        bps: Some(1_000),
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        contract_events(&env, &s.client.address),
+        vec![
+            &env,
+            (
+                s.client.address.clone(),
+                event.topics(&env),
+                event.data(&env)
+            ),
+        ]
+    );
+    assert_eq!(s.client.get_pool_platform_fee(&s.pool_id), 1_000);
+    assert_eq!(s.client.quote_contribution(&s.pool_id, &1_000).fee, 100);
+
+    let contributor = s.funded_contributor(1_000);
+    s.client
+        .contribute(&s.pool_id, &contributor, &s.token, &1_000, &false);
+    assert_eq!(s.client.get_fee_balance(&s.token), 100);
+
+    s.client.set_pool_platform_fee(&s.pool_id, &None);
+    assert_eq!(s.client.get_pool_platform_fee(&s.pool_id), FEE_BPS);
+}
+
+#[test]
+fn test_platform_fee_change_is_queued() {
+    let env = Env::default();
+    let s = setup_test(&env);
+
+    s.client.set_platform_fee(&s.admin, &0);
+    let effective_at = START + PARAMETER_CHANGE_DELAY;
+    // The change queued by the setup is due, so it is written first
+    let set = PlatformFeeSet {
+        admin: s.admin.clone(),
+        bps: FEE_BPS,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    let queued = PlatformFeeQueued {
+        admin: s.admin.clone(),
+        bps: 0,
+        effective_at,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        contract_events(&env, &s.client.address),
+        vec![
+            &env,
+            (s.client.address.clone(), set.topics(&env), set.data(&env)),
+            (
+                s.client.address.clone(),
+                queued.topics(&env),
+                queued.data(&env)
+            ),
+        ]
+    );
+    assert_eq!(
+        s.client.get_pending_platform_fee(),
+        Some(PendingPlatformFee {
+            bps: 0,
+            queued_by: s.admin.clone(),
+            effective_at,
+        })
+    );
+    assert_eq!(s.client.get_platform_fee(), FEE_BPS);
+
+    env.ledger().set_timestamp(effective_at);
+    assert_eq!(s.client.get_platform_fee(), 0);
+    assert_eq!(s.client.get_pending_platform_fee(), None);
+    assert_eq!(s.client.quote_contribution(&s.pool_id, &1_000).net, 1_000);
+}
+
+#[test]
+fn test_platform_fee_validation() {
+    let env = Env::default();
+    let s = setup_test(&env);
+
+    assert_eq!(
+        s.client.try_set_platform_fee(&s.admin, &10_001),
+        Err(Ok(CrowdfundingError::InvalidFee))
+    );
+    assert_eq!(
+        s.client
+            .try_set_pool_platform_fee(&s.pool_id, &Some(10_001)),
+        Err(Ok(CrowdfundingError::InvalidFee))
+    );
+    assert_eq!(
+        s.client.try_set_pool_platform_fee(&99, &Some(0)),
+        Err(Ok(CrowdfundingError::PoolNotFound))
+    );
+    assert_eq!(
+        s.client.try_quote_contribution(&99, &1_000),
+        Err(Ok(CrowdfundingError::PoolNotFound))
+    );
+    assert_eq!(
+        s.client.try_quote_contribution(&s.pool_id, &0),
+        Err(Ok(CrowdfundingError::InvalidAmount))
+    );
+
+    let treasury = Address::generate(&env);
+    s.client.grant_role(&Role::Treasury, &treasury);
+    assert_eq!(
+        s.client.try_set_platform_fee(&treasury, &1),
+        Err(Ok(CrowdfundingError::Unauthorized))
+    );
+
+    // A contribution that would leave nothing after the fee is refused
+    s.client.set_pool_platform_fee(&s.pool_id, &Some(10_000));
+    let contributor = s.funded_contributor(10);
+    assert_eq!(
+        s.client
+            .try_contribute(&s.pool_id, &contributor, &s.token, &10, &false),
+        Err(Ok(CrowdfundingError::InvalidAmount))
+    );
+}
@@ -0,0 +1,231 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Events as _, Ledger},
+    token, vec, Address, Env, Event, String,
+};
+
+use crate::{
+    base::{
+        errors::CrowdfundingError,
+        events::{RoleGranted, RoleRevoked, EVENT_SCHEMA_VERSION},
+        types::{PoolConfig, PoolState, Role},
+    },
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+
+fn setup_test(env: &Env) -> (CrowdfundingContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token_contract = env.register_stellar_asset_contract_v2(token_admin.clone());
+    let token_address = token_contract.address();
+
+    client.initialize(&admin, &token_address, &0);
+
+    (client, admin, token_address)
+}
+
+/// Grant `role` to a fresh address and return it.
+fn grant(env: &Env, client: &CrowdfundingContractClient, role: Role) -> Address {
+    let account = Address::generate(env);
+    client.grant_role(&role, &account);
+    account
+}
+
+#[test]
+fn test_grant_and_revoke_role() {
+    let env = Env::default();
+    let (client, admin, _) = setup_test(&env);
+    let account = Address::generate(&env);
+
+    assert!(!client.has_role(&Role::Pauser, &account));
+    assert_eq!(client.get_roles(&account), vec![&env]);
+
+    client.grant_role(&Role::Pauser, &account);
+    assert_eq!(env.auths()[0].0, admin);
+    let granted = RoleGranted {
+        admin: admin.clone(),
+        account: account.clone(),
+        role: Role::Pauser,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                granted.topics(&env),
+                granted.data(&env)
+            )
+        ]
+    );
+
+    client.grant_role(&Role::Treasury, &account);
+    assert!(client.has_role(&Role::Pauser, &account));
+    assert!(!client.has_role(&Role::FeeManager, &account));
+    assert_eq!(
+        client.get_roles(&account),
+        vec![&env, Role::Pauser, Role::Treasury]
+    );
+
+    client.revoke_role(&Role::Pauser, &account);
+    let revoked = RoleRevoked {
+        admin,
+        account: account.clone(),
+        role: Role::Pauser,
+        version: EVENT_SCHEMA_VERSION,
+    };
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                revoked.topics(&env),
+                revoked.data(&env)
+            )
+        ]
+    );
+    assert_eq!(client.get_roles(&account), vec![&env, Role::Treasury]);
+}
+
+#[test]
+fn test_duplicate_grant_and_missing_revoke() {
+    let env = Env::default();
+    let (client, _, _) = setup_test(&env);
+    let account = grant(&env, &client, Role::Verifier);
+
+    assert_eq!(
+        client.try_grant_role(&Role::Verifier, &account),
+        Err(Ok(CrowdfundingError::AlreadyExists))
+    );
+    assert_eq!(
+        client.try_revoke_role(&Role::Pauser, &account),
+        Err(Ok(CrowdfundingError::NotFound))
+    );
+}
+
+#[test]
+fn test_role_management_uninitialized() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+    let account = Address::generate(&env);
+
+    assert_eq!(
+        client.try_grant_role(&Role::Pauser, &account),
+        Err(Ok(CrowdfundingError::NotInitialized))
+    );
+    assert_eq!(
+        client.try_pause(&account),
+        Err(Ok(CrowdfundingError::NotInitialized))
+    );
+}
+
+#[test]
+fn test_pauser_can_pause_but_not_manage_fees() {
+    let env = Env::default();
+    let (client, _, token) = setup_test(&env);
+    let pauser = grant(&env, &client, Role::Pauser);
+
+    client.pause(&pauser);
+    assert_eq!(env.auths()[0].0, pauser);
+    assert!(client.is_paused());
+    client.unpause(&pauser);
+    assert!(!client.is_paused());
+
+    assert_eq!(
+        client.try_set_creation_fee(&pauser, &10),
+        Err(Ok(CrowdfundingError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_request_emergency_withdraw(&pauser, &token, &1, &None),
+        Err(Ok(CrowdfundingError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_fee_manager_sets_fee_and_token() {
+    let env = Env::default();
+    let (client, _, _) = setup_test(&env);
+    let fee_manager = grant(&env, &client, Role::FeeManager);
+
+    client.set_creation_fee(&fee_manager, &75);
+    let new_token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.set_accepted_token(&fee_manager, &new_token, &0);
+    client.set_crowdfunding_token(&fee_manager, &new_token);
+
+    let pending = client.get_pending_parameters().unwrap();
+    assert_eq!(pending.queued_by, fee_manager);
+    env.ledger().set_timestamp(pending.effective_at);
+    assert_eq!(client.get_creation_fee(), 75);
+    assert_eq!(client.get_crowdfunding_token(), new_token);
+
+    assert_eq!(
+        client.try_pause(&fee_manager),
+        Err(Ok(CrowdfundingError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_treasury_runs_emergency_withdrawal() {
+    let env = Env::default();
+    let (client, _, token) = setup_test(&env);
+    let treasury = grant(&env, &client, Role::Treasury);
+    token::StellarAssetClient::new(&env, &token).mint(&client.address, &1_000);
+
+    client.request_emergency_withdraw(&treasury, &token, &400, &None);
+    env.ledger().with_mut(|li| li.timestamp += 86_400);
+    client.execute_emergency_withdraw(&treasury);
+
+    let token_client = token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&treasury), 400);
+    assert_eq!(token_client.balance(&client.address), 600);
+}
+
+#[test]
+fn test_revoked_role_loses_access() {
+    let env = Env::default();
+    let (client, _, _) = setup_test(&env);
+    let pauser = grant(&env, &client, Role::Pauser);
+
+    client.revoke_role(&Role::Pauser, &pauser);
+    assert_eq!(
+        client.try_pause(&pauser),
+        Err(Ok(CrowdfundingError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_pool_moderator_closes_pool() {
+    let env = Env::default();
+    let (client, _, _) = setup_test(&env);
+    let moderator = grant(&env, &client, Role::PoolModerator);
+    let outsider = Address::generate(&env);
+
+    let config = PoolConfig {
+        name: String::from_str(&env, "Moderated Pool"),
+        description: String::from_str(&env, "Closed by a moderator"),
+        target_amount: 1_000,
+        is_private: false,
+        duration: 86_400,
+        created_at: 0,
+    };
+    let pool_id = client.create_pool(&Address::generate(&env), &config, &0);
+    client.update_pool_state(&pool_id, &PoolState::Disbursed);
+
+    assert_eq!(
+        client.try_close_pool(&pool_id, &outsider),
+        Err(Ok(CrowdfundingError::Unauthorized))
+    );
+    client.close_pool(&pool_id, &moderator);
+    assert!(client.is_closed(&pool_id));
+}
@@ -0,0 +1,253 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Events as _, Ledger},
+    vec, Address, Bytes, BytesN, Env, Event, Vec,
+};
+
+use crate::{
+    base::{
+        errors::CrowdfundingError,
+        events::{
+            UpgradeCancelled, UpgradeExecuted, UpgradeProposed, UpgradeTimelockSet,
+            EVENT_SCHEMA_VERSION,
+        },
+        types::{
+            CouncilAction, MultiSigConfig, CONTRACT_VERSION, DEFAULT_UPGRADE_TIMELOCK,
+            MAX_UPGRADE_TIMELOCK, MIN_UPGRADE_TIMELOCK,
+        },
+    },
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+
+/// Smallest module the host accepts: a wasm header followed by a
+/// `contractenvmetav0` section declaring protocol 23.
+const EMPTY_WASM: &[u8] = &[
+    0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // magic, version
+    0x00, 0x1e, 0x11, // custom section, 30 bytes, 17-byte name
+    b'c', b'o', b'n', b't', b'r', b'a', b'c', b't', b'e', b'n', b'v', b'm', b'e', b't', b'a', b'v',
+    b'0', // name
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00,
+    0x00, // interface version
+];
+
+fn setup_test(env: &Env) -> (CrowdfundingContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token_contract = env.register_stellar_asset_contract_v2(token_admin.clone());
+    client.initialize(&admin, &token_contract.address(), &0);
+
+    (client, admin)
+}
+
+fn upload_wasm(env: &Env) -> BytesN<32> {
+    env.deployer()
+        .upload_contract_wasm(Bytes::from_slice(env, EMPTY_WASM))
+}
+
+/// Assert that the last invocation emitted exactly `event`.
+fn assert_emitted(env: &Env, client: &CrowdfundingContractClient, event: &impl Event) {
+    assert_eq!(
+        env.events().all(),
+        vec![
+            env,
+            (client.address.clone(), event.topics(env), event.data(env))
+        ]
+    );
+}
+
+#[test]
+fn test_version_and_default_timelock() {
+    let env = Env::default();
+    let (client, _) = setup_test(&env);
+
+    assert_eq!(client.version(), CONTRACT_VERSION);
+    assert_eq!(client.get_upgrade_timelock(), DEFAULT_UPGRADE_TIMELOCK);
+    assert_eq!(client.get_pending_upgrade(), None);
+}
+
+#[test]
+fn test_propose_and_execute_upgrade_after_timelock() {
+    let env = Env::default();
+    let (client, admin) = setup_test(&env);
+    let wasm_hash = upload_wasm(&env);
+    env.ledger().set_timestamp(1_000);
+
+    client.propose_upgrade(&wasm_hash);
+    let executable_at = 1_000 + DEFAULT_UPGRADE_TIMELOCK;
+    assert_eq!(env.auths()[0].0, admin);
+    assert_emitted(
+        &env,
+        &client,
+        &UpgradeProposed {
+            admin: admin.clone(),
+            wasm_hash: wasm_hash.clone(),
+            executable_at,
+            version: EVENT_SCHEMA_VERSION,
+        },
+    );
+
+    let pending = client.get_pending_upgrade().unwrap();
+    assert_eq!(pending.wasm_hash, wasm_hash);
+    assert_eq!(pending.proposed_at, 1_000);
+    assert_eq!(pending.executable_at, executable_at);
+
+    env.ledger().set_timestamp(executable_at - 1);
+    assert_eq!(
+        client.try_execute_upgrade(),
+        Err(Ok(CrowdfundingError::TimelockNotPassed))
+    );
+
+    env.ledger().set_timestamp(executable_at);
+    client.execute_upgrade();
+    assert_eq!(env.auths()[0].0, admin);
+    assert_emitted(
+        &env,
+        &client,
+        &UpgradeExecuted {
+            admin,
+            wasm_hash,
+            from_version: CONTRACT_VERSION,
+            version: EVENT_SCHEMA_VERSION,
+        },
+    );
+}
+
+#[test]
+fn test_cancel_upgrade() {
+    let env = Env::default();
+    let (client, admin) = setup_test(&env);
+    let wasm_hash = BytesN::from_array(&env, &[7; 32]);
+
+    client.propose_upgrade(&wasm_hash);
+    assert_eq!(
+        client.try_propose_upgrade(&wasm_hash),
+        Err(Ok(CrowdfundingError::AlreadyExists))
+    );
+
+    client.cancel_upgrade();
+    assert_emitted(
+        &env,
+        &client,
+        &UpgradeCancelled {
+            admin,
+            wasm_hash: wasm_hash.clone(),
+            version: EVENT_SCHEMA_VERSION,
+        },
+    );
+    assert_eq!(client.get_pending_upgrade(), None);
+
+    env.ledger().set_timestamp(DEFAULT_UPGRADE_TIMELOCK);
+    assert_eq!(
+        client.try_execute_upgrade(),
+        Err(Ok(CrowdfundingError::NotFound))
+    );
+    assert_eq!(
+        client.try_cancel_upgrade(),
+        Err(Ok(CrowdfundingError::NotFound))
+    );
+
+    // A fresh proposal is accepted once the previous one is gone
+    client.propose_upgrade(&wasm_hash);
+}
+
+#[test]
+fn test_set_upgrade_timelock() {
+    let env = Env::default();
+    let (client, admin) = setup_test(&env);
+
+    for delay in [MIN_UPGRADE_TIMELOCK - 1, MAX_UPGRADE_TIMELOCK + 1] {
+        assert_eq!(
+            client.try_set_upgrade_timelock(&delay),
+            Err(Ok(CrowdfundingError::InvalidDeadline))
+        );
+    }
+
+    client.set_upgrade_timelock(&MIN_UPGRADE_TIMELOCK);
+    assert_emitted(
+        &env,
+        &client,
+        &UpgradeTimelockSet {
+            admin,
+            delay: MIN_UPGRADE_TIMELOCK,
+            version: EVENT_SCHEMA_VERSION,
+        },
+    );
+    assert_eq!(client.get_upgrade_timelock(), MIN_UPGRADE_TIMELOCK);
+
+    env.ledger().set_timestamp(500);
+    client.propose_upgrade(&BytesN::from_array(&env, &[1; 32]));
+    assert_eq!(
+        client.get_pending_upgrade().unwrap().executable_at,
+        500 + MIN_UPGRADE_TIMELOCK
+    );
+
+    // Changing the delay does not move an already pending upgrade
+    client.set_upgrade_timelock(&DEFAULT_UPGRADE_TIMELOCK);
+    assert_eq!(
+        client.get_pending_upgrade().unwrap().executable_at,
+        500 + MIN_UPGRADE_TIMELOCK
+    );
+}
+
+#[test]
+fn test_council_sets_upgrade_timelock() {
+    let env = Env::default();
+    let (client, _) = setup_test(&env);
+    let member = Address::generate(&env);
+    client.set_council(&MultiSigConfig {
+        required_signatures: 1,
+        signers: Vec::from_array(&env, [member.clone()]),
+    });
+
+    assert_eq!(
+        client.try_set_upgrade_timelock(&MIN_UPGRADE_TIMELOCK),
+        Err(Ok(CrowdfundingError::Unauthorized))
+    );
+    let proposal_id = client.propose_council_action(
+        &member,
+        &CouncilAction::SetUpgradeTimelock(MIN_UPGRADE_TIMELOCK),
+    );
+    client.execute_council_action(&member, &proposal_id);
+    assert_eq!(client.get_upgrade_timelock(), MIN_UPGRADE_TIMELOCK);
+}
+
+#[test]
+#[should_panic]
+fn test_propose_upgrade_requires_admin_auth() {
+    let env = Env::default();
+    let (client, _) = setup_test(&env);
+    env.mock_auths(&[]);
+
+    client.propose_upgrade(&BytesN::from_array(&env, &[1; 32]));
+}
+
+#[test]
+fn test_upgrade_requires_initialization() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+    let wasm_hash = BytesN::from_array(&env, &[1; 32]);
+
+    assert_eq!(
+        client.try_propose_upgrade(&wasm_hash),
+        Err(Ok(CrowdfundingError::NotInitialized))
+    );
+    assert_eq!(
+        client.try_execute_upgrade(),
+        Err(Ok(CrowdfundingError::NotInitialized))
+    );
+    assert_eq!(
+        client.try_cancel_upgrade(),
+        Err(Ok(CrowdfundingError::NotInitialized))
+    );
+    assert_eq!(
+        client.try_set_upgrade_timelock(&MIN_UPGRADE_TIMELOCK),
+        Err(Ok(CrowdfundingError::NotInitialized))
+    );
+}
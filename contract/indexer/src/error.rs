@@ -0,0 +1,60 @@
+use thiserror::Error;
+
+use crate::event::CampaignId;
+
+#[derive(Clone, Debug, Error, Eq, PartialEq)]
+pub enum DecodeError {
+    #[error("event has no name topic")]
+    MissingName,
+    #[error("unknown event `{0}`")]
+    UnknownEvent(String),
+    #[error("event `{0}` does not match its schema")]
+    SchemaMismatch(&'static str),
+    #[error("event `{event}` has a missing or malformed `{field}` field")]
+    InvalidField {
+        event: &'static str,
+        field: &'static str,
+    },
+    #[error("event `{event}` uses unsupported schema version {version}")]
+    UnsupportedVersion { event: &'static str, version: u32 },
+}
+
+#[derive(Clone, Debug, Error, Eq, PartialEq)]
+pub enum ApplyError {
+    #[error("campaign {} was not created before this event", hex(.0))]
+    UnknownCampaign(CampaignId),
+    #[error("campaign {} was created twice", hex(.0))]
+    DuplicateCampaign(CampaignId),
+    #[error("pool {0} was not created before this event")]
+    UnknownPool(u64),
+    #[error("pool {0} was created twice")]
+    DuplicatePool(u64),
+    #[error("refund for pool {pool_id} exceeds the recorded contribution of {contributor}")]
+    RefundExceedsContribution { pool_id: u64, contributor: String },
+    #[error("refund for campaign {} exceeds the recorded donation of {contributor}", hex(.campaign_id))]
+    CampaignRefundExceedsDonation {
+        campaign_id: CampaignId,
+        contributor: String,
+    },
+    #[error("fee withdrawal exceeds the recorded fee balance of {0}")]
+    FeeWithdrawalExceedsBalance(String),
+    #[error("council proposal {0} was not proposed before this event")]
+    UnknownCouncilProposal(u64),
+    #[error("replayed amounts overflowed")]
+    Overflow,
+}
+
+#[derive(Debug, Error)]
+pub enum IndexerError {
+    #[error(transparent)]
+    Decode(#[from] DecodeError),
+    #[error(transparent)]
+    Apply(#[from] ApplyError),
+    #[cfg(feature = "sqlite")]
+    #[error(transparent)]
+    Sqlite(#[from] rusqlite::Error),
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
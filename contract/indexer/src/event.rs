@@ -0,0 +1,1336 @@
+use std::collections::BTreeSet;
+
+use stellar_xdr::curr::{ContractEvent, ContractEventBody, ContractEventType, ScMap, ScVal};
+
+use crate::error::DecodeError;
+
+/// Event payload layout version understood by this decoder.
+pub const SUPPORTED_SCHEMA_VERSION: u32 = 1;
+
+/// Identifier of a campaign, as passed to `create_campaign`.
+pub type CampaignId = [u8; 32];
+
+/// Mirror of the contract's `PoolState`, encoded as its `u32` discriminant.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum PoolState {
+    #[default]
+    Active,
+    Paused,
+    Completed,
+    Cancelled,
+    Disbursed,
+    Closed,
+}
+
+impl PoolState {
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::Active),
+            1 => Some(Self::Paused),
+            2 => Some(Self::Completed),
+            3 => Some(Self::Cancelled),
+            4 => Some(Self::Disbursed),
+            5 => Some(Self::Closed),
+            _ => None,
+        }
+    }
+
+    pub fn as_u32(self) -> u32 {
+        self as u32
+    }
+}
+
+/// Mirror of the contract's `Role`, encoded as its `u32` discriminant.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Role {
+    Pauser,
+    FeeManager,
+    Treasury,
+    PoolModerator,
+    Verifier,
+}
+
+impl Role {
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::Pauser),
+            1 => Some(Self::FeeManager),
+            2 => Some(Self::Treasury),
+            3 => Some(Self::PoolModerator),
+            4 => Some(Self::Verifier),
+            _ => None,
+        }
+    }
+
+    pub fn as_u32(self) -> u32 {
+        self as u32
+    }
+}
+
+/// Mirror of the contract's `FeeEntity`, encoded as its `u32` discriminant.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum FeeEntity {
+    Campaign,
+    PublicPool,
+    PrivatePool,
+    MultiSigPool,
+}
+
+impl FeeEntity {
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::Campaign),
+            1 => Some(Self::PublicPool),
+            2 => Some(Self::PrivatePool),
+            3 => Some(Self::MultiSigPool),
+            _ => None,
+        }
+    }
+
+    pub fn as_u32(self) -> u32 {
+        self as u32
+    }
+}
+
+/// Mirror of the contract's `FeeTier`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FeeTier {
+    Waived,
+    /// Basis points taken off creation fees and the platform fee rate.
+    Discount(u32),
+    /// Creation fee for every entity; the platform fee rate is unchanged.
+    FlatFee(i128),
+}
+
+/// Topic and data field names of one contract event.
+///
+/// Topics are listed in emission order after the event name; data fields are
+/// the keys of the data map. Both mirror the `#[contractevent]` structs in
+/// the contract's `base/events.rs`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct EventSchema {
+    pub name: &'static str,
+    pub topics: &'static [&'static str],
+    pub data: &'static [&'static str],
+}
+
+/// Every event the decoder understands.
+pub const EVENT_SCHEMAS: &[EventSchema] = &[
+    EventSchema {
+        name: "contract_initialized",
+        topics: &["admin"],
+        data: &["token", "creation_fee", "version"],
+    },
+    EventSchema {
+        name: "campaign_created",
+        topics: &["campaign_id", "creator"],
+        data: &["title", "goal", "deadline", "token", "version"],
+    },
+    EventSchema {
+        name: "campaign_goal_reached",
+        topics: &["campaign_id"],
+        data: &["total_raised", "timestamp", "version"],
+    },
+    EventSchema {
+        name: "campaign_cancelled",
+        topics: &["campaign_id", "caller"],
+        data: &["reason", "timestamp", "version"],
+    },
+    EventSchema {
+        name: "campaign_amended",
+        topics: &["campaign_id"],
+        data: &["goal", "deadline", "revision", "version"],
+    },
+    EventSchema {
+        name: "campaign_details_updated",
+        topics: &["campaign_id"],
+        data: &[
+            "title",
+            "description",
+            "external_url",
+            "image_hash",
+            "version",
+        ],
+    },
+    EventSchema {
+        name: "campaign_ownership_proposed",
+        topics: &["campaign_id"],
+        data: &["owner", "proposed_owner", "version"],
+    },
+    EventSchema {
+        name: "campaign_transfer_cancelled",
+        topics: &["campaign_id"],
+        data: &["owner", "proposed_owner", "version"],
+    },
+    EventSchema {
+        name: "campaign_ownership_transferred",
+        topics: &["campaign_id"],
+        data: &["new_owner", "previous_owner", "version"],
+    },
+    EventSchema {
+        name: "donation_made",
+        topics: &["campaign_id", "contributor"],
+        data: &["asset", "amount", "timestamp", "version"],
+    },
+    EventSchema {
+        name: "pool_created",
+        topics: &["pool_id", "creator"],
+        data: &[
+            "name",
+            "description",
+            "target_amount",
+            "is_private",
+            "deadline",
+            "version",
+        ],
+    },
+    EventSchema {
+        name: "pool_metadata_set",
+        topics: &["pool_id"],
+        data: &["description", "external_url", "image_hash", "version"],
+    },
+    EventSchema {
+        name: "pool_multi_sig_set",
+        topics: &["pool_id"],
+        data: &["required_signatures", "signers", "version"],
+    },
+    EventSchema {
+        name: "pool_ownership_proposed",
+        topics: &["pool_id"],
+        data: &["owner", "proposed_owner", "version"],
+    },
+    EventSchema {
+        name: "pool_transfer_cancelled",
+        topics: &["pool_id"],
+        data: &["owner", "proposed_owner", "version"],
+    },
+    EventSchema {
+        name: "pool_ownership_transferred",
+        topics: &["pool_id"],
+        data: &["new_owner", "previous_owner", "version"],
+    },
+    EventSchema {
+        name: "pool_state_updated",
+        topics: &["pool_id"],
+        data: &["old_state", "new_state", "version"],
+    },
+    EventSchema {
+        name: "contract_paused",
+        topics: &["admin"],
+        data: &["timestamp", "version"],
+    },
+    EventSchema {
+        name: "contract_unpaused",
+        topics: &["admin"],
+        data: &["timestamp", "version"],
+    },
+    EventSchema {
+        name: "contribution",
+        topics: &["pool_id", "contributor"],
+        data: &["asset", "amount", "timestamp", "is_private", "version"],
+    },
+    EventSchema {
+        name: "emergency_withdraw_requested",
+        topics: &["admin"],
+        data: &["token", "amount", "recipient", "unlock_time", "version"],
+    },
+    EventSchema {
+        name: "emergency_withdraw_executed",
+        topics: &["admin"],
+        data: &["token", "amount", "recipient", "version"],
+    },
+    EventSchema {
+        name: "emergency_withdraw_cancelled",
+        topics: &["admin"],
+        data: &["token", "amount", "version"],
+    },
+    EventSchema {
+        name: "emergency_withdraw_delay_set",
+        topics: &["admin"],
+        data: &["delay", "version"],
+    },
+    EventSchema {
+        name: "crowdfunding_token_set",
+        topics: &["admin"],
+        data: &["token", "version"],
+    },
+    EventSchema {
+        name: "creation_fee_set",
+        topics: &["admin"],
+        data: &["fee", "version"],
+    },
+    EventSchema {
+        name: "creation_fee_paid",
+        topics: &["creator"],
+        data: &["token", "amount", "version"],
+    },
+    EventSchema {
+        name: "refund",
+        topics: &["pool_id", "contributor"],
+        data: &["asset", "amount", "timestamp", "version"],
+    },
+    EventSchema {
+        name: "pool_closed",
+        topics: &["pool_id", "closed_by"],
+        data: &["timestamp", "version"],
+    },
+    EventSchema {
+        name: "admin_proposed",
+        topics: &["admin"],
+        data: &["proposed_admin", "version"],
+    },
+    EventSchema {
+        name: "admin_proposal_cancelled",
+        topics: &["admin"],
+        data: &["proposed_admin", "version"],
+    },
+    EventSchema {
+        name: "admin_transferred",
+        topics: &["new_admin"],
+        data: &["previous_admin", "version"],
+    },
+    EventSchema {
+        name: "role_granted",
+        topics: &["admin"],
+        data: &["account", "role", "version"],
+    },
+    EventSchema {
+        name: "role_revoked",
+        topics: &["admin"],
+        data: &["account", "role", "version"],
+    },
+    EventSchema {
+        name: "upgrade_timelock_set",
+        topics: &["admin"],
+        data: &["delay", "version"],
+    },
+    EventSchema {
+        name: "upgrade_proposed",
+        topics: &["admin"],
+        data: &["wasm_hash", "executable_at", "version"],
+    },
+    EventSchema {
+        name: "upgrade_cancelled",
+        topics: &["admin"],
+        data: &["wasm_hash", "version"],
+    },
+    EventSchema {
+        name: "upgrade_executed",
+        topics: &["admin"],
+        data: &["wasm_hash", "from_version", "version"],
+    },
+    EventSchema {
+        name: "storage_migrated",
+        topics: &["admin"],
+        data: &["from_version", "to_version", "version"],
+    },
+    EventSchema {
+        name: "fees_withdrawn",
+        topics: &["admin"],
+        data: &["token", "amount", "to", "version"],
+    },
+    EventSchema {
+        name: "max_pause_duration_set",
+        topics: &["admin"],
+        data: &["duration", "version"],
+    },
+    EventSchema {
+        name: "features_paused",
+        topics: &["admin"],
+        data: &["features", "reason", "timestamp", "expires_at", "version"],
+    },
+    EventSchema {
+        name: "features_unpaused",
+        topics: &["admin"],
+        data: &["features", "timestamp", "version"],
+    },
+    EventSchema {
+        name: "campaign_refunded",
+        topics: &["campaign_id", "contributor"],
+        data: &["asset", "amount", "timestamp", "version"],
+    },
+    EventSchema {
+        name: "creation_fee_queued",
+        topics: &["admin"],
+        data: &["fee", "effective_at", "version"],
+    },
+    EventSchema {
+        name: "crowdfunding_token_queued",
+        topics: &["admin"],
+        data: &["token", "effective_at", "version"],
+    },
+    EventSchema {
+        name: "entity_creation_fee_set",
+        topics: &["admin"],
+        data: &["entity", "fee", "version"],
+    },
+    EventSchema {
+        name: "entity_creation_fee_queued",
+        topics: &["admin"],
+        data: &["entity", "fee", "effective_at", "version"],
+    },
+    EventSchema {
+        name: "platform_fee_set",
+        topics: &["admin"],
+        data: &["bps", "version"],
+    },
+    EventSchema {
+        name: "platform_fee_queued",
+        topics: &["admin"],
+        data: &["bps", "effective_at", "version"],
+    },
+    EventSchema {
+        name: "pool_platform_fee_set",
+        topics: &["pool_id"],
+        data: &["admin", "bps", "version"],
+    },
+    EventSchema {
+        name: "fee_tier_assigned",
+        topics: &["admin"],
+        data: &["creator", "tier", "expires_at", "version"],
+    },
+    EventSchema {
+        name: "fee_tier_revoked",
+        topics: &["admin"],
+        data: &["creator", "version"],
+    },
+    EventSchema {
+        name: "accepted_token_set",
+        topics: &["admin"],
+        data: &["token", "symbol", "decimals", "min_amount", "version"],
+    },
+    EventSchema {
+        name: "accepted_token_removed",
+        topics: &["admin"],
+        data: &["token", "version"],
+    },
+    EventSchema {
+        name: "campaign_amendment_rules_set",
+        topics: &["admin"],
+        data: &[
+            "max_extension",
+            "single_extension",
+            "material_goal_change_bps",
+            "version",
+        ],
+    },
+    EventSchema {
+        name: "platform_fee_paid",
+        topics: &["payer"],
+        data: &["token", "amount", "version"],
+    },
+    EventSchema {
+        name: "council_set",
+        topics: &["admin"],
+        data: &["required_signatures", "signers", "version"],
+    },
+    EventSchema {
+        name: "council_disabled",
+        topics: &["executor"],
+        data: &["version"],
+    },
+    EventSchema {
+        name: "council_action_proposed",
+        topics: &["proposal_id", "proposer"],
+        data: &["action", "expires_at", "version"],
+    },
+    EventSchema {
+        name: "council_action_approved",
+        topics: &["proposal_id", "signer"],
+        data: &["approvals", "version"],
+    },
+    EventSchema {
+        name: "council_action_executed",
+        topics: &["proposal_id", "executor"],
+        data: &["version"],
+    },
+];
+
+/// Look up the schema of an event by its name topic.
+pub fn schema(name: &str) -> Option<&'static EventSchema> {
+    EVENT_SCHEMAS.iter().find(|schema| schema.name == name)
+}
+
+/// A decoded `CrowdfundingContract` event.
+///
+/// Addresses are kept in their strkey form (`G...`/`C...`).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Event {
+    ContractInitialized {
+        admin: String,
+        token: String,
+        creation_fee: i128,
+    },
+    CampaignCreated {
+        campaign_id: CampaignId,
+        creator: String,
+        title: String,
+        goal: i128,
+        deadline: u64,
+        token: String,
+    },
+    CampaignGoalReached {
+        campaign_id: CampaignId,
+        total_raised: i128,
+        timestamp: u64,
+    },
+    CampaignCancelled {
+        campaign_id: CampaignId,
+        caller: String,
+        reason: String,
+        timestamp: u64,
+    },
+    CampaignAmended {
+        campaign_id: CampaignId,
+        goal: i128,
+        deadline: u64,
+        revision: u32,
+    },
+    CampaignDetailsUpdated {
+        campaign_id: CampaignId,
+        title: String,
+        description: String,
+        external_url: String,
+        image_hash: String,
+    },
+    CampaignOwnershipProposed {
+        campaign_id: CampaignId,
+        owner: String,
+        proposed_owner: String,
+    },
+    CampaignTransferCancelled {
+        campaign_id: CampaignId,
+        owner: String,
+        proposed_owner: String,
+    },
+    CampaignOwnershipTransferred {
+        campaign_id: CampaignId,
+        new_owner: String,
+        previous_owner: String,
+    },
+    DonationMade {
+        campaign_id: CampaignId,
+        contributor: String,
+        asset: String,
+        amount: i128,
+        timestamp: u64,
+    },
+    PoolCreated {
+        pool_id: u64,
+        creator: String,
+        name: String,
+        description: String,
+        target_amount: i128,
+        is_private: bool,
+        deadline: u64,
+    },
+    PoolMetadataSet {
+        pool_id: u64,
+        description: String,
+        external_url: String,
+        image_hash: String,
+    },
+    PoolMultiSigSet {
+        pool_id: u64,
+        required_signatures: u32,
+        signers: Vec<String>,
+    },
+    PoolOwnershipProposed {
+        pool_id: u64,
+        owner: String,
+        proposed_owner: String,
+    },
+    PoolTransferCancelled {
+        pool_id: u64,
+        owner: String,
+        proposed_owner: String,
+    },
+    PoolOwnershipTransferred {
+        pool_id: u64,
+        new_owner: String,
+        previous_owner: String,
+    },
+    PoolStateUpdated {
+        pool_id: u64,
+        old_state: PoolState,
+        new_state: PoolState,
+    },
+    ContractPaused {
+        admin: String,
+        timestamp: u64,
+    },
+    ContractUnpaused {
+        admin: String,
+        timestamp: u64,
+    },
+    Contribution {
+        pool_id: u64,
+        contributor: String,
+        asset: String,
+        amount: i128,
+        timestamp: u64,
+        is_private: bool,
+    },
+    /// `recipient` of `None` pays `admin`.
+    EmergencyWithdrawRequested {
+        admin: String,
+        token: String,
+        amount: i128,
+        recipient: Option<String>,
+        unlock_time: u64,
+    },
+    /// `recipient` of `None` paid `admin`.
+    EmergencyWithdrawExecuted {
+        admin: String,
+        token: String,
+        amount: i128,
+        recipient: Option<String>,
+    },
+    FeesWithdrawn {
+        admin: String,
+        token: String,
+        amount: i128,
+        to: String,
+    },
+    EmergencyWithdrawCancelled {
+        admin: String,
+        token: String,
+        amount: i128,
+    },
+    EmergencyWithdrawDelaySet {
+        admin: String,
+        delay: u64,
+    },
+    CrowdfundingTokenSet {
+        admin: String,
+        token: String,
+    },
+    CreationFeeSet {
+        admin: String,
+        fee: i128,
+    },
+    CreationFeePaid {
+        creator: String,
+        token: String,
+        amount: i128,
+    },
+    Refund {
+        pool_id: u64,
+        contributor: String,
+        asset: String,
+        amount: i128,
+        timestamp: u64,
+    },
+    PoolClosed {
+        pool_id: u64,
+        closed_by: String,
+        timestamp: u64,
+    },
+    AdminProposed {
+        admin: String,
+        proposed_admin: String,
+    },
+    AdminProposalCancelled {
+        admin: String,
+        proposed_admin: String,
+    },
+    AdminTransferred {
+        new_admin: String,
+        previous_admin: String,
+    },
+    RoleGranted {
+        admin: String,
+        account: String,
+        role: Role,
+    },
+    RoleRevoked {
+        admin: String,
+        account: String,
+        role: Role,
+    },
+    UpgradeTimelockSet {
+        admin: String,
+        delay: u64,
+    },
+    UpgradeProposed {
+        admin: String,
+        wasm_hash: [u8; 32],
+        executable_at: u64,
+    },
+    UpgradeCancelled {
+        admin: String,
+        wasm_hash: [u8; 32],
+    },
+    UpgradeExecuted {
+        admin: String,
+        wasm_hash: [u8; 32],
+        from_version: u32,
+    },
+    StorageMigrated {
+        admin: String,
+        from_version: u32,
+        to_version: u32,
+    },
+    MaxPauseDurationSet {
+        admin: String,
+        duration: u64,
+    },
+    FeaturesPaused {
+        admin: String,
+        features: u32,
+        reason: u32,
+        timestamp: u64,
+        expires_at: u64,
+    },
+    FeaturesUnpaused {
+        admin: String,
+        features: u32,
+        timestamp: u64,
+    },
+    CampaignRefunded {
+        campaign_id: CampaignId,
+        contributor: String,
+        asset: String,
+        amount: i128,
+        timestamp: u64,
+    },
+    CreationFeeQueued {
+        admin: String,
+        fee: i128,
+        effective_at: u64,
+    },
+    CrowdfundingTokenQueued {
+        admin: String,
+        token: String,
+        effective_at: u64,
+    },
+    EntityCreationFeeSet {
+        admin: String,
+        entity: FeeEntity,
+        fee: i128,
+    },
+    EntityCreationFeeQueued {
+        admin: String,
+        entity: FeeEntity,
+        fee: i128,
+        effective_at: u64,
+    },
+    PlatformFeeSet {
+        admin: String,
+        bps: u32,
+    },
+    PlatformFeeQueued {
+        admin: String,
+        bps: u32,
+        effective_at: u64,
+    },
+    /// `bps` of `None` returns the pool to the platform rate.
+    PoolPlatformFeeSet {
+        pool_id: u64,
+        admin: String,
+        bps: Option<u32>,
+    },
+    /// `expires_at` of 0 never expires.
+    FeeTierAssigned {
+        admin: String,
+        creator: String,
+        tier: FeeTier,
+        expires_at: u64,
+    },
+    FeeTierRevoked {
+        admin: String,
+        creator: String,
+    },
+    AcceptedTokenSet {
+        admin: String,
+        token: String,
+        symbol: String,
+        decimals: u32,
+        min_amount: i128,
+    },
+    AcceptedTokenRemoved {
+        admin: String,
+        token: String,
+    },
+    CampaignAmendmentRulesSet {
+        admin: String,
+        max_extension: u64,
+        single_extension: bool,
+        material_goal_change_bps: u32,
+    },
+    /// Taken out of the donation or contribution that follows it, whose
+    /// amount is net of the fee.
+    PlatformFeePaid {
+        payer: String,
+        token: String,
+        amount: i128,
+    },
+    CouncilSet {
+        admin: String,
+        required_signatures: u32,
+        signers: Vec<String>,
+    },
+    CouncilDisabled {
+        executor: String,
+    },
+    /// `action` is the `CouncilAction` case name; its arguments are not kept.
+    CouncilActionProposed {
+        proposal_id: u64,
+        proposer: String,
+        action: String,
+        expires_at: u64,
+    },
+    CouncilActionApproved {
+        proposal_id: u64,
+        signer: String,
+        approvals: u32,
+    },
+    CouncilActionExecuted {
+        proposal_id: u64,
+        executor: String,
+    },
+}
+
+impl Event {
+    /// Decode an event from its topic list and data value.
+    pub fn decode(topics: &[ScVal], data: &ScVal) -> Result<Self, DecodeError> {
+        let name = match topics.first() {
+            Some(ScVal::Symbol(symbol)) => symbol.to_utf8_string_lossy(),
+            _ => return Err(DecodeError::MissingName),
+        };
+        let schema = schema(&name).ok_or_else(|| DecodeError::UnknownEvent(name.clone()))?;
+        let fields = Fields::new(schema, &topics[1..], data)?;
+
+        let version: u32 = fields.get("version")?;
+        if version > SUPPORTED_SCHEMA_VERSION {
+            return Err(DecodeError::UnsupportedVersion {
+                event: schema.name,
+                version,
+            });
+        }
+
+        let event = match schema.name {
+            "contract_initialized" => Self::ContractInitialized {
+                admin: fields.get("admin")?,
+                token: fields.get("token")?,
+                creation_fee: fields.get("creation_fee")?,
+            },
+            "campaign_created" => Self::CampaignCreated {
+                campaign_id: fields.get("campaign_id")?,
+                creator: fields.get("creator")?,
+                title: fields.get("title")?,
+                goal: fields.get("goal")?,
+                deadline: fields.get("deadline")?,
+                token: fields.get("token")?,
+            },
+            "campaign_goal_reached" => Self::CampaignGoalReached {
+                campaign_id: fields.get("campaign_id")?,
+                total_raised: fields.get("total_raised")?,
+                timestamp: fields.get("timestamp")?,
+            },
+            "campaign_cancelled" => Self::CampaignCancelled {
+                campaign_id: fields.get("campaign_id")?,
+                caller: fields.get("caller")?,
+                reason: fields.get("reason")?,
+                timestamp: fields.get("timestamp")?,
+            },
+            "campaign_amended" => Self::CampaignAmended {
+                campaign_id: fields.get("campaign_id")?,
+                goal: fields.get("goal")?,
+                deadline: fields.get("deadline")?,
+                revision: fields.get("revision")?,
+            },
+            "campaign_details_updated" => Self::CampaignDetailsUpdated {
+                campaign_id: fields.get("campaign_id")?,
+                title: fields.get("title")?,
+                description: fields.get("description")?,
+                external_url: fields.get("external_url")?,
+                image_hash: fields.get("image_hash")?,
+            },
+            "campaign_ownership_proposed" => Self::CampaignOwnershipProposed {
+                campaign_id: fields.get("campaign_id")?,
+                owner: fields.get("owner")?,
+                proposed_owner: fields.get("proposed_owner")?,
+            },
+            "campaign_transfer_cancelled" => Self::CampaignTransferCancelled {
+                campaign_id: fields.get("campaign_id")?,
+                owner: fields.get("owner")?,
+                proposed_owner: fields.get("proposed_owner")?,
+            },
+            "campaign_ownership_transferred" => Self::CampaignOwnershipTransferred {
+                campaign_id: fields.get("campaign_id")?,
+                new_owner: fields.get("new_owner")?,
+                previous_owner: fields.get("previous_owner")?,
+            },
+            "donation_made" => Self::DonationMade {
+                campaign_id: fields.get("campaign_id")?,
+                contributor: fields.get("contributor")?,
+                asset: fields.get("asset")?,
+                amount: fields.get("amount")?,
+                timestamp: fields.get("timestamp")?,
+            },
+            "pool_created" => Self::PoolCreated {
+                pool_id: fields.get("pool_id")?,
+                creator: fields.get("creator")?,
+                name: fields.get("name")?,
+                description: fields.get("description")?,
+                target_amount: fields.get("target_amount")?,
+                is_private: fields.get("is_private")?,
+                deadline: fields.get("deadline")?,
+            },
+            "pool_metadata_set" => Self::PoolMetadataSet {
+                pool_id: fields.get("pool_id")?,
+                description: fields.get("description")?,
+                external_url: fields.get("external_url")?,
+                image_hash: fields.get("image_hash")?,
+            },
+            "pool_multi_sig_set" => Self::PoolMultiSigSet {
+                pool_id: fields.get("pool_id")?,
+                required_signatures: fields.get("required_signatures")?,
+                signers: fields.get("signers")?,
+            },
+            "pool_ownership_proposed" => Self::PoolOwnershipProposed {
+                pool_id: fields.get("pool_id")?,
+                owner: fields.get("owner")?,
+                proposed_owner: fields.get("proposed_owner")?,
+            },
+            "pool_transfer_cancelled" => Self::PoolTransferCancelled {
+                pool_id: fields.get("pool_id")?,
+                owner: fields.get("owner")?,
+                proposed_owner: fields.get("proposed_owner")?,
+            },
+            "pool_ownership_transferred" => Self::PoolOwnershipTransferred {
+                pool_id: fields.get("pool_id")?,
+                new_owner: fields.get("new_owner")?,
+                previous_owner: fields.get("previous_owner")?,
+            },
+            "pool_state_updated" => Self::PoolStateUpdated {
+                pool_id: fields.get("pool_id")?,
+                old_state: fields.get("old_state")?,
+                new_state: fields.get("new_state")?,
+            },
+            "contract_paused" => Self::ContractPaused {
+                admin: fields.get("admin")?,
+                timestamp: fields.get("timestamp")?,
+            },
+            "contract_unpaused" => Self::ContractUnpaused {
+                admin: fields.get("admin")?,
+                timestamp: fields.get("timestamp")?,
+            },
+            "contribution" => Self::Contribution {
+                pool_id: fields.get("pool_id")?,
+                contributor: fields.get("contributor")?,
+                asset: fields.get("asset")?,
+                amount: fields.get("amount")?,
+                timestamp: fields.get("timestamp")?,
+                is_private: fields.get("is_private")?,
+            },
+            "emergency_withdraw_requested" => Self::EmergencyWithdrawRequested {
+                admin: fields.get("admin")?,
+                token: fields.get("token")?,
+                amount: fields.get("amount")?,
+                recipient: fields.get("recipient")?,
+                unlock_time: fields.get("unlock_time")?,
+            },
+            "emergency_withdraw_executed" => Self::EmergencyWithdrawExecuted {
+                admin: fields.get("admin")?,
+                token: fields.get("token")?,
+                amount: fields.get("amount")?,
+                recipient: fields.get("recipient")?,
+            },
+            "fees_withdrawn" => Self::FeesWithdrawn {
+                admin: fields.get("admin")?,
+                token: fields.get("token")?,
+                amount: fields.get("amount")?,
+                to: fields.get("to")?,
+            },
+            "emergency_withdraw_cancelled" => Self::EmergencyWithdrawCancelled {
+                admin: fields.get("admin")?,
+                token: fields.get("token")?,
+                amount: fields.get("amount")?,
+            },
+            "emergency_withdraw_delay_set" => Self::EmergencyWithdrawDelaySet {
+                admin: fields.get("admin")?,
+                delay: fields.get("delay")?,
+            },
+            "crowdfunding_token_set" => Self::CrowdfundingTokenSet {
+                admin: fields.get("admin")?,
+                token: fields.get("token")?,
+            },
+            "creation_fee_set" => Self::CreationFeeSet {
+                admin: fields.get("admin")?,
+                fee: fields.get("fee")?,
+            },
+            "creation_fee_paid" => Self::CreationFeePaid {
+                creator: fields.get("creator")?,
+                token: fields.get("token")?,
+                amount: fields.get("amount")?,
+            },
+            "refund" => Self::Refund {
+                pool_id: fields.get("pool_id")?,
+                contributor: fields.get("contributor")?,
+                asset: fields.get("asset")?,
+                amount: fields.get("amount")?,
+                timestamp: fields.get("timestamp")?,
+            },
+            "pool_closed" => Self::PoolClosed {
+                pool_id: fields.get("pool_id")?,
+                closed_by: fields.get("closed_by")?,
+                timestamp: fields.get("timestamp")?,
+            },
+            "admin_proposed" => Self::AdminProposed {
+                admin: fields.get("admin")?,
+                proposed_admin: fields.get("proposed_admin")?,
+            },
+            "admin_proposal_cancelled" => Self::AdminProposalCancelled {
+                admin: fields.get("admin")?,
+                proposed_admin: fields.get("proposed_admin")?,
+            },
+            "admin_transferred" => Self::AdminTransferred {
+                new_admin: fields.get("new_admin")?,
+                previous_admin: fields.get("previous_admin")?,
+            },
+            "role_granted" => Self::RoleGranted {
+                admin: fields.get("admin")?,
+                account: fields.get("account")?,
+                role: fields.get("role")?,
+            },
+            "role_revoked" => Self::RoleRevoked {
+                admin: fields.get("admin")?,
+                account: fields.get("account")?,
+                role: fields.get("role")?,
+            },
+            "upgrade_timelock_set" => Self::UpgradeTimelockSet {
+                admin: fields.get("admin")?,
+                delay: fields.get("delay")?,
+            },
+            "upgrade_proposed" => Self::UpgradeProposed {
+                admin: fields.get("admin")?,
+                wasm_hash: fields.get("wasm_hash")?,
+                executable_at: fields.get("executable_at")?,
+            },
+            "upgrade_cancelled" => Self::UpgradeCancelled {
+                admin: fields.get("admin")?,
+                wasm_hash: fields.get("wasm_hash")?,
+            },
+            "upgrade_executed" => Self::UpgradeExecuted {
+                admin: fields.get("admin")?,
+                wasm_hash: fields.get("wasm_hash")?,
+                from_version: fields.get("from_version")?,
+            },
+            "storage_migrated" => Self::StorageMigrated {
+                admin: fields.get("admin")?,
+                from_version: fields.get("from_version")?,
+                to_version: fields.get("to_version")?,
+            },
+            "max_pause_duration_set" => Self::MaxPauseDurationSet {
+                admin: fields.get("admin")?,
+                duration: fields.get("duration")?,
+            },
+            "features_paused" => Self::FeaturesPaused {
+                admin: fields.get("admin")?,
+                features: fields.get("features")?,
+                reason: fields.get("reason")?,
+                timestamp: fields.get("timestamp")?,
+                expires_at: fields.get("expires_at")?,
+            },
+            "features_unpaused" => Self::FeaturesUnpaused {
+                admin: fields.get("admin")?,
+                features: fields.get("features")?,
+                timestamp: fields.get("timestamp")?,
+            },
+            "campaign_refunded" => Self::CampaignRefunded {
+                campaign_id: fields.get("campaign_id")?,
+                contributor: fields.get("contributor")?,
+                asset: fields.get("asset")?,
+                amount: fields.get("amount")?,
+                timestamp: fields.get("timestamp")?,
+            },
+            "creation_fee_queued" => Self::CreationFeeQueued {
+                admin: fields.get("admin")?,
+                fee: fields.get("fee")?,
+                effective_at: fields.get("effective_at")?,
+            },
+            "crowdfunding_token_queued" => Self::CrowdfundingTokenQueued {
+                admin: fields.get("admin")?,
+                token: fields.get("token")?,
+                effective_at: fields.get("effective_at")?,
+            },
+            "entity_creation_fee_set" => Self::EntityCreationFeeSet {
+                admin: fields.get("admin")?,
+                entity: fields.get("entity")?,
+                fee: fields.get("fee")?,
+            },
+            "entity_creation_fee_queued" => Self::EntityCreationFeeQueued {
+                admin: fields.get("admin")?,
+                entity: fields.get("entity")?,
+                fee: fields.get("fee")?,
+                effective_at: fields.get("effective_at")?,
+            },
+            "platform_fee_set" => Self::PlatformFeeSet {
+                admin: fields.get("admin")?,
+                bps: fields.get("bps")?,
+            },
+            "platform_fee_queued" => Self::PlatformFeeQueued {
+                admin: fields.get("admin")?,
+                bps: fields.get("bps")?,
+                effective_at: fields.get("effective_at")?,
+            },
+            "pool_platform_fee_set" => Self::PoolPlatformFeeSet {
+                pool_id: fields.get("pool_id")?,
+                admin: fields.get("admin")?,
+                bps: fields.get("bps")?,
+            },
+            "fee_tier_assigned" => Self::FeeTierAssigned {
+                admin: fields.get("admin")?,
+                creator: fields.get("creator")?,
+                tier: fields.get("tier")?,
+                expires_at: fields.get("expires_at")?,
+            },
+            "fee_tier_revoked" => Self::FeeTierRevoked {
+                admin: fields.get("admin")?,
+                creator: fields.get("creator")?,
+            },
+            "accepted_token_set" => Self::AcceptedTokenSet {
+                admin: fields.get("admin")?,
+                token: fields.get("token")?,
+                symbol: fields.get("symbol")?,
+                decimals: fields.get("decimals")?,
+                min_amount: fields.get("min_amount")?,
+            },
+            "accepted_token_removed" => Self::AcceptedTokenRemoved {
+                admin: fields.get("admin")?,
+                token: fields.get("token")?,
+            },
+            "campaign_amendment_rules_set" => Self::CampaignAmendmentRulesSet {
+                admin: fields.get("admin")?,
+                max_extension: fields.get("max_extension")?,
+                single_extension: fields.get("single_extension")?,
+                material_goal_change_bps: fields.get("material_goal_change_bps")?,
+            },
+            "platform_fee_paid" => Self::PlatformFeePaid {
+                payer: fields.get("payer")?,
+                token: fields.get("token")?,
+                amount: fields.get("amount")?,
+            },
+            "council_set" => Self::CouncilSet {
+                admin: fields.get("admin")?,
+                required_signatures: fields.get("required_signatures")?,
+                signers: fields.get("signers")?,
+            },
+            "council_disabled" => Self::CouncilDisabled {
+                executor: fields.get("executor")?,
+            },
+            "council_action_proposed" => Self::CouncilActionProposed {
+                proposal_id: fields.get("proposal_id")?,
+                proposer: fields.get("proposer")?,
+                action: fields.get::<CaseName>("action")?.0,
+                expires_at: fields.get("expires_at")?,
+            },
+            "council_action_approved" => Self::CouncilActionApproved {
+                proposal_id: fields.get("proposal_id")?,
+                signer: fields.get("signer")?,
+                approvals: fields.get("approvals")?,
+            },
+            "council_action_executed" => Self::CouncilActionExecuted {
+                proposal_id: fields.get("proposal_id")?,
+                executor: fields.get("executor")?,
+            },
+            other => return Err(DecodeError::UnknownEvent(other.to_string())),
+        };
+
+        Ok(event)
+    }
+
+    /// Decode a contract event as returned by RPC `getEvents` or ledger meta.
+    ///
+    /// Returns `Ok(None)` for system and diagnostic events.
+    pub fn from_xdr(event: &ContractEvent) -> Result<Option<Self>, DecodeError> {
+        if event.type_ != ContractEventType::Contract {
+            return Ok(None);
+        }
+        let ContractEventBody::V0(body) = &event.body;
+        Self::decode(&body.topics, &body.data).map(Some)
+    }
+}
+
+/// Named access to an event's topics and data map, checked against its schema.
+struct Fields<'a> {
+    schema: &'static EventSchema,
+    topics: &'a [ScVal],
+    data: &'a ScMap,
+}
+
+impl<'a> Fields<'a> {
+    fn new(
+        schema: &'static EventSchema,
+        topics: &'a [ScVal],
+        data: &'a ScVal,
+    ) -> Result<Self, DecodeError> {
+        if topics.len() != schema.topics.len() {
+            return Err(DecodeError::SchemaMismatch(schema.name));
+        }
+
+        let data = match data {
+            ScVal::Map(Some(map)) => map,
+            _ => return Err(DecodeError::SchemaMismatch(schema.name)),
+        };
+        let mut keys = BTreeSet::new();
+        for entry in data.iter() {
+            match &entry.key {
+                ScVal::Symbol(symbol) => keys.insert(symbol.to_utf8_string_lossy()),
+                _ => return Err(DecodeError::SchemaMismatch(schema.name)),
+            };
+        }
+        let expected: BTreeSet<String> = schema.data.iter().map(|k| k.to_string()).collect();
+        if keys != expected {
+            return Err(DecodeError::SchemaMismatch(schema.name));
+        }
+
+        Ok(Self {
+            schema,
+            topics,
+            data,
+        })
+    }
+
+    fn get<T: FromScVal>(&self, field: &'static str) -> Result<T, DecodeError> {
+        let value = if let Some(index) = self.schema.topics.iter().position(|t| *t == field) {
+            self.topics.get(index)
+        } else {
+            self.data
+                .iter()
+                .find(|entry| matches!(&entry.key, ScVal::Symbol(s) if s.0.as_slice() == field.as_bytes()))
+                .map(|entry| &entry.val)
+        };
+
+        value
+            .and_then(T::from_sc_val)
+            .ok_or(DecodeError::InvalidField {
+                event: self.schema.name,
+                field,
+            })
+    }
+}
+
+trait FromScVal: Sized {
+    fn from_sc_val(value: &ScVal) -> Option<Self>;
+}
+
+impl FromScVal for u32 {
+    fn from_sc_val(value: &ScVal) -> Option<Self> {
+        match value {
+            ScVal::U32(v) => Some(*v),
+            _ => None,
+        }
+    }
+}
+
+impl FromScVal for u64 {
+    fn from_sc_val(value: &ScVal) -> Option<Self> {
+        match value {
+            ScVal::U64(v) => Some(*v),
+            _ => None,
+        }
+    }
+}
+
+impl FromScVal for i128 {
+    fn from_sc_val(value: &ScVal) -> Option<Self> {
+        i128::try_from(value.clone()).ok()
+    }
+}
+
+impl FromScVal for bool {
+    fn from_sc_val(value: &ScVal) -> Option<Self> {
+        match value {
+            ScVal::Bool(v) => Some(*v),
+            _ => None,
+        }
+    }
+}
+
+impl FromScVal for String {
+    fn from_sc_val(value: &ScVal) -> Option<Self> {
+        match value {
+            ScVal::String(s) => Some(s.to_utf8_string_lossy()),
+            ScVal::Address(address) => Some(address.to_string()),
+            _ => None,
+        }
+    }
+}
+
+impl FromScVal for CampaignId {
+    fn from_sc_val(value: &ScVal) -> Option<Self> {
+        match value {
+            ScVal::Bytes(bytes) => bytes.as_slice().try_into().ok(),
+            _ => None,
+        }
+    }
+}
+
+impl FromScVal for PoolState {
+    fn from_sc_val(value: &ScVal) -> Option<Self> {
+        u32::from_sc_val(value).and_then(PoolState::from_u32)
+    }
+}
+
+impl FromScVal for Role {
+    fn from_sc_val(value: &ScVal) -> Option<Self> {
+        u32::from_sc_val(value).and_then(Role::from_u32)
+    }
+}
+
+impl FromScVal for FeeEntity {
+    fn from_sc_val(value: &ScVal) -> Option<Self> {
+        u32::from_sc_val(value).and_then(FeeEntity::from_u32)
+    }
+}
+
+impl FromScVal for FeeTier {
+    fn from_sc_val(value: &ScVal) -> Option<Self> {
+        let ScVal::Vec(Some(items)) = value else {
+            return None;
+        };
+        match (CaseName::from_sc_val(value)?.0.as_str(), &items[1..]) {
+            ("Waived", []) => Some(Self::Waived),
+            ("Discount", [bps]) => u32::from_sc_val(bps).map(Self::Discount),
+            ("FlatFee", [fee]) => i128::from_sc_val(fee).map(Self::FlatFee),
+            _ => None,
+        }
+    }
+}
+
+/// Case name of an encoded `#[contracttype]` enum, which is a vec led by
+/// the name symbol.
+struct CaseName(String);
+
+impl FromScVal for CaseName {
+    fn from_sc_val(value: &ScVal) -> Option<Self> {
+        match value {
+            ScVal::Vec(Some(items)) => match items.first() {
+                Some(ScVal::Symbol(case)) => Some(Self(case.to_utf8_string_lossy())),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+impl<T: FromScVal> FromScVal for Option<T> {
+    fn from_sc_val(value: &ScVal) -> Option<Self> {
+        match value {
+            ScVal::Void => Some(None),
+            value => T::from_sc_val(value).map(Some),
+        }
+    }
+}
+
+impl<T: FromScVal> FromScVal for Vec<T> {
+    fn from_sc_val(value: &ScVal) -> Option<Self> {
+        match value {
+            ScVal::Vec(Some(items)) => items.iter().map(T::from_sc_val).collect(),
+            _ => None,
+        }
+    }
+}
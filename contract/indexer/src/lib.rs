@@ -0,0 +1,75 @@
+//! Off-chain indexer for `CrowdfundingContract`.
+//!
+//! Decodes the contract's events into [`Event`] values and folds them, in
+//! emission order, into a [`State`] model of the platform, campaigns, pools,
+//! contributions and refunds. With the `sqlite` feature the model can be
+//! persisted through [`sqlite::SqliteStore`].
+pub mod error;
+pub mod event;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+pub mod state;
+
+use stellar_xdr::curr::{ContractEvent, ScAddress, ScVal};
+
+pub use error::{ApplyError, DecodeError, IndexerError};
+pub use event::{CampaignId, Event, FeeEntity, FeeTier, PoolState, Role, SUPPORTED_SCHEMA_VERSION};
+pub use state::State;
+
+/// Rebuilds contract state from a stream of events.
+#[derive(Clone, Debug, Default)]
+pub struct Indexer {
+    contract_id: Option<String>,
+    state: State,
+}
+
+impl Indexer {
+    /// An indexer that accepts events from any contract.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// An indexer that ignores events not emitted by `contract_id` (strkey).
+    pub fn for_contract(contract_id: impl Into<String>) -> Self {
+        Self {
+            contract_id: Some(contract_id.into()),
+            state: State::new(),
+        }
+    }
+
+    pub fn state(&self) -> &State {
+        &self.state
+    }
+
+    pub fn into_state(self) -> State {
+        self.state
+    }
+
+    /// Decode and apply one event given as its topics and data.
+    pub fn ingest(&mut self, topics: &[ScVal], data: &ScVal) -> Result<Event, IndexerError> {
+        let event = Event::decode(topics, data)?;
+        self.state.apply(&event)?;
+        Ok(event)
+    }
+
+    /// Decode and apply one XDR contract event.
+    ///
+    /// Events from other contracts and non-contract events are skipped.
+    pub fn ingest_xdr(&mut self, event: &ContractEvent) -> Result<Option<Event>, IndexerError> {
+        if let Some(expected) = &self.contract_id {
+            let emitter = event
+                .contract_id
+                .clone()
+                .map(|id| ScAddress::Contract(id).to_string());
+            if emitter.as_ref() != Some(expected) {
+                return Ok(None);
+            }
+        }
+
+        let Some(decoded) = Event::from_xdr(event)? else {
+            return Ok(None);
+        };
+        self.state.apply(&decoded)?;
+        Ok(Some(decoded))
+    }
+}
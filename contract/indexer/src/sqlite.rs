@@ -0,0 +1,1113 @@
+//! SQLite persistence for the replayed [`State`].
+//!
+//! `i128` amounts are stored as decimal text and `u64` values are stored
+//! bit-for-bit in SQLite's signed 64-bit integers.
+use std::{collections::BTreeMap, path::Path};
+
+use rusqlite::{params, Connection, OptionalExtension, Row};
+
+use crate::{
+    event::{CampaignId, FeeEntity, FeeTier, PoolState, Role},
+    state::{
+        AcceptedToken, Campaign, CampaignAmendmentRules, CampaignMetadata, CouncilProposal,
+        CreatorFeeTier, EmergencyWithdrawal, FeaturePause, MultiSig, PendingEntityFee,
+        PendingParameters, PendingPlatformFee, PendingUpgrade, Platform, Pool, PoolContribution,
+        PoolMetadata, RefundRecord, State,
+    },
+};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS platform (
+    id INTEGER PRIMARY KEY CHECK (id = 0),
+    admin TEXT NOT NULL,
+    token TEXT NOT NULL,
+    creation_fee TEXT NOT NULL,
+    paused INTEGER NOT NULL,
+    withdrawal_token TEXT,
+    withdrawal_amount TEXT,
+    withdrawal_unlock_time INTEGER,
+    pending_admin TEXT,
+    upgrade_timelock INTEGER,
+    upgrade_wasm_hash BLOB,
+    upgrade_executable_at INTEGER,
+    emergency_withdraw_delay INTEGER,
+    paused_at INTEGER,
+    max_pause_duration INTEGER,
+    council_required_signatures INTEGER,
+    pending_creation_fee TEXT,
+    pending_token TEXT,
+    pending_queued_by TEXT,
+    pending_effective_at INTEGER,
+    withdrawal_recipient TEXT,
+    unpaused_at INTEGER
+);
+CREATE TABLE IF NOT EXISTS council_signers (
+    position INTEGER PRIMARY KEY,
+    signer TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS council_proposals (
+    id INTEGER PRIMARY KEY,
+    action TEXT NOT NULL,
+    proposer TEXT NOT NULL,
+    expires_at INTEGER NOT NULL,
+    executed INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS council_approvals (
+    proposal_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    signer TEXT NOT NULL,
+    PRIMARY KEY (proposal_id, position)
+);
+CREATE TABLE IF NOT EXISTS fees_paid (
+    token TEXT PRIMARY KEY,
+    amount TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS fee_balances (
+    token TEXT PRIMARY KEY,
+    amount TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS entity_creation_fees (
+    entity INTEGER PRIMARY KEY,
+    fee TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS pending_entity_fees (
+    entity INTEGER PRIMARY KEY,
+    fee TEXT NOT NULL,
+    queued_by TEXT NOT NULL,
+    effective_at INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS platform_fee (
+    id INTEGER PRIMARY KEY CHECK (id = 0),
+    bps INTEGER NOT NULL,
+    pending_bps INTEGER,
+    pending_queued_by TEXT,
+    pending_effective_at INTEGER
+);
+CREATE TABLE IF NOT EXISTS pool_platform_fees (
+    pool_id INTEGER PRIMARY KEY,
+    bps INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS fee_tiers (
+    creator TEXT PRIMARY KEY,
+    tier TEXT NOT NULL,
+    tier_value TEXT,
+    assigned_by TEXT NOT NULL,
+    expires_at INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS accepted_tokens (
+    token TEXT PRIMARY KEY,
+    symbol TEXT NOT NULL,
+    decimals INTEGER NOT NULL,
+    min_amount TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS campaign_amendment_rules (
+    id INTEGER PRIMARY KEY CHECK (id = 0),
+    max_extension INTEGER NOT NULL,
+    single_extension INTEGER NOT NULL,
+    material_goal_change_bps INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS roles (
+    account TEXT NOT NULL,
+    role INTEGER NOT NULL,
+    PRIMARY KEY (account, role)
+);
+CREATE TABLE IF NOT EXISTS feature_pauses (
+    feature INTEGER PRIMARY KEY,
+    reason INTEGER NOT NULL,
+    paused_at INTEGER NOT NULL,
+    expires_at INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS campaigns (
+    id BLOB PRIMARY KEY,
+    creator TEXT NOT NULL,
+    title TEXT NOT NULL,
+    goal TEXT NOT NULL,
+    deadline INTEGER NOT NULL,
+    token TEXT NOT NULL,
+    total_raised TEXT NOT NULL,
+    donor_count INTEGER NOT NULL,
+    last_donation_at INTEGER NOT NULL,
+    goal_reached_at INTEGER,
+    cancelled_at INTEGER,
+    cancellation_reason TEXT,
+    revision INTEGER NOT NULL,
+    description TEXT NOT NULL,
+    external_url TEXT NOT NULL,
+    image_hash TEXT NOT NULL,
+    pending_owner TEXT
+);
+CREATE TABLE IF NOT EXISTS campaign_contributions (
+    campaign_id BLOB NOT NULL,
+    contributor TEXT NOT NULL,
+    amount TEXT NOT NULL,
+    PRIMARY KEY (campaign_id, contributor)
+);
+CREATE TABLE IF NOT EXISTS pools (
+    id INTEGER PRIMARY KEY,
+    creator TEXT NOT NULL,
+    name TEXT NOT NULL,
+    description TEXT NOT NULL,
+    target_amount TEXT NOT NULL,
+    is_private INTEGER NOT NULL,
+    deadline INTEGER NOT NULL,
+    state INTEGER NOT NULL,
+    metadata_description TEXT,
+    metadata_external_url TEXT,
+    metadata_image_hash TEXT,
+    required_signatures INTEGER,
+    total_raised TEXT NOT NULL,
+    contributor_count INTEGER NOT NULL,
+    last_donation_at INTEGER NOT NULL,
+    closed_by TEXT,
+    pending_owner TEXT
+);
+CREATE TABLE IF NOT EXISTS pool_signers (
+    pool_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    signer TEXT NOT NULL,
+    PRIMARY KEY (pool_id, position)
+);
+CREATE TABLE IF NOT EXISTS pool_contributions (
+    pool_id INTEGER NOT NULL,
+    contributor TEXT NOT NULL,
+    asset TEXT NOT NULL,
+    amount TEXT NOT NULL,
+    PRIMARY KEY (pool_id, contributor)
+);
+CREATE TABLE IF NOT EXISTS refunds (
+    pool_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    contributor TEXT NOT NULL,
+    asset TEXT NOT NULL,
+    amount TEXT NOT NULL,
+    timestamp INTEGER NOT NULL,
+    PRIMARY KEY (pool_id, position)
+);
+";
+
+const TABLES: &[&str] = &[
+    "platform",
+    "council_signers",
+    "council_proposals",
+    "council_approvals",
+    "fees_paid",
+    "fee_balances",
+    "entity_creation_fees",
+    "pending_entity_fees",
+    "platform_fee",
+    "pool_platform_fees",
+    "fee_tiers",
+    "accepted_tokens",
+    "campaign_amendment_rules",
+    "roles",
+    "feature_pauses",
+    "campaigns",
+    "campaign_contributions",
+    "pools",
+    "pool_signers",
+    "pool_contributions",
+    "refunds",
+];
+
+/// A SQLite database holding the latest replayed [`State`].
+pub struct SqliteStore {
+    conn: Connection,
+}
+
+impl SqliteStore {
+    pub fn open(path: impl AsRef<Path>) -> rusqlite::Result<Self> {
+        Self::with_connection(Connection::open(path)?)
+    }
+
+    pub fn open_in_memory() -> rusqlite::Result<Self> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    fn with_connection(conn: Connection) -> rusqlite::Result<Self> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self { conn })
+    }
+
+    /// Replace the stored model with `state` in a single transaction.
+    pub fn save(&mut self, state: &State) -> rusqlite::Result<()> {
+        let tx = self.conn.transaction()?;
+        for table in TABLES {
+            tx.execute(&format!("DELETE FROM {table}"), [])?;
+        }
+
+        if let Some(platform) = &state.platform {
+            let withdrawal = platform.emergency_withdrawal.as_ref();
+            let upgrade = platform.pending_upgrade.as_ref();
+            let parameters = platform.pending_parameters.as_ref();
+            tx.execute(
+                "INSERT INTO platform VALUES (0, ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21)",
+                params![
+                    platform.admin,
+                    platform.token,
+                    platform.creation_fee.to_string(),
+                    platform.paused,
+                    withdrawal.map(|w| w.token.clone()),
+                    withdrawal.map(|w| w.amount.to_string()),
+                    withdrawal.map(|w| w.unlock_time as i64),
+                    platform.pending_admin,
+                    platform.upgrade_timelock.map(|t| t as i64),
+                    upgrade.map(|u| u.wasm_hash.to_vec()),
+                    upgrade.map(|u| u.executable_at as i64),
+                    platform.emergency_withdraw_delay.map(|d| d as i64),
+                    platform.paused_at.map(|t| t as i64),
+                    platform.max_pause_duration.map(|d| d as i64),
+                    platform.council.as_ref().map(|c| c.required_signatures),
+                    parameters.and_then(|p| p.creation_fee.map(|fee| fee.to_string())),
+                    parameters.and_then(|p| p.token.clone()),
+                    parameters.map(|p| p.queued_by.clone()),
+                    parameters.map(|p| p.effective_at as i64),
+                    withdrawal.map(|w| w.recipient.clone()),
+                    platform.unpaused_at.map(|t| t as i64),
+                ],
+            )?;
+            if let Some(council) = &platform.council {
+                for (position, signer) in council.signers.iter().enumerate() {
+                    tx.execute(
+                        "INSERT INTO council_signers VALUES (?1, ?2)",
+                        params![position as i64, signer],
+                    )?;
+                }
+            }
+        }
+
+        for (id, proposal) in &state.council_proposals {
+            tx.execute(
+                "INSERT INTO council_proposals VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![
+                    *id as i64,
+                    proposal.action,
+                    proposal.proposer,
+                    proposal.expires_at as i64,
+                    proposal.executed,
+                ],
+            )?;
+            for (position, signer) in proposal.approvals.iter().enumerate() {
+                tx.execute(
+                    "INSERT INTO council_approvals VALUES (?1, ?2, ?3)",
+                    params![*id as i64, position as i64, signer],
+                )?;
+            }
+        }
+
+        for (token, amount) in &state.fees_paid {
+            tx.execute(
+                "INSERT INTO fees_paid VALUES (?1, ?2)",
+                params![token, amount.to_string()],
+            )?;
+        }
+        for (token, amount) in &state.fee_balances {
+            tx.execute(
+                "INSERT INTO fee_balances VALUES (?1, ?2)",
+                params![token, amount.to_string()],
+            )?;
+        }
+
+        for (entity, fee) in &state.entity_creation_fees {
+            tx.execute(
+                "INSERT INTO entity_creation_fees VALUES (?1, ?2)",
+                params![entity.as_u32(), fee.to_string()],
+            )?;
+        }
+        for (entity, pending) in &state.pending_entity_fees {
+            tx.execute(
+                "INSERT INTO pending_entity_fees VALUES (?1, ?2, ?3, ?4)",
+                params![
+                    entity.as_u32(),
+                    pending.fee.to_string(),
+                    pending.queued_by,
+                    pending.effective_at as i64,
+                ],
+            )?;
+        }
+
+        let pending = state.pending_platform_fee.as_ref();
+        tx.execute(
+            "INSERT INTO platform_fee VALUES (0, ?1, ?2, ?3, ?4)",
+            params![
+                state.platform_fee_bps,
+                pending.map(|p| p.bps),
+                pending.map(|p| p.queued_by.clone()),
+                pending.map(|p| p.effective_at as i64),
+            ],
+        )?;
+        for (pool_id, bps) in &state.pool_platform_fees {
+            tx.execute(
+                "INSERT INTO pool_platform_fees VALUES (?1, ?2)",
+                params![*pool_id as i64, bps],
+            )?;
+        }
+
+        for (creator, tier) in &state.fee_tiers {
+            let (case, value) = match tier.tier {
+                FeeTier::Waived => ("Waived", None),
+                FeeTier::Discount(bps) => ("Discount", Some(bps.to_string())),
+                FeeTier::FlatFee(fee) => ("FlatFee", Some(fee.to_string())),
+            };
+            tx.execute(
+                "INSERT INTO fee_tiers VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![
+                    creator,
+                    case,
+                    value,
+                    tier.assigned_by,
+                    tier.expires_at as i64
+                ],
+            )?;
+        }
+
+        for (token, accepted) in &state.accepted_tokens {
+            tx.execute(
+                "INSERT INTO accepted_tokens VALUES (?1, ?2, ?3, ?4)",
+                params![
+                    token,
+                    accepted.symbol,
+                    accepted.decimals,
+                    accepted.min_amount.to_string(),
+                ],
+            )?;
+        }
+
+        if let Some(rules) = &state.campaign_amendment_rules {
+            tx.execute(
+                "INSERT INTO campaign_amendment_rules VALUES (0, ?1, ?2, ?3)",
+                params![
+                    rules.max_extension as i64,
+                    rules.single_extension,
+                    rules.material_goal_change_bps,
+                ],
+            )?;
+        }
+
+        for (account, roles) in &state.roles {
+            for role in roles {
+                tx.execute(
+                    "INSERT INTO roles VALUES (?1, ?2)",
+                    params![account, role.as_u32()],
+                )?;
+            }
+        }
+
+        for (feature, pause) in &state.feature_pauses {
+            tx.execute(
+                "INSERT INTO feature_pauses VALUES (?1, ?2, ?3, ?4)",
+                params![
+                    feature,
+                    pause.reason,
+                    pause.paused_at as i64,
+                    pause.expires_at as i64,
+                ],
+            )?;
+        }
+
+        for campaign in state.campaigns.values() {
+            tx.execute(
+                "INSERT INTO campaigns VALUES
+                 (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17)",
+                params![
+                    campaign.id.as_slice(),
+                    campaign.creator,
+                    campaign.title,
+                    campaign.goal.to_string(),
+                    campaign.deadline as i64,
+                    campaign.token,
+                    campaign.total_raised.to_string(),
+                    campaign.donor_count,
+                    campaign.last_donation_at as i64,
+                    campaign.goal_reached_at.map(|t| t as i64),
+                    campaign.cancelled_at.map(|t| t as i64),
+                    campaign.cancellation_reason,
+                    campaign.revision,
+                    campaign.metadata.description,
+                    campaign.metadata.external_url,
+                    campaign.metadata.image_hash,
+                    campaign.pending_owner,
+                ],
+            )?;
+            for (contributor, amount) in &campaign.contributions {
+                tx.execute(
+                    "INSERT INTO campaign_contributions VALUES (?1, ?2, ?3)",
+                    params![campaign.id.as_slice(), contributor, amount.to_string()],
+                )?;
+            }
+        }
+
+        for pool in state.pools.values() {
+            let metadata = pool.metadata.as_ref();
+            tx.execute(
+                "INSERT INTO pools VALUES
+                 (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17)",
+                params![
+                    pool.id as i64,
+                    pool.creator,
+                    pool.name,
+                    pool.description,
+                    pool.target_amount.to_string(),
+                    pool.is_private,
+                    pool.deadline as i64,
+                    pool.state.as_u32(),
+                    metadata.map(|m| m.description.clone()),
+                    metadata.map(|m| m.external_url.clone()),
+                    metadata.map(|m| m.image_hash.clone()),
+                    pool.multisig.as_ref().map(|m| m.required_signatures),
+                    pool.total_raised.to_string(),
+                    pool.contributor_count,
+                    pool.last_donation_at as i64,
+                    pool.closed_by,
+                    pool.pending_owner,
+                ],
+            )?;
+            if let Some(multisig) = &pool.multisig {
+                for (position, signer) in multisig.signers.iter().enumerate() {
+                    tx.execute(
+                        "INSERT INTO pool_signers VALUES (?1, ?2, ?3)",
+                        params![pool.id as i64, position as i64, signer],
+                    )?;
+                }
+            }
+            for (contributor, contribution) in &pool.contributions {
+                tx.execute(
+                    "INSERT INTO pool_contributions VALUES (?1, ?2, ?3, ?4)",
+                    params![
+                        pool.id as i64,
+                        contributor,
+                        contribution.asset,
+                        contribution.amount.to_string()
+                    ],
+                )?;
+            }
+            for (position, refund) in pool.refunds.iter().enumerate() {
+                tx.execute(
+                    "INSERT INTO refunds VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                    params![
+                        pool.id as i64,
+                        position as i64,
+                        refund.contributor,
+                        refund.asset,
+                        refund.amount.to_string(),
+                        refund.timestamp as i64,
+                    ],
+                )?;
+            }
+        }
+
+        tx.commit()
+    }
+
+    /// Load the stored model.
+    pub fn load(&self) -> rusqlite::Result<State> {
+        let mut state = State::new();
+
+        state.platform = self
+            .conn
+            .query_row("SELECT * FROM platform WHERE id = 0", [], |row| {
+                let withdrawal_token: Option<String> = row.get(5)?;
+                let emergency_withdrawal = match withdrawal_token {
+                    Some(token) => Some(EmergencyWithdrawal {
+                        token,
+                        amount: amount(row, 6)?,
+                        recipient: row.get(20)?,
+                        unlock_time: row.get::<_, i64>(7)? as u64,
+                    }),
+                    None => None,
+                };
+                let upgrade_wasm_hash: Option<Vec<u8>> = row.get(10)?;
+                let pending_upgrade = match upgrade_wasm_hash {
+                    Some(_) => Some(PendingUpgrade {
+                        wasm_hash: bytes32(row, 10)?,
+                        executable_at: row.get::<_, i64>(11)? as u64,
+                    }),
+                    None => None,
+                };
+                let pending_queued_by: Option<String> = row.get(18)?;
+                let pending_parameters = match pending_queued_by {
+                    Some(queued_by) => Some(PendingParameters {
+                        creation_fee: match row.get::<_, Option<String>>(16)? {
+                            Some(_) => Some(amount(row, 16)?),
+                            None => None,
+                        },
+                        token: row.get(17)?,
+                        queued_by,
+                        effective_at: row.get::<_, i64>(19)? as u64,
+                    }),
+                    None => None,
+                };
+                Ok(Platform {
+                    admin: row.get(1)?,
+                    token: row.get(2)?,
+                    creation_fee: amount(row, 3)?,
+                    paused: row.get(4)?,
+                    paused_at: row.get::<_, Option<i64>>(13)?.map(|t| t as u64),
+                    unpaused_at: row.get::<_, Option<i64>>(21)?.map(|t| t as u64),
+                    max_pause_duration: row.get::<_, Option<i64>>(14)?.map(|d| d as u64),
+                    emergency_withdrawal,
+                    emergency_withdraw_delay: row.get::<_, Option<i64>>(12)?.map(|d| d as u64),
+                    pending_admin: row.get(8)?,
+                    upgrade_timelock: row.get::<_, Option<i64>>(9)?.map(|t| t as u64),
+                    pending_upgrade,
+                    council: row
+                        .get::<_, Option<u32>>(15)?
+                        .map(|required_signatures| MultiSig {
+                            required_signatures,
+                            signers: Vec::new(),
+                        }),
+                    pending_parameters,
+                })
+            })
+            .optional()?;
+
+        let mut stmt = self
+            .conn
+            .prepare("SELECT signer FROM council_signers ORDER BY position")?;
+        for signer in stmt.query_map([], |row| row.get(0))? {
+            let signer = signer?;
+            if let Some(council) = state
+                .platform
+                .as_mut()
+                .and_then(|platform| platform.council.as_mut())
+            {
+                council.signers.push(signer);
+            }
+        }
+
+        let mut stmt = self.conn.prepare("SELECT * FROM council_proposals")?;
+        for row in stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, i64>(0)? as u64,
+                CouncilProposal {
+                    action: row.get(1)?,
+                    proposer: row.get(2)?,
+                    approvals: Vec::new(),
+                    expires_at: row.get::<_, i64>(3)? as u64,
+                    executed: row.get(4)?,
+                },
+            ))
+        })? {
+            let (id, proposal) = row?;
+            state.council_proposals.insert(id, proposal);
+        }
+
+        let mut stmt = self.conn.prepare(
+            "SELECT proposal_id, signer FROM council_approvals ORDER BY proposal_id, position",
+        )?;
+        for row in stmt.query_map([], |row| Ok((row.get::<_, i64>(0)?, row.get(1)?)))? {
+            let (proposal_id, signer) = row?;
+            if let Some(proposal) = state.council_proposals.get_mut(&(proposal_id as u64)) {
+                proposal.approvals.push(signer);
+            }
+        }
+
+        let mut stmt = self.conn.prepare("SELECT token, amount FROM fees_paid")?;
+        for row in stmt.query_map([], |row| Ok((row.get(0)?, amount(row, 1)?)))? {
+            let (token, paid) = row?;
+            state.fees_paid.insert(token, paid);
+        }
+
+        let mut stmt = self
+            .conn
+            .prepare("SELECT token, amount FROM fee_balances")?;
+        for row in stmt.query_map([], |row| Ok((row.get(0)?, amount(row, 1)?)))? {
+            let (token, balance) = row?;
+            state.fee_balances.insert(token, balance);
+        }
+
+        let mut stmt = self
+            .conn
+            .prepare("SELECT entity, fee FROM entity_creation_fees")?;
+        for row in stmt.query_map([], |row| Ok((fee_entity(row, 0)?, amount(row, 1)?)))? {
+            let (entity, fee) = row?;
+            state.entity_creation_fees.insert(entity, fee);
+        }
+
+        let mut stmt = self.conn.prepare("SELECT * FROM pending_entity_fees")?;
+        for row in stmt.query_map([], |row| {
+            Ok((
+                fee_entity(row, 0)?,
+                PendingEntityFee {
+                    fee: amount(row, 1)?,
+                    queued_by: row.get(2)?,
+                    effective_at: row.get::<_, i64>(3)? as u64,
+                },
+            ))
+        })? {
+            let (entity, pending) = row?;
+            state.pending_entity_fees.insert(entity, pending);
+        }
+
+        let platform_fee = self
+            .conn
+            .query_row("SELECT * FROM platform_fee WHERE id = 0", [], |row| {
+                let pending = match row.get::<_, Option<u32>>(2)? {
+                    Some(bps) => Some(PendingPlatformFee {
+                        bps,
+                        queued_by: row.get(3)?,
+                        effective_at: row.get::<_, i64>(4)? as u64,
+                    }),
+                    None => None,
+                };
+                Ok((row.get(1)?, pending))
+            })
+            .optional()?;
+        if let Some((bps, pending)) = platform_fee {
+            state.platform_fee_bps = bps;
+            state.pending_platform_fee = pending;
+        }
+
+        let mut stmt = self
+            .conn
+            .prepare("SELECT pool_id, bps FROM pool_platform_fees")?;
+        for row in stmt.query_map([], |row| Ok((row.get::<_, i64>(0)?, row.get(1)?)))? {
+            let (pool_id, bps) = row?;
+            state.pool_platform_fees.insert(pool_id as u64, bps);
+        }
+
+        let mut stmt = self
+            .conn
+            .prepare("SELECT creator, tier, tier_value, assigned_by, expires_at FROM fee_tiers")?;
+        for row in stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                CreatorFeeTier {
+                    tier: fee_tier(row, 1)?,
+                    assigned_by: row.get(3)?,
+                    expires_at: row.get::<_, i64>(4)? as u64,
+                },
+            ))
+        })? {
+            let (creator, tier) = row?;
+            state.fee_tiers.insert(creator, tier);
+        }
+
+        let mut stmt = self
+            .conn
+            .prepare("SELECT token, symbol, decimals, min_amount FROM accepted_tokens")?;
+        for row in stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                AcceptedToken {
+                    symbol: row.get(1)?,
+                    decimals: row.get(2)?,
+                    min_amount: amount(row, 3)?,
+                },
+            ))
+        })? {
+            let (token, accepted) = row?;
+            state.accepted_tokens.insert(token, accepted);
+        }
+
+        state.campaign_amendment_rules = self
+            .conn
+            .query_row(
+                "SELECT * FROM campaign_amendment_rules WHERE id = 0",
+                [],
+                |row| {
+                    Ok(CampaignAmendmentRules {
+                        max_extension: row.get::<_, i64>(1)? as u64,
+                        single_extension: row.get(2)?,
+                        material_goal_change_bps: row.get(3)?,
+                    })
+                },
+            )
+            .optional()?;
+
+        let mut stmt = self.conn.prepare("SELECT account, role FROM roles")?;
+        for row in stmt.query_map([], |row| {
+            let role = Role::from_u32(row.get(1)?).ok_or_else(|| {
+                rusqlite::Error::IntegralValueOutOfRange(1, row.get::<_, i64>(1).unwrap_or(-1))
+            })?;
+            Ok((row.get::<_, String>(0)?, role))
+        })? {
+            let (account, role) = row?;
+            state.roles.entry(account).or_default().insert(role);
+        }
+
+        let mut stmt = self.conn.prepare("SELECT * FROM feature_pauses")?;
+        for row in stmt.query_map([], |row| {
+            Ok((
+                row.get(0)?,
+                FeaturePause {
+                    reason: row.get(1)?,
+                    paused_at: row.get::<_, i64>(2)? as u64,
+                    expires_at: row.get::<_, i64>(3)? as u64,
+                },
+            ))
+        })? {
+            let (feature, pause) = row?;
+            state.feature_pauses.insert(feature, pause);
+        }
+
+        let mut stmt = self.conn.prepare("SELECT * FROM campaigns")?;
+        for campaign in stmt.query_map([], |row| {
+            Ok(Campaign {
+                id: bytes32(row, 0)?,
+                creator: row.get(1)?,
+                title: row.get(2)?,
+                goal: amount(row, 3)?,
+                deadline: row.get::<_, i64>(4)? as u64,
+                token: row.get(5)?,
+                total_raised: amount(row, 6)?,
+                donor_count: row.get(7)?,
+                last_donation_at: row.get::<_, i64>(8)? as u64,
+                goal_reached_at: row.get::<_, Option<i64>>(9)?.map(|t| t as u64),
+                cancelled_at: row.get::<_, Option<i64>>(10)?.map(|t| t as u64),
+                cancellation_reason: row.get(11)?,
+                revision: row.get(12)?,
+                metadata: CampaignMetadata {
+                    description: row.get(13)?,
+                    external_url: row.get(14)?,
+                    image_hash: row.get(15)?,
+                },
+                pending_owner: row.get(16)?,
+                contributions: BTreeMap::new(),
+            })
+        })? {
+            let campaign = campaign?;
+            state.campaigns.insert(campaign.id, campaign);
+        }
+
+        let mut stmt = self.conn.prepare("SELECT * FROM campaign_contributions")?;
+        for row in stmt.query_map([], |row| {
+            Ok((bytes32(row, 0)?, row.get(1)?, amount(row, 2)?))
+        })? {
+            let (id, contributor, donated): (CampaignId, String, i128) = row?;
+            if let Some(campaign) = state.campaigns.get_mut(&id) {
+                campaign.contributions.insert(contributor, donated);
+            }
+        }
+
+        let mut stmt = self.conn.prepare("SELECT * FROM pools")?;
+        for pool in stmt.query_map([], |row| {
+            let metadata_description: Option<String> = row.get(8)?;
+            let metadata = match metadata_description {
+                Some(description) => Some(PoolMetadata {
+                    description,
+                    external_url: row.get(9)?,
+                    image_hash: row.get(10)?,
+                }),
+                None => None,
+            };
+            let state = PoolState::from_u32(row.get(7)?).ok_or_else(|| {
+                rusqlite::Error::IntegralValueOutOfRange(7, row.get::<_, i64>(7).unwrap_or(-1))
+            })?;
+            Ok(Pool {
+                id: row.get::<_, i64>(0)? as u64,
+                creator: row.get(1)?,
+                name: row.get(2)?,
+                description: row.get(3)?,
+                target_amount: amount(row, 4)?,
+                is_private: row.get(5)?,
+                deadline: row.get::<_, i64>(6)? as u64,
+                state,
+                metadata,
+                multisig: row
+                    .get::<_, Option<u32>>(11)?
+                    .map(|required_signatures| MultiSig {
+                        required_signatures,
+                        signers: Vec::new(),
+                    }),
+                total_raised: amount(row, 12)?,
+                contributor_count: row.get(13)?,
+                last_donation_at: row.get::<_, i64>(14)? as u64,
+                closed_by: row.get(15)?,
+                pending_owner: row.get(16)?,
+                contributions: BTreeMap::new(),
+                refunds: Vec::new(),
+            })
+        })? {
+            let pool = pool?;
+            state.pools.insert(pool.id, pool);
+        }
+
+        let mut stmt = self
+            .conn
+            .prepare("SELECT pool_id, signer FROM pool_signers ORDER BY pool_id, position")?;
+        for row in stmt.query_map([], |row| Ok((row.get::<_, i64>(0)?, row.get(1)?)))? {
+            let (pool_id, signer) = row?;
+            if let Some(multisig) = state
+                .pools
+                .get_mut(&(pool_id as u64))
+                .and_then(|pool| pool.multisig.as_mut())
+            {
+                multisig.signers.push(signer);
+            }
+        }
+
+        let mut stmt = self.conn.prepare("SELECT * FROM pool_contributions")?;
+        for row in stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get(1)?,
+                PoolContribution {
+                    asset: row.get(2)?,
+                    amount: amount(row, 3)?,
+                },
+            ))
+        })? {
+            let (pool_id, contributor, contribution) = row?;
+            if let Some(pool) = state.pools.get_mut(&(pool_id as u64)) {
+                pool.contributions.insert(contributor, contribution);
+            }
+        }
+
+        let mut stmt = self.conn.prepare(
+            "SELECT pool_id, contributor, asset, amount, timestamp FROM refunds
+             ORDER BY pool_id, position",
+        )?;
+        for row in stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                RefundRecord {
+                    contributor: row.get(1)?,
+                    asset: row.get(2)?,
+                    amount: amount(row, 3)?,
+                    timestamp: row.get::<_, i64>(4)? as u64,
+                },
+            ))
+        })? {
+            let (pool_id, refund) = row?;
+            if let Some(pool) = state.pools.get_mut(&(pool_id as u64)) {
+                pool.refunds.push(refund);
+            }
+        }
+
+        Ok(state)
+    }
+}
+
+fn amount(row: &Row<'_>, index: usize) -> rusqlite::Result<i128> {
+    let text: String = row.get(index)?;
+    text.parse().map_err(|err| {
+        rusqlite::Error::FromSqlConversionFailure(index, rusqlite::types::Type::Text, Box::new(err))
+    })
+}
+
+fn fee_entity(row: &Row<'_>, index: usize) -> rusqlite::Result<FeeEntity> {
+    FeeEntity::from_u32(row.get(index)?).ok_or_else(|| {
+        rusqlite::Error::IntegralValueOutOfRange(index, row.get::<_, i64>(index).unwrap_or(-1))
+    })
+}
+
+/// A `FeeTier` stored as its case name at `index` and its value, if any, as
+/// decimal text in the column after it.
+fn fee_tier(row: &Row<'_>, index: usize) -> rusqlite::Result<FeeTier> {
+    match row.get::<_, String>(index)?.as_str() {
+        "Waived" => Ok(FeeTier::Waived),
+        "Discount" => {
+            let bps = amount(row, index + 1)?;
+            u32::try_from(bps)
+                .map(FeeTier::Discount)
+                .map_err(|_| rusqlite::Error::IntegralValueOutOfRange(index + 1, bps as i64))
+        }
+        "FlatFee" => Ok(FeeTier::FlatFee(amount(row, index + 1)?)),
+        case => Err(rusqlite::Error::InvalidColumnType(
+            index,
+            case.into(),
+            rusqlite::types::Type::Text,
+        )),
+    }
+}
+
+fn bytes32(row: &Row<'_>, index: usize) -> rusqlite::Result<CampaignId> {
+    let bytes: Vec<u8> = row.get(index)?;
+    bytes.as_slice().try_into().map_err(|_| {
+        rusqlite::Error::InvalidColumnType(index, "bytes32".into(), rusqlite::types::Type::Blob)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let mut state = State::new();
+        state.platform = Some(Platform {
+            admin: "GADMIN".into(),
+            token: "CTOKEN".into(),
+            creation_fee: i128::MAX,
+            paused: true,
+            paused_at: Some(u64::MAX),
+            unpaused_at: Some(u64::MAX),
+            max_pause_duration: Some(u64::MAX),
+            emergency_withdrawal: Some(EmergencyWithdrawal {
+                token: "CTOKEN".into(),
+                amount: 5,
+                recipient: "GRECIPIENT".into(),
+                unlock_time: u64::MAX,
+            }),
+            emergency_withdraw_delay: Some(u64::MAX),
+            pending_admin: Some("GNEXT".into()),
+            upgrade_timelock: Some(u64::MAX),
+            pending_upgrade: Some(PendingUpgrade {
+                wasm_hash: [9u8; 32],
+                executable_at: u64::MAX,
+            }),
+            council: Some(MultiSig {
+                required_signatures: 2,
+                signers: vec!["GM1".into(), "GM2".into(), "GM3".into()],
+            }),
+            pending_parameters: Some(PendingParameters {
+                creation_fee: Some(i128::MIN),
+                token: None,
+                queued_by: "GADMIN".into(),
+                effective_at: u64::MAX,
+            }),
+        });
+        state.council_proposals.insert(
+            u64::MAX,
+            CouncilProposal {
+                action: "SetCreationFee".into(),
+                proposer: "GM1".into(),
+                approvals: vec!["GM1".into(), "GM3".into()],
+                expires_at: u64::MAX,
+                executed: true,
+            },
+        );
+        state.fees_paid.insert("CTOKEN".into(), 42);
+        state.fee_balances.insert("CTOKEN".into(), 12);
+        state
+            .entity_creation_fees
+            .insert(FeeEntity::MultiSigPool, 30);
+        state.pending_entity_fees.insert(
+            FeeEntity::PrivatePool,
+            PendingEntityFee {
+                fee: i128::MAX,
+                queued_by: "GFEES".into(),
+                effective_at: u64::MAX,
+            },
+        );
+        state.platform_fee_bps = 250;
+        state.pending_platform_fee = Some(PendingPlatformFee {
+            bps: u32::MAX,
+            queued_by: "GFEES".into(),
+            effective_at: u64::MAX,
+        });
+        state.pool_platform_fees.insert(u64::MAX, 10_000);
+        state.fee_tiers.insert(
+            "GCHARITY".into(),
+            CreatorFeeTier {
+                tier: FeeTier::FlatFee(i128::MIN),
+                assigned_by: "GVERIFIER".into(),
+                expires_at: u64::MAX,
+            },
+        );
+        state.fee_tiers.insert(
+            "GPARTNER".into(),
+            CreatorFeeTier {
+                tier: FeeTier::Discount(u32::MAX),
+                assigned_by: "GADMIN".into(),
+                expires_at: 0,
+            },
+        );
+        state.fee_tiers.insert(
+            "GWAIVED".into(),
+            CreatorFeeTier {
+                tier: FeeTier::Waived,
+                assigned_by: "GADMIN".into(),
+                expires_at: 0,
+            },
+        );
+        state.accepted_tokens.insert(
+            "CUSDC".into(),
+            AcceptedToken {
+                symbol: "USDC".into(),
+                decimals: u32::MAX,
+                min_amount: i128::MIN,
+            },
+        );
+        state.campaign_amendment_rules = Some(CampaignAmendmentRules {
+            max_extension: u64::MAX,
+            single_extension: true,
+            material_goal_change_bps: 500,
+        });
+        state.roles.insert(
+            "GPAUSER".into(),
+            [Role::Pauser, Role::Treasury].into_iter().collect(),
+        );
+        state.feature_pauses.insert(
+            8,
+            FeaturePause {
+                reason: u32::MAX,
+                paused_at: 7,
+                expires_at: u64::MAX,
+            },
+        );
+
+        let mut campaign = Campaign {
+            id: [3u8; 32],
+            creator: "GCREATOR".into(),
+            title: "Title".into(),
+            goal: 1_000,
+            deadline: 99,
+            token: "CTOKEN".into(),
+            total_raised: 10,
+            donor_count: 1,
+            last_donation_at: 50,
+            goal_reached_at: None,
+            cancelled_at: Some(60),
+            cancellation_reason: Some("Permit denied".into()),
+            revision: u32::MAX,
+            metadata: CampaignMetadata {
+                description: "Wells".into(),
+                external_url: String::new(),
+                image_hash: "QmWells".into(),
+            },
+            pending_owner: Some("GNEXT".into()),
+            contributions: BTreeMap::new(),
+        };
+        campaign.contributions.insert("GDONOR".into(), 10);
+        state.campaigns.insert(campaign.id, campaign);
+
+        let mut pool = Pool {
+            id: 7,
+            creator: "GCREATOR".into(),
+            name: "Pool".into(),
+            description: "Desc".into(),
+            target_amount: 500,
+            is_private: false,
+            deadline: 1_000,
+            state: PoolState::Disbursed,
+            metadata: Some(PoolMetadata {
+                description: "Desc".into(),
+                external_url: "https://example.com".into(),
+                image_hash: "hash".into(),
+            }),
+            multisig: Some(MultiSig {
+                required_signatures: 2,
+                signers: vec!["GA".into(), "GB".into(), "GC".into()],
+            }),
+            total_raised: 0,
+            contributor_count: 1,
+            last_donation_at: 20,
+            closed_by: None,
+            pending_owner: None,
+            contributions: BTreeMap::new(),
+            refunds: vec![RefundRecord {
+                contributor: "GDONOR".into(),
+                asset: "CTOKEN".into(),
+                amount: 30,
+                timestamp: 2_000,
+            }],
+        };
+        pool.contributions.insert(
+            "GDONOR".into(),
+            PoolContribution {
+                asset: "CTOKEN".into(),
+                amount: 0,
+            },
+        );
+        state.pools.insert(pool.id, pool);
+
+        let mut store = SqliteStore::open_in_memory().unwrap();
+        store.save(&state).unwrap();
+        assert_eq!(store.load().unwrap(), state);
+
+        // Saving again replaces rather than duplicates rows
+        store.save(&State::new()).unwrap();
+        assert_eq!(store.load().unwrap(), State::new());
+    }
+}
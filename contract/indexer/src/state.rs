@@ -0,0 +1,924 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{
+    error::ApplyError,
+    event::{CampaignId, Event, FeeEntity, FeeTier, PoolState, Role},
+};
+
+/// How long the contract or a feature must stay unpaused before a pause
+/// starts a new clock, mirroring the contract's `MIN_UNPAUSED_PERIOD`.
+pub const MIN_UNPAUSED_PERIOD: u64 = 7 * 24 * 60 * 60;
+
+/// Platform-wide configuration set through `initialize` and the admin setters.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Platform {
+    pub admin: String,
+    pub token: String,
+    pub creation_fee: i128,
+    pub paused: bool,
+    /// Start of the latest pause. A pause within `MIN_UNPAUSED_PERIOD` of
+    /// the previous one ending continues from the same start.
+    pub paused_at: Option<u64>,
+    /// Timestamp of the `contract_unpaused` event while unpaused.
+    pub unpaused_at: Option<u64>,
+    /// Duration set through `set_max_pause_duration`; `None` means the
+    /// contract default.
+    pub max_pause_duration: Option<u64>,
+    pub emergency_withdrawal: Option<EmergencyWithdrawal>,
+    /// Delay set through `set_emergency_withdraw_delay`; `None` means the
+    /// contract default.
+    pub emergency_withdraw_delay: Option<u64>,
+    /// Address proposed through `propose_admin` and not yet accepted.
+    pub pending_admin: Option<String>,
+    /// Delay set through `set_upgrade_timelock`; `None` means the contract default.
+    pub upgrade_timelock: Option<u64>,
+    pub pending_upgrade: Option<PendingUpgrade>,
+    /// Council set through `set_council`; privileged setters go through its
+    /// proposals while it is set.
+    pub council: Option<MultiSig>,
+    pub pending_parameters: Option<PendingParameters>,
+}
+
+/// Creation fee and token changes queued by the setters. They take effect at
+/// `effective_at`, but are only written, with their `*_set` events, by the
+/// first call that needs them afterwards.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingParameters {
+    pub creation_fee: Option<i128>,
+    pub token: Option<String>,
+    pub queued_by: String,
+    pub effective_at: u64,
+}
+
+/// A pool entity fee change queued by `set_entity_creation_fee`. Like
+/// `PendingParameters`, it is only written by the first call that needs it
+/// after `effective_at`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingEntityFee {
+    pub fee: i128,
+    pub queued_by: String,
+    pub effective_at: u64,
+}
+
+/// A platform fee rate change queued by `set_platform_fee`, written like
+/// `PendingEntityFee`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingPlatformFee {
+    pub bps: u32,
+    pub queued_by: String,
+    pub effective_at: u64,
+}
+
+/// A creator's fee tier. `expires_at` of 0 never expires; otherwise the tier
+/// has lapsed once the ledger reaches `expires_at`, which emits no event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreatorFeeTier {
+    pub tier: FeeTier,
+    pub assigned_by: String,
+    pub expires_at: u64,
+}
+
+/// A token registered through `set_accepted_token`, or the platform token
+/// registered by `initialize`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AcceptedToken {
+    pub symbol: String,
+    pub decimals: u32,
+    pub min_amount: i128,
+}
+
+/// Limits set through `set_campaign_amendment_rules`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CampaignAmendmentRules {
+    pub max_extension: u64,
+    pub single_extension: bool,
+    pub material_goal_change_bps: u32,
+}
+
+/// A council proposal. `action` is the `CouncilAction` case name.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CouncilProposal {
+    pub action: String,
+    pub proposer: String,
+    pub approvals: Vec<String>,
+    pub expires_at: u64,
+    pub executed: bool,
+}
+
+/// A pause on a single feature. The pause has lapsed once the ledger reaches
+/// `expires_at`, which emits no event; unpausing sets `expires_at` to the
+/// unpause time.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeaturePause {
+    pub reason: u32,
+    pub paused_at: u64,
+    pub expires_at: u64,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingUpgrade {
+    pub wasm_hash: [u8; 32],
+    pub executable_at: u64,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyWithdrawal {
+    pub token: String,
+    pub amount: i128,
+    pub recipient: String,
+    pub unlock_time: u64,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Campaign {
+    pub id: CampaignId,
+    pub creator: String,
+    pub title: String,
+    pub goal: i128,
+    pub deadline: u64,
+    pub token: String,
+    pub total_raised: i128,
+    pub donor_count: u32,
+    pub last_donation_at: u64,
+    pub goal_reached_at: Option<u64>,
+    pub cancelled_at: Option<u64>,
+    pub cancellation_reason: Option<String>,
+    /// Bumped by material amendments to the goal or deadline.
+    pub revision: u32,
+    pub metadata: CampaignMetadata,
+    /// Account offered ownership that has not accepted yet.
+    pub pending_owner: Option<String>,
+    /// Cumulative donations keyed by donor address.
+    pub contributions: BTreeMap<String, i128>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CampaignMetadata {
+    pub description: String,
+    pub external_url: String,
+    pub image_hash: String,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PoolMetadata {
+    pub description: String,
+    pub external_url: String,
+    pub image_hash: String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MultiSig {
+    pub required_signatures: u32,
+    pub signers: Vec<String>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolContribution {
+    pub asset: String,
+    pub amount: i128,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundRecord {
+    pub contributor: String,
+    pub asset: String,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Pool {
+    pub id: u64,
+    pub creator: String,
+    pub name: String,
+    pub description: String,
+    pub target_amount: i128,
+    pub is_private: bool,
+    pub deadline: u64,
+    pub state: PoolState,
+    pub metadata: Option<PoolMetadata>,
+    pub multisig: Option<MultiSig>,
+    pub total_raised: i128,
+    pub contributor_count: u32,
+    pub last_donation_at: u64,
+    pub closed_by: Option<String>,
+    /// Account offered ownership that has not accepted yet.
+    pub pending_owner: Option<String>,
+    /// Outstanding contribution per contributor; zeroed once refunded.
+    pub contributions: BTreeMap<String, PoolContribution>,
+    pub refunds: Vec<RefundRecord>,
+}
+
+/// Contract state rebuilt by folding decoded events in emission order.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct State {
+    pub platform: Option<Platform>,
+    pub campaigns: BTreeMap<CampaignId, Campaign>,
+    pub pools: BTreeMap<u64, Pool>,
+    /// Creation fees paid, keyed by token address.
+    pub fees_paid: BTreeMap<String, i128>,
+    /// Creation and platform fees not yet taken through `withdraw_fees`,
+    /// keyed by token address.
+    pub fee_balances: BTreeMap<String, i128>,
+    /// Roles granted by the admin, keyed by account address.
+    pub roles: BTreeMap<String, BTreeSet<Role>>,
+    /// Feature pauses, keyed by the feature's `PAUSE_*` bit. Lapsed and
+    /// unpaused pauses are kept, like in the contract.
+    pub feature_pauses: BTreeMap<u32, FeaturePause>,
+    /// Council proposals keyed by proposal id. Executed and expired proposals
+    /// are kept, though the contract removes them.
+    pub council_proposals: BTreeMap<u64, CouncilProposal>,
+    /// Pool creation fees; the campaign fee is `Platform::creation_fee`.
+    pub entity_creation_fees: BTreeMap<FeeEntity, i128>,
+    pub pending_entity_fees: BTreeMap<FeeEntity, PendingEntityFee>,
+    /// Platform fee rate in basis points of donations and contributions.
+    pub platform_fee_bps: u32,
+    pub pending_platform_fee: Option<PendingPlatformFee>,
+    /// Platform fee rates overriding `platform_fee_bps`, keyed by pool id.
+    pub pool_platform_fees: BTreeMap<u64, u32>,
+    /// Fee tiers assigned through `assign_fee_tier`, keyed by creator.
+    pub fee_tiers: BTreeMap<String, CreatorFeeTier>,
+    /// Tokens campaigns and pools may raise in, keyed by token address.
+    pub accepted_tokens: BTreeMap<String, AcceptedToken>,
+    /// `None` means the contract defaults.
+    pub campaign_amendment_rules: Option<CampaignAmendmentRules>,
+}
+
+impl Platform {
+    /// The queued changes, extended by another one that restarts the delay.
+    fn queue_pending(&mut self, queued_by: &str, effective_at: u64) -> &mut PendingParameters {
+        let pending = self
+            .pending_parameters
+            .get_or_insert_with(|| PendingParameters {
+                creation_fee: None,
+                token: None,
+                queued_by: String::new(),
+                effective_at,
+            });
+        pending.queued_by = queued_by.to_string();
+        pending.effective_at = effective_at;
+        pending
+    }
+
+    /// Drop a queued change once it has been written.
+    fn settle_pending(&mut self, settle: impl FnOnce(&mut PendingParameters)) {
+        if let Some(pending) = &mut self.pending_parameters {
+            settle(pending);
+            if pending.creation_fee.is_none() && pending.token.is_none() {
+                self.pending_parameters = None;
+            }
+        }
+    }
+}
+
+impl State {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fold a single event into the model.
+    pub fn apply(&mut self, event: &Event) -> Result<(), ApplyError> {
+        match event {
+            Event::ContractInitialized {
+                admin,
+                token,
+                creation_fee,
+            } => {
+                self.platform = Some(Platform {
+                    admin: admin.clone(),
+                    token: token.clone(),
+                    creation_fee: *creation_fee,
+                    paused: false,
+                    paused_at: None,
+                    unpaused_at: None,
+                    max_pause_duration: None,
+                    emergency_withdrawal: None,
+                    emergency_withdraw_delay: None,
+                    pending_admin: None,
+                    upgrade_timelock: None,
+                    pending_upgrade: None,
+                    council: None,
+                    pending_parameters: None,
+                });
+            }
+            Event::CrowdfundingTokenSet { token, .. } => {
+                let platform = self.platform_mut();
+                platform.token = token.clone();
+                platform.settle_pending(|pending| pending.token = None);
+            }
+            Event::CreationFeeSet { fee, .. } => {
+                let platform = self.platform_mut();
+                platform.creation_fee = *fee;
+                platform.settle_pending(|pending| pending.creation_fee = None);
+            }
+            Event::CreationFeeQueued {
+                admin,
+                fee,
+                effective_at,
+            } => {
+                self.platform_mut()
+                    .queue_pending(admin, *effective_at)
+                    .creation_fee = Some(*fee);
+            }
+            Event::CrowdfundingTokenQueued {
+                admin,
+                token,
+                effective_at,
+            } => {
+                self.platform_mut()
+                    .queue_pending(admin, *effective_at)
+                    .token = Some(token.clone());
+            }
+            Event::EntityCreationFeeQueued {
+                admin,
+                entity,
+                fee,
+                effective_at,
+            } => {
+                self.pending_entity_fees.insert(
+                    *entity,
+                    PendingEntityFee {
+                        fee: *fee,
+                        queued_by: admin.clone(),
+                        effective_at: *effective_at,
+                    },
+                );
+            }
+            Event::EntityCreationFeeSet { entity, fee, .. } => {
+                self.entity_creation_fees.insert(*entity, *fee);
+                self.pending_entity_fees.remove(entity);
+            }
+            Event::PlatformFeeQueued {
+                admin,
+                bps,
+                effective_at,
+            } => {
+                self.pending_platform_fee = Some(PendingPlatformFee {
+                    bps: *bps,
+                    queued_by: admin.clone(),
+                    effective_at: *effective_at,
+                });
+            }
+            Event::PlatformFeeSet { bps, .. } => {
+                self.platform_fee_bps = *bps;
+                self.pending_platform_fee = None;
+            }
+            Event::PoolPlatformFeeSet { pool_id, bps, .. } => match bps {
+                Some(bps) => {
+                    self.pool_platform_fees.insert(*pool_id, *bps);
+                }
+                None => {
+                    self.pool_platform_fees.remove(pool_id);
+                }
+            },
+            Event::FeeTierAssigned {
+                admin,
+                creator,
+                tier,
+                expires_at,
+            } => {
+                self.fee_tiers.insert(
+                    creator.clone(),
+                    CreatorFeeTier {
+                        tier: *tier,
+                        assigned_by: admin.clone(),
+                        expires_at: *expires_at,
+                    },
+                );
+            }
+            Event::FeeTierRevoked { creator, .. } => {
+                self.fee_tiers.remove(creator);
+            }
+            Event::AcceptedTokenSet {
+                token,
+                symbol,
+                decimals,
+                min_amount,
+                ..
+            } => {
+                self.accepted_tokens.insert(
+                    token.clone(),
+                    AcceptedToken {
+                        symbol: symbol.clone(),
+                        decimals: *decimals,
+                        min_amount: *min_amount,
+                    },
+                );
+            }
+            Event::AcceptedTokenRemoved { token, .. } => {
+                self.accepted_tokens.remove(token);
+            }
+            Event::CampaignAmendmentRulesSet {
+                max_extension,
+                single_extension,
+                material_goal_change_bps,
+                ..
+            } => {
+                self.campaign_amendment_rules = Some(CampaignAmendmentRules {
+                    max_extension: *max_extension,
+                    single_extension: *single_extension,
+                    material_goal_change_bps: *material_goal_change_bps,
+                });
+            }
+            Event::PlatformFeePaid { token, amount, .. } => {
+                let balance = self.fee_balances.entry(token.clone()).or_default();
+                *balance = balance.checked_add(*amount).ok_or(ApplyError::Overflow)?;
+            }
+            Event::CreationFeePaid { token, amount, .. } => {
+                let paid = self.fees_paid.entry(token.clone()).or_default();
+                *paid = paid.checked_add(*amount).ok_or(ApplyError::Overflow)?;
+                let balance = self.fee_balances.entry(token.clone()).or_default();
+                *balance = balance.checked_add(*amount).ok_or(ApplyError::Overflow)?;
+            }
+            Event::FeesWithdrawn { token, amount, .. } => {
+                let balance = self.fee_balances.get(token).copied().unwrap_or_default();
+                if *amount > balance {
+                    return Err(ApplyError::FeeWithdrawalExceedsBalance(token.clone()));
+                }
+                self.fee_balances.insert(token.clone(), balance - amount);
+            }
+            Event::ContractPaused { timestamp, .. } => {
+                let platform = self.platform_mut();
+                platform.paused = true;
+                platform.paused_at = match (platform.paused_at, platform.unpaused_at) {
+                    (Some(paused_at), Some(unpaused_at))
+                        if *timestamp < unpaused_at.saturating_add(MIN_UNPAUSED_PERIOD) =>
+                    {
+                        Some(paused_at)
+                    }
+                    _ => Some(*timestamp),
+                };
+                platform.unpaused_at = None;
+            }
+            Event::ContractUnpaused { timestamp, .. } => {
+                let platform = self.platform_mut();
+                platform.paused = false;
+                platform.unpaused_at = Some(*timestamp);
+            }
+            Event::FeaturesPaused {
+                features,
+                reason,
+                timestamp,
+                expires_at,
+                ..
+            } => {
+                for feature in feature_bits(*features) {
+                    // A feature re-paused, or paused again within
+                    // `MIN_UNPAUSED_PERIOD`, keeps counting from its original start
+                    let paused_at = match self.feature_pauses.get(&feature) {
+                        Some(pause)
+                            if *timestamp
+                                < pause.expires_at.saturating_add(MIN_UNPAUSED_PERIOD) =>
+                        {
+                            pause.paused_at
+                        }
+                        _ => *timestamp,
+                    };
+                    self.feature_pauses.insert(
+                        feature,
+                        FeaturePause {
+                            reason: *reason,
+                            paused_at,
+                            expires_at: *expires_at,
+                        },
+                    );
+                }
+            }
+            Event::FeaturesUnpaused {
+                features,
+                timestamp,
+                ..
+            } => {
+                for feature in feature_bits(*features) {
+                    if let Some(pause) = self.feature_pauses.get_mut(&feature) {
+                        pause.expires_at = pause.expires_at.min(*timestamp);
+                    }
+                }
+            }
+            Event::MaxPauseDurationSet { duration, .. } => {
+                self.platform_mut().max_pause_duration = Some(*duration);
+            }
+            Event::EmergencyWithdrawRequested {
+                admin,
+                token,
+                amount,
+                recipient,
+                unlock_time,
+            } => {
+                self.platform_mut().emergency_withdrawal = Some(EmergencyWithdrawal {
+                    token: token.clone(),
+                    amount: *amount,
+                    recipient: recipient.clone().unwrap_or_else(|| admin.clone()),
+                    unlock_time: *unlock_time,
+                });
+            }
+            Event::EmergencyWithdrawExecuted { .. } | Event::EmergencyWithdrawCancelled { .. } => {
+                self.platform_mut().emergency_withdrawal = None;
+            }
+            Event::EmergencyWithdrawDelaySet { delay, .. } => {
+                self.platform_mut().emergency_withdraw_delay = Some(*delay);
+            }
+            Event::AdminProposed { proposed_admin, .. } => {
+                self.platform_mut().pending_admin = Some(proposed_admin.clone());
+            }
+            Event::AdminProposalCancelled { .. } => {
+                self.platform_mut().pending_admin = None;
+            }
+            Event::AdminTransferred { new_admin, .. } => {
+                let platform = self.platform_mut();
+                platform.admin = new_admin.clone();
+                platform.pending_admin = None;
+            }
+            Event::RoleGranted { account, role, .. } => {
+                self.roles.entry(account.clone()).or_default().insert(*role);
+            }
+            Event::RoleRevoked { account, role, .. } => {
+                if let Some(roles) = self.roles.get_mut(account) {
+                    roles.remove(role);
+                    if roles.is_empty() {
+                        self.roles.remove(account);
+                    }
+                }
+            }
+            Event::UpgradeTimelockSet { delay, .. } => {
+                self.platform_mut().upgrade_timelock = Some(*delay);
+            }
+            Event::UpgradeProposed {
+                wasm_hash,
+                executable_at,
+                ..
+            } => {
+                self.platform_mut().pending_upgrade = Some(PendingUpgrade {
+                    wasm_hash: *wasm_hash,
+                    executable_at: *executable_at,
+                });
+            }
+            Event::UpgradeCancelled { .. } | Event::UpgradeExecuted { .. } => {
+                self.platform_mut().pending_upgrade = None;
+            }
+            // Rewrites the storage layout only; the replayed model is unchanged
+            Event::StorageMigrated { .. } => {}
+            Event::CouncilSet {
+                required_signatures,
+                signers,
+                ..
+            } => {
+                self.platform_mut().council = Some(MultiSig {
+                    required_signatures: *required_signatures,
+                    signers: signers.clone(),
+                });
+            }
+            Event::CouncilDisabled { .. } => {
+                self.platform_mut().council = None;
+            }
+            Event::CouncilActionProposed {
+                proposal_id,
+                proposer,
+                action,
+                expires_at,
+            } => {
+                self.council_proposals.insert(
+                    *proposal_id,
+                    CouncilProposal {
+                        action: action.clone(),
+                        proposer: proposer.clone(),
+                        approvals: vec![proposer.clone()],
+                        expires_at: *expires_at,
+                        executed: false,
+                    },
+                );
+            }
+            Event::CouncilActionApproved {
+                proposal_id,
+                signer,
+                ..
+            } => {
+                self.council_proposal_mut(*proposal_id)?
+                    .approvals
+                    .push(signer.clone());
+            }
+            Event::CouncilActionExecuted { proposal_id, .. } => {
+                self.council_proposal_mut(*proposal_id)?.executed = true;
+            }
+            Event::CampaignCreated {
+                campaign_id,
+                creator,
+                title,
+                goal,
+                deadline,
+                token,
+            } => {
+                if self.campaigns.contains_key(campaign_id) {
+                    return Err(ApplyError::DuplicateCampaign(*campaign_id));
+                }
+                self.campaigns.insert(
+                    *campaign_id,
+                    Campaign {
+                        id: *campaign_id,
+                        creator: creator.clone(),
+                        title: title.clone(),
+                        goal: *goal,
+                        deadline: *deadline,
+                        token: token.clone(),
+                        total_raised: 0,
+                        donor_count: 0,
+                        last_donation_at: 0,
+                        goal_reached_at: None,
+                        cancelled_at: None,
+                        cancellation_reason: None,
+                        revision: 0,
+                        metadata: CampaignMetadata::default(),
+                        pending_owner: None,
+                        contributions: BTreeMap::new(),
+                    },
+                );
+            }
+            Event::DonationMade {
+                campaign_id,
+                contributor,
+                amount,
+                timestamp,
+                ..
+            } => {
+                let campaign = self.campaign_mut(campaign_id)?;
+                campaign.total_raised = campaign
+                    .total_raised
+                    .checked_add(*amount)
+                    .ok_or(ApplyError::Overflow)?;
+                campaign.last_donation_at = *timestamp;
+                if !campaign.contributions.contains_key(contributor) {
+                    campaign.donor_count += 1;
+                }
+                let donated = campaign
+                    .contributions
+                    .entry(contributor.clone())
+                    .or_default();
+                *donated = donated.checked_add(*amount).ok_or(ApplyError::Overflow)?;
+            }
+            Event::CampaignRefunded {
+                campaign_id,
+                contributor,
+                amount,
+                ..
+            } => {
+                let campaign = self.campaign_mut(campaign_id)?;
+                // The donor stays counted, as in the contract
+                let donated = campaign
+                    .contributions
+                    .get_mut(contributor)
+                    .filter(|donated| **donated >= *amount)
+                    .ok_or_else(|| ApplyError::CampaignRefundExceedsDonation {
+                        campaign_id: *campaign_id,
+                        contributor: contributor.clone(),
+                    })?;
+                *donated -= *amount;
+                campaign.total_raised = campaign
+                    .total_raised
+                    .checked_sub(*amount)
+                    .ok_or(ApplyError::Overflow)?;
+            }
+            Event::CampaignGoalReached {
+                campaign_id,
+                timestamp,
+                ..
+            } => {
+                let campaign = self.campaign_mut(campaign_id)?;
+                campaign.goal_reached_at.get_or_insert(*timestamp);
+            }
+            Event::CampaignCancelled {
+                campaign_id,
+                reason,
+                timestamp,
+                ..
+            } => {
+                let campaign = self.campaign_mut(campaign_id)?;
+                campaign.cancelled_at = Some(*timestamp);
+                campaign.cancellation_reason = Some(reason.clone());
+            }
+            Event::CampaignAmended {
+                campaign_id,
+                goal,
+                deadline,
+                revision,
+            } => {
+                let campaign = self.campaign_mut(campaign_id)?;
+                campaign.goal = *goal;
+                campaign.deadline = *deadline;
+                campaign.revision = *revision;
+                // A lowered goal is reached by the `campaign_goal_reached`
+                // event that follows; a raised one may no longer be
+                if campaign.total_raised < *goal {
+                    campaign.goal_reached_at = None;
+                }
+            }
+            Event::CampaignDetailsUpdated {
+                campaign_id,
+                title,
+                description,
+                external_url,
+                image_hash,
+            } => {
+                let campaign = self.campaign_mut(campaign_id)?;
+                campaign.title = title.clone();
+                campaign.metadata = CampaignMetadata {
+                    description: description.clone(),
+                    external_url: external_url.clone(),
+                    image_hash: image_hash.clone(),
+                };
+            }
+            Event::CampaignOwnershipProposed {
+                campaign_id,
+                proposed_owner,
+                ..
+            } => {
+                self.campaign_mut(campaign_id)?.pending_owner = Some(proposed_owner.clone());
+            }
+            Event::CampaignTransferCancelled { campaign_id, .. } => {
+                self.campaign_mut(campaign_id)?.pending_owner = None;
+            }
+            Event::CampaignOwnershipTransferred {
+                campaign_id,
+                new_owner,
+                ..
+            } => {
+                let campaign = self.campaign_mut(campaign_id)?;
+                campaign.creator = new_owner.clone();
+                campaign.pending_owner = None;
+            }
+            Event::PoolCreated {
+                pool_id,
+                creator,
+                name,
+                description,
+                target_amount,
+                is_private,
+                deadline,
+            } => {
+                if self.pools.contains_key(pool_id) {
+                    return Err(ApplyError::DuplicatePool(*pool_id));
+                }
+                self.pools.insert(
+                    *pool_id,
+                    Pool {
+                        id: *pool_id,
+                        creator: creator.clone(),
+                        name: name.clone(),
+                        description: description.clone(),
+                        target_amount: *target_amount,
+                        is_private: *is_private,
+                        deadline: *deadline,
+                        state: PoolState::Active,
+                        metadata: None,
+                        multisig: None,
+                        total_raised: 0,
+                        contributor_count: 0,
+                        last_donation_at: 0,
+                        closed_by: None,
+                        pending_owner: None,
+                        contributions: BTreeMap::new(),
+                        refunds: Vec::new(),
+                    },
+                );
+            }
+            Event::PoolMetadataSet {
+                pool_id,
+                description,
+                external_url,
+                image_hash,
+            } => {
+                self.pool_mut(*pool_id)?.metadata = Some(PoolMetadata {
+                    description: description.clone(),
+                    external_url: external_url.clone(),
+                    image_hash: image_hash.clone(),
+                });
+            }
+            Event::PoolMultiSigSet {
+                pool_id,
+                required_signatures,
+                signers,
+            } => {
+                self.pool_mut(*pool_id)?.multisig = Some(MultiSig {
+                    required_signatures: *required_signatures,
+                    signers: signers.clone(),
+                });
+            }
+            Event::PoolOwnershipProposed {
+                pool_id,
+                proposed_owner,
+                ..
+            } => {
+                self.pool_mut(*pool_id)?.pending_owner = Some(proposed_owner.clone());
+            }
+            Event::PoolTransferCancelled { pool_id, .. } => {
+                self.pool_mut(*pool_id)?.pending_owner = None;
+            }
+            Event::PoolOwnershipTransferred {
+                pool_id, new_owner, ..
+            } => {
+                let pool = self.pool_mut(*pool_id)?;
+                pool.creator = new_owner.clone();
+                pool.pending_owner = None;
+            }
+            Event::PoolStateUpdated {
+                pool_id, new_state, ..
+            } => {
+                self.pool_mut(*pool_id)?.state = *new_state;
+            }
+            Event::Contribution {
+                pool_id,
+                contributor,
+                asset,
+                amount,
+                timestamp,
+                ..
+            } => {
+                let pool = self.pool_mut(*pool_id)?;
+                let contribution =
+                    pool.contributions
+                        .entry(contributor.clone())
+                        .or_insert(PoolContribution {
+                            asset: asset.clone(),
+                            amount: 0,
+                        });
+                // Mirrors the contract: a contributor is counted again after a refund
+                if contribution.amount == 0 {
+                    pool.contributor_count += 1;
+                }
+                contribution.asset = asset.clone();
+                contribution.amount = contribution
+                    .amount
+                    .checked_add(*amount)
+                    .ok_or(ApplyError::Overflow)?;
+                pool.total_raised = pool
+                    .total_raised
+                    .checked_add(*amount)
+                    .ok_or(ApplyError::Overflow)?;
+                pool.last_donation_at = *timestamp;
+            }
+            Event::Refund {
+                pool_id,
+                contributor,
+                asset,
+                amount,
+                timestamp,
+            } => {
+                let pool = self.pool_mut(*pool_id)?;
+                let contribution = pool
+                    .contributions
+                    .get_mut(contributor)
+                    .filter(|c| c.amount >= *amount)
+                    .ok_or_else(|| ApplyError::RefundExceedsContribution {
+                        pool_id: *pool_id,
+                        contributor: contributor.clone(),
+                    })?;
+                contribution.amount -= *amount;
+                pool.total_raised = pool
+                    .total_raised
+                    .checked_sub(*amount)
+                    .ok_or(ApplyError::Overflow)?;
+                pool.refunds.push(RefundRecord {
+                    contributor: contributor.clone(),
+                    asset: asset.clone(),
+                    amount: *amount,
+                    timestamp: *timestamp,
+                });
+            }
+            Event::PoolClosed {
+                pool_id, closed_by, ..
+            } => {
+                let pool = self.pool_mut(*pool_id)?;
+                pool.state = PoolState::Closed;
+                pool.closed_by = Some(closed_by.clone());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn platform_mut(&mut self) -> &mut Platform {
+        self.platform.get_or_insert_with(Platform::default)
+    }
+
+    fn campaign_mut(&mut self, id: &CampaignId) -> Result<&mut Campaign, ApplyError> {
+        self.campaigns
+            .get_mut(id)
+            .ok_or(ApplyError::UnknownCampaign(*id))
+    }
+
+    fn pool_mut(&mut self, id: u64) -> Result<&mut Pool, ApplyError> {
+        self.pools.get_mut(&id).ok_or(ApplyError::UnknownPool(id))
+    }
+
+    fn council_proposal_mut(&mut self, id: u64) -> Result<&mut CouncilProposal, ApplyError> {
+        self.council_proposals
+            .get_mut(&id)
+            .ok_or(ApplyError::UnknownCouncilProposal(id))
+    }
+}
+
+/// The individual bits set in a feature mask.
+fn feature_bits(features: u32) -> impl Iterator<Item = u32> {
+    (0..u32::BITS)
+        .map(|bit| 1 << bit)
+        .filter(move |feature| features & feature != 0)
+}
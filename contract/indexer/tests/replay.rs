@@ -0,0 +1,1284 @@
+use crowdfunding_indexer::{
+    event::{schema, EVENT_SCHEMAS},
+    DecodeError, Event, FeeEntity, FeeTier, Indexer, IndexerError, PoolState, Role, State,
+    SUPPORTED_SCHEMA_VERSION,
+};
+use hello_world::{
+    base::{
+        events,
+        types::{
+            CampaignAmendmentRules, CampaignMetadata, CouncilAction,
+            FeeEntity as ContractFeeEntity, FeeTier as ContractFeeTier, MultiSigConfig, PoolConfig,
+            PoolMetadata, PoolState as ContractPoolState, Role as ContractRole,
+            MIN_UNPAUSED_PERIOD, PAUSE_DONATIONS, PAUSE_POOL_CREATION, PAUSE_REFUNDS,
+        },
+    },
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+use soroban_sdk::{
+    testutils::{Address as _, Events as _, Ledger},
+    token::{Client as TokenClient, StellarAssetClient},
+    vec,
+    xdr::{Limits, ReadXdr, ScAddress, ScSpecEntry, ScSpecEventParamLocationV0, ScVal},
+    Address, BytesN, Env, String as SorobanString, TryFromVal,
+};
+
+/// Runs a contract under `testutils` and feeds every event it emits to an
+/// [`Indexer`], one invocation at a time.
+struct Harness {
+    env: Env,
+    contract_id: Address,
+    client: CrowdfundingContractClient<'static>,
+    indexer: Indexer,
+}
+
+impl Harness {
+    fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CrowdfundingContract, ());
+        let client = CrowdfundingContractClient::new(&env, &contract_id);
+        let indexer = Indexer::for_contract(strkey(&contract_id));
+        Self {
+            env,
+            contract_id,
+            client,
+            indexer,
+        }
+    }
+
+    /// Ingest the events of the last invocation.
+    fn sync(&mut self) {
+        for (emitter, topics, data) in self.env.events().all().iter() {
+            if emitter != self.contract_id {
+                continue;
+            }
+            let topics: std::vec::Vec<ScVal> = topics
+                .iter()
+                .map(|topic| ScVal::try_from_val(&self.env, &topic).unwrap())
+                .collect();
+            let data = ScVal::try_from_val(&self.env, &data).unwrap();
+            self.indexer.ingest(&topics, &data).unwrap();
+        }
+    }
+
+    fn state(&self) -> &State {
+        self.indexer.state()
+    }
+
+    fn token(&self) -> Address {
+        self.env
+            .register_stellar_asset_contract_v2(Address::generate(&self.env))
+            .address()
+    }
+}
+
+fn strkey(address: &Address) -> String {
+    ScAddress::from(address).to_string()
+}
+
+fn text(value: &SorobanString) -> String {
+    value.to_string()
+}
+
+fn pool_state(state: ContractPoolState) -> PoolState {
+    PoolState::from_u32(state as u32).unwrap()
+}
+
+#[test]
+fn replayed_campaigns_match_contract_getters() {
+    let mut h = Harness::new();
+    let admin = Address::generate(&h.env);
+    let token = h.token();
+    let minter = StellarAssetClient::new(&h.env, &token);
+
+    h.client.initialize(&admin, &token, &100);
+    h.sync();
+
+    let creator = Address::generate(&h.env);
+    minter.mint(&creator, &1_000);
+    let donors = [
+        Address::generate(&h.env),
+        Address::generate(&h.env),
+        Address::generate(&h.env),
+    ];
+    for donor in &donors {
+        minter.mint(donor, &10_000);
+    }
+
+    let ids = [
+        BytesN::from_array(&h.env, &[1u8; 32]),
+        BytesN::from_array(&h.env, &[2u8; 32]),
+    ];
+    let deadline = h.env.ledger().timestamp() + 86_400;
+    for (i, id) in ids.iter().enumerate() {
+        let title = SorobanString::from_str(&h.env, &format!("Campaign {i}"));
+        h.client.create_campaign(
+            id,
+            &title,
+            &creator,
+            &(1_000 * (i as i128 + 1)),
+            &deadline,
+            &token,
+            &100,
+        );
+        h.sync();
+    }
+
+    h.client.donate(&ids[0], &donors[0], &token, &300);
+    h.sync();
+    h.client.donate(&ids[0], &donors[1], &token, &200);
+    h.sync();
+    h.client.donate(&ids[0], &donors[0], &token, &500);
+    h.sync();
+    h.client.donate(&ids[1], &donors[2], &token, &750);
+    h.sync();
+
+    h.client.set_creation_fee(&admin, &250);
+    h.sync();
+    h.client.pause(&admin);
+    h.sync();
+
+    let platform = h.state().platform.clone().unwrap();
+    assert_eq!(platform.admin, strkey(&admin));
+    assert_eq!(platform.token, strkey(&h.client.get_crowdfunding_token()));
+    assert_eq!(platform.creation_fee, h.client.get_creation_fee());
+    assert_eq!(platform.paused, h.client.is_paused());
+    assert_eq!(h.state().fees_paid.get(&strkey(&token)), Some(&200));
+
+    assert_eq!(
+        h.state().campaigns.len(),
+        h.client.get_all_campaigns().len() as usize
+    );
+    for id in &ids {
+        let onchain = h.client.get_campaign(id);
+        let replayed = &h.state().campaigns[&id.to_array()];
+        assert_eq!(replayed.creator, strkey(&onchain.creator));
+        assert_eq!(replayed.title, text(&onchain.title));
+        assert_eq!(replayed.goal, onchain.goal);
+        assert_eq!(replayed.deadline, onchain.deadline);
+        assert_eq!(replayed.token, strkey(&onchain.token_address));
+        assert_eq!(replayed.total_raised, h.client.get_total_raised(id));
+        assert_eq!(replayed.total_raised, h.client.get_campaign_balance(id));
+        assert_eq!(replayed.donor_count, h.client.get_donor_count(id));
+        assert_eq!(
+            replayed.goal_reached_at.is_some(),
+            h.client.is_campaign_completed(id)
+        );
+        for donor in &donors {
+            let donated = replayed
+                .contributions
+                .get(&strkey(donor))
+                .copied()
+                .unwrap_or(0);
+            assert_eq!(donated, h.client.get_contribution(id, donor));
+        }
+    }
+}
+
+#[test]
+fn replayed_campaign_cancellation_matches_contract() {
+    let mut h = Harness::new();
+    let admin = Address::generate(&h.env);
+    let token = h.token();
+    h.client.initialize(&admin, &token, &0);
+    h.sync();
+
+    let creator = Address::generate(&h.env);
+    let id = BytesN::from_array(&h.env, &[3u8; 32]);
+    h.client.create_campaign(
+        &id,
+        &SorobanString::from_str(&h.env, "Cancelled"),
+        &creator,
+        &1_000,
+        &86_400,
+        &token,
+        &0,
+    );
+    h.sync();
+    let donor = Address::generate(&h.env);
+    StellarAssetClient::new(&h.env, &token).mint(&donor, &400);
+    h.client.donate(&id, &donor, &token, &400);
+    h.sync();
+
+    h.env.ledger().set_timestamp(500);
+    let reason = SorobanString::from_str(&h.env, "Permit denied");
+    h.client.cancel_campaign(&id, &creator, &reason);
+    h.sync();
+    h.client.refund_campaign(&id, &donor);
+    h.sync();
+
+    let replayed = &h.state().campaigns[&id.to_array()];
+    assert_eq!(replayed.cancelled_at, Some(500));
+    assert_eq!(replayed.cancellation_reason, Some(text(&reason)));
+    assert_eq!(replayed.total_raised, h.client.get_total_raised(&id));
+    assert_eq!(
+        replayed.contributions.get(&strkey(&donor)).copied(),
+        Some(h.client.get_contribution(&id, &donor))
+    );
+}
+
+#[test]
+fn replayed_campaign_amendments_match_contract() {
+    let mut h = Harness::new();
+    let admin = Address::generate(&h.env);
+    let token = h.token();
+    h.client.initialize(&admin, &token, &0);
+    h.sync();
+
+    let rules = CampaignAmendmentRules {
+        max_extension: 7_200,
+        single_extension: false,
+        material_goal_change_bps: 0,
+    };
+    h.client.set_campaign_amendment_rules(&rules);
+    h.sync();
+
+    let id = BytesN::from_array(&h.env, &[4u8; 32]);
+    h.client.create_campaign(
+        &id,
+        &SorobanString::from_str(&h.env, "Amended"),
+        &Address::generate(&h.env),
+        &1_000,
+        &86_400,
+        &token,
+        &0,
+    );
+    h.sync();
+    let donor = Address::generate(&h.env);
+    StellarAssetClient::new(&h.env, &token).mint(&donor, &600);
+    h.client.donate(&id, &donor, &token, &600);
+    h.sync();
+
+    h.client.extend_campaign_deadline(&id, &90_000);
+    h.sync();
+    h.client.update_campaign_goal(&id, &600);
+    h.sync();
+    h.client.update_campaign_goal(&id, &2_000);
+    h.sync();
+
+    let replayed_rules = h.state().campaign_amendment_rules.clone().unwrap();
+    assert_eq!(replayed_rules.max_extension, rules.max_extension);
+    assert_eq!(replayed_rules.single_extension, rules.single_extension);
+    assert_eq!(
+        replayed_rules.material_goal_change_bps,
+        rules.material_goal_change_bps
+    );
+
+    let onchain = h.client.get_campaign(&id);
+    let replayed = &h.state().campaigns[&id.to_array()];
+    assert_eq!(replayed.goal, onchain.goal);
+    assert_eq!(replayed.deadline, onchain.deadline);
+    assert_eq!(
+        replayed.revision,
+        h.client.get_campaign_amendments(&id).revision
+    );
+    assert_eq!(
+        replayed.goal_reached_at.is_some(),
+        h.client.is_campaign_completed(&id)
+    );
+}
+
+#[test]
+fn replayed_campaign_details_match_contract() {
+    let mut h = Harness::new();
+    let admin = Address::generate(&h.env);
+    let token = h.token();
+    h.client.initialize(&admin, &token, &0);
+    h.sync();
+
+    let id = BytesN::from_array(&h.env, &[5u8; 32]);
+    h.client.create_campaign(
+        &id,
+        &SorobanString::from_str(&h.env, "Wells"),
+        &Address::generate(&h.env),
+        &1_000,
+        &86_400,
+        &token,
+        &0,
+    );
+    h.sync();
+
+    h.client.update_campaign_details(
+        &id,
+        &SorobanString::from_str(&h.env, "Clean water"),
+        &CampaignMetadata {
+            description: SorobanString::from_str(&h.env, "Wells for the valley."),
+            external_url: SorobanString::from_str(&h.env, ""),
+            image_hash: SorobanString::from_str(&h.env, "QmWells"),
+        },
+    );
+    h.sync();
+    let donor = Address::generate(&h.env);
+    StellarAssetClient::new(&h.env, &token).mint(&donor, &100);
+    h.client.donate(&id, &donor, &token, &100);
+    h.sync();
+    h.client.update_campaign_details(
+        &id,
+        &SorobanString::from_str(&h.env, "Clean water"),
+        &CampaignMetadata {
+            description: SorobanString::from_str(&h.env, "Wells for the valley. Drilling soon."),
+            external_url: SorobanString::from_str(&h.env, "https://wells.org"),
+            image_hash: SorobanString::from_str(&h.env, "QmWells"),
+        },
+    );
+    h.sync();
+
+    let onchain = h.client.get_campaign(&id);
+    let replayed = &h.state().campaigns[&id.to_array()];
+    assert_eq!(replayed.title, text(&onchain.title));
+    assert_eq!(
+        replayed.metadata.description,
+        text(&onchain.metadata.description)
+    );
+    assert_eq!(
+        replayed.metadata.external_url,
+        text(&onchain.metadata.external_url)
+    );
+    assert_eq!(
+        replayed.metadata.image_hash,
+        text(&onchain.metadata.image_hash)
+    );
+}
+
+#[test]
+fn replayed_ownership_transfers_match_contract() {
+    let mut h = Harness::new();
+    let admin = Address::generate(&h.env);
+    let token = h.token();
+    h.client.initialize(&admin, &token, &0);
+    h.sync();
+
+    let creator = Address::generate(&h.env);
+    let id = BytesN::from_array(&h.env, &[6u8; 32]);
+    h.client.create_campaign(
+        &id,
+        &SorobanString::from_str(&h.env, "Handed over"),
+        &creator,
+        &1_000,
+        &86_400,
+        &token,
+        &0,
+    );
+    h.sync();
+    let pool_id = h.client.create_pool(
+        &creator,
+        &PoolConfig {
+            name: SorobanString::from_str(&h.env, "Handed over"),
+            description: SorobanString::from_str(&h.env, "Pool"),
+            target_amount: 1_000,
+            is_private: false,
+            duration: 86_400,
+            created_at: 0,
+        },
+        &0,
+    );
+    h.sync();
+
+    let new_owner = Address::generate(&h.env);
+    h.client.transfer_campaign_ownership(&id, &new_owner);
+    h.sync();
+    h.client.accept_campaign_ownership(&id);
+    h.sync();
+    h.client.transfer_pool_ownership(&pool_id, &new_owner);
+    h.sync();
+    h.client.cancel_pool_transfer(&pool_id);
+    h.sync();
+    h.client.transfer_pool_ownership(&pool_id, &admin);
+    h.sync();
+
+    let campaign = &h.state().campaigns[&id.to_array()];
+    assert_eq!(
+        campaign.creator,
+        strkey(&h.client.get_campaign(&id).creator)
+    );
+    assert_eq!(
+        campaign.pending_owner,
+        h.client.get_pending_campaign_owner(&id).map(|a| strkey(&a))
+    );
+    let pool = &h.state().pools[&pool_id];
+    assert_eq!(
+        Some(pool.creator.clone()),
+        h.client.get_pool_creator(&pool_id).map(|a| strkey(&a))
+    );
+    assert_eq!(
+        pool.pending_owner,
+        h.client
+            .get_pending_pool_owner(&pool_id)
+            .map(|a| strkey(&a))
+    );
+}
+
+#[test]
+fn replayed_pools_match_contract_getters() {
+    let mut h = Harness::new();
+    let admin = Address::generate(&h.env);
+    let token = h.token();
+    let minter = StellarAssetClient::new(&h.env, &token);
+    h.env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    h.client.initialize(&admin, &token, &0);
+    h.sync();
+
+    let creator = Address::generate(&h.env);
+    let metadata = PoolMetadata {
+        description: SorobanString::from_str(&h.env, "Saved pool"),
+        external_url: SorobanString::from_str(&h.env, "https://example.com"),
+        image_hash: SorobanString::from_str(&h.env, "hash"),
+    };
+    let signers = vec![&h.env, Address::generate(&h.env), Address::generate(&h.env)];
+    let saved = h.client.save_pool(
+        &SorobanString::from_str(&h.env, "Saved"),
+        &metadata,
+        &creator,
+        &10_000,
+        &(h.env.ledger().timestamp() + 86_400),
+        &Some(2),
+        &Some(signers.clone()),
+        &0,
+    );
+    h.sync();
+
+    let created = h.client.create_pool(
+        &creator,
+        &PoolConfig {
+            name: SorobanString::from_str(&h.env, "Created"),
+            description: SorobanString::from_str(&h.env, "Created pool"),
+            target_amount: 5_000,
+            is_private: true,
+            duration: 7_200,
+            created_at: 0,
+        },
+        &0,
+    );
+    h.sync();
+
+    let contributors = [Address::generate(&h.env), Address::generate(&h.env)];
+    for contributor in &contributors {
+        minter.mint(contributor, &10_000);
+    }
+    h.client
+        .contribute(&saved, &contributors[0], &token, &1_000, &false);
+    h.sync();
+    h.client
+        .contribute(&saved, &contributors[1], &token, &2_500, &true);
+    h.sync();
+    h.client
+        .contribute(&saved, &contributors[0], &token, &400, &false);
+    h.sync();
+    h.client
+        .contribute(&created, &contributors[1], &token, &600, &false);
+    h.sync();
+
+    // Refund one contributor of the saved pool after its deadline and grace period
+    let deadline = h.client.get_pool(&saved).unwrap().deadline();
+    h.env
+        .ledger()
+        .with_mut(|li| li.timestamp = deadline + 604_800 + 1);
+    h.client.refund(&saved, &contributors[0]);
+    h.sync();
+
+    h.client
+        .update_pool_state(&created, &ContractPoolState::Cancelled);
+    h.sync();
+    h.client.close_pool(&created, &admin);
+    h.sync();
+
+    let token_client = TokenClient::new(&h.env, &token);
+    let mut escrowed = 0;
+    for pool_id in [saved, created] {
+        let onchain = h.client.get_pool(&pool_id).unwrap();
+        let replayed = &h.state().pools[&pool_id];
+        assert_eq!(replayed.creator, strkey(&creator));
+        assert_eq!(replayed.name, text(&onchain.name));
+        assert_eq!(replayed.description, text(&onchain.description));
+        assert_eq!(replayed.target_amount, onchain.target_amount);
+        assert_eq!(replayed.is_private, onchain.is_private);
+        assert_eq!(replayed.deadline, onchain.deadline());
+        assert_eq!(
+            replayed.state == PoolState::Closed,
+            h.client.is_closed(&pool_id)
+        );
+
+        let (description, external_url, image_hash) = h.client.get_pool_metadata(&pool_id);
+        let replayed_metadata = replayed.metadata.clone().unwrap_or_default();
+        assert_eq!(replayed_metadata.description, text(&description));
+        assert_eq!(replayed_metadata.external_url, text(&external_url));
+        assert_eq!(replayed_metadata.image_hash, text(&image_hash));
+
+        escrowed += replayed.total_raised;
+    }
+
+    let saved_pool = &h.state().pools[&saved];
+    assert_eq!(saved_pool.state, pool_state(ContractPoolState::Active));
+    assert_eq!(saved_pool.contributor_count, 2);
+    assert_eq!(
+        saved_pool.contributions[&strkey(&contributors[0])].amount,
+        0
+    );
+    assert_eq!(saved_pool.refunds.len(), 1);
+    assert_eq!(saved_pool.refunds[0].amount, 1_400);
+    let multisig = saved_pool.multisig.clone().unwrap();
+    assert_eq!(multisig.required_signatures, 2);
+    assert_eq!(
+        multisig.signers,
+        signers.iter().map(|s| strkey(&s)).collect::<Vec<_>>()
+    );
+    assert_eq!(h.state().pools[&created].closed_by, Some(strkey(&admin)));
+
+    // What the contract still holds is exactly what the replay says is escrowed
+    assert_eq!(escrowed, token_client.balance(&h.contract_id));
+}
+
+#[test]
+fn replayed_admin_transfer_matches_contract_getters() {
+    let mut h = Harness::new();
+    let admin = Address::generate(&h.env);
+    let token = h.token();
+    h.client.initialize(&admin, &token, &0);
+    h.sync();
+
+    let rejected = Address::generate(&h.env);
+    h.client.propose_admin(&rejected);
+    h.sync();
+    assert_eq!(
+        h.state().platform.as_ref().unwrap().pending_admin,
+        Some(strkey(&rejected))
+    );
+    h.client.cancel_admin_proposal();
+    h.sync();
+    assert_eq!(h.state().platform.as_ref().unwrap().pending_admin, None);
+
+    let new_admin = Address::generate(&h.env);
+    h.client.propose_admin(&new_admin);
+    h.sync();
+    h.client.accept_admin();
+    h.sync();
+
+    let platform = h.state().platform.clone().unwrap();
+    assert_eq!(platform.admin, strkey(&h.client.get_admin()));
+    assert_eq!(
+        platform.pending_admin,
+        h.client.get_pending_admin().map(|a| strkey(&a))
+    );
+}
+
+#[test]
+fn replayed_roles_match_contract_getters() {
+    let mut h = Harness::new();
+    let admin = Address::generate(&h.env);
+    let token = h.token();
+    h.client.initialize(&admin, &token, &0);
+    h.sync();
+
+    let operators = [Address::generate(&h.env), Address::generate(&h.env)];
+    for role in [ContractRole::Pauser, ContractRole::Treasury] {
+        h.client.grant_role(&role, &operators[0]);
+        h.sync();
+    }
+    h.client.grant_role(&ContractRole::Verifier, &operators[1]);
+    h.sync();
+    h.client.revoke_role(&ContractRole::Pauser, &operators[0]);
+    h.sync();
+    h.client.revoke_role(&ContractRole::Verifier, &operators[1]);
+    h.sync();
+
+    for operator in &operators {
+        let onchain: Vec<Role> = h
+            .client
+            .get_roles(operator)
+            .iter()
+            .map(|role| Role::from_u32(role as u32).unwrap())
+            .collect();
+        let replayed: Vec<Role> = h
+            .state()
+            .roles
+            .get(&strkey(operator))
+            .map(|roles| roles.iter().copied().collect())
+            .unwrap_or_default();
+        assert_eq!(replayed, onchain);
+    }
+    assert_eq!(h.state().roles.len(), 1);
+}
+
+#[test]
+fn replayed_emergency_withdrawal_matches_contract_getters() {
+    let mut h = Harness::new();
+    let admin = Address::generate(&h.env);
+    let token = h.token();
+    h.client.initialize(&admin, &token, &0);
+    h.sync();
+    StellarAssetClient::new(&h.env, &token).mint(&h.contract_id, &100);
+
+    h.client.set_emergency_withdraw_delay(&(2 * 60 * 60));
+    h.sync();
+    h.client
+        .request_emergency_withdraw(&admin, &token, &100, &None);
+    h.sync();
+
+    let platform = h.state().platform.clone().unwrap();
+    let onchain = h.client.get_emergency_withdrawal().unwrap();
+    assert_eq!(
+        platform.emergency_withdraw_delay,
+        Some(h.client.get_emergency_withdraw_delay())
+    );
+    let pending = platform.emergency_withdrawal.unwrap();
+    assert_eq!(pending.amount, onchain.amount);
+    assert_eq!(pending.recipient, strkey(&onchain.recipient));
+    assert_eq!(pending.unlock_time, onchain.unlock_time);
+
+    h.client.cancel_emergency_withdraw(&admin);
+    h.sync();
+    assert_eq!(
+        h.state().platform.as_ref().unwrap().emergency_withdrawal,
+        None
+    );
+
+    let recipient = Address::generate(&h.env);
+    h.client
+        .request_emergency_withdraw(&admin, &token, &100, &Some(recipient.clone()));
+    h.sync();
+    let platform = h.state().platform.clone().unwrap();
+    assert_eq!(
+        platform.emergency_withdrawal.unwrap().recipient,
+        strkey(&recipient)
+    );
+}
+
+#[test]
+fn replayed_fee_withdrawals_match_contract_getters() {
+    let mut h = Harness::new();
+    let admin = Address::generate(&h.env);
+    let token = h.token();
+    h.client.initialize(&admin, &token, &40);
+    h.sync();
+
+    let creator = Address::generate(&h.env);
+    StellarAssetClient::new(&h.env, &token).mint(&creator, &80);
+    for seed in 1..=2 {
+        h.client.create_campaign(
+            &BytesN::from_array(&h.env, &[seed; 32]),
+            &SorobanString::from_str(&h.env, "Campaign"),
+            &creator,
+            &1_000,
+            &(h.env.ledger().timestamp() + 86_400),
+            &token,
+            &40,
+        );
+        h.sync();
+    }
+
+    let treasury = Address::generate(&h.env);
+    h.client.grant_role(&ContractRole::Treasury, &treasury);
+    h.sync();
+    h.client
+        .withdraw_fees(&treasury, &token, &50, &Address::generate(&h.env));
+    h.sync();
+
+    assert_eq!(h.state().fees_paid.get(&strkey(&token)), Some(&80));
+    assert_eq!(
+        h.state().fee_balances.get(&strkey(&token)),
+        Some(&h.client.get_fee_balance(&token))
+    );
+}
+
+#[test]
+fn replayed_upgrade_matches_contract_getters() {
+    let mut h = Harness::new();
+    let admin = Address::generate(&h.env);
+    let token = h.token();
+    h.client.initialize(&admin, &token, &0);
+    h.sync();
+
+    h.client.set_upgrade_timelock(&(2 * 24 * 60 * 60));
+    h.sync();
+    h.env.ledger().set_timestamp(10);
+    h.client
+        .propose_upgrade(&BytesN::from_array(&h.env, &[4; 32]));
+    h.sync();
+
+    let platform = h.state().platform.clone().unwrap();
+    let onchain = h.client.get_pending_upgrade().unwrap();
+    assert_eq!(
+        platform.upgrade_timelock,
+        Some(h.client.get_upgrade_timelock())
+    );
+    let pending = platform.pending_upgrade.unwrap();
+    assert_eq!(pending.wasm_hash, onchain.wasm_hash.to_array());
+    assert_eq!(pending.executable_at, onchain.executable_at);
+
+    h.client.cancel_upgrade();
+    h.sync();
+    assert_eq!(h.state().platform.as_ref().unwrap().pending_upgrade, None);
+}
+
+#[test]
+fn replayed_escape_hatch_matches_contract_getters() {
+    let mut h = Harness::new();
+    let admin = Address::generate(&h.env);
+    let token = h.token();
+    h.client.initialize(&admin, &token, &0);
+    h.sync();
+
+    let donor = Address::generate(&h.env);
+    StellarAssetClient::new(&h.env, &token).mint(&donor, &500);
+    let id = BytesN::from_array(&h.env, &[5u8; 32]);
+    h.client.create_campaign(
+        &id,
+        &SorobanString::from_str(&h.env, "Campaign"),
+        &Address::generate(&h.env),
+        &10_000,
+        &(h.env.ledger().timestamp() + 86_400),
+        &token,
+        &0,
+    );
+    h.sync();
+    h.client.donate(&id, &donor, &token, &500);
+    h.sync();
+
+    let duration = 24 * 60 * 60;
+    h.client.set_max_pause_duration(&duration);
+    h.sync();
+    h.env.ledger().set_timestamp(20);
+    h.client.pause(&admin);
+    h.sync();
+
+    let platform = h.state().platform.clone().unwrap();
+    assert_eq!(
+        platform.max_pause_duration,
+        Some(h.client.get_max_pause_duration())
+    );
+    assert_eq!(platform.paused_at, h.client.get_paused_at());
+
+    h.env.ledger().set_timestamp(20 + duration);
+    h.client.escape_campaign_contribution(&id, &donor);
+    h.sync();
+    let campaign = &h.state().campaigns[&id.to_array()];
+    assert_eq!(campaign.total_raised, h.client.get_total_raised(&id));
+    assert_eq!(
+        campaign.contributions.get(&strkey(&donor)),
+        Some(&h.client.get_contribution(&id, &donor))
+    );
+    assert_eq!(campaign.donor_count, h.client.get_donor_count(&id));
+
+    h.client.unpause(&admin);
+    h.sync();
+    let platform = h.state().platform.clone().unwrap();
+    assert!(!platform.paused);
+    assert_eq!(platform.unpaused_at, Some(20 + duration));
+
+    // A pause soon after unpausing continues the previous one
+    h.client.pause(&admin);
+    h.sync();
+    assert_eq!(
+        h.state().platform.as_ref().unwrap().paused_at,
+        h.client.get_paused_at()
+    );
+    h.client.unpause(&admin);
+    h.sync();
+    h.env
+        .ledger()
+        .set_timestamp(20 + duration + MIN_UNPAUSED_PERIOD);
+    h.client.pause(&admin);
+    h.sync();
+    assert_eq!(
+        h.state().platform.as_ref().unwrap().paused_at,
+        h.client.get_paused_at()
+    );
+}
+
+#[test]
+fn replayed_feature_pauses_match_contract_getters() {
+    let mut h = Harness::new();
+    let admin = Address::generate(&h.env);
+    let token = h.token();
+    h.client.initialize(&admin, &token, &0);
+    h.sync();
+
+    h.env.ledger().set_timestamp(30);
+    h.client
+        .pause_features(&admin, &(PAUSE_DONATIONS | PAUSE_REFUNDS), &9, &600);
+    h.sync();
+    h.client
+        .pause_features(&admin, &PAUSE_POOL_CREATION, &2, &900);
+    h.sync();
+    h.env.ledger().set_timestamp(60);
+    h.client.pause_features(&admin, &PAUSE_REFUNDS, &4, &1_200);
+    h.sync();
+    h.client.unpause_features(&admin, &PAUSE_DONATIONS);
+    h.sync();
+
+    // The unpaused feature is kept with its pause ending now
+    let replayed = &h.state().feature_pauses;
+    assert_eq!(replayed[&PAUSE_DONATIONS].expires_at, 60);
+    let active: Vec<_> = replayed
+        .iter()
+        .filter(|(_, pause)| 60 < pause.expires_at)
+        .collect();
+    let mask = active.iter().fold(0, |mask, (feature, _)| mask | *feature);
+    assert_eq!(mask, h.client.get_paused_features());
+    for (feature, pause) in active {
+        let onchain = h.client.get_feature_pause(feature).unwrap();
+        assert_eq!(pause.reason, onchain.reason);
+        assert_eq!(pause.paused_at, onchain.paused_at);
+        assert_eq!(pause.expires_at, onchain.expires_at);
+    }
+}
+
+#[test]
+fn replayed_parameter_changes_match_contract_getters() {
+    let mut h = Harness::new();
+    let admin = Address::generate(&h.env);
+    let token = h.token();
+    h.client.initialize(&admin, &token, &0);
+    h.sync();
+
+    h.env.ledger().set_timestamp(50);
+    let new_token = h.token();
+    h.client.set_accepted_token(&admin, &new_token, &0);
+    h.sync();
+    h.client.set_creation_fee(&admin, &30);
+    h.sync();
+    h.client.set_crowdfunding_token(&admin, &new_token);
+    h.sync();
+
+    let onchain = h.client.get_pending_parameters().unwrap();
+    let platform = h.state().platform.clone().unwrap();
+    let pending = platform.pending_parameters.unwrap();
+    assert_eq!(pending.creation_fee, onchain.creation_fee);
+    assert_eq!(
+        pending.token,
+        onchain.crowdfunding_token.map(|t| strkey(&t))
+    );
+    assert_eq!(pending.queued_by, strkey(&onchain.queued_by));
+    assert_eq!(pending.effective_at, onchain.effective_at);
+    assert_eq!(platform.creation_fee, h.client.get_creation_fee());
+
+    // The changes are written by the next creation after the delay
+    h.env.ledger().set_timestamp(onchain.effective_at);
+    StellarAssetClient::new(&h.env, &new_token).mint(&admin, &30);
+    h.client.create_campaign(
+        &BytesN::from_array(&h.env, &[1; 32]),
+        &SorobanString::from_str(&h.env, "After"),
+        &admin,
+        &100,
+        &(onchain.effective_at + 100),
+        &new_token,
+        &30,
+    );
+    h.sync();
+    let platform = h.state().platform.clone().unwrap();
+    assert_eq!(platform.pending_parameters, None);
+    assert_eq!(platform.creation_fee, h.client.get_creation_fee());
+    assert_eq!(platform.token, strkey(&h.client.get_crowdfunding_token()));
+    assert_eq!(h.state().fees_paid.get(&strkey(&new_token)), Some(&30));
+}
+
+#[test]
+fn replayed_entity_fees_match_contract_getters() {
+    let mut h = Harness::new();
+    let admin = Address::generate(&h.env);
+    let token = h.token();
+    h.client.initialize(&admin, &token, &0);
+    h.sync();
+
+    h.env.ledger().set_timestamp(50);
+    h.client
+        .set_entity_creation_fee(&admin, &ContractFeeEntity::PrivatePool, &25);
+    h.sync();
+
+    let onchain = h
+        .client
+        .get_pending_entity_creation_fee(&ContractFeeEntity::PrivatePool)
+        .unwrap();
+    let pending = h.state().pending_entity_fees[&FeeEntity::PrivatePool].clone();
+    assert_eq!(pending.fee, onchain.fee);
+    assert_eq!(pending.queued_by, strkey(&onchain.queued_by));
+    assert_eq!(pending.effective_at, onchain.effective_at);
+
+    // The fee is written by the next private pool created after the delay
+    h.env.ledger().set_timestamp(onchain.effective_at);
+    let creator = Address::generate(&h.env);
+    StellarAssetClient::new(&h.env, &token).mint(&creator, &25);
+    let config = PoolConfig {
+        name: SorobanString::from_str(&h.env, "Private"),
+        description: SorobanString::from_str(&h.env, "Invite only"),
+        target_amount: 1_000,
+        is_private: true,
+        duration: 86_400,
+        created_at: 0,
+    };
+    h.client.create_pool(&creator, &config, &25);
+    h.sync();
+    assert!(h.state().pending_entity_fees.is_empty());
+    assert_eq!(
+        h.state().entity_creation_fees.get(&FeeEntity::PrivatePool),
+        Some(
+            &h.client
+                .get_entity_creation_fee(&ContractFeeEntity::PrivatePool)
+        )
+    );
+    assert_eq!(h.state().fees_paid.get(&strkey(&token)), Some(&25));
+}
+
+#[test]
+fn replayed_platform_fees_match_contract_getters() {
+    let mut h = Harness::new();
+    let admin = Address::generate(&h.env);
+    let token = h.token();
+    h.client.initialize(&admin, &token, &0);
+    h.sync();
+
+    h.env.ledger().set_timestamp(50);
+    h.client.set_platform_fee(&admin, &300);
+    h.sync();
+    let onchain = h.client.get_pending_platform_fee().unwrap();
+    let pending = h.state().pending_platform_fee.clone().unwrap();
+    assert_eq!(pending.bps, onchain.bps);
+    assert_eq!(pending.queued_by, strkey(&onchain.queued_by));
+    assert_eq!(pending.effective_at, onchain.effective_at);
+
+    h.env.ledger().set_timestamp(onchain.effective_at);
+    let config = PoolConfig {
+        name: SorobanString::from_str(&h.env, "Pool"),
+        description: SorobanString::from_str(&h.env, "Pays the platform"),
+        target_amount: 10_000,
+        is_private: false,
+        duration: 86_400,
+        created_at: 0,
+    };
+    let pools = [0, 1].map(|_| {
+        let pool_id = h
+            .client
+            .create_pool(&Address::generate(&h.env), &config, &0);
+        h.sync();
+        pool_id
+    });
+    h.client.set_pool_platform_fee(&pools[1], &Some(1_000));
+    h.sync();
+
+    // The queued rate is written by the first contribution after the delay
+    let contributor = Address::generate(&h.env);
+    StellarAssetClient::new(&h.env, &token).mint(&contributor, &2_000);
+    for pool_id in pools {
+        h.client
+            .contribute(&pool_id, &contributor, &token, &1_000, &false);
+        h.sync();
+    }
+    assert_eq!(h.state().pending_platform_fee, None);
+    assert_eq!(h.state().platform_fee_bps, h.client.get_platform_fee());
+    assert_eq!(
+        h.state().pool_platform_fees.get(&pools[1]),
+        Some(&h.client.get_pool_platform_fee(&pools[1]))
+    );
+    assert_eq!(
+        h.state().fee_balances.get(&strkey(&token)),
+        Some(&h.client.get_fee_balance(&token))
+    );
+    for pool_id in pools {
+        assert_eq!(
+            h.state().pools[&pool_id].total_raised,
+            1_000 - h.client.quote_contribution(&pool_id, &1_000).fee
+        );
+    }
+
+    h.client.set_pool_platform_fee(&pools[1], &None);
+    h.sync();
+    assert!(h.state().pool_platform_fees.is_empty());
+}
+
+#[test]
+fn replayed_fee_tiers_match_contract_getters() {
+    let mut h = Harness::new();
+    let admin = Address::generate(&h.env);
+    let token = h.token();
+    h.client.initialize(&admin, &token, &0);
+    h.sync();
+
+    let verifier = Address::generate(&h.env);
+    h.client.grant_role(&ContractRole::Verifier, &verifier);
+    h.sync();
+    let creators = [0, 1, 2].map(|_| Address::generate(&h.env));
+    let tiers = [
+        ContractFeeTier::Waived,
+        ContractFeeTier::Discount(2_500),
+        ContractFeeTier::FlatFee(15),
+    ];
+    for (creator, tier) in creators.iter().zip(tiers) {
+        h.client.assign_fee_tier(&verifier, creator, &tier, &500);
+        h.sync();
+    }
+    h.client.revoke_fee_tier(&admin, &creators[0]);
+    h.sync();
+
+    assert!(!h.state().fee_tiers.contains_key(&strkey(&creators[0])));
+    let expected = [FeeTier::Discount(2_500), FeeTier::FlatFee(15)];
+    for (creator, tier) in creators[1..].iter().zip(expected) {
+        let onchain = h.client.get_fee_tier(creator).unwrap();
+        let replayed = &h.state().fee_tiers[&strkey(creator)];
+        assert_eq!(replayed.tier, tier);
+        assert_eq!(replayed.assigned_by, strkey(&onchain.assigned_by));
+        assert_eq!(replayed.expires_at, onchain.expires_at);
+    }
+}
+
+#[test]
+fn replayed_accepted_tokens_match_contract_getters() {
+    let mut h = Harness::new();
+    let admin = Address::generate(&h.env);
+    let token = h.token();
+    h.client.initialize(&admin, &token, &0);
+    h.sync();
+
+    let others = [0, 1].map(|_| {
+        let other = h.token();
+        h.client.set_accepted_token(&admin, &other, &25);
+        h.sync();
+        other
+    });
+    h.client.remove_accepted_token(&admin, &others[0]);
+    h.sync();
+
+    let onchain = h.client.get_accepted_tokens();
+    assert_eq!(h.state().accepted_tokens.len() as u32, onchain.len());
+    for accepted in onchain.iter() {
+        let expected = h.client.get_accepted_token(&accepted).unwrap();
+        let replayed = &h.state().accepted_tokens[&strkey(&accepted)];
+        assert_eq!(replayed.symbol, expected.symbol.to_string());
+        assert_eq!(replayed.decimals, expected.decimals);
+        assert_eq!(replayed.min_amount, expected.min_amount);
+    }
+    assert!(!h.state().accepted_tokens.contains_key(&strkey(&others[0])));
+}
+
+#[test]
+fn replayed_council_matches_contract_getters() {
+    let mut h = Harness::new();
+    let admin = Address::generate(&h.env);
+    let token = h.token();
+    h.client.initialize(&admin, &token, &0);
+    h.sync();
+
+    let members = [
+        Address::generate(&h.env),
+        Address::generate(&h.env),
+        Address::generate(&h.env),
+    ];
+    h.client.set_council(&MultiSigConfig {
+        required_signatures: 2,
+        signers: vec![
+            &h.env,
+            members[0].clone(),
+            members[1].clone(),
+            members[2].clone(),
+        ],
+    });
+    h.sync();
+
+    let fee = h
+        .client
+        .propose_council_action(&members[0], &CouncilAction::SetCreationFee(40));
+    h.sync();
+    h.client.approve_council_action(&members[2], &fee);
+    h.sync();
+    h.client.execute_council_action(&members[2], &fee);
+    h.sync();
+    let pending = h
+        .client
+        .propose_council_action(&members[1], &CouncilAction::DisableCouncil);
+    h.sync();
+
+    let state = h.state();
+    let platform = state.platform.as_ref().unwrap();
+    assert_eq!(platform.creation_fee, h.client.get_creation_fee());
+    let council = h.client.get_council().unwrap();
+    let replayed = platform.council.as_ref().unwrap();
+    assert_eq!(replayed.required_signatures, council.required_signatures);
+    assert_eq!(
+        replayed.signers,
+        council
+            .signers
+            .iter()
+            .map(|s| strkey(&s))
+            .collect::<Vec<_>>()
+    );
+
+    // The contract removes executed proposals; the index keeps them
+    assert_eq!(h.client.get_council_proposal(&fee), None);
+    assert!(state.council_proposals[&fee].executed);
+    let onchain = h.client.get_council_proposal(&pending).unwrap();
+    let proposal = &state.council_proposals[&pending];
+    assert_eq!(proposal.action, "DisableCouncil");
+    assert_eq!(proposal.proposer, proposal.approvals[0]);
+    assert_eq!(
+        proposal.approvals,
+        onchain
+            .approvals
+            .iter()
+            .map(|s| strkey(&s))
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(proposal.expires_at, onchain.expires_at);
+    assert_eq!(proposal.executed, onchain.executed);
+
+    // Disabling the council through its own proposal clears it
+    h.client.approve_council_action(&members[0], &pending);
+    h.sync();
+    h.client.execute_council_action(&members[0], &pending);
+    h.sync();
+    assert_eq!(h.client.get_council(), None);
+    assert_eq!(h.state().platform.as_ref().unwrap().council, None);
+}
+
+#[test]
+fn decoder_rejects_unknown_events_and_future_versions() {
+    let env = Env::default();
+    let name = |n: &str| ScVal::try_from_val(&env, &soroban_sdk::Symbol::new(&env, n).to_val());
+
+    let err = Event::decode(&[name("not_an_event").unwrap()], &ScVal::Void).unwrap_err();
+    assert_eq!(err, DecodeError::UnknownEvent("not_an_event".into()));
+
+    let admin = Address::generate(&env);
+    let mut event = events::ContractPaused {
+        admin,
+        timestamp: 5,
+        version: SUPPORTED_SCHEMA_VERSION + 1,
+    };
+    let (topics, data) = to_sc(&env, &event);
+    let err = Event::decode(&topics, &data).unwrap_err();
+    assert_eq!(
+        err,
+        DecodeError::UnsupportedVersion {
+            event: "contract_paused",
+            version: SUPPORTED_SCHEMA_VERSION + 1,
+        }
+    );
+
+    event.version = SUPPORTED_SCHEMA_VERSION;
+    let (topics, data) = to_sc(&env, &event);
+    let mut indexer = Indexer::new();
+    indexer.ingest(&topics, &data).unwrap();
+    assert!(indexer.state().platform.as_ref().unwrap().paused);
+
+    // Events that reference pools the indexer has never seen are rejected
+    let refund = events::Refund {
+        pool_id: 9,
+        contributor: Address::generate(&env),
+        asset: Address::generate(&env),
+        amount: 1,
+        timestamp: 0,
+        version: SUPPORTED_SCHEMA_VERSION,
+    };
+    let (topics, data) = to_sc(&env, &refund);
+    assert!(matches!(
+        indexer.ingest(&topics, &data),
+        Err(IndexerError::Apply(_))
+    ));
+}
+
+fn to_sc(env: &Env, event: &impl soroban_sdk::Event) -> (Vec<ScVal>, ScVal) {
+    let topics = event
+        .topics(env)
+        .iter()
+        .map(|topic| ScVal::try_from_val(env, &topic).unwrap())
+        .collect();
+    let data = ScVal::try_from_val(env, &event.data(env)).unwrap();
+    (topics, data)
+}
+
+#[test]
+fn decoder_schemas_match_contract_spec() {
+    assert_eq!(SUPPORTED_SCHEMA_VERSION, events::EVENT_SCHEMA_VERSION);
+
+    let specs: &[&[u8]] = &[
+        &events::ContractInitialized::spec_xdr(),
+        &events::CampaignCreated::spec_xdr(),
+        &events::CampaignGoalReached::spec_xdr(),
+        &events::CampaignCancelled::spec_xdr(),
+        &events::CampaignAmended::spec_xdr(),
+        &events::CampaignDetailsUpdated::spec_xdr(),
+        &events::CampaignOwnershipProposed::spec_xdr(),
+        &events::CampaignTransferCancelled::spec_xdr(),
+        &events::CampaignOwnershipTransferred::spec_xdr(),
+        &events::DonationMade::spec_xdr(),
+        &events::PoolCreated::spec_xdr(),
+        &events::PoolMetadataSet::spec_xdr(),
+        &events::PoolMultiSigSet::spec_xdr(),
+        &events::PoolOwnershipProposed::spec_xdr(),
+        &events::PoolTransferCancelled::spec_xdr(),
+        &events::PoolOwnershipTransferred::spec_xdr(),
+        &events::PoolStateUpdated::spec_xdr(),
+        &events::ContractPaused::spec_xdr(),
+        &events::ContractUnpaused::spec_xdr(),
+        &events::Contribution::spec_xdr(),
+        &events::EmergencyWithdrawRequested::spec_xdr(),
+        &events::EmergencyWithdrawExecuted::spec_xdr(),
+        &events::EmergencyWithdrawCancelled::spec_xdr(),
+        &events::EmergencyWithdrawDelaySet::spec_xdr(),
+        &events::CrowdfundingTokenSet::spec_xdr(),
+        &events::CreationFeeSet::spec_xdr(),
+        &events::CreationFeePaid::spec_xdr(),
+        &events::Refund::spec_xdr(),
+        &events::PoolClosed::spec_xdr(),
+        &events::AdminProposed::spec_xdr(),
+        &events::AdminProposalCancelled::spec_xdr(),
+        &events::AdminTransferred::spec_xdr(),
+        &events::RoleGranted::spec_xdr(),
+        &events::RoleRevoked::spec_xdr(),
+        &events::UpgradeTimelockSet::spec_xdr(),
+        &events::UpgradeProposed::spec_xdr(),
+        &events::UpgradeCancelled::spec_xdr(),
+        &events::UpgradeExecuted::spec_xdr(),
+        &events::StorageMigrated::spec_xdr(),
+        &events::MaxPauseDurationSet::spec_xdr(),
+        &events::FeaturesPaused::spec_xdr(),
+        &events::FeaturesUnpaused::spec_xdr(),
+        &events::CampaignRefunded::spec_xdr(),
+        &events::CreationFeeQueued::spec_xdr(),
+        &events::CrowdfundingTokenQueued::spec_xdr(),
+        &events::CouncilSet::spec_xdr(),
+        &events::CouncilDisabled::spec_xdr(),
+        &events::CouncilActionProposed::spec_xdr(),
+        &events::CouncilActionApproved::spec_xdr(),
+        &events::CouncilActionExecuted::spec_xdr(),
+        &events::FeesWithdrawn::spec_xdr(),
+        &events::EntityCreationFeeSet::spec_xdr(),
+        &events::EntityCreationFeeQueued::spec_xdr(),
+        &events::PlatformFeeSet::spec_xdr(),
+        &events::PlatformFeeQueued::spec_xdr(),
+        &events::PoolPlatformFeeSet::spec_xdr(),
+        &events::FeeTierAssigned::spec_xdr(),
+        &events::FeeTierRevoked::spec_xdr(),
+        &events::AcceptedTokenSet::spec_xdr(),
+        &events::AcceptedTokenRemoved::spec_xdr(),
+        &events::CampaignAmendmentRulesSet::spec_xdr(),
+        &events::PlatformFeePaid::spec_xdr(),
+    ];
+    assert_eq!(specs.len(), EVENT_SCHEMAS.len());
+
+    for xdr in specs {
+        let ScSpecEntry::EventV0(spec) = ScSpecEntry::from_xdr(xdr, Limits::none()).unwrap() else {
+            panic!("not an event spec");
+        };
+        let name = spec.prefix_topics[0].to_utf8_string_lossy();
+        let schema = schema(&name).unwrap_or_else(|| panic!("indexer does not decode `{name}`"));
+
+        let fields = |location| {
+            spec.params
+                .iter()
+                .filter(|p| p.location == location)
+                .map(|p| p.name.to_utf8_string_lossy())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            fields(ScSpecEventParamLocationV0::TopicList),
+            schema.topics,
+            "{name} topics"
+        );
+        assert_eq!(
+            fields(ScSpecEventParamLocationV0::Data),
+            schema.data,
+            "{name} data"
+        );
+    }
+}